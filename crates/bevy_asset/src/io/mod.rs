@@ -12,7 +12,10 @@ pub mod embedded;
 pub mod file;
 pub mod gated;
 pub mod memory;
+pub mod pack;
 pub mod processor_gated;
+#[cfg(all(feature = "remote_assets", not(target_arch = "wasm32")))]
+pub mod remote;
 #[cfg(target_arch = "wasm32")]
 pub mod wasm;
 
@@ -762,6 +765,62 @@ impl Reader for SliceReader<'_> {
     }
 }
 
+/// A [`Reader`] counting the number of bytes read from the wrapped reader.
+pub(crate) struct CountingReader<'a> {
+    reader: &'a mut dyn Reader,
+    bytes_read: usize,
+}
+
+impl<'a> CountingReader<'a> {
+    pub(crate) fn new(reader: &'a mut dyn Reader) -> Self {
+        Self {
+            reader,
+            bytes_read: 0,
+        }
+    }
+
+    /// Returns the number of bytes read so far.
+    pub(crate) fn bytes_read(&self) -> usize {
+        self.bytes_read
+    }
+}
+
+impl AsyncRead for CountingReader<'_> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        let n = ready!(Pin::new(&mut *self.reader).poll_read(cx, buf))?;
+        self.bytes_read += n;
+        Poll::Ready(Ok(n))
+    }
+}
+
+impl AsyncSeekForward for CountingReader<'_> {
+    fn poll_seek_forward(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        offset: u64,
+    ) -> Poll<std::io::Result<u64>> {
+        Pin::new(&mut *self.reader).poll_seek_forward(cx, offset)
+    }
+}
+
+impl Reader for CountingReader<'_> {
+    fn read_to_end<'a>(
+        &'a mut self,
+        buf: &'a mut Vec<u8>,
+    ) -> StackFuture<'a, std::io::Result<usize>, STACK_FUTURE_SIZE> {
+        // The wrapped future is itself a `StackFuture`, so it has to be boxed to fit.
+        StackFuture::from(Box::pin(async {
+            let n = self.reader.read_to_end(buf).await?;
+            self.bytes_read += n;
+            Ok(n)
+        }))
+    }
+}
+
 /// Appends `.meta` to the given path.
 pub(crate) fn get_meta_path(path: &Path) -> PathBuf {
     let mut meta_path = path.to_path_buf();
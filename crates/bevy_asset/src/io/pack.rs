@@ -0,0 +1,649 @@
+//! A pack file format bundling many assets into a single indexed archive, along with an
+//! [`AssetReader`] reading from it and a [`PackWriter`] creating it.
+//!
+//! Shipping thousands of loose asset files is slow to install and to open on some filesystems.
+//! Instead, the processed assets written by the [`AssetProcessor`](crate::processor::AssetProcessor)
+//! (usually in `imported_assets/Default`) can be packed into a single file with [`PackWriter`],
+//! and read back with a [`PackAssetReader`] registered as an asset source:
+//!
+//! ```no_run
+//! # use bevy_app::App;
+//! # use bevy_asset::{AssetApp, io::AssetSourceBuilder};
+//! # let mut app = App::new();
+//! app.register_asset_source("packed", AssetSourceBuilder::pack_file("assets.bpak"));
+//! ```
+//!
+//! Asset paths like `packed://models/tree.glb` then resolve to the entries of the pack, and their
+//! `.meta` files are read from the pack as well.
+//!
+//! # Format
+//!
+//! All integers are little-endian. A pack file starts with [`PACK_MAGIC`], followed by
+//! [`PACK_VERSION`] as a `u32` and the number of entries as a `u32`. Each entry of the index is
+//! made of:
+//! - the length of its path as a `u32`, followed by the path as UTF-8, with `/` separators,
+//! - its kind as a `u8`: `0` for an asset, `1` for the meta of the asset at the same path,
+//! - its [`PackCompression`] as a `u8`,
+//! - the offset of its data as a `u64`, relative to the end of the index,
+//! - the length of its (possibly compressed) data as a `u64`.
+//!
+//! The data of the entries follows the index.
+
+use crate::io::{AssetReader, AssetReaderError, ErasedAssetReader, PathStream, Reader, VecReader};
+use alloc::{
+    borrow::ToOwned, boxed::Box, collections::BTreeMap, format, string::String, sync::Arc, vec,
+    vec::Vec,
+};
+use bevy_platform::collections::HashMap;
+use futures_lite::StreamExt;
+use std::{
+    io::{self, Write},
+    path::{Component, Path, PathBuf},
+};
+
+/// Unique identifier for the pack file format, written at the start of every pack.
+pub const PACK_MAGIC: [u8; 8] = *b"BEVYPAK\0";
+
+/// The version of the pack file format written by [`PackWriter`].
+///
+/// Packs written with a different version are rejected by [`PackAssetReader`].
+pub const PACK_VERSION: u32 = 1;
+
+/// The compression of an entry of a pack file.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PackCompression {
+    /// The entry is stored as is.
+    #[default]
+    None,
+    /// The entry is compressed with [Zstandard](https://facebook.github.io/zstd/).
+    ///
+    /// Writing and reading such entries requires the `zstd` feature.
+    Zstd,
+}
+
+impl PackCompression {
+    fn from_u8(value: u8) -> io::Result<Self> {
+        match value {
+            0 => Ok(Self::None),
+            1 => Ok(Self::Zstd),
+            _ => Err(invalid_data("unknown pack entry compression")),
+        }
+    }
+
+    fn to_u8(self) -> u8 {
+        match self {
+            Self::None => 0,
+            Self::Zstd => 1,
+        }
+    }
+
+    fn compress(self, bytes: &[u8]) -> io::Result<Vec<u8>> {
+        match self {
+            Self::None => Ok(bytes.to_vec()),
+            #[cfg(feature = "zstd")]
+            Self::Zstd => Ok(ruzstd::encoding::compress_to_vec(
+                bytes,
+                ruzstd::encoding::CompressionLevel::Fastest,
+            )),
+            #[cfg(not(feature = "zstd"))]
+            Self::Zstd => Err(zstd_unsupported()),
+        }
+    }
+
+    fn decompress(self, bytes: Vec<u8>) -> io::Result<Vec<u8>> {
+        match self {
+            Self::None => Ok(bytes),
+            #[cfg(feature = "zstd")]
+            Self::Zstd => {
+                use alloc::string::ToString;
+                use std::io::Read;
+
+                let mut decoder = ruzstd::decoding::StreamingDecoder::new(bytes.as_slice())
+                    .map_err(|error| invalid_data(error.to_string()))?;
+                let mut decompressed = Vec::new();
+                decoder.read_to_end(&mut decompressed)?;
+                Ok(decompressed)
+            }
+            #[cfg(not(feature = "zstd"))]
+            Self::Zstd => Err(zstd_unsupported()),
+        }
+    }
+}
+
+#[cfg(not(feature = "zstd"))]
+fn zstd_unsupported() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Unsupported,
+        "zstd compressed pack entries require the `zstd` feature of `bevy_asset`",
+    )
+}
+
+fn invalid_data(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
+
+/// Whether an entry of a pack is an asset or the meta of an asset.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum EntryKind {
+    Asset,
+    Meta,
+}
+
+/// The location of an entry in a pack.
+#[derive(Clone, Copy, Debug)]
+struct PackEntry {
+    compression: PackCompression,
+    offset: u64,
+    len: u64,
+}
+
+/// Converts a path to the `/` separated form used by the entries of a pack.
+fn pack_path(path: &Path) -> String {
+    let mut pack_path = String::new();
+    for component in path.components() {
+        if let Component::Normal(component) = component {
+            if !pack_path.is_empty() {
+                pack_path.push('/');
+            }
+            pack_path.push_str(&component.to_string_lossy());
+        }
+    }
+    pack_path
+}
+
+/// Where the data of the entries of a pack is read from.
+enum PackData {
+    Bytes(Arc<[u8]>),
+    #[cfg(not(target_arch = "wasm32"))]
+    File(PathBuf),
+}
+
+struct Pack {
+    assets: HashMap<Box<str>, PackEntry>,
+    metas: HashMap<Box<str>, PackEntry>,
+    /// The entries of each directory, including the root directory `""`.
+    dirs: HashMap<Box<str>, Vec<PathBuf>>,
+    /// Where the data of the entries starts.
+    data_offset: u64,
+    data: PackData,
+}
+
+impl Pack {
+    /// Parses the header and index of a pack.
+    fn parse(reader: &mut impl io::Read, data: PackData) -> io::Result<Self> {
+        fn read_array<const N: usize>(reader: &mut impl io::Read) -> io::Result<[u8; N]> {
+            let mut bytes = [0; N];
+            reader.read_exact(&mut bytes)?;
+            Ok(bytes)
+        }
+
+        if read_array::<8>(reader)? != PACK_MAGIC {
+            return Err(invalid_data("the file is not an asset pack"));
+        }
+        let version = u32::from_le_bytes(read_array(reader)?);
+        if version != PACK_VERSION {
+            return Err(invalid_data(format!(
+                "expected asset pack version {PACK_VERSION} but found version {version}"
+            )));
+        }
+        let entry_count = u32::from_le_bytes(read_array(reader)?);
+
+        let mut pack = Pack {
+            assets: HashMap::default(),
+            metas: HashMap::default(),
+            dirs: HashMap::default(),
+            data_offset: 16,
+            data,
+        };
+        pack.dirs.insert("".into(), Vec::new());
+        for _ in 0..entry_count {
+            let path_len = u32::from_le_bytes(read_array(reader)?) as usize;
+            let mut path = vec![0; path_len];
+            reader.read_exact(&mut path)?;
+            let path = String::from_utf8(path)
+                .map_err(|_| invalid_data("asset pack entry path is not valid UTF-8"))?;
+            let [kind, compression] = read_array(reader)?;
+            let entry = PackEntry {
+                compression: PackCompression::from_u8(compression)?,
+                offset: u64::from_le_bytes(read_array(reader)?),
+                len: u64::from_le_bytes(read_array(reader)?),
+            };
+            pack.data_offset += 4 + path_len as u64 + 2 + 8 + 8;
+
+            match kind {
+                0 => {
+                    pack.insert_into_dirs(&path);
+                    pack.assets.insert(path.into(), entry);
+                }
+                1 => {
+                    pack.metas.insert(path.into(), entry);
+                }
+                _ => return Err(invalid_data("unknown asset pack entry kind")),
+            }
+        }
+
+        Ok(pack)
+    }
+
+    /// Registers the asset at `path` in its directory, and its directory in its parents.
+    fn insert_into_dirs(&mut self, path: &str) {
+        let mut path = path;
+        loop {
+            let parent = path.rsplit_once('/').map_or("", |(parent, _)| parent);
+            let (entries, parent_is_new) = match self.dirs.get_mut(parent) {
+                Some(entries) => (entries, false),
+                None => (self.dirs.entry(parent.into()).or_default(), true),
+            };
+            entries.push(PathBuf::from(path));
+            if !parent_is_new {
+                return;
+            }
+            path = parent;
+        }
+    }
+
+    async fn read(&self, entry: PackEntry) -> io::Result<Vec<u8>> {
+        let start = self.data_offset + entry.offset;
+        let bytes = match &self.data {
+            PackData::Bytes(bytes) => usize::try_from(start)
+                .ok()
+                .zip(usize::try_from(start + entry.len).ok())
+                .and_then(|(start, end)| bytes.get(start..end))
+                .ok_or_else(|| invalid_data("asset pack entry is out of bounds"))?
+                .to_vec(),
+            #[cfg(not(target_arch = "wasm32"))]
+            PackData::File(path) => {
+                use futures_lite::{AsyncReadExt, AsyncSeekExt};
+
+                let mut file = async_fs::File::open(path).await?;
+                file.seek(io::SeekFrom::Start(start)).await?;
+                let mut bytes = vec![0; entry.len as usize];
+                file.read_exact(&mut bytes).await?;
+                bytes
+            }
+        };
+        entry.compression.decompress(bytes)
+    }
+}
+
+/// An [`AssetReader`] reading assets and their meta files from a single pack file written by
+/// [`PackWriter`].
+///
+/// Only the index of the pack is kept in memory: the data of each asset is read from the pack
+/// when it is loaded. See the [module docs](self) for more details.
+pub struct PackAssetReader {
+    pack: Result<Pack, Arc<io::Error>>,
+}
+
+impl PackAssetReader {
+    /// Creates a new [`PackAssetReader`] reading the pack file at `path`.
+    ///
+    /// Only the index of the pack is read, the data of the assets is read when they are loaded.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn open(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let mut file = io::BufReader::new(std::fs::File::open(&path)?);
+        Ok(Self {
+            pack: Ok(Pack::parse(&mut file, PackData::File(path))?),
+        })
+    }
+
+    /// Creates a new [`PackAssetReader`] reading the pack from memory, for example from bytes
+    /// included in the executable with [`include_bytes`].
+    pub fn from_bytes(bytes: impl Into<Arc<[u8]>>) -> io::Result<Self> {
+        let bytes = bytes.into();
+        let pack = Pack::parse(&mut &bytes[..], PackData::Bytes(bytes.clone()))?;
+        Ok(Self { pack: Ok(pack) })
+    }
+
+    /// Creates a [`PackAssetReader`] failing every read with `error`.
+    ///
+    /// This is used when a pack can't be opened by a reader built by an asset source, which can't
+    /// fail.
+    pub(crate) fn failed(error: io::Error) -> Self {
+        Self {
+            pack: Err(Arc::new(error)),
+        }
+    }
+
+    fn pack(&self) -> Result<&Pack, AssetReaderError> {
+        self.pack
+            .as_ref()
+            .map_err(|error| AssetReaderError::Io(error.clone()))
+    }
+
+    async fn read_entry(
+        &self,
+        path: &Path,
+        kind: EntryKind,
+    ) -> Result<VecReader, AssetReaderError> {
+        let pack = self.pack()?;
+        let entries = match kind {
+            EntryKind::Asset => &pack.assets,
+            EntryKind::Meta => &pack.metas,
+        };
+        let entry = entries
+            .get(pack_path(path).as_str())
+            .ok_or_else(|| AssetReaderError::NotFound(path.to_owned()))?;
+        Ok(VecReader::new(pack.read(*entry).await?))
+    }
+}
+
+impl AssetReader for PackAssetReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        self.read_entry(path, EntryKind::Asset).await
+    }
+
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        self.read_entry(path, EntryKind::Meta).await
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        let entries = self
+            .pack()?
+            .dirs
+            .get(pack_path(path).as_str())
+            .ok_or_else(|| AssetReaderError::NotFound(path.to_owned()))?
+            .clone();
+        let stream: Box<PathStream> = Box::new(futures_lite::stream::iter(entries));
+        Ok(stream)
+    }
+
+    async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        Ok(self.pack()?.dirs.contains_key(pack_path(path).as_str()))
+    }
+}
+
+/// Writes pack files read by [`PackAssetReader`].
+///
+/// Entries are added from memory, from a directory on disk or from any [`AssetReader`], and the
+/// pack is then written with [`PackWriter::write`]. To pack the processed assets of the default
+/// asset source:
+///
+/// ```no_run
+/// # use bevy_asset::io::pack::{PackCompression, PackWriter};
+/// let mut writer = PackWriter::default().with_compression(PackCompression::Zstd);
+/// writer.add_directory("imported_assets/Default")?;
+/// writer.write(std::fs::File::create("assets.bpak")?)?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Default)]
+pub struct PackWriter {
+    entries: BTreeMap<(String, EntryKind), Vec<u8>>,
+    compression: PackCompression,
+}
+
+impl PackWriter {
+    /// Sets the compression used for the entries of the pack.
+    ///
+    /// Entries that do not get smaller when compressed are stored uncompressed.
+    pub fn with_compression(mut self, compression: PackCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Adds an asset to the pack, replacing any asset previously added at the same `path`.
+    pub fn add_asset(&mut self, path: impl AsRef<Path>, bytes: impl Into<Vec<u8>>) {
+        self.entries
+            .insert((pack_path(path.as_ref()), EntryKind::Asset), bytes.into());
+    }
+
+    /// Adds the meta of the asset at `path` to the pack, replacing any meta previously added for
+    /// the same asset. The `path` must not include the `.meta` extension.
+    pub fn add_meta(&mut self, path: impl AsRef<Path>, bytes: impl Into<Vec<u8>>) {
+        self.entries
+            .insert((pack_path(path.as_ref()), EntryKind::Meta), bytes.into());
+    }
+
+    /// Returns the number of assets and meta files added to the pack.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no asset or meta file was added to the pack.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Adds every file in the directory at `root` and its subdirectories to the pack, using their
+    /// path relative to `root`, like the [`FileAssetReader`](crate::io::file::FileAssetReader)
+    /// does. Files with the `.meta` extension are added as the meta of their asset, and hidden
+    /// files are skipped.
+    ///
+    /// This is typically used on the output directory of the
+    /// [`AssetProcessor`](crate::processor::AssetProcessor) for a source, like
+    /// `imported_assets/Default`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn add_directory(&mut self, root: impl AsRef<Path>) -> io::Result<()> {
+        let root = root.as_ref();
+        let mut directories = vec![root.to_owned()];
+        while let Some(directory) = directories.pop() {
+            for entry in std::fs::read_dir(&directory)? {
+                let path = entry?.path();
+                if path
+                    .file_name()
+                    .is_some_and(|file_name| file_name.to_string_lossy().starts_with('.'))
+                {
+                    continue;
+                }
+                if path.is_dir() {
+                    directories.push(path);
+                    continue;
+                }
+
+                let bytes = std::fs::read(&path)?;
+                let relative_path = path.strip_prefix(root).unwrap();
+                if path
+                    .extension()
+                    .is_some_and(|extension| extension.eq_ignore_ascii_case("meta"))
+                {
+                    self.add_meta(relative_path.with_extension(""), bytes);
+                } else {
+                    self.add_asset(relative_path, bytes);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds every asset in the directory at `path` of `reader` and its subdirectories to the pack,
+    /// along with their meta files if they exist.
+    ///
+    /// This can be used to pack the assets of any asset source, for example through
+    /// [`AssetSource::processed_reader`](crate::io::AssetSource::processed_reader).
+    pub async fn add_reader_directory(
+        &mut self,
+        reader: &dyn ErasedAssetReader,
+        path: &Path,
+    ) -> Result<(), AssetReaderError> {
+        let mut directories = vec![path.to_owned()];
+        while let Some(directory) = directories.pop() {
+            let mut paths = reader.read_directory(&directory).await?;
+            while let Some(path) = paths.next().await {
+                if reader.is_directory(&path).await? {
+                    directories.push(path);
+                    continue;
+                }
+
+                let mut bytes = Vec::new();
+                reader.read(&path).await?.read_to_end(&mut bytes).await?;
+                self.add_asset(&path, bytes);
+                match reader.read_meta_bytes(&path).await {
+                    Ok(meta) => self.add_meta(&path, meta),
+                    Err(AssetReaderError::NotFound(_)) => {}
+                    Err(error) => return Err(error),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the pack to `writer`.
+    pub fn write(&self, mut writer: impl Write) -> io::Result<()> {
+        let mut data = Vec::new();
+        let mut index = Vec::new();
+        for ((path, kind), bytes) in &self.entries {
+            let mut compression = self.compression;
+            let mut stored = compression.compress(bytes)?;
+            if stored.len() >= bytes.len() {
+                compression = PackCompression::None;
+                stored.clone_from(bytes);
+            }
+
+            let path_len = u32::try_from(path.len())
+                .map_err(|_| invalid_data("asset pack entry path is too long"))?;
+            index.extend_from_slice(&path_len.to_le_bytes());
+            index.extend_from_slice(path.as_bytes());
+            index.push(match kind {
+                EntryKind::Asset => 0,
+                EntryKind::Meta => 1,
+            });
+            index.push(compression.to_u8());
+            index.extend_from_slice(&(data.len() as u64).to_le_bytes());
+            index.extend_from_slice(&(stored.len() as u64).to_le_bytes());
+            data.extend_from_slice(&stored);
+        }
+
+        let entry_count = u32::try_from(self.entries.len())
+            .map_err(|_| invalid_data("too many asset pack entries"))?;
+        writer.write_all(&PACK_MAGIC)?;
+        writer.write_all(&PACK_VERSION.to_le_bytes())?;
+        writer.write_all(&entry_count.to_le_bytes())?;
+        writer.write_all(&index)?;
+        writer.write_all(&data)?;
+        writer.flush()
+    }
+
+    /// Writes the pack into a new [`Vec<u8>`], which can be read with
+    /// [`PackAssetReader::from_bytes`].
+    pub fn to_bytes(&self) -> io::Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        self.write(&mut bytes)?;
+        Ok(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PackAssetReader, PackWriter};
+    use crate::io::{
+        memory::{Dir, MemoryAssetReader},
+        AssetReader, AssetReaderError, Reader,
+    };
+    use alloc::{format, vec, vec::Vec};
+    use bevy_tasks::block_on;
+    use futures_lite::StreamExt;
+    use std::path::{Path, PathBuf};
+
+    fn read(reader: &PackAssetReader, path: &str) -> Result<Vec<u8>, AssetReaderError> {
+        block_on(async {
+            let mut bytes = Vec::new();
+            AssetReader::read(reader, Path::new(path))
+                .await?
+                .read_to_end(&mut bytes)
+                .await?;
+            Ok(bytes)
+        })
+    }
+
+    #[test]
+    fn roundtrip() {
+        let mut writer = PackWriter::default();
+        writer.add_asset("a.txt", b"a".to_vec());
+        writer.add_meta("a.txt", b"a meta".to_vec());
+        writer.add_asset("x/y/b.txt", b"b".to_vec());
+        writer.add_asset("x/c.txt", b"c".to_vec());
+        let reader = PackAssetReader::from_bytes(writer.to_bytes().unwrap()).unwrap();
+
+        assert_eq!(b"a".to_vec(), read(&reader, "a.txt").unwrap());
+        assert_eq!(b"b".to_vec(), read(&reader, "x/y/b.txt").unwrap());
+        assert_eq!(
+            b"a meta".to_vec(),
+            block_on(reader.read_meta_bytes(Path::new("a.txt"))).unwrap()
+        );
+        assert_eq!(
+            Err(AssetReaderError::NotFound(PathBuf::from("x/b.txt"))),
+            read(&reader, "x/b.txt")
+        );
+        assert!(block_on(reader.read_meta_bytes(Path::new("x/c.txt"))).is_err());
+
+        assert!(block_on(reader.is_directory(Path::new("x/y"))).unwrap());
+        assert!(!block_on(reader.is_directory(Path::new("x/c.txt"))).unwrap());
+        let mut entries = block_on(async {
+            AssetReader::read_directory(&reader, Path::new("x"))
+                .await
+                .unwrap()
+                .collect::<Vec<_>>()
+                .await
+        });
+        entries.sort();
+        assert_eq!(
+            vec![PathBuf::from("x/c.txt"), PathBuf::from("x/y")],
+            entries
+        );
+    }
+
+    #[test]
+    fn pack_reader_directory() {
+        let dir = Dir::default();
+        dir.insert_asset_text(Path::new("a.txt"), "a");
+        dir.insert_meta_text(Path::new("a.txt"), "a meta");
+        dir.insert_asset_text(Path::new("x/b.txt"), "b");
+        let source = MemoryAssetReader { root: dir };
+
+        let mut writer = PackWriter::default();
+        block_on(writer.add_reader_directory(&source, Path::new(""))).unwrap();
+        assert_eq!(3, writer.len());
+
+        let reader = PackAssetReader::from_bytes(writer.to_bytes().unwrap()).unwrap();
+        assert_eq!(b"b".to_vec(), read(&reader, "x/b.txt").unwrap());
+        assert_eq!(
+            b"a meta".to_vec(),
+            block_on(reader.read_meta_bytes(Path::new("a.txt"))).unwrap()
+        );
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn zstd_roundtrip() {
+        use super::PackCompression;
+
+        let text = "compressible ".repeat(100);
+        let mut writer = PackWriter::default().with_compression(PackCompression::Zstd);
+        writer.add_asset("a.txt", text.clone());
+        writer.add_asset("b.txt", "b");
+        let bytes = writer.to_bytes().unwrap();
+        assert!(bytes.len() < text.len());
+
+        let reader = PackAssetReader::from_bytes(bytes).unwrap();
+        assert_eq!(text.into_bytes(), read(&reader, "a.txt").unwrap());
+        assert_eq!(b"b".to_vec(), read(&reader, "b.txt").unwrap());
+    }
+
+    #[test]
+    fn open_file() {
+        let mut writer = PackWriter::default();
+        writer.add_asset("a.txt", "a");
+        writer.add_asset("x/b.txt", "b");
+        let path =
+            std::env::temp_dir().join(format!("bevy_asset_pack_{}.bpak", std::process::id()));
+        writer.write(std::fs::File::create(&path).unwrap()).unwrap();
+
+        let reader = PackAssetReader::open(&path).unwrap();
+        assert_eq!(b"a".to_vec(), read(&reader, "a.txt").unwrap());
+        assert_eq!(b"b".to_vec(), read(&reader, "x/b.txt").unwrap());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn rejects_other_files() {
+        assert!(PackAssetReader::from_bytes(b"not a pack".to_vec()).is_err());
+
+        let mut bytes = PackWriter::default().to_bytes().unwrap();
+        bytes[8] += 1;
+        assert!(PackAssetReader::from_bytes(bytes).is_err());
+    }
+}
@@ -60,7 +60,7 @@ impl AssetReader for ProcessorGatedReader {
             .await;
         match process_result {
             ProcessStatus::Processed => {}
-            ProcessStatus::Failed | ProcessStatus::NonExistent => {
+            ProcessStatus::Failed | ProcessStatus::Invalid | ProcessStatus::NonExistent => {
                 return Err(AssetReaderError::NotFound(path.to_owned()));
             }
         }
@@ -80,7 +80,7 @@ impl AssetReader for ProcessorGatedReader {
             .await;
         match process_result {
             ProcessStatus::Processed => {}
-            ProcessStatus::Failed | ProcessStatus::NonExistent => {
+            ProcessStatus::Failed | ProcessStatus::Invalid | ProcessStatus::NonExistent => {
                 return Err(AssetReaderError::NotFound(path.to_owned()));
             }
         }
@@ -0,0 +1,875 @@
+//! Serving assets over TCP from one process to another, with change notifications for
+//! hot-reloading.
+//!
+//! When a build runs on another machine than the one the assets are authored on (such as a
+//! console devkit, a phone or a VM), its local [`FileWatcher`](crate::io::file::FileWatcher) never
+//! sees the edits. Instead, the authoring machine can run a [`RemoteAssetServer`] serving its
+//! (usually processed) assets, and the build can read them with a [`RemoteAssetReader`] and listen
+//! for changes with a [`RemoteAssetWatcher`]:
+//!
+//! ```no_run
+//! # use bevy_asset::io::{file::FileAssetReader, remote::RemoteAssetServer, AssetSource};
+//! # use core::time::Duration;
+//! // On the authoring machine, next to the app running the `AssetProcessor`:
+//! let server = RemoteAssetServer::new(FileAssetReader::new("imported_assets/Default"))
+//!     .with_watcher(AssetSource::get_default_watcher(
+//!         "imported_assets/Default".into(),
+//!         Duration::from_millis(300),
+//!     ))
+//!     .serve("0.0.0.0:4300")?;
+//! server.wait();
+//! # Ok::<(), std::io::Error>(())
+//! ```
+//!
+//! ```no_run
+//! # use bevy_app::App;
+//! # use bevy_asset::{AssetApp, io::AssetSourceBuilder};
+//! # let mut app = App::new();
+//! // In the running build, before adding the `AssetPlugin`:
+//! app.register_asset_source(
+//!     "remote",
+//!     AssetSourceBuilder::remote("192.168.1.20:4300".parse().unwrap()),
+//! );
+//! ```
+//!
+//! The server doesn't authenticate its clients and serves everything its [`AssetReader`] can
+//! read, so it should only be exposed on trusted networks. It responds to a fixed number of
+//! requests at a time, and drops the connections that stall for longer than its
+//! [timeout](RemoteAssetServer::with_timeout).
+//!
+//! # Protocol
+//!
+//! All integers are little-endian, and strings are prefixed with their length as a `u32`. Paths
+//! are UTF-8 strings with `/` separators. Every request is sent on its own connection, and starts
+//! with [`REMOTE_PROTOCOL_VERSION`] as a `u32`, followed by the kind of request as a `u8`
+//! (`0`: read, `1`: read meta, `2`: read directory, `3`: is directory, `4`: watch) and a path.
+//!
+//! The server responds with a status `u8`: `0` for success, `1` if the path was not found and `2`
+//! for any other error, followed by the error message. On success, the status is followed by:
+//! - for reads, the length of the bytes as a `u64` and the bytes,
+//! - for directory reads, the number of paths as a `u32` and the paths,
+//! - for directory checks, `1` if the path is a directory and `0` otherwise.
+//!
+//! The server closes the connection after responding, except for watch requests, where it keeps
+//! sending [`AssetSourceEvent`]s until the client disconnects. Each event is made of its kind as a
+//! `u8`, in the order of the variants of [`AssetSourceEvent`], followed by its path, by the new
+//! path for renames, and by `1` or `0` for the `is_meta` field of
+//! [`AssetSourceEvent::RemovedUnknown`].
+
+use crate::io::{
+    AssetReader, AssetReaderError, AssetSourceEvent, AssetWatcher, ErasedAssetReader, PathStream,
+    Reader, VecReader,
+};
+use alloc::{
+    borrow::ToOwned,
+    boxed::Box,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use async_io::{Async, Timer};
+use core::{
+    future::Future,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+use crossbeam_channel::{Receiver, Sender};
+use futures_lite::{future::block_on, AsyncReadExt, AsyncWriteExt, StreamExt};
+use parking_lot::Mutex;
+use std::{
+    io::{self, Read, Write},
+    net::{Shutdown, TcpListener, TcpStream, ToSocketAddrs},
+    path::{Component, Path, PathBuf},
+    thread::{self, JoinHandle},
+};
+use tracing::{error, info, warn};
+
+/// The version of the protocol spoken by [`RemoteAssetServer`], [`RemoteAssetReader`] and
+/// [`RemoteAssetWatcher`].
+///
+/// Requests with a different version are rejected by the server.
+pub const REMOTE_PROTOCOL_VERSION: u32 = 1;
+
+/// How long a [`RemoteAssetWatcher`] waits before reconnecting to a server it lost.
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// How long connecting, reading or writing a remote asset connection can take before it fails.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The number of requests a [`RemoteAssetServer`] responds to at the same time. The next
+/// connections wait in the backlog of its listener.
+const CONNECTION_THREADS: usize = 8;
+
+const STATUS_OK: u8 = 0;
+const STATUS_NOT_FOUND: u8 = 1;
+const STATUS_ERROR: u8 = 2;
+
+/// The kind of a request sent to a [`RemoteAssetServer`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum RequestKind {
+    Read,
+    ReadMeta,
+    ReadDirectory,
+    IsDirectory,
+    Watch,
+}
+
+impl RequestKind {
+    fn from_u8(value: u8) -> io::Result<Self> {
+        match value {
+            0 => Ok(Self::Read),
+            1 => Ok(Self::ReadMeta),
+            2 => Ok(Self::ReadDirectory),
+            3 => Ok(Self::IsDirectory),
+            4 => Ok(Self::Watch),
+            _ => Err(invalid_data("unknown remote asset request")),
+        }
+    }
+
+    fn to_u8(self) -> u8 {
+        match self {
+            Self::Read => 0,
+            Self::ReadMeta => 1,
+            Self::ReadDirectory => 2,
+            Self::IsDirectory => 3,
+            Self::Watch => 4,
+        }
+    }
+}
+
+/// Serves the assets of an [`AssetReader`] to [`RemoteAssetReader`]s, and forwards the events of
+/// an optional [`AssetWatcher`] to [`RemoteAssetWatcher`]s.
+///
+/// See the [module documentation](self) for an example.
+pub struct RemoteAssetServer {
+    reader: Arc<dyn ErasedAssetReader>,
+    watcher: Option<
+        Box<dyn FnMut(Sender<AssetSourceEvent>) -> Option<Box<dyn AssetWatcher>> + Send + Sync>,
+    >,
+    timeout: Duration,
+}
+
+impl RemoteAssetServer {
+    /// Creates a new [`RemoteAssetServer`] serving the assets read by `reader`.
+    pub fn new(reader: impl AssetReader) -> Self {
+        Self {
+            reader: Arc::new(reader),
+            watcher: None,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Will use the given `watcher` function to construct the [`AssetWatcher`] whose events are
+    /// forwarded to the connected [`RemoteAssetWatcher`]s, such as
+    /// [`AssetSource::get_default_watcher`](crate::io::AssetSource::get_default_watcher).
+    pub fn with_watcher(
+        mut self,
+        watcher: impl FnMut(Sender<AssetSourceEvent>) -> Option<Box<dyn AssetWatcher>>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        self.watcher = Some(Box::new(watcher));
+        self
+    }
+
+    /// Sets how long the server waits for a client to send its request, or to receive a response
+    /// or an event, before dropping the connection. Defaults to 30 seconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `timeout` is zero.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        assert!(
+            !timeout.is_zero(),
+            "the timeout of a remote asset server must not be zero"
+        );
+        self.timeout = timeout;
+        self
+    }
+
+    /// Starts serving the assets on `addr`, on background threads.
+    ///
+    /// The server stops when the returned [`RemoteAssetServerHandle`] is dropped.
+    pub fn serve(mut self, addr: impl ToSocketAddrs) -> io::Result<RemoteAssetServerHandle> {
+        let listener = TcpListener::bind(addr)?;
+        let local_addr = listener.local_addr()?;
+        let subscribers = Arc::new(Mutex::new(Vec::<TcpStream>::new()));
+
+        let watcher = match self.watcher.as_mut() {
+            Some(watcher) => {
+                let (sender, receiver) = crossbeam_channel::unbounded();
+                let watcher = watcher(sender);
+                if watcher.is_some() {
+                    let subscribers = subscribers.clone();
+                    thread::Builder::new()
+                        .name("Remote Asset Server Events".to_string())
+                        .spawn(move || forward_events(&receiver, &subscribers))?;
+                } else {
+                    warn!(
+                        "The remote asset server on {local_addr} failed to create its AssetWatcher. Changes will not be forwarded."
+                    );
+                }
+                watcher
+            }
+            None => None,
+        };
+
+        // Connections are handled by a fixed number of threads. Watch connections are handed
+        // over to the event thread once subscribed, so they don't keep a thread busy.
+        let (connections, connection_receiver) = crossbeam_channel::bounded(CONNECTION_THREADS);
+        let watching = watcher.is_some();
+        for _ in 0..CONNECTION_THREADS {
+            let connection_receiver = connection_receiver.clone();
+            let reader = self.reader.clone();
+            let subscribers = subscribers.clone();
+            thread::Builder::new()
+                .name("Remote Asset Server Connection".to_string())
+                .spawn(move || {
+                    for stream in connection_receiver {
+                        if let Err(err) =
+                            handle_connection(&*reader, &subscribers, watching, stream)
+                        {
+                            warn!("Failed to respond to a remote asset request: {err}");
+                        }
+                    }
+                })?;
+        }
+
+        let stopped = Arc::new(AtomicBool::new(false));
+        let thread = {
+            let stopped = stopped.clone();
+            let timeout = self.timeout;
+            thread::Builder::new()
+                .name("Remote Asset Server".to_string())
+                .spawn(move || {
+                    for stream in listener.incoming() {
+                        if stopped.load(Ordering::Acquire) {
+                            break;
+                        }
+                        let stream = match stream.and_then(|stream| {
+                            stream.set_read_timeout(Some(timeout))?;
+                            stream.set_write_timeout(Some(timeout))?;
+                            Ok(stream)
+                        }) {
+                            Ok(stream) => stream,
+                            Err(err) => {
+                                warn!("Failed to accept a remote asset connection: {err}");
+                                continue;
+                            }
+                        };
+                        // Blocks while all the connection threads are busy.
+                        if connections.send(stream).is_err() {
+                            error!("The remote asset connection threads stopped");
+                            break;
+                        }
+                    }
+                })?
+        };
+        info!("Serving remote assets on {local_addr}");
+
+        Ok(RemoteAssetServerHandle {
+            local_addr,
+            stopped,
+            subscribers,
+            thread: Some(thread),
+            _watcher: watcher,
+        })
+    }
+}
+
+/// A running [`RemoteAssetServer`], which stops when dropped.
+pub struct RemoteAssetServerHandle {
+    local_addr: SocketAddr,
+    stopped: Arc<AtomicBool>,
+    subscribers: Arc<Mutex<Vec<TcpStream>>>,
+    thread: Option<JoinHandle<()>>,
+    _watcher: Option<Box<dyn AssetWatcher>>,
+}
+
+impl RemoteAssetServerHandle {
+    /// Returns the address the server listens on. This is useful when serving on port `0`, which
+    /// lets the operating system pick a free port.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Blocks the current thread until the server stops accepting connections, which only happens
+    /// if it fails. This is useful to run the server from its own process.
+    pub fn wait(mut self) {
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for RemoteAssetServerHandle {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::Release);
+        for subscriber in self.subscribers.lock().drain(..) {
+            let _ = subscriber.shutdown(Shutdown::Both);
+        }
+        if let Some(thread) = self.thread.take() {
+            // Wake up the accept loop so it notices it was stopped.
+            let mut addr = self.local_addr;
+            if addr.ip().is_unspecified() {
+                addr.set_ip(match addr.ip() {
+                    IpAddr::V4(_) => IpAddr::V4(Ipv4Addr::LOCALHOST),
+                    IpAddr::V6(_) => IpAddr::V6(Ipv6Addr::LOCALHOST),
+                });
+            }
+            if TcpStream::connect(addr).is_ok() {
+                let _ = thread.join();
+            }
+        }
+    }
+}
+
+/// Sends the events received from the [`AssetWatcher`] of a [`RemoteAssetServer`] to its
+/// subscribers, dropping the subscribers that disconnected.
+fn forward_events(receiver: &Receiver<AssetSourceEvent>, subscribers: &Mutex<Vec<TcpStream>>) {
+    for event in receiver {
+        let mut bytes = Vec::new();
+        write_event(&mut bytes, &event);
+        subscribers
+            .lock()
+            .retain_mut(|subscriber| subscriber.write_all(&bytes).is_ok());
+    }
+}
+
+fn handle_connection(
+    reader: &dyn ErasedAssetReader,
+    subscribers: &Mutex<Vec<TcpStream>>,
+    watching: bool,
+    mut stream: TcpStream,
+) -> io::Result<()> {
+    let version = read_u32(&mut stream)?;
+    if version != REMOTE_PROTOCOL_VERSION {
+        return write_error(
+            &mut stream,
+            &alloc::format!(
+                "unsupported remote asset protocol version {version}, expected {REMOTE_PROTOCOL_VERSION}"
+            ),
+        );
+    }
+    let kind = RequestKind::from_u8(read_u8(&mut stream)?)?;
+    let path = read_path(&mut stream)?;
+    // Only serve paths inside of the root of the reader.
+    if !path
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)))
+    {
+        return write_error(&mut stream, "invalid path");
+    }
+
+    let mut response = alloc::vec![STATUS_OK];
+    let result = block_on(async {
+        match kind {
+            RequestKind::Read => {
+                let mut bytes = Vec::new();
+                Reader::read_to_end(&mut reader.read(&path).await?, &mut bytes).await?;
+                write_bytes(&mut response, &bytes);
+            }
+            RequestKind::ReadMeta => {
+                write_bytes(&mut response, &reader.read_meta_bytes(&path).await?);
+            }
+            RequestKind::ReadDirectory => {
+                let paths: Vec<_> = reader.read_directory(&path).await?.collect().await;
+                write_u32(&mut response, paths.len() as u32);
+                for path in &paths {
+                    write_path(&mut response, path);
+                }
+            }
+            RequestKind::IsDirectory => {
+                response.push(reader.is_directory(&path).await? as u8);
+            }
+            RequestKind::Watch => {}
+        }
+        Ok::<_, AssetReaderError>(())
+    });
+
+    match result {
+        Ok(()) if kind == RequestKind::Watch => {
+            if !watching {
+                return write_error(&mut stream, "the remote asset server does not watch assets");
+            }
+            stream.write_all(&response)?;
+            subscribers.lock().push(stream);
+            Ok(())
+        }
+        Ok(()) => stream.write_all(&response),
+        Err(AssetReaderError::NotFound(_)) => stream.write_all(&[STATUS_NOT_FOUND]),
+        Err(err) => write_error(&mut stream, &err.to_string()),
+    }
+}
+
+fn write_error(stream: &mut TcpStream, message: &str) -> io::Result<()> {
+    let mut response = alloc::vec![STATUS_ERROR];
+    write_string(&mut response, message);
+    stream.write_all(&response)
+}
+
+/// An [`AssetReader`] reading assets from a [`RemoteAssetServer`].
+///
+/// Requests fail if connecting to the server, or sending or receiving any part of a request,
+/// takes longer than 30 seconds.
+pub struct RemoteAssetReader {
+    addr: SocketAddr,
+}
+
+impl RemoteAssetReader {
+    /// Creates a new [`RemoteAssetReader`] reading assets from the [`RemoteAssetServer`] at `addr`.
+    pub fn new(addr: SocketAddr) -> Self {
+        Self { addr }
+    }
+
+    /// Sends a request to the server, returning the bytes following the success status.
+    async fn request(&self, kind: RequestKind, path: &Path) -> Result<Vec<u8>, AssetReaderError> {
+        let mut stream = with_timeout(Async::<TcpStream>::connect(self.addr)).await?;
+        let mut request = Vec::new();
+        write_request(&mut request, kind, path);
+        with_timeout(stream.write_all(&request)).await?;
+        let mut response = Vec::new();
+        let mut chunk = [0; 16 * 1024];
+        loop {
+            let read = with_timeout(stream.read(&mut chunk)).await?;
+            if read == 0 {
+                break;
+            }
+            response.extend_from_slice(&chunk[..read]);
+        }
+
+        match response.first().copied() {
+            Some(STATUS_OK) => Ok(response.split_off(1)),
+            Some(STATUS_NOT_FOUND) => Err(AssetReaderError::NotFound(path.to_owned())),
+            Some(STATUS_ERROR) => Err(io::Error::other(read_string(&mut &response[1..])?).into()),
+            _ => Err(invalid_data("invalid remote asset response").into()),
+        }
+    }
+
+    async fn read_bytes(
+        &self,
+        kind: RequestKind,
+        path: &Path,
+    ) -> Result<Vec<u8>, AssetReaderError> {
+        let mut bytes = self.request(kind, path).await?;
+        let len = read_u64(&mut &bytes[..])?;
+        if bytes.len() as u64 - 8 != len {
+            return Err(invalid_data("truncated remote asset response").into());
+        }
+        bytes.drain(..8);
+        Ok(bytes)
+    }
+}
+
+/// Fails with [`io::ErrorKind::TimedOut`] if `future` takes longer than [`DEFAULT_TIMEOUT`].
+async fn with_timeout<T>(future: impl Future<Output = io::Result<T>>) -> io::Result<T> {
+    futures_lite::future::or(future, async {
+        Timer::after(DEFAULT_TIMEOUT).await;
+        Err(io::ErrorKind::TimedOut.into())
+    })
+    .await
+}
+
+impl AssetReader for RemoteAssetReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        Ok(VecReader::new(
+            self.read_bytes(RequestKind::Read, path).await?,
+        ))
+    }
+
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        Ok(VecReader::new(
+            self.read_bytes(RequestKind::ReadMeta, path).await?,
+        ))
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        let bytes = self.request(RequestKind::ReadDirectory, path).await?;
+        let mut body = &bytes[..];
+        let len = read_u32(&mut body)?;
+        let paths = (0..len)
+            .map(|_| read_path(&mut body))
+            .collect::<io::Result<Vec<_>>>()?;
+        let stream: Box<PathStream> = Box::new(futures_lite::stream::iter(paths));
+        Ok(stream)
+    }
+
+    async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        let bytes = self.request(RequestKind::IsDirectory, path).await?;
+        Ok(read_u8(&mut &bytes[..])? != 0)
+    }
+}
+
+/// An [`AssetWatcher`] emitting the [`AssetSourceEvent`]s forwarded by a [`RemoteAssetServer`].
+///
+/// The watcher listens on a background thread, and reconnects to the server if the connection is
+/// lost, or if the server stalls for more than 30 seconds while subscribing or sending an event.
+/// Changes made while disconnected are not emitted.
+pub struct RemoteAssetWatcher {
+    stopped: Arc<AtomicBool>,
+    stream: Arc<Mutex<Option<TcpStream>>>,
+}
+
+impl RemoteAssetWatcher {
+    /// Creates a new [`RemoteAssetWatcher`] that sends the changes forwarded by the
+    /// [`RemoteAssetServer`] at `addr` to `sender`.
+    pub fn new(addr: SocketAddr, sender: Sender<AssetSourceEvent>) -> io::Result<Self> {
+        let stopped = Arc::new(AtomicBool::new(false));
+        let stream = Arc::new(Mutex::new(None));
+        {
+            let stopped = stopped.clone();
+            let stream = stream.clone();
+            thread::Builder::new()
+                .name("Remote Asset Watcher".to_string())
+                .spawn(move || watch(addr, &sender, &stopped, &stream))?;
+        }
+        Ok(Self { stopped, stream })
+    }
+}
+
+impl AssetWatcher for RemoteAssetWatcher {}
+
+impl Drop for RemoteAssetWatcher {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::Release);
+        if let Some(stream) = self.stream.lock().take() {
+            let _ = stream.shutdown(Shutdown::Both);
+        }
+    }
+}
+
+fn watch(
+    addr: SocketAddr,
+    sender: &Sender<AssetSourceEvent>,
+    stopped: &AtomicBool,
+    current_stream: &Mutex<Option<TcpStream>>,
+) {
+    let mut connected = true;
+    while !stopped.load(Ordering::Acquire) {
+        let result = subscribe(addr).and_then(|mut stream| {
+            *current_stream.lock() = Some(stream.try_clone()?);
+            if stopped.load(Ordering::Acquire) {
+                return Ok(());
+            }
+            if !connected {
+                info!("Reconnected to the remote asset server at {addr}");
+                connected = true;
+            }
+            loop {
+                let kind = match read_u8(&mut stream) {
+                    Ok(kind) => kind,
+                    // No asset changed in the meantime.
+                    Err(err) if is_timeout(&err) => continue,
+                    Err(err) => return Err(err),
+                };
+                let event = read_event(kind, &mut stream)?;
+                if sender.send(event).is_err() {
+                    // The asset source was dropped.
+                    stopped.store(true, Ordering::Release);
+                    return Ok(());
+                }
+            }
+        });
+        if stopped.load(Ordering::Acquire) {
+            break;
+        }
+        if let Err(err) = result
+            && connected
+        {
+            warn!("Lost the connection to the remote asset server at {addr}, retrying: {err}");
+            connected = false;
+        }
+        thread::sleep(RECONNECT_DELAY);
+    }
+}
+
+fn subscribe(addr: SocketAddr) -> io::Result<TcpStream> {
+    let mut stream = TcpStream::connect_timeout(&addr, DEFAULT_TIMEOUT)?;
+    stream.set_read_timeout(Some(DEFAULT_TIMEOUT))?;
+    stream.set_write_timeout(Some(DEFAULT_TIMEOUT))?;
+    let mut request = Vec::new();
+    write_request(&mut request, RequestKind::Watch, Path::new(""));
+    stream.write_all(&request)?;
+    match read_u8(&mut stream)? {
+        STATUS_OK => Ok(stream),
+        STATUS_ERROR => Err(io::Error::other(read_string(&mut stream)?)),
+        _ => Err(invalid_data("invalid remote asset response")),
+    }
+}
+
+fn write_request(bytes: &mut Vec<u8>, kind: RequestKind, path: &Path) {
+    write_u32(bytes, REMOTE_PROTOCOL_VERSION);
+    bytes.push(kind.to_u8());
+    write_path(bytes, path);
+}
+
+fn write_event(bytes: &mut Vec<u8>, event: &AssetSourceEvent) {
+    let (kind, path, new_path) = match event {
+        AssetSourceEvent::AddedAsset(path) => (0, path, None),
+        AssetSourceEvent::ModifiedAsset(path) => (1, path, None),
+        AssetSourceEvent::RemovedAsset(path) => (2, path, None),
+        AssetSourceEvent::RenamedAsset { old, new } => (3, old, Some(new)),
+        AssetSourceEvent::AddedMeta(path) => (4, path, None),
+        AssetSourceEvent::ModifiedMeta(path) => (5, path, None),
+        AssetSourceEvent::RemovedMeta(path) => (6, path, None),
+        AssetSourceEvent::RenamedMeta { old, new } => (7, old, Some(new)),
+        AssetSourceEvent::AddedFolder(path) => (8, path, None),
+        AssetSourceEvent::RemovedFolder(path) => (9, path, None),
+        AssetSourceEvent::RenamedFolder { old, new } => (10, old, Some(new)),
+        AssetSourceEvent::RemovedUnknown { path, .. } => (11, path, None),
+    };
+    bytes.push(kind);
+    write_path(bytes, path);
+    if let Some(new_path) = new_path {
+        write_path(bytes, new_path);
+    }
+    if let AssetSourceEvent::RemovedUnknown { is_meta, .. } = event {
+        bytes.push(*is_meta as u8);
+    }
+}
+
+/// Reads the event of the given `kind`, which was already read from `reader`.
+fn read_event(kind: u8, reader: &mut impl Read) -> io::Result<AssetSourceEvent> {
+    let path = read_path(reader)?;
+    Ok(match kind {
+        0 => AssetSourceEvent::AddedAsset(path),
+        1 => AssetSourceEvent::ModifiedAsset(path),
+        2 => AssetSourceEvent::RemovedAsset(path),
+        3 => AssetSourceEvent::RenamedAsset {
+            old: path,
+            new: read_path(reader)?,
+        },
+        4 => AssetSourceEvent::AddedMeta(path),
+        5 => AssetSourceEvent::ModifiedMeta(path),
+        6 => AssetSourceEvent::RemovedMeta(path),
+        7 => AssetSourceEvent::RenamedMeta {
+            old: path,
+            new: read_path(reader)?,
+        },
+        8 => AssetSourceEvent::AddedFolder(path),
+        9 => AssetSourceEvent::RemovedFolder(path),
+        10 => AssetSourceEvent::RenamedFolder {
+            old: path,
+            new: read_path(reader)?,
+        },
+        11 => AssetSourceEvent::RemovedUnknown {
+            path,
+            is_meta: read_u8(reader)? != 0,
+        },
+        _ => return Err(invalid_data("unknown remote asset event")),
+    })
+}
+
+fn write_u32(bytes: &mut Vec<u8>, value: u32) {
+    bytes.extend_from_slice(&value.to_le_bytes());
+}
+
+fn write_bytes(bytes: &mut Vec<u8>, value: &[u8]) {
+    bytes.extend_from_slice(&(value.len() as u64).to_le_bytes());
+    bytes.extend_from_slice(value);
+}
+
+fn write_string(bytes: &mut Vec<u8>, value: &str) {
+    write_u32(bytes, value.len() as u32);
+    bytes.extend_from_slice(value.as_bytes());
+}
+
+/// Writes `path` with `/` separators, so that paths are portable across platforms.
+fn write_path(bytes: &mut Vec<u8>, path: &Path) {
+    let mut remote_path = String::new();
+    for component in path.components() {
+        if !remote_path.is_empty() {
+            remote_path.push('/');
+        }
+        remote_path.push_str(&component.as_os_str().to_string_lossy());
+    }
+    write_string(bytes, &remote_path);
+}
+
+fn read_u8(reader: &mut impl Read) -> io::Result<u8> {
+    let mut bytes = [0; 1];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes[0])
+}
+
+fn read_u32(reader: &mut impl Read) -> io::Result<u32> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64(reader: &mut impl Read) -> io::Result<u64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+fn read_string(reader: &mut impl Read) -> io::Result<String> {
+    let len = read_u32(reader)? as usize;
+    let mut bytes = Vec::new();
+    reader.take(len as u64).read_to_end(&mut bytes)?;
+    if bytes.len() != len {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+    String::from_utf8(bytes).map_err(|_| invalid_data("remote asset path is not valid UTF-8"))
+}
+
+fn read_path(reader: &mut impl Read) -> io::Result<PathBuf> {
+    Ok(PathBuf::from(read_string(reader)?))
+}
+
+/// Returns true if `err` was returned by a blocking socket operation that timed out, which is
+/// reported differently across platforms.
+fn is_timeout(err: &io::Error) -> bool {
+    matches!(
+        err.kind(),
+        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+    )
+}
+
+fn invalid_data(message: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RemoteAssetReader, RemoteAssetServer, RemoteAssetWatcher, CONNECTION_THREADS};
+    use crate::io::{
+        memory::{Dir, MemoryAssetReader},
+        AssetReader, AssetReaderError, AssetSourceEvent, AssetWatcher,
+    };
+    use alloc::{boxed::Box, sync::Arc, vec, vec::Vec};
+    use core::time::Duration;
+    use crossbeam_channel::Sender;
+    use futures_lite::{future::block_on, AsyncReadExt, StreamExt};
+    use parking_lot::Mutex;
+    use std::{
+        io::Read,
+        net::TcpStream,
+        path::{Path, PathBuf},
+    };
+
+    struct TestWatcher;
+
+    impl AssetWatcher for TestWatcher {}
+
+    #[test]
+    fn remote_reader_and_watcher() {
+        let dir = Dir::default();
+        dir.insert_asset_text(Path::new("a.txt"), "a");
+        dir.insert_meta_text(Path::new("a.txt"), "meta");
+        dir.insert_asset_text(Path::new("folder/b.txt"), "b");
+
+        let event_sender = Arc::new(Mutex::new(None::<Sender<AssetSourceEvent>>));
+        let server = RemoteAssetServer::new(MemoryAssetReader { root: dir })
+            .with_watcher({
+                let event_sender = event_sender.clone();
+                move |sender| {
+                    *event_sender.lock() = Some(sender);
+                    Some(Box::new(TestWatcher))
+                }
+            })
+            .serve("127.0.0.1:0")
+            .unwrap();
+        let reader = RemoteAssetReader::new(server.local_addr());
+
+        block_on(async {
+            let mut bytes = Vec::new();
+            let mut asset = reader.read(Path::new("a.txt")).await.unwrap();
+            asset.read_to_end(&mut bytes).await.unwrap();
+            assert_eq!(b"a", &bytes[..]);
+            assert_eq!(
+                b"meta",
+                &reader.read_meta_bytes(Path::new("a.txt")).await.unwrap()[..]
+            );
+
+            let paths: Vec<PathBuf> = reader
+                .read_directory(Path::new("folder"))
+                .await
+                .unwrap()
+                .collect()
+                .await;
+            assert_eq!(vec![PathBuf::from("folder/b.txt")], paths);
+            assert!(reader.is_directory(Path::new("folder")).await.unwrap());
+            assert!(!reader.is_directory(Path::new("a.txt")).await.unwrap());
+
+            assert!(matches!(
+                reader.read(Path::new("missing.txt")).await,
+                Err(AssetReaderError::NotFound(_))
+            ));
+            // Paths escaping the root of the served reader are rejected.
+            assert!(matches!(
+                reader.read(Path::new("../a.txt")).await,
+                Err(AssetReaderError::Io(_))
+            ));
+        });
+
+        let (sender, receiver) = crossbeam_channel::unbounded();
+        let _watcher = RemoteAssetWatcher::new(server.local_addr(), sender).unwrap();
+        let event_sender = event_sender.lock().clone().unwrap();
+        let renamed = AssetSourceEvent::RenamedAsset {
+            old: PathBuf::from("folder/b.txt"),
+            new: PathBuf::from("folder/c.txt"),
+        };
+        // Events are only forwarded once the watcher is connected, so send until one arrives.
+        let mut received = None;
+        for _ in 0..100 {
+            event_sender.send(renamed.clone()).unwrap();
+            if let Ok(event) = receiver.recv_timeout(Duration::from_millis(50)) {
+                received = Some(event);
+                break;
+            }
+        }
+        assert_eq!(Some(renamed), received);
+
+        let removed = AssetSourceEvent::RemovedUnknown {
+            path: PathBuf::from("a.txt"),
+            is_meta: true,
+        };
+        event_sender.send(removed.clone()).unwrap();
+        while let Ok(event) = receiver.recv_timeout(Duration::from_secs(5)) {
+            if event == removed {
+                return;
+            }
+        }
+        panic!("the watcher did not receive the removal");
+    }
+
+    #[test]
+    fn stalled_connections_time_out() {
+        let dir = Dir::default();
+        dir.insert_asset_text(Path::new("a.txt"), "a");
+        let server = RemoteAssetServer::new(MemoryAssetReader { root: dir })
+            .with_timeout(Duration::from_millis(100))
+            .serve("127.0.0.1:0")
+            .unwrap();
+
+        // Keep every connection thread busy with clients that never send their request.
+        let mut stalled: Vec<_> = (0..CONNECTION_THREADS)
+            .map(|_| TcpStream::connect(server.local_addr()).unwrap())
+            .collect();
+
+        let reader = RemoteAssetReader::new(server.local_addr());
+        let bytes = block_on(reader.read_meta_bytes(Path::new("a.txt")));
+        assert!(matches!(bytes, Err(AssetReaderError::NotFound(_))));
+        let mut bytes = Vec::new();
+        block_on(async {
+            let mut asset = reader.read(Path::new("a.txt")).await.unwrap();
+            asset.read_to_end(&mut bytes).await.unwrap();
+        });
+        assert_eq!(b"a", &bytes[..]);
+
+        // The server closed the stalled connections.
+        for stream in &mut stalled {
+            stream
+                .set_read_timeout(Some(Duration::from_secs(5)))
+                .unwrap();
+            assert_eq!(0, stream.read(&mut [0; 1]).unwrap());
+        }
+    }
+}
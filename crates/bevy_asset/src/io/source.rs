@@ -1,6 +1,8 @@
 use crate::{
     io::{processor_gated::ProcessorGatedReader, AssetSourceEvent, AssetWatcher},
     processor::AssetProcessorData,
+    server::load_queue::{LoadPermit, LoadQueue},
+    LoadPriority,
 };
 use alloc::{
     boxed::Box,
@@ -161,6 +163,8 @@ pub struct AssetSourceBuilder {
     pub watch_warning: Option<&'static str>,
     /// The warning message to display when watching a processed asset fails.
     pub processed_watch_warning: Option<&'static str>,
+    /// The maximum number of assets loaded at the same time from this source, if any.
+    pub max_concurrent_loads: Option<usize>,
 }
 
 impl AssetSourceBuilder {
@@ -185,6 +189,9 @@ impl AssetSourceBuilder {
             watcher: None,
             processed_event_receiver: None,
             processed_watcher: None,
+            load_queue: self
+                .max_concurrent_loads
+                .map(|max_concurrent_loads| Arc::new(LoadQueue::new(max_concurrent_loads))),
         };
 
         if watch {
@@ -291,6 +298,16 @@ impl AssetSourceBuilder {
         self
     }
 
+    /// Limits the number of assets loaded at the same time from this source to `max_concurrent_loads`.
+    ///
+    /// Once the limit is reached, further loads wait for a running load to finish, and start in the
+    /// order of their [`LoadPriority`]. This is useful for sources with a limited bandwidth, such as
+    /// remote sources, so that the assets needed soon are not stuck behind background loads.
+    pub fn with_max_concurrent_loads(mut self, max_concurrent_loads: usize) -> Self {
+        self.max_concurrent_loads = Some(max_concurrent_loads);
+        self
+    }
+
     /// Returns a builder containing the "platform default source" for the given `path` and `processed_path`.
     /// For most platforms, this will use [`FileAssetReader`](crate::io::file::FileAssetReader) / [`FileAssetWriter`](crate::io::file::FileAssetWriter),
     /// but some platforms (such as Android) have their own default readers / writers / watchers.
@@ -316,6 +333,57 @@ impl AssetSourceBuilder {
             default
         }
     }
+
+    /// Returns a builder reading both unprocessed and processed assets from the pack file at `path`,
+    /// using a [`PackAssetReader`](crate::io::pack::PackAssetReader). `path` is relative to the
+    /// asset root, like the paths of the [`FileAssetReader`](crate::io::file::FileAssetReader).
+    ///
+    /// If the pack can't be opened, an error is logged and loading any asset from the source fails.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn pack_file(path: &str) -> Self {
+        let path = super::file::get_base_path().join(path);
+        let reader = move || -> Box<dyn ErasedAssetReader> {
+            match super::pack::PackAssetReader::open(&path) {
+                Ok(reader) => Box::new(reader),
+                Err(err) => {
+                    error!("Failed to open asset pack {}: {err}", path.display());
+                    Box::new(super::pack::PackAssetReader::failed(err))
+                }
+            }
+        };
+        Self::default()
+            .with_reader(reader.clone())
+            .with_processed_reader(reader)
+    }
+
+    /// Returns a builder reading both unprocessed and processed assets from the
+    /// [`RemoteAssetServer`](crate::io::remote::RemoteAssetServer) at `addr`, using a
+    /// [`RemoteAssetReader`](crate::io::remote::RemoteAssetReader), and watching them for changes
+    /// with a [`RemoteAssetWatcher`](crate::io::remote::RemoteAssetWatcher).
+    ///
+    /// Since the server serves a single directory, it should serve the processed assets when the
+    /// app runs in [`AssetMode::Processed`](crate::AssetMode::Processed).
+    #[cfg(all(feature = "remote_assets", not(target_arch = "wasm32")))]
+    pub fn remote(addr: core::net::SocketAddr) -> Self {
+        use super::remote::{RemoteAssetReader, RemoteAssetWatcher};
+
+        let reader =
+            move || -> Box<dyn ErasedAssetReader> { Box::new(RemoteAssetReader::new(addr)) };
+        let watcher = move |sender| -> Option<Box<dyn AssetWatcher>> {
+            match RemoteAssetWatcher::new(addr, sender) {
+                Ok(watcher) => Some(Box::new(watcher)),
+                Err(err) => {
+                    error!("Failed to watch the remote asset server at {addr}: {err}");
+                    None
+                }
+            }
+        };
+        Self::default()
+            .with_reader(reader)
+            .with_watcher(watcher)
+            .with_processed_reader(reader)
+            .with_processed_watcher(watcher)
+    }
 }
 
 /// A [`Resource`] that hold (repeatable) functions capable of producing new [`AssetReader`](crate::io::AssetReader) and [`AssetWriter`](crate::io::AssetWriter) instances
@@ -393,6 +461,7 @@ pub struct AssetSource {
     processed_watcher: Option<Box<dyn AssetWatcher>>,
     event_receiver: Option<crossbeam_channel::Receiver<AssetSourceEvent>>,
     processed_event_receiver: Option<crossbeam_channel::Receiver<AssetSourceEvent>>,
+    load_queue: Option<Arc<LoadQueue>>,
 }
 
 impl AssetSource {
@@ -461,6 +530,24 @@ impl AssetSource {
         self.processed_writer.is_some()
     }
 
+    /// Returns the maximum number of assets loaded at the same time from this source, if it is
+    /// limited. See [`AssetSourceBuilder::with_max_concurrent_loads`].
+    pub fn max_concurrent_loads(&self) -> Option<usize> {
+        self.load_queue
+            .as_ref()
+            .map(|load_queue| load_queue.max_concurrent_loads())
+    }
+
+    /// Waits until a load with the given `priority` can start from this source.
+    ///
+    /// The load is considered running until the returned [`LoadPermit`] is dropped.
+    pub(crate) async fn acquire_load_permit(&self, priority: LoadPriority) -> Option<LoadPermit> {
+        match &self.load_queue {
+            Some(load_queue) => Some(load_queue.acquire(priority).await),
+            None => None,
+        }
+    }
+
     /// Returns a builder function for this platform's default [`AssetReader`](crate::io::AssetReader). `path` is the relative path to
     /// the asset root.
     pub fn get_default_reader(
@@ -196,7 +196,7 @@ pub use handle::*;
 pub use id::*;
 pub use loader::*;
 pub use loader_builders::{
-    Deferred, DynamicTyped, Immediate, NestedLoader, StaticTyped, UnknownTyped,
+    Deferred, DynamicTyped, Immediate, LoadBuilder, NestedLoader, StaticTyped, UnknownTyped,
 };
 pub use path::*;
 pub use reflect::*;
@@ -209,7 +209,7 @@ pub use uuid;
 
 use crate::{
     io::{embedded::EmbeddedAssetRegistry, AssetSourceBuilder, AssetSourceBuilders, AssetSourceId},
-    processor::{AssetProcessor, Process},
+    processor::{AssetProcessor, AssetValidator, Process, ProcessorCache},
 };
 use alloc::{
     string::{String, ToString},
@@ -380,6 +380,11 @@ impl Plugin for AssetPlugin {
                     {
                         let mut builders = app.world_mut().resource_mut::<AssetSourceBuilders>();
                         let processor = AssetProcessor::new(&mut builders);
+                        processor.set_validation_report_path(
+                            processor::ValidationReport::path_for(std::path::Path::new(
+                                &self.processed_file_path,
+                            )),
+                        );
                         let mut sources = builders.build_sources(false, watch);
                         sources.gate_on_processor(processor.data.clone());
                         // the main asset server shares loaders with the processor asset server
@@ -519,6 +524,11 @@ pub trait AssetApp {
     ) -> &mut Self;
     /// Sets the default asset processor for the given `extension`.
     fn set_default_asset_processor<P: Process>(&mut self, extension: &str) -> &mut Self;
+    /// Registers the given `validator` in the [`App`]'s [`AssetProcessor`].
+    fn register_asset_validator<V: AssetValidator>(&mut self, validator: V) -> &mut Self;
+    /// Sets the [`ProcessorCache`] of the [`App`]'s [`AssetProcessor`], used to reuse processed assets across
+    /// processor runs and machines.
+    fn set_asset_processor_cache(&mut self, cache: ProcessorCache) -> &mut Self;
     /// Initializes the given loader in the [`App`]'s [`AssetServer`].
     fn init_asset_loader<L: AssetLoader + FromWorld>(&mut self) -> &mut Self;
     /// Initializes the given [`Asset`] in the [`App`] by:
@@ -583,6 +593,20 @@ impl AssetApp for App {
         self
     }
 
+    fn register_asset_validator<V: AssetValidator>(&mut self, validator: V) -> &mut Self {
+        if let Some(asset_processor) = self.world().get_resource::<AssetProcessor>() {
+            asset_processor.register_validator(validator);
+        }
+        self
+    }
+
+    fn set_asset_processor_cache(&mut self, cache: ProcessorCache) -> &mut Self {
+        if let Some(asset_processor) = self.world().get_resource::<AssetProcessor>() {
+            asset_processor.set_cache(cache);
+        }
+        self
+    }
+
     fn init_asset_loader<L: AssetLoader + FromWorld>(&mut self) -> &mut Self {
         let loader = L::from_world(self.world_mut());
         self.register_asset_loader(loader)
@@ -677,7 +701,8 @@ mod tests {
         },
         loader::{AssetLoader, LoadContext},
         Asset, AssetApp, AssetEvent, AssetId, AssetLoadError, AssetLoadFailedEvent, AssetPath,
-        AssetPlugin, AssetServer, Assets, LoadState, UnapprovedPathMode,
+        AssetPlugin, AssetServer, Assets, LoadPriority, LoadState, ReflectHandle,
+        UnapprovedPathMode,
     };
     use alloc::{
         boxed::Box,
@@ -694,7 +719,7 @@ mod tests {
         schedule::{LogLevel, ScheduleBuildSettings},
     };
     use bevy_platform::collections::HashMap;
-    use bevy_reflect::TypePath;
+    use bevy_reflect::{Reflect, TypePath};
     use core::time::Duration;
     use serde::{Deserialize, Serialize};
     use std::path::Path;
@@ -854,6 +879,35 @@ mod tests {
         }
     }
 
+    /// An [`AssetReader`] that reports the paths it starts reading through `started`.
+    #[derive(Clone)]
+    struct ReportingReader<R: AssetReader> {
+        reader: R,
+        started: crossbeam_channel::Sender<Box<Path>>,
+    }
+
+    impl<R: AssetReader> AssetReader for ReportingReader<R> {
+        async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+            self.started.send(path.into()).unwrap();
+            self.reader.read(path).await
+        }
+        async fn read_meta<'a>(
+            &'a self,
+            path: &'a Path,
+        ) -> Result<impl Reader + 'a, AssetReaderError> {
+            self.reader.read_meta(path).await
+        }
+        async fn read_directory<'a>(
+            &'a self,
+            path: &'a Path,
+        ) -> Result<Box<bevy_asset::io::PathStream>, AssetReaderError> {
+            self.reader.read_directory(path).await
+        }
+        async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+            self.reader.is_directory(path).await
+        }
+    }
+
     fn test_app(dir: Dir) -> (App, GateOpener) {
         let mut app = App::new();
         let (gated_memory_reader, gate_opener) = GatedReader::new(MemoryAssetReader { root: dir });
@@ -1472,6 +1526,174 @@ mod tests {
         );
     }
 
+    #[test]
+    fn load_priorities_and_cancellation() {
+        // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
+        #[cfg(not(feature = "multi_threaded"))]
+        panic!("This test requires the \"multi_threaded\" feature, otherwise it will deadlock.\ncargo test --package bevy_asset --features multi_threaded");
+
+        let dir = Dir::default();
+        for path in ["a.cool.ron", "b.cool.ron", "c.cool.ron", "e.cool.ron"] {
+            dir.insert_asset_text(Path::new(path), SIMPLE_TEXT);
+        }
+        dir.insert_asset_text(
+            Path::new("d.cool.ron"),
+            r#"
+(
+    text: "d",
+    dependencies: [],
+    embedded_dependencies: ["never_opened.cool.ron"],
+    sub_texts: [],
+)"#,
+        );
+
+        let mut app = App::new();
+        let (gated_memory_reader, gate_opener) = GatedReader::new(MemoryAssetReader { root: dir });
+        let (started, started_reads) = crossbeam_channel::unbounded();
+        let reader = ReportingReader {
+            reader: gated_memory_reader,
+            started,
+        };
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || Box::new(reader.clone()))
+                .with_max_concurrent_loads(1),
+        )
+        .add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+        .init_asset::<CoolText>()
+        .init_asset::<SubText>()
+        .register_asset_loader(CoolTextLoader);
+
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        assert_eq!(
+            Some(1),
+            asset_server
+                .get_source(AssetSourceId::Default)
+                .unwrap()
+                .max_concurrent_loads()
+        );
+
+        let a = asset_server.load::<CoolText>("a.cool.ron");
+        let b = asset_server
+            .load_builder()
+            .with_priority(LoadPriority::LOW)
+            .load::<CoolText>("b.cool.ron");
+        let c = asset_server
+            .load_builder()
+            .with_priority(LoadPriority::HIGH)
+            .load::<CoolText>("c.cool.ron");
+        let b_id = b.id();
+        // `b` waits for `a` to finish loading, and is cancelled as its handle is dropped. Since its
+        // gate is never opened, `c` could not finish loading if `b` was started.
+        drop(b);
+        app.update();
+
+        gate_opener.open("a.cool.ron");
+        gate_opener.open("c.cool.ron");
+        run_app_until(&mut app, |world| {
+            let _a = get::<CoolText>(world, a.id())?;
+            let _c = get::<CoolText>(world, c.id())?;
+            Some(())
+        });
+        assert!(get::<CoolText>(app.world(), b_id).is_none());
+
+        // `d` starts loading and waits for its gate, while `e` waits for `d` to finish. Dropping
+        // the handle of `d` aborts its running load before `d` loads its embedded dependency, whose
+        // gate is never opened, which lets `e` start.
+        let d = asset_server.load::<CoolText>("d.cool.ron");
+        let e = asset_server.load::<CoolText>("e.cool.ron");
+        while *started_reads.recv_timeout(Duration::from_secs(10)).unwrap()
+            != *Path::new("d.cool.ron")
+        {}
+        let d_id = d.id();
+        drop(d);
+        app.update();
+        assert!(asset_server.get_load_state(d_id).is_none());
+
+        gate_opener.open("d.cool.ron");
+        gate_opener.open("e.cool.ron");
+        run_app_until(&mut app, |world| {
+            let _e = get::<CoolText>(world, e.id())?;
+            Some(())
+        });
+        assert!(get::<CoolText>(app.world(), d_id).is_none());
+        assert!(started_reads
+            .try_iter()
+            .all(|path| *path != *Path::new("never_opened.cool.ron")));
+    }
+
+    #[test]
+    fn dependency_graph() {
+        // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
+        #[cfg(not(feature = "multi_threaded"))]
+        panic!("This test requires the \"multi_threaded\" feature, otherwise it will deadlock.\ncargo test --package bevy_asset --features multi_threaded");
+
+        #[derive(Component, Reflect)]
+        #[reflect(Component)]
+        struct TextHolder(Handle<CoolText>);
+
+        let dir = Dir::default();
+        let a_path = "a.cool.ron";
+        let a_ron = r#"
+(
+    text: "a",
+    dependencies: [
+        "b.cool.ron",
+    ],
+    embedded_dependencies: [],
+    sub_texts: [],
+)"#;
+        let b_path = "b.cool.ron";
+        dir.insert_asset_text(Path::new(a_path), a_ron);
+        dir.insert_asset_text(Path::new(b_path), SIMPLE_TEXT);
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader)
+            .register_type::<TextHolder>()
+            .register_type_data::<Handle<CoolText>, ReflectHandle>();
+
+        let a: Handle<CoolText> = app.world().resource::<AssetServer>().load(a_path);
+        let holder = app.world_mut().spawn(TextHolder(a.clone())).id();
+        gate_opener.open(a_path);
+        gate_opener.open(b_path);
+        run_app_until(&mut app, |world| {
+            world
+                .resource::<AssetServer>()
+                .is_loaded_with_dependencies(&a)
+                .then_some(())
+        });
+
+        let mut graph = app.world().resource::<AssetServer>().dependency_graph();
+        graph.collect_entity_holders(app.world());
+
+        let a_node = graph.get(&a).unwrap();
+        let b_index = a_node.dependencies[0];
+        let b_node = &graph.nodes[b_index];
+        assert_eq!(Some(&AssetPath::from(a_path)), a_node.path.as_ref());
+        assert_eq!(CoolText::type_path(), a_node.type_path);
+        assert_eq!(Some(a_ron.len()), a_node.loaded_bytes);
+        assert_eq!(2, a_node.strong_handles);
+        assert_eq!(vec![holder], a_node.entities);
+        assert_eq!(Some(&AssetPath::from(b_path)), b_node.path.as_ref());
+        assert_eq!(1, b_node.strong_handles);
+        assert_eq!(vec![graph.index_of(&a).unwrap()], b_node.dependents);
+        assert!(b_node.entities.is_empty());
+        assert_eq!(a_ron.len() + SIMPLE_TEXT.len(), graph.total_loaded_bytes());
+
+        let why_loaded: Vec<_> = graph
+            .why_loaded(b_node.id)
+            .into_iter()
+            .map(|node| node.path.clone().unwrap())
+            .collect();
+        assert_eq!(
+            vec![AssetPath::from(b_path), AssetPath::from(a_path)],
+            why_loaded
+        );
+    }
+
     #[test]
     fn manual_asset_management() {
         // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
@@ -2088,4 +2310,454 @@ mod tests {
             Some(())
         });
     }
+
+    #[cfg(all(feature = "asset_processor", feature = "multi_threaded"))]
+    #[test]
+    fn invalid_processed_assets() {
+        use crate::{
+            processor::{
+                AssetProcessor, AssetValidator, ProcessStatus, ProcessorTransactionLog,
+                ValidationContext, ValidationReport, ValidationSeverity,
+            },
+            AssetMode,
+        };
+        use bevy_tasks::block_on;
+
+        struct EmptyTextValidator;
+
+        impl AssetValidator for EmptyTextValidator {
+            type Asset = CoolText;
+
+            fn validate(&self, asset: &CoolText, context: &mut ValidationContext) {
+                if asset.text.is_empty() {
+                    context.error("text is empty");
+                }
+            }
+        }
+
+        // The processor needs a writable source, while its log is written to `imported_assets` and its report next
+        // to the processed assets
+        let dir = std::env::temp_dir().join(format!(
+            "bevy_asset_invalid_processed_assets_{}",
+            std::process::id()
+        ));
+        let source_dir = dir.join("assets");
+        let processed_dir = dir.join("imported_assets");
+        std::fs::create_dir_all(&source_dir).unwrap();
+        let cool_text = |text: &str| {
+            format!(
+                r#"(text: "{text}", dependencies: [], embedded_dependencies: [], sub_texts: [])"#
+            )
+        };
+        std::fs::write(source_dir.join("valid.cool.ron"), cool_text("valid")).unwrap();
+        std::fs::write(source_dir.join("invalid.cool.ron"), cool_text("")).unwrap();
+
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin {
+                file_path: source_dir.to_string_lossy().into_owned(),
+                processed_file_path: processed_dir.to_string_lossy().into_owned(),
+                watch_for_changes_override: Some(false),
+                mode: AssetMode::Processed,
+                ..Default::default()
+            },
+        ))
+        .init_asset::<CoolText>()
+        .init_asset::<SubText>()
+        .register_asset_loader(CoolTextLoader)
+        .register_asset_validator(EmptyTextValidator);
+        // Starts the processor
+        app.update();
+
+        let processor = app.world().resource::<AssetProcessor>().clone();
+        block_on(processor.data().wait_until_finished());
+        let status =
+            |path: &'static str| block_on(processor.data().wait_until_processed(path.into()));
+        assert_eq!(ProcessStatus::Processed, status("valid.cool.ron"));
+        assert_eq!(ProcessStatus::Invalid, status("invalid.cool.ron"));
+
+        // The invalid asset is removed, and its transaction is finished so the next run is not a full rebuild
+        assert!(processed_dir.join("valid.cool.ron").exists());
+        assert!(!processed_dir.join("invalid.cool.ron").exists());
+        assert!(!processed_dir.join("invalid.cool.ron.meta").exists());
+        block_on(ProcessorTransactionLog::validate()).unwrap();
+
+        let report = processor.validation_report();
+        assert!(report.get("valid.cool.ron").is_empty());
+        let issues = report.get("invalid.cool.ron");
+        assert_eq!(1, issues.len());
+        assert_eq!(ValidationSeverity::Error, issues[0].severity);
+        assert_eq!("text is empty", issues[0].message);
+        let written: ValidationReport =
+            serde_json::from_slice(&std::fs::read(dir.join("validation_report.json")).unwrap())
+                .unwrap();
+        assert_eq!(report, written);
+
+        // The app can't load the invalid asset, as it is gated by the processor
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let valid = asset_server.load::<CoolText>("valid.cool.ron");
+        let invalid = asset_server.load::<CoolText>("invalid.cool.ron");
+        run_app_until(&mut app, |world| {
+            let asset_server = world.resource::<AssetServer>();
+            match asset_server.load_state(&invalid) {
+                LoadState::Failed(err) => {
+                    assert!(matches!(
+                        *err,
+                        AssetLoadError::AssetReaderError(AssetReaderError::NotFound(_))
+                    ));
+                    asset_server.is_loaded(&valid).then_some(())
+                }
+                _ => None,
+            }
+        });
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(all(feature = "asset_processor", feature = "multi_threaded"))]
+    #[test]
+    fn missing_dependencies_without_validators() {
+        use crate::{
+            processor::{AssetProcessor, ProcessStatus, ValidationReport},
+            AssetMode,
+        };
+        use bevy_tasks::block_on;
+
+        let dir = std::env::temp_dir().join(format!(
+            "bevy_asset_missing_dependencies_without_validators_{}",
+            std::process::id()
+        ));
+        let source_dir = dir.join("assets");
+        let processed_dir = dir.join("imported_assets");
+        std::fs::create_dir_all(&source_dir).unwrap();
+        std::fs::write(
+            source_dir.join("dangling.cool.ron"),
+            r#"(text: "a", dependencies: ["missing.cool.ron"], embedded_dependencies: [], sub_texts: [])"#,
+        )
+        .unwrap();
+
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin {
+                file_path: source_dir.to_string_lossy().into_owned(),
+                processed_file_path: processed_dir.to_string_lossy().into_owned(),
+                watch_for_changes_override: Some(false),
+                mode: AssetMode::Processed,
+                ..Default::default()
+            },
+        ))
+        .init_asset::<CoolText>()
+        .init_asset::<SubText>()
+        .register_asset_loader(CoolTextLoader);
+        // Starts the processor
+        app.update();
+
+        let processor = app.world().resource::<AssetProcessor>().clone();
+        block_on(processor.data().wait_until_finished());
+        assert_eq!(
+            ProcessStatus::Invalid,
+            block_on(
+                processor
+                    .data()
+                    .wait_until_processed("dangling.cool.ron".into())
+            )
+        );
+        assert!(!processed_dir.join("dangling.cool.ron").exists());
+
+        let report = processor.validation_report();
+        let issues = report.get("dangling.cool.ron");
+        assert_eq!(1, issues.len());
+        assert_eq!("missing_dependency", issues[0].validator);
+        assert_eq!(
+            dir.join("validation_report.json"),
+            processor.validation_report_path()
+        );
+        let written: ValidationReport =
+            serde_json::from_slice(&std::fs::read(processor.validation_report_path()).unwrap())
+                .unwrap();
+        assert_eq!(report, written);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(all(feature = "asset_processor", feature = "multi_threaded"))]
+    #[test]
+    fn processor_cache_is_shared_across_runs() {
+        use crate::{
+            io::Writer,
+            meta::{get_asset_hash, AssetAction, AssetMeta, AssetMetaDyn, ProcessedInfo},
+            processor::{
+                AssetProcessor, Process, ProcessContext, ProcessError, ProcessStatus,
+                ProcessorCache,
+            },
+            AssetMode,
+        };
+        use bevy_tasks::block_on;
+        use core::sync::atomic::{AtomicUsize, Ordering};
+        use futures_lite::AsyncWriteExt;
+
+        static PROCESSED: AtomicUsize = AtomicUsize::new(0);
+
+        /// Copies the source text, counting how many times it runs.
+        struct CopyText<const VERSION: u32>;
+
+        #[derive(Serialize, Deserialize, Default)]
+        struct CopyTextSettings {
+            comment: String,
+        }
+
+        impl<const VERSION: u32> Process for CopyText<VERSION> {
+            type Settings = CopyTextSettings;
+            type OutputLoader = CoolTextLoader;
+            const VERSION: u32 = VERSION;
+
+            async fn process(
+                &self,
+                context: &mut ProcessContext<'_>,
+                _meta: AssetMeta<(), Self>,
+                writer: &mut Writer,
+            ) -> Result<(), ProcessError> {
+                PROCESSED.fetch_add(1, Ordering::SeqCst);
+                writer
+                    .write_all(context.asset_bytes())
+                    .await
+                    .map_err(|err| ProcessError::AssetWriterError {
+                        path: context.path().clone_owned(),
+                        err: err.into(),
+                    })?;
+                Ok(())
+            }
+        }
+
+        fn process_asset<const VERSION: u32>(
+            source_dir: &Path,
+            processed_dir: &Path,
+            cache_dir: &Path,
+        ) {
+            let mut app = App::new();
+            app.add_plugins((
+                TaskPoolPlugin::default(),
+                AssetPlugin {
+                    file_path: source_dir.to_string_lossy().into_owned(),
+                    processed_file_path: processed_dir.to_string_lossy().into_owned(),
+                    watch_for_changes_override: Some(false),
+                    mode: AssetMode::Processed,
+                    ..Default::default()
+                },
+            ))
+            .init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader)
+            .register_asset_processor(CopyText::<VERSION>)
+            .set_default_asset_processor::<CopyText<VERSION>>("cool.ron")
+            .set_asset_processor_cache(ProcessorCache::directory(cache_dir));
+            // Starts the processor
+            app.update();
+
+            let processor = app.world().resource::<AssetProcessor>().clone();
+            block_on(processor.data().wait_until_finished());
+            assert_eq!(
+                ProcessStatus::Processed,
+                block_on(
+                    processor
+                        .data()
+                        .wait_until_processed("text.cool.ron".into())
+                )
+            );
+        }
+
+        let dir = std::env::temp_dir().join(format!(
+            "bevy_asset_processor_cache_is_shared_across_runs_{}",
+            std::process::id()
+        ));
+        let source_dir = dir.join("assets");
+        let cache_dir = dir.join("cache");
+        // Every run writes to new processed assets, so that only the cache can skip processing
+        let processed_dir = |run: usize| dir.join(format!("imported_assets_{run}"));
+        let processed =
+            |run: usize| std::fs::read(processed_dir(run).join("text.cool.ron")).unwrap();
+        std::fs::create_dir_all(&source_dir).unwrap();
+        let text =
+            br#"(text: "a", dependencies: [], embedded_dependencies: [], sub_texts: [])"#.to_vec();
+        std::fs::write(source_dir.join("text.cool.ron"), &text).unwrap();
+        let write_meta = |processor: &str, comment: &str| -> Vec<u8> {
+            let meta =
+                AssetMetaDyn::serialize(&AssetMeta::<(), CopyText<0>>::new(AssetAction::Process {
+                    processor: processor.into(),
+                    settings: CopyTextSettings {
+                        comment: comment.into(),
+                    },
+                }));
+            std::fs::write(source_dir.join("text.cool.ron.meta"), &meta).unwrap();
+            meta
+        };
+
+        process_asset::<0>(&source_dir, &processed_dir(0), &cache_dir);
+        assert_eq!(1, PROCESSED.load(Ordering::SeqCst));
+        assert_eq!(text, processed(0));
+
+        // The second run reuses the cached output
+        process_asset::<0>(&source_dir, &processed_dir(1), &cache_dir);
+        assert_eq!(1, PROCESSED.load(Ordering::SeqCst));
+        assert_eq!(text, processed(1));
+
+        // Changing the settings changes the key of the cache entry
+        write_meta(core::any::type_name::<CopyText<0>>(), "changed");
+        process_asset::<0>(&source_dir, &processed_dir(2), &cache_dir);
+        assert_eq!(2, PROCESSED.load(Ordering::SeqCst));
+
+        // Outputs of previous versions of a processor are not reused, even for the same source and settings. Since
+        // processors are identified by their type, a previous version is simulated with an entry written to its key.
+        let meta = write_meta(core::any::type_name::<CopyText<1>>(), "changed");
+        let hash = get_asset_hash(&meta, &text);
+        let mut stale_meta = AssetMeta::<(), ()>::new(AssetAction::Ignore);
+        stale_meta.processed_info = Some(ProcessedInfo {
+            hash,
+            full_hash: hash,
+            process_dependencies: Vec::new(),
+        });
+        block_on(ProcessorCache::directory(&cache_dir).insert(
+            &ProcessorCache::key(hash, 0),
+            b"stale",
+            &AssetMetaDyn::serialize(&stale_meta),
+        ))
+        .unwrap();
+        process_asset::<1>(&source_dir, &processed_dir(3), &cache_dir);
+        assert_eq!(3, PROCESSED.load(Ordering::SeqCst));
+        assert_eq!(text, processed(3));
+        process_asset::<1>(&source_dir, &processed_dir(4), &cache_dir);
+        assert_eq!(3, PROCESSED.load(Ordering::SeqCst));
+        assert_eq!(text, processed(4));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(all(feature = "asset_processor", feature = "multi_threaded"))]
+    #[test]
+    fn processor_writes_migrated_meta() {
+        use crate::{
+            io::Writer,
+            meta::{AssetAction, AssetMeta},
+            processor::{AssetProcessor, Process, ProcessContext, ProcessError, ProcessStatus},
+            AssetMode,
+        };
+        use bevy_tasks::block_on;
+        use futures_lite::AsyncWriteExt;
+
+        /// Copies the source text. Version 0 of its settings named `uppercase` `upper`.
+        struct CopyText;
+
+        #[derive(Serialize, Deserialize, Default)]
+        struct CopyTextSettings {
+            uppercase: bool,
+        }
+
+        impl Process for CopyText {
+            type Settings = CopyTextSettings;
+            type OutputLoader = CoolTextLoader;
+            const SETTINGS_VERSION: u32 = 1;
+
+            async fn process(
+                &self,
+                context: &mut ProcessContext<'_>,
+                meta: AssetMeta<(), Self>,
+                writer: &mut Writer,
+            ) -> Result<(), ProcessError> {
+                let AssetAction::Process { settings, .. } = meta.asset else {
+                    unreachable!("processed assets have process settings");
+                };
+                assert!(settings.uppercase);
+                writer
+                    .write_all(context.asset_bytes())
+                    .await
+                    .map_err(|err| ProcessError::AssetWriterError {
+                        path: context.path().clone_owned(),
+                        err: err.into(),
+                    })?;
+                Ok(())
+            }
+
+            fn migrate_settings(
+                from_version: u32,
+                settings: &str,
+            ) -> Result<String, Box<dyn core::error::Error + Send + Sync>> {
+                assert_eq!(0, from_version);
+                Ok(settings.replace("upper:", "uppercase:"))
+            }
+        }
+
+        /// Processes an asset whose meta has version 0 of the settings, returning its meta afterwards.
+        fn process_asset(dir: &Path, write_migrated_meta: bool) -> String {
+            let source_dir = dir.join("assets");
+            std::fs::create_dir_all(&source_dir).unwrap();
+            std::fs::write(
+                source_dir.join("text.cool.ron"),
+                r#"(text: "a", dependencies: [], embedded_dependencies: [], sub_texts: [])"#,
+            )
+            .unwrap();
+            std::fs::write(
+                source_dir.join("text.cool.ron.meta"),
+                format!(
+                    r#"(meta_format_version: "1.0", asset: Process(processor: "{}", settings: (upper: true)))"#,
+                    core::any::type_name::<CopyText>()
+                ),
+            )
+            .unwrap();
+
+            let mut app = App::new();
+            app.add_plugins((
+                TaskPoolPlugin::default(),
+                AssetPlugin {
+                    file_path: source_dir.to_string_lossy().into_owned(),
+                    processed_file_path: dir.join("imported_assets").to_string_lossy().into_owned(),
+                    watch_for_changes_override: Some(false),
+                    mode: AssetMode::Processed,
+                    ..Default::default()
+                },
+            ))
+            .init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader)
+            .register_asset_processor(CopyText);
+            let processor = app.world().resource::<AssetProcessor>().clone();
+            processor.set_write_migrated_meta(write_migrated_meta);
+            // Starts the processor
+            app.update();
+
+            block_on(processor.data().wait_until_finished());
+            assert_eq!(
+                ProcessStatus::Processed,
+                block_on(
+                    processor
+                        .data()
+                        .wait_until_processed("text.cool.ron".into())
+                )
+            );
+            std::fs::read_to_string(source_dir.join("text.cool.ron.meta")).unwrap()
+        }
+
+        let dir = std::env::temp_dir().join(format!(
+            "bevy_asset_processor_writes_migrated_meta_{}",
+            std::process::id()
+        ));
+
+        // By default, the source meta is left alone and migrated again on every run
+        let meta = process_asset(&dir.join("kept"), false);
+        assert!(meta.contains("(upper: true)"), "{meta}");
+
+        let meta = process_asset(&dir.join("written"), true);
+        let meta: AssetMeta<(), CopyText> = ron::de::from_str(&meta).unwrap();
+        assert_eq!(1, meta.settings_version);
+        assert!(matches!(
+            meta.asset,
+            AssetAction::Process {
+                settings: CopyTextSettings { uppercase: true },
+                ..
+            }
+        ));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
 }
@@ -1,7 +1,10 @@
 use crate::{
     io::{AssetReaderError, MissingAssetSourceError, MissingProcessedAssetReaderError, Reader},
     loader_builders::{Deferred, NestedLoader, StaticTyped},
-    meta::{AssetHash, AssetMeta, AssetMetaDyn, ProcessedInfoMinimal, Settings},
+    meta::{
+        migrate_meta, AssetHash, AssetMeta, AssetMetaDyn, MetaMigrationError, ProcessedInfoMinimal,
+        Settings,
+    },
     path::AssetPath,
     Asset, AssetLoadError, AssetServer, AssetServerMode, Assets, Handle, UntypedAssetId,
     UntypedHandle,
@@ -35,6 +38,9 @@ pub trait AssetLoader: Send + Sync + 'static {
     type Settings: Settings + Default + Serialize + for<'a> Deserialize<'a>;
     /// The type of [error](`std::error::Error`) which could be encountered by this loader.
     type Error: Into<BevyError>;
+    /// The version of [`AssetLoader::Settings`]. Increment it whenever a change to the settings type breaks the
+    /// existing `.meta` files, and migrate the settings of the previous version in [`AssetLoader::migrate_settings`].
+    const SETTINGS_VERSION: u32 = 0;
     /// Asynchronously loads [`AssetLoader::Asset`] (and any other labeled assets) from the bytes provided by [`Reader`].
     fn load(
         &self,
@@ -48,6 +54,26 @@ pub trait AssetLoader: Send + Sync + 'static {
     fn extensions(&self) -> &[&str] {
         &[]
     }
+
+    /// Migrates `settings`, the RON serialization of version `from_version` of [`AssetLoader::Settings`], to version
+    /// `from_version + 1`.
+    ///
+    /// When a `.meta` file has an older [`AssetMeta::settings_version`] than [`AssetLoader::SETTINGS_VERSION`], this is
+    /// called once for each version in between, whenever the meta is read. The [`AssetProcessor`] can also write the
+    /// migrated meta back to the asset source, see [`AssetProcessor::set_write_migrated_meta`].
+    ///
+    /// The previous versions of the settings can be kept around as separate types to deserialize them with
+    /// [`ron::de::from_str`]. By default, migrating fails.
+    ///
+    /// [`AssetProcessor`]: crate::processor::AssetProcessor
+    /// [`AssetProcessor::set_write_migrated_meta`]: crate::processor::AssetProcessor::set_write_migrated_meta
+    fn migrate_settings(
+        from_version: u32,
+        settings: &str,
+    ) -> Result<String, Box<dyn core::error::Error + Send + Sync>> {
+        let _ = (from_version, settings);
+        Err("this asset loader does not support migrating its settings".into())
+    }
 }
 
 /// Provides type-erased access to an [`AssetLoader`].
@@ -63,7 +89,12 @@ pub trait ErasedAssetLoader: Send + Sync + 'static {
     /// Returns a list of extensions supported by this asset loader, without the preceding dot.
     fn extensions(&self) -> &[&str];
     /// Deserializes metadata from the input `meta` bytes into the appropriate type (erased as [`Box<dyn AssetMetaDyn>`]).
+    ///
+    /// If the settings of `meta` are of an older version, they are migrated first, see [`ErasedAssetLoader::migrate_meta`].
     fn deserialize_meta(&self, meta: &[u8]) -> Result<Box<dyn AssetMetaDyn>, DeserializeMetaError>;
+    /// Migrates the settings of the input `meta` bytes to [`AssetLoader::SETTINGS_VERSION`], returning the migrated meta
+    /// bytes, or [`None`] if the settings are already up to date.
+    fn migrate_meta(&self, meta: &[u8]) -> Result<Option<Vec<u8>>, MetaMigrationError>;
     /// Returns the default meta value for the [`AssetLoader`] (erased as [`Box<dyn AssetMetaDyn>`]).
     fn default_meta(&self) -> Box<dyn AssetMetaDyn>;
     /// Returns the type name of the [`AssetLoader`].
@@ -105,10 +136,22 @@ where
     }
 
     fn deserialize_meta(&self, meta: &[u8]) -> Result<Box<dyn AssetMetaDyn>, DeserializeMetaError> {
-        let meta = AssetMeta::<L, ()>::deserialize(meta)?;
+        let meta = match self.migrate_meta(meta)? {
+            Some(migrated) => AssetMeta::<L, ()>::deserialize(&migrated)?,
+            None => AssetMeta::<L, ()>::deserialize(meta)?,
+        };
         Ok(Box::new(meta))
     }
 
+    fn migrate_meta(&self, meta: &[u8]) -> Result<Option<Vec<u8>>, MetaMigrationError> {
+        migrate_meta(
+            meta,
+            self.type_name(),
+            L::SETTINGS_VERSION,
+            L::migrate_settings,
+        )
+    }
+
     fn default_meta(&self) -> Box<dyn AssetMetaDyn> {
         Box::new(AssetMeta::<L, ()>::new(crate::meta::AssetAction::Load {
             loader: self.type_name().to_string(),
@@ -307,6 +350,8 @@ pub enum DeserializeMetaError {
     DeserializeSettings(#[from] SpannedError),
     #[error("Failed to deserialize minimal asset meta: {0:?}")]
     DeserializeMinimal(SpannedError),
+    #[error(transparent)]
+    Migration(#[from] MetaMigrationError),
 }
 
 /// A context that provides access to assets in [`AssetLoader`]s, tracks dependencies, and collects asset load state.
@@ -1,11 +1,11 @@
-//! Implementations of the builder-pattern used for loading dependent assets via
-//! [`LoadContext::loader`].
+//! Implementations of the builder-pattern used for loading assets via
+//! [`AssetServer::load_builder`] and dependent assets via [`LoadContext::loader`].
 
 use crate::{
     io::Reader,
     meta::{meta_transform_settings, AssetMetaDyn, MetaTransform, Settings},
-    Asset, AssetLoadError, AssetPath, ErasedAssetLoader, ErasedLoadedAsset, Handle, LoadContext,
-    LoadDirectError, LoadedAsset, LoadedUntypedAsset, UntypedHandle,
+    Asset, AssetLoadError, AssetPath, AssetServer, ErasedAssetLoader, ErasedLoadedAsset, Handle,
+    LoadContext, LoadDirectError, LoadPriority, LoadedAsset, LoadedUntypedAsset, UntypedHandle,
 };
 use alloc::{borrow::ToOwned, boxed::Box, sync::Arc};
 use core::any::TypeId;
@@ -118,6 +118,7 @@ impl ReaderRef<'_> {
 pub struct NestedLoader<'ctx, 'builder, T, M> {
     load_context: &'builder mut LoadContext<'ctx>,
     meta_transform: Option<MetaTransform>,
+    priority: LoadPriority,
     typing: T,
     mode: M,
 }
@@ -169,6 +170,7 @@ impl<'ctx, 'builder> NestedLoader<'ctx, 'builder, StaticTyped, Deferred> {
         NestedLoader {
             load_context,
             meta_transform: None,
+            priority: LoadPriority::NORMAL,
             typing: StaticTyped(()),
             mode: Deferred(()),
         }
@@ -203,6 +205,16 @@ impl<'ctx, 'builder, T: sealed::Typing, M: sealed::Mode> NestedLoader<'ctx, 'bui
         self.with_transform(move |meta| meta_transform_settings(meta, &settings))
     }
 
+    /// Configure the [`LoadPriority`] of deferred loads, used to pick which load starts next when
+    /// the asset source has reached its maximum number of concurrent loads.
+    ///
+    /// Immediate loads are not queued, since the current load is already running.
+    #[must_use]
+    pub fn with_priority(mut self, priority: LoadPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+
     // convert between `T`s
 
     /// When [`load`]ing, you must pass in the asset type as a type parameter
@@ -219,6 +231,7 @@ impl<'ctx, 'builder, T: sealed::Typing, M: sealed::Mode> NestedLoader<'ctx, 'bui
         NestedLoader {
             load_context: self.load_context,
             meta_transform: self.meta_transform,
+            priority: self.priority,
             typing: StaticTyped(()),
             mode: self.mode,
         }
@@ -236,6 +249,7 @@ impl<'ctx, 'builder, T: sealed::Typing, M: sealed::Mode> NestedLoader<'ctx, 'bui
         NestedLoader {
             load_context: self.load_context,
             meta_transform: self.meta_transform,
+            priority: self.priority,
             typing: DynamicTyped { asset_type_id },
             mode: self.mode,
         }
@@ -250,6 +264,7 @@ impl<'ctx, 'builder, T: sealed::Typing, M: sealed::Mode> NestedLoader<'ctx, 'bui
         NestedLoader {
             load_context: self.load_context,
             meta_transform: self.meta_transform,
+            priority: self.priority,
             typing: UnknownTyped(()),
             mode: self.mode,
         }
@@ -265,6 +280,7 @@ impl<'ctx, 'builder, T: sealed::Typing, M: sealed::Mode> NestedLoader<'ctx, 'bui
         NestedLoader {
             load_context: self.load_context,
             meta_transform: self.meta_transform,
+            priority: self.priority,
             typing: self.typing,
             mode: Deferred(()),
         }
@@ -282,6 +298,7 @@ impl<'ctx, 'builder, T: sealed::Typing, M: sealed::Mode> NestedLoader<'ctx, 'bui
         NestedLoader {
             load_context: self.load_context,
             meta_transform: self.meta_transform,
+            priority: self.priority,
             typing: self.typing,
             mode: Immediate { reader: None },
         }
@@ -310,6 +327,7 @@ impl NestedLoader<'_, '_, StaticTyped, Deferred> {
                 self.meta_transform,
                 (),
                 true,
+                self.priority,
             )
         } else {
             self.load_context
@@ -339,6 +357,7 @@ impl NestedLoader<'_, '_, DynamicTyped, Deferred> {
                     self.typing.asset_type_id,
                     self.meta_transform,
                     (),
+                    self.priority,
                 )
         } else {
             self.load_context
@@ -364,7 +383,7 @@ impl NestedLoader<'_, '_, UnknownTyped, Deferred> {
         let handle = if self.load_context.should_load_dependencies {
             self.load_context
                 .asset_server
-                .load_unknown_type_with_meta_transform(path, self.meta_transform)
+                .load_unknown_type_with_meta_transform(path, self.meta_transform, self.priority)
         } else {
             self.load_context
                 .asset_server
@@ -507,3 +526,103 @@ impl NestedLoader<'_, '_, UnknownTyped, Immediate<'_, '_>> {
             .map(|(_, asset)| asset)
     }
 }
+
+/// A builder for loading assets with the [`AssetServer`], created with
+/// [`AssetServer::load_builder`].
+///
+/// This combines the options of the various `AssetServer::load_*` methods, and additionally
+/// allows configuring the [`LoadPriority`] of the load.
+///
+/// ```
+/// # use bevy_asset::{Asset, AssetServer, Handle, LoadPriority};
+/// # use bevy_reflect::TypePath;
+/// # #[derive(Asset, TypePath)]
+/// # struct Image;
+/// fn load_splash_screen(asset_server: &AssetServer) -> Handle<Image> {
+///     asset_server
+///         .load_builder()
+///         .with_priority(LoadPriority::HIGH)
+///         .load("splash.png")
+/// }
+/// ```
+pub struct LoadBuilder<'a> {
+    asset_server: &'a AssetServer,
+    meta_transform: Option<MetaTransform>,
+    priority: LoadPriority,
+    override_unapproved: bool,
+}
+
+impl<'a> LoadBuilder<'a> {
+    pub(crate) fn new(asset_server: &'a AssetServer) -> Self {
+        Self {
+            asset_server,
+            meta_transform: None,
+            priority: LoadPriority::NORMAL,
+            override_unapproved: false,
+        }
+    }
+
+    /// Configure the settings used to load the asset.
+    ///
+    /// The type `S` _must_ match the configured [`AssetLoader::Settings`](crate::AssetLoader::Settings)
+    /// or the settings changes will be ignored and an error will be printed to the log.
+    #[must_use]
+    pub fn with_settings<S: Settings>(
+        mut self,
+        settings: impl Fn(&mut S) + Send + Sync + 'static,
+    ) -> Self {
+        let transform = move |meta: &mut dyn AssetMetaDyn| meta_transform_settings(meta, &settings);
+        if let Some(prev_transform) = self.meta_transform {
+            self.meta_transform = Some(Box::new(move |meta| {
+                prev_transform(meta);
+                transform(meta);
+            }));
+        } else {
+            self.meta_transform = Some(Box::new(transform));
+        }
+        self
+    }
+
+    /// Configure the [`LoadPriority`] of the load, used to pick which load starts next when the
+    /// asset source has reached its
+    /// [maximum number of concurrent loads](crate::io::AssetSourceBuilder::with_max_concurrent_loads).
+    #[must_use]
+    pub fn with_priority(mut self, priority: LoadPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Allows loading assets from unapproved paths, if
+    /// [`AssetPlugin::unapproved_path_mode`](crate::AssetPlugin::unapproved_path_mode) is
+    /// [`Deny`](crate::UnapprovedPathMode::Deny).
+    ///
+    /// See [`UnapprovedPathMode`](crate::UnapprovedPathMode) and [`AssetPath::is_unapproved`].
+    #[must_use]
+    pub fn override_unapproved(mut self) -> Self {
+        self.override_unapproved = true;
+        self
+    }
+
+    /// Begins loading an [`Asset`] of type `A` stored at `path`. See [`AssetServer::load`].
+    #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
+    pub fn load<'p, A: Asset>(self, path: impl Into<AssetPath<'p>>) -> Handle<A> {
+        self.load_acquire(path, ())
+    }
+
+    /// Begins loading an [`Asset`] of type `A` stored at `path` while holding a guard item. See
+    /// [`AssetServer::load_acquire`].
+    #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
+    pub fn load_acquire<'p, A: Asset, G: Send + Sync + 'static>(
+        self,
+        path: impl Into<AssetPath<'p>>,
+        guard: G,
+    ) -> Handle<A> {
+        self.asset_server.load_with_meta_transform(
+            path,
+            self.meta_transform,
+            guard,
+            self.override_unapproved,
+            self.priority,
+        )
+    }
+}
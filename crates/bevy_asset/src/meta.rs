@@ -9,8 +9,9 @@ use crate::{
     VisitAssetDependencies,
 };
 use downcast_rs::{impl_downcast, Downcast};
-use ron::ser::PrettyConfig;
+use ron::{error::SpannedError, ser::PrettyConfig, value::RawValue};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 use tracing::error;
 
 pub const META_FORMAT_VERSION: &str = "1.0";
@@ -25,6 +26,11 @@ pub struct AssetMeta<L: AssetLoader, P: Process> {
     /// The version of the meta format being used. This will change whenever a breaking change is made to
     /// the meta format.
     pub meta_format_version: String,
+    /// The version of the settings of the [`AssetLoader`] or [`Process`] configured by the [`AssetAction`], see
+    /// [`AssetLoader::SETTINGS_VERSION`] and [`Process::SETTINGS_VERSION`]. Meta files with an older version are
+    /// migrated when they are read. This is omitted from the meta file when it is `0`.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub settings_version: u32,
     /// Information produced by the [`AssetProcessor`] _after_ processing this asset.
     /// This will only exist alongside processed versions of assets. You should not manually set it in your asset source files.
     ///
@@ -37,8 +43,14 @@ pub struct AssetMeta<L: AssetLoader, P: Process> {
 
 impl<L: AssetLoader, P: Process> AssetMeta<L, P> {
     pub fn new(asset: AssetAction<L::Settings, P::Settings>) -> Self {
+        let settings_version = match &asset {
+            AssetAction::Load { .. } => L::SETTINGS_VERSION,
+            AssetAction::Process { .. } => P::SETTINGS_VERSION,
+            AssetAction::Ignore => 0,
+        };
         Self {
             meta_format_version: META_FORMAT_VERSION.to_string(),
+            settings_version,
             processed_info: None,
             asset,
         }
@@ -120,6 +132,119 @@ pub struct ProcessedInfoMinimal {
     pub processed_info: Option<ProcessedInfo>,
 }
 
+/// This is a minimal counterpart to [`AssetMeta`] that only reads the [`AssetMeta::settings_version`], to check whether
+/// the meta needs to be migrated.
+#[derive(Deserialize)]
+struct AssetMetaSettingsVersion {
+    #[serde(default)]
+    settings_version: u32,
+}
+
+/// A counterpart to [`AssetMeta`] that keeps the settings as raw RON, so that they can be migrated without knowing
+/// the types of their previous versions.
+#[derive(Serialize, Deserialize)]
+struct AssetMetaRaw {
+    meta_format_version: String,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    settings_version: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    processed_info: Option<ProcessedInfo>,
+    asset: AssetAction<Box<RawValue>, Box<RawValue>>,
+}
+
+fn is_zero(value: &u32) -> bool {
+    *value == 0
+}
+
+/// Migrates the settings of the serialized asset `meta` to the `settings_version` of the [`AssetLoader`] or
+/// [`Process`] named `type_name`, by calling `migrate_settings` once for each version between the version of the
+/// meta and `settings_version`.
+///
+/// Returns the migrated meta, or [`None`] if the meta is already up to date.
+pub(crate) fn migrate_meta(
+    meta: &[u8],
+    type_name: &str,
+    settings_version: u32,
+    migrate_settings: impl Fn(u32, &str) -> Result<String, Box<dyn core::error::Error + Send + Sync>>,
+) -> Result<Option<Vec<u8>>, MetaMigrationError> {
+    let version = ron::de::from_bytes::<AssetMetaSettingsVersion>(meta)
+        .map_err(MetaMigrationError::DeserializeMeta)?
+        .settings_version;
+    if version == settings_version {
+        return Ok(None);
+    }
+    if version > settings_version {
+        return Err(MetaMigrationError::NewerVersion {
+            type_name: type_name.to_string(),
+            version,
+            settings_version,
+        });
+    }
+
+    let mut raw: AssetMetaRaw =
+        ron::de::from_bytes(meta).map_err(MetaMigrationError::DeserializeMeta)?;
+    let settings = match &mut raw.asset {
+        AssetAction::Load { settings, .. } | AssetAction::Process { settings, .. } => settings,
+        AssetAction::Ignore => return Ok(None),
+    };
+    let mut migrated = settings.get_ron().to_string();
+    for from_version in version..settings_version {
+        migrated = migrate_settings(from_version, &migrated).map_err(|error| {
+            MetaMigrationError::Migrate {
+                type_name: type_name.to_string(),
+                from_version,
+                error: error.to_string(),
+            }
+        })?;
+    }
+    *settings = RawValue::from_boxed_ron(migrated.into_boxed_str()).map_err(|error| {
+        MetaMigrationError::InvalidMigratedSettings {
+            type_name: type_name.to_string(),
+            settings_version,
+            error,
+        }
+    })?;
+    raw.settings_version = settings_version;
+    Ok(Some(
+        ron::ser::to_string_pretty(&raw, PrettyConfig::default())
+            .expect("type is convertible to ron")
+            .into_bytes(),
+    ))
+}
+
+/// An error that occurs while migrating the settings of an [`AssetMeta`] to the current
+/// [`AssetLoader::SETTINGS_VERSION`] or [`Process::SETTINGS_VERSION`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum MetaMigrationError {
+    #[error("Failed to deserialize asset meta for migration: {0}")]
+    DeserializeMeta(SpannedError),
+    #[error(
+        "The asset meta has settings version {version}, which is newer than the version {settings_version} supported by '{type_name}'"
+    )]
+    NewerVersion {
+        type_name: String,
+        version: u32,
+        settings_version: u32,
+    },
+    #[error(
+        "Failed to migrate the settings of '{type_name}' from version {from_version} to version {}: {error}",
+        from_version + 1
+    )]
+    Migrate {
+        type_name: String,
+        from_version: u32,
+        error: String,
+    },
+    #[error(
+        "The settings of '{type_name}' migrated to version {settings_version} are not valid RON: {error}"
+    )]
+    InvalidMigratedSettings {
+        type_name: String,
+        settings_version: u32,
+        error: SpannedError,
+    },
+}
+
 /// A dynamic type-erased counterpart to [`AssetMeta`] that enables passing around and interacting with [`AssetMeta`] without knowing
 /// its type.
 pub trait AssetMetaDyn: Downcast + Send + Sync {
@@ -260,3 +385,208 @@ pub(crate) fn get_full_asset_hash(
     }
     *hasher.finalize().as_bytes()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{AssetAction, AssetMeta, MetaMigrationError};
+    use crate::{
+        io::{Reader, Writer},
+        loader::ErasedAssetLoader,
+        processor::{ErasedProcessor, LoadTransformAndSave, Process},
+        saver::{AssetSaver, SavedAsset},
+        transformer::IdentityAssetTransformer,
+        AssetLoader, DeserializeMetaError,
+    };
+    use alloc::{
+        boxed::Box,
+        format,
+        string::{String, ToString},
+    };
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct SettingsV0 {
+        scale: u32,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct SettingsV1 {
+        scale: f32,
+    }
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct Settings {
+        scale: f32,
+        flip: bool,
+    }
+
+    struct VersionedLoader;
+
+    impl AssetLoader for VersionedLoader {
+        type Asset = ();
+        type Settings = Settings;
+        type Error = std::io::Error;
+        const SETTINGS_VERSION: u32 = 2;
+
+        async fn load(
+            &self,
+            _reader: &mut dyn Reader,
+            _settings: &Self::Settings,
+            _load_context: &mut crate::LoadContext<'_>,
+        ) -> Result<Self::Asset, Self::Error> {
+            unreachable!()
+        }
+
+        fn migrate_settings(
+            from_version: u32,
+            settings: &str,
+        ) -> Result<String, Box<dyn core::error::Error + Send + Sync>> {
+            Ok(match from_version {
+                0 => {
+                    let old: SettingsV0 = ron::de::from_str(settings)?;
+                    ron::ser::to_string(&SettingsV1 {
+                        scale: old.scale as f32,
+                    })?
+                }
+                1 => {
+                    let old: SettingsV1 = ron::de::from_str(settings)?;
+                    if old.scale < 0.0 {
+                        return Err("negative scales are not supported".into());
+                    }
+                    ron::ser::to_string(&Settings {
+                        scale: old.scale,
+                        flip: false,
+                    })?
+                }
+                _ => unreachable!(),
+            })
+        }
+    }
+
+    struct UnitSaver;
+
+    impl AssetSaver for UnitSaver {
+        type Asset = ();
+        type Settings = ();
+        type OutputLoader = VersionedLoader;
+        type Error = std::io::Error;
+
+        async fn save(
+            &self,
+            _writer: &mut Writer,
+            _asset: SavedAsset<'_, Self::Asset>,
+            _settings: &Self::Settings,
+        ) -> Result<Settings, Self::Error> {
+            unreachable!()
+        }
+    }
+
+    type VersionedProcessor =
+        LoadTransformAndSave<VersionedLoader, IdentityAssetTransformer<()>, UnitSaver>;
+
+    fn meta(settings_version: &str, settings: &str) -> String {
+        format!(
+            r#"(
+    meta_format_version: "1.0",
+    {settings_version}
+    asset: Load(
+        loader: "{}",
+        settings: {settings},
+    ),
+)"#,
+            core::any::type_name::<VersionedLoader>()
+        )
+    }
+
+    #[test]
+    fn migrate_settings() {
+        let loader = VersionedLoader;
+
+        let current = AssetMeta::<VersionedLoader, ()>::new(AssetAction::Load {
+            loader: loader.type_name().to_string(),
+            settings: Settings::default(),
+        });
+        assert_eq!(2, current.settings_version);
+        assert_eq!(
+            None,
+            loader
+                .migrate_meta(&super::AssetMetaDyn::serialize(&current))
+                .unwrap()
+        );
+
+        // Meta files without a settings version are at version 0.
+        let migrated = loader
+            .migrate_meta(meta("", "(scale: 3)").as_bytes())
+            .unwrap()
+            .unwrap();
+        let migrated = AssetMeta::<VersionedLoader, ()>::deserialize(&migrated).unwrap();
+        assert_eq!(2, migrated.settings_version);
+        let AssetAction::Load { settings, .. } = migrated.asset else {
+            panic!("the asset action should be preserved");
+        };
+        assert_eq!(3.0, settings.scale);
+        assert!(!settings.flip);
+
+        let meta_v1 = meta("settings_version: 1,", "(scale: 1.5)");
+        let deserialized = loader.deserialize_meta(meta_v1.as_bytes()).unwrap();
+        let settings = deserialized
+            .loader_settings()
+            .unwrap()
+            .downcast_ref::<Settings>()
+            .unwrap();
+        assert_eq!(1.5, settings.scale);
+
+        assert_eq!(
+            Err(MetaMigrationError::NewerVersion {
+                type_name: loader.type_name().to_string(),
+                version: 3,
+                settings_version: 2,
+            }),
+            loader.migrate_meta(meta("settings_version: 3,", "(scale: 1.0)").as_bytes())
+        );
+
+        let Err(DeserializeMetaError::Migration(MetaMigrationError::Migrate {
+            from_version,
+            error,
+            ..
+        })) = loader.deserialize_meta(meta("settings_version: 1,", "(scale: -1.0)").as_bytes())
+        else {
+            panic!("migrating negative scales should fail");
+        };
+        assert_eq!(1, from_version);
+        assert_eq!("negative scales are not supported", error);
+    }
+
+    #[test]
+    fn migrate_load_transform_and_save_settings() {
+        let processor = VersionedProcessor::from(UnitSaver);
+        assert_eq!(
+            2,
+            <VersionedProcessor as Process>::SETTINGS_VERSION,
+            "the processor settings should follow the loader settings version"
+        );
+
+        let meta = format!(
+            r#"(
+    meta_format_version: "1.0",
+    asset: Process(
+        processor: "{}",
+        settings: (
+            loader_settings: (scale: 3),
+            transformer_settings: (),
+            saver_settings: (),
+        ),
+    ),
+)"#,
+            core::any::type_name::<VersionedProcessor>()
+        );
+        let migrated = processor.migrate_meta(meta.as_bytes()).unwrap().unwrap();
+        let migrated = AssetMeta::<(), VersionedProcessor>::deserialize(&migrated).unwrap();
+        assert_eq!(2, migrated.settings_version);
+        let AssetAction::Process { settings, .. } = migrated.asset else {
+            panic!("the asset action should be preserved");
+        };
+        assert_eq!(3.0, settings.loader_settings.scale);
+        assert!(!settings.loader_settings.flip);
+    }
+}
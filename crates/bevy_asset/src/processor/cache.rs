@@ -0,0 +1,220 @@
+use crate::{
+    io::{
+        AssetReader, AssetReaderError, AssetWriter, AssetWriterError, ErasedAssetReader,
+        ErasedAssetWriter,
+    },
+    meta::{AssetHash, ProcessedInfo, ProcessedInfoMinimal},
+};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::fmt::Write;
+use futures_lite::AsyncReadExt;
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// The version of the layout of [`ProcessorCache`] entries.
+///
+/// NOTE: changing the cache key or the layout of the entries is a _breaking change_ that requires bumping this.
+const PROCESSOR_CACHE_VERSION: u32 = 1;
+
+/// A content-addressed cache of processed assets, shared across [`AssetProcessor`] runs and machines.
+///
+/// Entries are keyed by the hash of the source asset and its meta (which contains the processor and its settings),
+/// and by the [`Process::VERSION`] of the processor. Before processing an asset, the [`AssetProcessor`] looks for
+/// an entry with the same key whose process dependencies are unchanged, and copies it to the processed
+/// [`AssetSource`](crate::io::AssetSource) instead of running the processor. Newly processed assets are stored in the
+/// cache. Assets that are only loaded (and not processed) are not cached.
+///
+/// The cache can be backed by any [`AssetReader`] / [`AssetWriter`] pair, such as a local directory with
+/// [`ProcessorCache::directory`]. Pointing every developer machine and CI at the same storage lets them reuse the
+/// outputs of expensive processors, such as texture compression, instead of running them on every checkout.
+///
+/// Set the cache with [`AssetProcessor::set_cache`] or [`AssetApp::set_asset_processor_cache`](crate::AssetApp::set_asset_processor_cache).
+///
+/// [`AssetProcessor`]: super::AssetProcessor
+/// [`AssetProcessor::set_cache`]: super::AssetProcessor::set_cache
+/// [`Process::VERSION`]: super::Process::VERSION
+pub struct ProcessorCache {
+    reader: Box<dyn ErasedAssetReader>,
+    writer: Option<Box<dyn ErasedAssetWriter>>,
+}
+
+impl ProcessorCache {
+    /// Creates a new [`ProcessorCache`] reading and writing entries with the given `reader` and `writer`.
+    pub fn new(reader: impl AssetReader, writer: impl AssetWriter) -> Self {
+        Self {
+            reader: Box::new(reader),
+            writer: Some(Box::new(writer)),
+        }
+    }
+
+    /// Creates a new [`ProcessorCache`] that only reads entries with the given `reader`, and never stores the
+    /// assets processed locally. This is useful to consume a cache populated by CI.
+    pub fn read_only(reader: impl AssetReader) -> Self {
+        Self {
+            reader: Box::new(reader),
+            writer: None,
+        }
+    }
+
+    /// Creates a new [`ProcessorCache`] storing its entries in the directory at `path`, relative to the
+    /// executable's directory (see [`FileAssetReader::get_base_path`](crate::io::file::FileAssetReader::get_base_path)).
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn directory<P: AsRef<std::path::Path> + core::fmt::Debug>(path: P) -> Self {
+        use crate::io::file::{FileAssetReader, FileAssetWriter};
+
+        Self::new(
+            FileAssetReader::new(&path),
+            FileAssetWriter::new(path, false),
+        )
+    }
+
+    /// Returns true if this cache never stores the assets processed locally.
+    pub fn is_read_only(&self) -> bool {
+        self.writer.is_none()
+    }
+
+    /// Returns the key of the entry for an asset with the given `hash` (see [`ProcessedInfo::hash`]), processed by a
+    /// processor with the given `version`.
+    pub(crate) fn key(hash: AssetHash, version: u32) -> ProcessorCacheKey {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&PROCESSOR_CACHE_VERSION.to_le_bytes());
+        hasher.update(&hash);
+        hasher.update(&version.to_le_bytes());
+        let mut name = String::with_capacity(64);
+        for byte in hasher.finalize().as_bytes() {
+            // Writing to a String cannot fail.
+            let _ = write!(name, "{byte:02x}");
+        }
+        // Spread the entries across sub folders to keep directories small.
+        ProcessorCacheKey(PathBuf::from(&name[..2]).join(&name))
+    }
+
+    /// Reads the entry with the given `key`, returning the processed asset bytes, the processed meta bytes and the
+    /// [`ProcessedInfo`] stored in the meta. Returns [`None`] if the entry does not exist.
+    pub(crate) async fn get(
+        &self,
+        key: &ProcessorCacheKey,
+    ) -> Result<Option<ProcessorCacheEntry>, ProcessorCacheError> {
+        let meta_bytes = match self.reader.read_meta_bytes(&key.0).await {
+            Ok(meta_bytes) => meta_bytes,
+            Err(AssetReaderError::NotFound(_)) => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let Some(processed_info) =
+            ron::de::from_bytes::<ProcessedInfoMinimal>(&meta_bytes)?.processed_info
+        else {
+            return Err(ProcessorCacheError::MissingProcessedInfo(key.0.clone()));
+        };
+        let mut asset_bytes = Vec::new();
+        self.reader
+            .read(&key.0)
+            .await?
+            .read_to_end(&mut asset_bytes)
+            .await
+            .map_err(|err| AssetReaderError::Io(err.into()))?;
+        Ok(Some(ProcessorCacheEntry {
+            asset_bytes,
+            meta_bytes,
+            processed_info,
+        }))
+    }
+
+    /// Stores the processed `asset_bytes` and `meta_bytes` in the entry with the given `key`. Does nothing if the
+    /// cache is [read only](Self::read_only).
+    pub(crate) async fn insert(
+        &self,
+        key: &ProcessorCacheKey,
+        asset_bytes: &[u8],
+        meta_bytes: &[u8],
+    ) -> Result<(), ProcessorCacheError> {
+        let Some(writer) = &self.writer else {
+            return Ok(());
+        };
+        // The meta is written last, since it marks the entry as complete in `get`.
+        writer.write_bytes(&key.0, asset_bytes).await?;
+        writer.write_meta_bytes(&key.0, meta_bytes).await?;
+        Ok(())
+    }
+}
+
+/// The key of a [`ProcessorCache`] entry, which is its path in the cache storage.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ProcessorCacheKey(PathBuf);
+
+/// A processed asset read from a [`ProcessorCache`].
+pub(crate) struct ProcessorCacheEntry {
+    pub(crate) asset_bytes: Vec<u8>,
+    pub(crate) meta_bytes: Vec<u8>,
+    pub(crate) processed_info: ProcessedInfo,
+}
+
+/// An error that occurs when reading or writing [`ProcessorCache`] entries.
+#[derive(Error, Debug)]
+pub enum ProcessorCacheError {
+    /// An error reading a cache entry.
+    #[error(transparent)]
+    AssetReaderError(#[from] AssetReaderError),
+    /// An error writing a cache entry.
+    #[error(transparent)]
+    AssetWriterError(#[from] AssetWriterError),
+    /// The meta of a cache entry could not be deserialized.
+    #[error("Failed to deserialize the meta of a processor cache entry: {0}")]
+    DeserializeMeta(#[from] ron::error::SpannedError),
+    /// The meta of a cache entry does not contain [`ProcessedInfo`].
+    #[error("The processor cache entry '{}' does not contain processed info", .0.display())]
+    MissingProcessedInfo(PathBuf),
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::ProcessorCache;
+    use crate::meta::{AssetAction, AssetMeta, AssetMetaDyn, ProcessDependencyInfo, ProcessedInfo};
+    use alloc::{format, vec};
+    use futures_lite::future::block_on;
+
+    #[test]
+    fn cache_roundtrip() {
+        let path = std::env::temp_dir().join(format!("bevy_asset_cache_{}", std::process::id()));
+        let cache = ProcessorCache::directory(&path);
+        let read_only = ProcessorCache::read_only(crate::io::file::FileAssetReader::new(&path));
+        assert!(!cache.is_read_only());
+        assert!(read_only.is_read_only());
+
+        let hash = [7; 32];
+        let key = ProcessorCache::key(hash, 1);
+        assert_ne!(key, ProcessorCache::key(hash, 2));
+        assert_ne!(key, ProcessorCache::key([8; 32], 1));
+
+        let mut meta = AssetMeta::<(), ()>::new(AssetAction::Ignore);
+        meta.processed_info = Some(ProcessedInfo {
+            hash,
+            full_hash: [9; 32],
+            process_dependencies: vec![ProcessDependencyInfo {
+                full_hash: [1; 32],
+                path: "dep.txt".into(),
+            }],
+        });
+        let meta_bytes = meta.serialize();
+
+        block_on(async {
+            assert!(cache.get(&key).await.unwrap().is_none());
+            cache.insert(&key, b"processed", &meta_bytes).await.unwrap();
+
+            let entry = read_only.get(&key).await.unwrap().unwrap();
+            assert_eq!(b"processed", &entry.asset_bytes[..]);
+            assert_eq!(meta_bytes, entry.meta_bytes);
+            assert_eq!(hash, entry.processed_info.hash);
+            assert_eq!([9; 32], entry.processed_info.full_hash);
+            assert_eq!(1, entry.processed_info.process_dependencies.len());
+
+            // Read only caches never store entries.
+            let other_key = ProcessorCache::key([8; 32], 1);
+            read_only
+                .insert(&other_key, b"processed", &meta_bytes)
+                .await
+                .unwrap();
+            assert!(cache.get(&other_key).await.unwrap().is_none());
+        });
+        std::fs::remove_dir_all(path).unwrap();
+    }
+}
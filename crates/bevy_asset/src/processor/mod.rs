@@ -26,6 +26,8 @@
 //!
 //! If a default asset processor is set, assets with a matching extension will be processed using that processor before loading.
 //!
+//! To check processed assets for problems, register an [`AssetValidator`] with [`AssetProcessor::register_validator`].
+//!
 //! For an end-to-end example, check out the examples in the [`examples/asset/processing`](https://github.com/bevyengine/bevy/tree/latest/examples/asset/processing) directory of the Bevy repository.
 //!
 //!  # Defining asset processors
@@ -37,11 +39,15 @@
 //!
 //! In most cases, [`LoadTransformAndSave`] should be sufficient.
 
+mod cache;
 mod log;
 mod process;
+mod validate;
 
+pub use cache::*;
 pub use log::*;
 pub use process::*;
+pub use validate::*;
 
 use crate::{
     io::{
@@ -53,23 +59,27 @@ use crate::{
         get_asset_hash, get_full_asset_hash, AssetAction, AssetActionMinimal, AssetHash, AssetMeta,
         AssetMetaDyn, AssetMetaMinimal, ProcessedInfo, ProcessedInfoMinimal,
     },
-    AssetLoadError, AssetMetaCheck, AssetPath, AssetServer, AssetServerMode, DeserializeMetaError,
-    MissingAssetLoaderForExtensionError, UnapprovedPathMode, WriteDefaultMetaError,
+    AssetLoadError, AssetMetaCheck, AssetPath, AssetPlugin, AssetServer, AssetServerMode,
+    DeserializeMetaError, MissingAssetLoaderForExtensionError, UnapprovedPathMode,
+    WriteDefaultMetaError,
+};
+use alloc::{
+    borrow::ToOwned, boxed::Box, collections::VecDeque, string::ToString, sync::Arc, vec, vec::Vec,
 };
-use alloc::{borrow::ToOwned, boxed::Box, collections::VecDeque, sync::Arc, vec, vec::Vec};
 use bevy_ecs::prelude::*;
 use bevy_platform::collections::{HashMap, HashSet};
 use bevy_tasks::IoTaskPool;
+use bevy_utils::TypeIdMap;
+use core::sync::atomic::{AtomicBool, Ordering};
 use futures_io::ErrorKind;
 use futures_lite::{AsyncReadExt, AsyncWriteExt, StreamExt};
 use parking_lot::RwLock;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
-use tracing::{debug, error, trace, warn};
+use tracing::{debug, error, info, trace, warn};
 
 #[cfg(feature = "trace")]
 use {
-    alloc::string::ToString,
     bevy_tasks::ConditionalSendFuture,
     tracing::{info_span, instrument::Instrument},
 };
@@ -102,6 +112,11 @@ pub struct AssetProcessorData {
     processors: RwLock<HashMap<&'static str, Arc<dyn ErasedProcessor>>>,
     /// Default processors for file extensions
     default_processors: RwLock<HashMap<Box<str>, &'static str>>,
+    cache: RwLock<Option<Arc<ProcessorCache>>>,
+    write_migrated_meta: AtomicBool,
+    validators: RwLock<TypeIdMap<Vec<Arc<dyn ErasedAssetValidator>>>>,
+    validation_report: RwLock<ValidationReport>,
+    validation_report_path: RwLock<PathBuf>,
     state: async_lock::RwLock<ProcessorState>,
     sources: AssetSources,
     initialized_sender: async_broadcast::Sender<()>,
@@ -145,6 +160,7 @@ impl AssetProcessor {
         if last_state != ProcessorState::Finished && state == ProcessorState::Finished {
             self.data.finished_sender.broadcast(()).await.unwrap();
         } else if last_state != ProcessorState::Processing && state == ProcessorState::Processing {
+            self.data.validation_report.write().clear();
             self.data.initialized_sender.broadcast(()).await.unwrap();
         }
     }
@@ -534,6 +550,14 @@ impl AssetProcessor {
         self.try_reprocessing_queued().await;
         // clean up metadata in asset server
         self.server.data.infos.write().consume_handle_drop_events();
+        #[cfg(feature = "asset_processor")]
+        if let Err(err) = self
+            .validation_report()
+            .write(&self.validation_report_path())
+            .await
+        {
+            error!("Failed to write the asset validation report: {err}");
+        }
         self.set_state(ProcessorState::Finished).await;
     }
 
@@ -606,6 +630,32 @@ impl AssetProcessor {
         processors.get(processor_type_name).cloned()
     }
 
+    /// Sets the [`ProcessorCache`] used to reuse processed assets across processor runs and machines.
+    pub fn set_cache(&self, cache: ProcessorCache) {
+        *self.data.cache.write() = Some(Arc::new(cache));
+    }
+
+    /// Returns the [`ProcessorCache`] used by this processor, if it is set.
+    pub fn cache(&self) -> Option<Arc<ProcessorCache>> {
+        self.data.cache.read().clone()
+    }
+
+    /// Sets whether `.meta` files whose settings are migrated to the current
+    /// [`AssetLoader::SETTINGS_VERSION`](crate::AssetLoader::SETTINGS_VERSION) or [`Process::SETTINGS_VERSION`] while
+    /// processing are written back to the asset source. This is disabled by default, in which case the migration is
+    /// repeated whenever the asset is processed.
+    pub fn set_write_migrated_meta(&self, write_migrated_meta: bool) {
+        self.data
+            .write_migrated_meta
+            .store(write_migrated_meta, Ordering::Relaxed);
+    }
+
+    /// Returns whether `.meta` files whose settings are migrated while processing are written back to the asset
+    /// source. See [`AssetProcessor::set_write_migrated_meta`].
+    pub fn writes_migrated_meta(&self) -> bool {
+        self.data.write_migrated_meta.load(Ordering::Relaxed)
+    }
+
     /// Populates the initial view of each asset by scanning the unprocessed and processed asset folders.
     /// This info will later be used to determine whether or not to re-process an asset
     ///
@@ -790,6 +840,31 @@ impl AssetProcessor {
         infos.finish_processing(asset_path, result).await;
     }
 
+    /// Writes the `migrated` meta of the asset at `asset_path` back to the `source` if
+    /// [enabled](AssetProcessor::set_write_migrated_meta), and returns it.
+    async fn migrated_meta(
+        &self,
+        source: &AssetSource,
+        asset_path: &AssetPath<'static>,
+        migrated: Vec<u8>,
+    ) -> Vec<u8> {
+        info!("Migrated the settings of the meta of {asset_path} to their current version");
+        if self.writes_migrated_meta() {
+            let result = match source.writer() {
+                Ok(writer) => writer
+                    .write_meta_bytes(asset_path.path(), &migrated)
+                    .await
+                    .map_err(|err| err.to_string()),
+                Err(err) => Err(err.to_string()),
+            };
+            match result {
+                Ok(()) => info!("Wrote the migrated meta of {asset_path} back to its asset source"),
+                Err(err) => error!("Failed to write the migrated meta of {asset_path}: {err}"),
+            }
+        }
+        migrated
+    }
+
     async fn process_asset_internal(
         &self,
         source: &AssetSource,
@@ -815,13 +890,19 @@ impl AssetProcessor {
         let mut byte_reader = reader.read(path).await.map_err(reader_err)?;
 
         let (mut source_meta, meta_bytes, processor) = match reader.read_meta_bytes(path).await {
-            Ok(meta_bytes) => {
+            Ok(mut meta_bytes) => {
                 let minimal: AssetMetaMinimal = ron::de::from_bytes(&meta_bytes).map_err(|e| {
                     ProcessError::DeserializeMetaError(DeserializeMetaError::DeserializeMinimal(e))
                 })?;
                 let (meta, processor) = match minimal.asset {
                     AssetActionMinimal::Load { loader } => {
                         let loader = server.get_asset_loader_with_type_name(&loader).await?;
+                        let migrated = loader
+                            .migrate_meta(&meta_bytes)
+                            .map_err(DeserializeMetaError::from)?;
+                        if let Some(migrated) = migrated {
+                            meta_bytes = self.migrated_meta(source, asset_path, migrated).await;
+                        }
                         let meta = loader.deserialize_meta(&meta_bytes)?;
                         (meta, None)
                     }
@@ -829,6 +910,12 @@ impl AssetProcessor {
                         let processor = self
                             .get_processor(&processor)
                             .ok_or_else(|| ProcessError::MissingProcessor(processor))?;
+                        let migrated = processor
+                            .migrate_meta(&meta_bytes)
+                            .map_err(DeserializeMetaError::from)?;
+                        if let Some(migrated) = migrated {
+                            meta_bytes = self.migrated_meta(source, asset_path, migrated).await;
+                        }
                         let meta = processor.deserialize_meta(&meta_bytes)?;
                         (meta, Some(processor))
                     }
@@ -923,38 +1010,56 @@ impl AssetProcessor {
         // Directly writing to the asset destination in the processor necessitates this behavior
         // TODO: this class of failure can be recovered via re-processing + smarter log validation that allows for duplicate transactions in the event of failures
         self.log_begin_processing(asset_path).await;
-        if let Some(processor) = processor {
-            let mut writer = processed_writer.write(path).await.map_err(writer_err)?;
-            let mut processed_meta = {
-                let mut context =
-                    ProcessContext::new(self, asset_path, &asset_bytes, &mut new_processed_info);
-                processor
-                    .process(&mut context, source_meta, &mut *writer)
+        let mut cache_entry = None;
+        let processed_info = if let Some(processor) = processor {
+            let cache = self
+                .cache()
+                .map(|cache| (ProcessorCache::key(new_hash, processor.version()), cache));
+            if let Some((key, cache)) = &cache
+                && let Some(processed_info) = self
+                    .write_cached_asset(cache, key, asset_path, processed_writer)
                     .await?
-            };
-
-            writer
-                .flush()
-                .await
-                .map_err(|e| ProcessError::AssetWriterError {
-                    path: asset_path.clone(),
-                    err: AssetWriterError::Io(e),
-                })?;
+            {
+                processed_info
+            } else {
+                let mut writer = processed_writer.write(path).await.map_err(writer_err)?;
+                let mut processed_meta = {
+                    let mut context = ProcessContext::new(
+                        self,
+                        asset_path,
+                        &asset_bytes,
+                        &mut new_processed_info,
+                    );
+                    processor
+                        .process(&mut context, source_meta, &mut *writer)
+                        .await?
+                };
 
-            let full_hash = get_full_asset_hash(
-                new_hash,
+                writer
+                    .flush()
+                    .await
+                    .map_err(|e| ProcessError::AssetWriterError {
+                        path: asset_path.clone(),
+                        err: AssetWriterError::Io(e),
+                    })?;
+
+                let full_hash = get_full_asset_hash(
+                    new_hash,
+                    new_processed_info
+                        .process_dependencies
+                        .iter()
+                        .map(|i| i.full_hash),
+                );
+                new_processed_info.full_hash = full_hash;
+                *processed_meta.processed_info_mut() = Some(new_processed_info.clone());
+                let meta_bytes = processed_meta.serialize();
+                processed_writer
+                    .write_meta_bytes(path, &meta_bytes)
+                    .await
+                    .map_err(writer_err)?;
+                cache_entry = cache.map(|(key, cache)| (key, cache, meta_bytes));
                 new_processed_info
-                    .process_dependencies
-                    .iter()
-                    .map(|i| i.full_hash),
-            );
-            new_processed_info.full_hash = full_hash;
-            *processed_meta.processed_info_mut() = Some(new_processed_info.clone());
-            let meta_bytes = processed_meta.serialize();
-            processed_writer
-                .write_meta_bytes(path, &meta_bytes)
-                .await
-                .map_err(writer_err)?;
+            }
         } else {
             processed_writer
                 .write_bytes(path, &asset_bytes)
@@ -966,10 +1071,101 @@ impl AssetProcessor {
                 .write_meta_bytes(path, &meta_bytes)
                 .await
                 .map_err(writer_err)?;
+            new_processed_info
+        };
+
+        // Invalid assets are removed, so that they are not loaded and are processed again on the next run
+        if let Err(err) = self.validate_processed_asset(source, asset_path).await {
+            self.remove_processed_asset_and_meta(source, path).await;
+            self.log_end_processing(asset_path).await;
+            return Err(err);
+        }
+
+        // Only valid assets are stored in the cache
+        if let Some((key, cache, meta_bytes)) = cache_entry
+            && let Err(err) = Self::insert_cached_asset(
+                &cache,
+                &key,
+                source.processed_reader()?,
+                path,
+                &meta_bytes,
+            )
+            .await
+        {
+            warn!("Failed to store processed asset {asset_path} in the processor cache: {err}");
         }
         self.log_end_processing(asset_path).await;
 
-        Ok(ProcessResult::Processed(new_processed_info))
+        Ok(ProcessResult::Processed(processed_info))
+    }
+
+    /// Writes the processed asset stored in the `cache` entry with the given `key` to the `processed_writer`, if the
+    /// entry exists and its process dependencies are unchanged. Returns the [`ProcessedInfo`] of the written asset.
+    async fn write_cached_asset(
+        &self,
+        cache: &ProcessorCache,
+        key: &ProcessorCacheKey,
+        asset_path: &AssetPath<'static>,
+        processed_writer: &dyn ErasedAssetWriter,
+    ) -> Result<Option<ProcessedInfo>, ProcessError> {
+        let entry = match cache.get(key).await {
+            Ok(Some(entry)) => entry,
+            Ok(None) => return Ok(None),
+            Err(err) => {
+                warn!("Failed to read {asset_path} from the processor cache: {err}");
+                return Ok(None);
+            }
+        };
+        for dependency in &entry.processed_info.process_dependencies {
+            self.data
+                .wait_until_processed(dependency.path.clone())
+                .await;
+            let infos = self.data.asset_infos.read().await;
+            let live_hash = infos
+                .get(&dependency.path)
+                .and_then(|i| i.processed_info.as_ref())
+                .map(|i| i.full_hash);
+            if live_hash != Some(dependency.full_hash) {
+                return Ok(None);
+            }
+        }
+
+        let writer_err = |err| ProcessError::AssetWriterError {
+            path: asset_path.clone(),
+            err,
+        };
+        let path = asset_path.path();
+        processed_writer
+            .write_bytes(path, &entry.asset_bytes)
+            .await
+            .map_err(writer_err)?;
+        processed_writer
+            .write_meta_bytes(path, &entry.meta_bytes)
+            .await
+            .map_err(writer_err)?;
+        debug!("Reused {asset_path} from the processor cache");
+        Ok(Some(entry.processed_info))
+    }
+
+    /// Stores the processed asset at `path` in the `cache` entry with the given `key`.
+    async fn insert_cached_asset(
+        cache: &ProcessorCache,
+        key: &ProcessorCacheKey,
+        processed_reader: &dyn ErasedAssetReader,
+        path: &Path,
+        meta_bytes: &[u8],
+    ) -> Result<(), ProcessorCacheError> {
+        if cache.is_read_only() {
+            return Ok(());
+        }
+        let mut asset_bytes = Vec::new();
+        processed_reader
+            .read(path)
+            .await?
+            .read_to_end(&mut asset_bytes)
+            .await
+            .map_err(|err| AssetReaderError::Io(err.into()))?;
+        cache.insert(key, &asset_bytes, meta_bytes).await
     }
 
     async fn validate_transaction_log_and_recover(&self) {
@@ -1079,6 +1275,13 @@ impl AssetProcessorData {
             processors: Default::default(),
             asset_infos: Default::default(),
             default_processors: Default::default(),
+            cache: Default::default(),
+            write_migrated_meta: AtomicBool::new(false),
+            validators: Default::default(),
+            validation_report: Default::default(),
+            validation_report_path: RwLock::new(ValidationReport::path_for(Path::new(
+                AssetPlugin::DEFAULT_PROCESSED_FILE_PATH,
+            ))),
         }
     }
 
@@ -1144,6 +1347,8 @@ struct InstrumentedAssetProcessor<T>(T);
 impl<T: Process> Process for InstrumentedAssetProcessor<T> {
     type Settings = T::Settings;
     type OutputLoader = T::OutputLoader;
+    const VERSION: u32 = T::VERSION;
+    const SETTINGS_VERSION: u32 = T::SETTINGS_VERSION;
 
     fn process(
         &self,
@@ -1156,6 +1361,7 @@ impl<T: Process> Process for InstrumentedAssetProcessor<T> {
         // Change the processor type for the `AssetMeta`, which works because we share the `Settings` type.
         let meta = AssetMeta {
             meta_format_version: meta.meta_format_version,
+            settings_version: meta.settings_version,
             processed_info: meta.processed_info,
             asset: meta.asset,
         };
@@ -1166,6 +1372,13 @@ impl<T: Process> Process for InstrumentedAssetProcessor<T> {
         );
         self.0.process(context, meta, writer).instrument(span)
     }
+
+    fn migrate_settings(
+        from_version: u32,
+        settings: &str,
+    ) -> Result<alloc::string::String, Box<dyn core::error::Error + Send + Sync>> {
+        T::migrate_settings(from_version, settings)
+    }
 }
 
 /// The (successful) result of processing an asset
@@ -1181,6 +1394,9 @@ pub enum ProcessResult {
 pub enum ProcessStatus {
     Processed,
     Failed,
+    /// The asset was processed, but an [`AssetValidator`] reported errors, so its processed asset was removed.
+    /// See [`AssetProcessor::validation_report`].
+    Invalid,
     NonExistent,
 }
 
@@ -1338,6 +1554,35 @@ impl ProcessorAssetInfos {
                 // if there is no asset source, no processing can be done
                 trace!("No need to process asset {asset_path} because it does not exist");
             }
+            Err(ProcessError::InvalidAsset {
+                issues,
+                missing_dependencies,
+                ..
+            }) => {
+                for issue in issues {
+                    let label = issue.label.map(|label| ["#", &label].concat());
+                    error!(
+                        "Validation of {asset_path}{} reported an error ({}): {}",
+                        label.unwrap_or_default(),
+                        issue.validator,
+                        issue.message
+                    );
+                }
+                // the processed asset was removed, so it must not be skipped when it is reprocessed
+                let old_processed_info = self
+                    .infos
+                    .get_mut(&asset_path)
+                    .and_then(|i| i.processed_info.take());
+                if let Some(old_processed_info) = old_processed_info {
+                    self.clear_dependencies(&asset_path, old_processed_info);
+                }
+                // make sure this is reprocessed once the missing dependencies are added
+                for dependency in missing_dependencies {
+                    self.add_dependent(&dependency, asset_path.to_owned());
+                }
+                let info = self.get_mut(&asset_path).expect("info should exist");
+                info.update_status(ProcessStatus::Invalid).await;
+            }
             Err(err) => {
                 error!("Failed to process asset {asset_path}: {err}");
                 // if this failed because a dependency could not be loaded, make sure it is reprocessed if that dependency is reprocessed
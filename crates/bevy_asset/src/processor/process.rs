@@ -3,8 +3,11 @@ use crate::{
         AssetReaderError, AssetWriterError, MissingAssetWriterError,
         MissingProcessedAssetReaderError, MissingProcessedAssetWriterError, SliceReader, Writer,
     },
-    meta::{AssetAction, AssetMeta, AssetMetaDyn, ProcessDependencyInfo, ProcessedInfo, Settings},
-    processor::AssetProcessor,
+    meta::{
+        migrate_meta, AssetAction, AssetMeta, AssetMetaDyn, MetaMigrationError,
+        ProcessDependencyInfo, ProcessedInfo, Settings,
+    },
+    processor::{AssetProcessor, ValidationIssue},
     saver::{AssetSaver, SavedAsset},
     transformer::{AssetTransformer, IdentityAssetTransformer, TransformedAsset},
     AssetLoadError, AssetLoader, AssetPath, DeserializeMetaError, ErasedLoadedAsset,
@@ -14,9 +17,11 @@ use alloc::{
     borrow::ToOwned,
     boxed::Box,
     string::{String, ToString},
+    vec::Vec,
 };
 use bevy_tasks::{BoxedFuture, ConditionalSendFuture};
 use core::marker::PhantomData;
+use ron::value::RawValue;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -30,6 +35,12 @@ pub trait Process: Send + Sync + Sized + 'static {
     type Settings: Settings + Default + Serialize + for<'a> Deserialize<'a>;
     /// The [`AssetLoader`] that will be used to load the final processed asset.
     type OutputLoader: AssetLoader;
+    /// The version of this processor. Increment it whenever a change to the processor changes its output, so that
+    /// assets processed by previous versions are not reused from the [`ProcessorCache`](super::ProcessorCache).
+    const VERSION: u32 = 0;
+    /// The version of [`Process::Settings`]. Increment it whenever a change to the settings type breaks the existing
+    /// `.meta` files, and migrate the settings of the previous version in [`Process::migrate_settings`].
+    const SETTINGS_VERSION: u32 = 0;
     /// Processes the asset stored on `context` in some way using the settings stored on `meta`. The results are written to `writer`. The
     /// final written processed asset is loadable using [`Process::OutputLoader`]. This load will use the returned [`AssetLoader::Settings`].
     fn process(
@@ -40,6 +51,16 @@ pub trait Process: Send + Sync + Sized + 'static {
     ) -> impl ConditionalSendFuture<
         Output = Result<<Self::OutputLoader as AssetLoader>::Settings, ProcessError>,
     >;
+
+    /// Migrates `settings`, the RON serialization of version `from_version` of [`Process::Settings`], to version
+    /// `from_version + 1`. This works like [`AssetLoader::migrate_settings`]. By default, migrating fails.
+    fn migrate_settings(
+        from_version: u32,
+        settings: &str,
+    ) -> Result<String, Box<dyn core::error::Error + Send + Sync>> {
+        let _ = (from_version, settings);
+        Err("this processor does not support migrating its settings".into())
+    }
 }
 
 /// A flexible [`Process`] implementation that loads the source [`Asset`] using the `L` [`AssetLoader`], then transforms
@@ -158,6 +179,15 @@ pub enum ProcessError {
     AssetTransformError(Box<dyn core::error::Error + Send + Sync + 'static>),
     #[error("Assets without extensions are not supported.")]
     ExtensionRequired,
+    #[error("The processed asset '{path}' failed validation with {} error(s)", issues.len())]
+    #[from(ignore)]
+    InvalidAsset {
+        path: AssetPath<'static>,
+        /// The [`ValidationSeverity::Error`](super::ValidationSeverity::Error) issues reported for the asset.
+        issues: Vec<ValidationIssue>,
+        /// The dependencies of the asset that do not exist.
+        missing_dependencies: Vec<AssetPath<'static>>,
+    },
 }
 
 impl<Loader, Transformer, Saver> Process for LoadTransformAndSave<Loader, Transformer, Saver>
@@ -169,6 +199,8 @@ where
     type Settings =
         LoadTransformAndSaveSettings<Loader::Settings, Transformer::Settings, Saver::Settings>;
     type OutputLoader = Saver::OutputLoader;
+    /// Only the [`AssetLoader::Settings`] are versioned, so this matches [`AssetLoader::SETTINGS_VERSION`].
+    const SETTINGS_VERSION: u32 = Loader::SETTINGS_VERSION;
 
     async fn process(
         &self,
@@ -204,6 +236,23 @@ where
             .map_err(|error| ProcessError::AssetSaveError(error.into()))?;
         Ok(output_settings)
     }
+
+    /// Migrates the nested `loader_settings` with [`AssetLoader::migrate_settings`], leaving the transformer and
+    /// saver settings untouched.
+    fn migrate_settings(
+        from_version: u32,
+        settings: &str,
+    ) -> Result<String, Box<dyn core::error::Error + Send + Sync>> {
+        let mut settings: LoadTransformAndSaveSettings<
+            Box<RawValue>,
+            Box<RawValue>,
+            Box<RawValue>,
+        > = ron::de::from_str(settings)?;
+        let loader_settings =
+            Loader::migrate_settings(from_version, settings.loader_settings.get_ron())?;
+        settings.loader_settings = RawValue::from_boxed_ron(loader_settings.into_boxed_str())?;
+        Ok(ron::ser::to_string(&settings)?)
+    }
 }
 
 /// A type-erased variant of [`Process`] that enables interacting with processor implementations without knowing
@@ -218,9 +267,16 @@ pub trait ErasedProcessor: Send + Sync {
     ) -> BoxedFuture<'a, Result<Box<dyn AssetMetaDyn>, ProcessError>>;
     /// Deserialized `meta` as type-erased [`AssetMeta`], operating under the assumption that it matches the meta
     /// for the underlying [`Process`] impl.
+    ///
+    /// If the settings of `meta` are of an older version, they are migrated first, see [`ErasedProcessor::migrate_meta`].
     fn deserialize_meta(&self, meta: &[u8]) -> Result<Box<dyn AssetMetaDyn>, DeserializeMetaError>;
+    /// Migrates the settings of the input `meta` bytes to [`Process::SETTINGS_VERSION`], returning the migrated meta
+    /// bytes, or [`None`] if the settings are already up to date.
+    fn migrate_meta(&self, meta: &[u8]) -> Result<Option<Vec<u8>>, MetaMigrationError>;
     /// Returns the default type-erased [`AssetMeta`] for the underlying [`Process`] impl.
     fn default_meta(&self) -> Box<dyn AssetMetaDyn>;
+    /// Returns the [`Process::VERSION`] of the underlying [`Process`] impl.
+    fn version(&self) -> u32;
 }
 
 impl<P: Process> ErasedProcessor for P {
@@ -245,16 +301,31 @@ impl<P: Process> ErasedProcessor for P {
     }
 
     fn deserialize_meta(&self, meta: &[u8]) -> Result<Box<dyn AssetMetaDyn>, DeserializeMetaError> {
-        let meta: AssetMeta<(), P> = ron::de::from_bytes(meta)?;
+        let meta: AssetMeta<(), P> = match self.migrate_meta(meta)? {
+            Some(migrated) => ron::de::from_bytes(&migrated)?,
+            None => ron::de::from_bytes(meta)?,
+        };
         Ok(Box::new(meta))
     }
 
+    fn migrate_meta(&self, meta: &[u8]) -> Result<Option<Vec<u8>>, MetaMigrationError> {
+        migrate_meta(
+            meta,
+            core::any::type_name::<P>(),
+            P::SETTINGS_VERSION,
+            P::migrate_settings,
+        )
+    }
+
     fn default_meta(&self) -> Box<dyn AssetMetaDyn> {
         Box::new(AssetMeta::<(), P>::new(AssetAction::Process {
             processor: core::any::type_name::<P>().to_string(),
             settings: P::Settings::default(),
         }))
     }
+    fn version(&self) -> u32 {
+        P::VERSION
+    }
 }
 
 /// Provides scoped data access to the [`AssetProcessor`].
@@ -0,0 +1,468 @@
+use crate::{
+    io::{AssetReaderError, AssetSource, VecReader},
+    meta::{AssetActionMinimal, AssetMetaMinimal},
+    processor::{AssetProcessor, ProcessError},
+    Asset, AssetPath, DeserializeMetaError, ErasedLoadedAsset,
+};
+use alloc::{
+    borrow::ToOwned,
+    collections::BTreeMap,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use bevy_platform::collections::HashSet;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+/// The file name of the [`ValidationReport`], written next to the folder of the processed assets.
+const VALIDATION_REPORT_FILE_NAME: &str = "validation_report.json";
+
+/// The name reported for issues found by the built-in check for missing asset dependencies.
+const MISSING_DEPENDENCY_VALIDATOR: &str = "missing_dependency";
+
+/// Checks processed assets of type [`AssetValidator::Asset`] for problems, such as textures that are not a
+/// power of two when they need to be compressed, or meshes without normals.
+///
+/// After an asset is processed, the [`AssetProcessor`] loads the processed asset and runs every validator registered
+/// for its type (and the types of its labeled assets) with [`AssetProcessor::register_validator`] or
+/// [`AssetApp::register_asset_validator`](crate::AssetApp::register_asset_validator). Validators report
+/// [`ValidationSeverity::Warning`]s and [`ValidationSeverity::Error`]s through the [`ValidationContext`].
+///
+/// Warnings are logged. If any error is reported, the asset is considered [`ProcessStatus::Invalid`](super::ProcessStatus::Invalid):
+/// its processed asset is removed, so it will not be loaded by the app and it will be processed again on the next run
+/// of the processor. All issues are collected in the processor's [`ValidationReport`].
+///
+/// In addition to registered validators, the processor reports an error for every dependency of a validated asset
+/// that does not exist in its [`AssetSource`].
+pub trait AssetValidator: Send + Sync + 'static {
+    /// The type of [`Asset`] validated by this validator.
+    type Asset: Asset;
+
+    /// Checks the given `asset`, reporting problems to the `context`.
+    fn validate(&self, asset: &Self::Asset, context: &mut ValidationContext);
+}
+
+/// A type-erased variant of [`AssetValidator`], used by the [`AssetProcessor`] to run validators for loaded assets.
+pub trait ErasedAssetValidator: Send + Sync + 'static {
+    /// Runs the validator on the given `asset`, if it is of the validated type.
+    fn validate(&self, asset: &ErasedLoadedAsset, context: &mut ValidationContext);
+
+    /// The type name of the [`AssetValidator`].
+    fn type_name(&self) -> &'static str;
+}
+
+impl<V: AssetValidator> ErasedAssetValidator for V {
+    fn validate(&self, asset: &ErasedLoadedAsset, context: &mut ValidationContext) {
+        if let Some(asset) = asset.get::<V::Asset>() {
+            AssetValidator::validate(self, asset, context);
+        }
+    }
+
+    fn type_name(&self) -> &'static str {
+        core::any::type_name::<V>()
+    }
+}
+
+/// Collects the issues found by an [`AssetValidator`] in a single asset.
+pub struct ValidationContext<'a> {
+    path: &'a AssetPath<'static>,
+    validator: &'a str,
+    issues: &'a mut Vec<ValidationIssue>,
+}
+
+impl<'a> ValidationContext<'a> {
+    /// The path of the validated asset. Labeled assets are validated with the path of their label.
+    pub fn path(&self) -> &AssetPath<'static> {
+        self.path
+    }
+
+    /// Reports a problem that does not prevent the asset from being used.
+    pub fn warn(&mut self, message: impl Into<String>) {
+        self.report(ValidationSeverity::Warning, message.into());
+    }
+
+    /// Reports a problem that makes the asset invalid.
+    pub fn error(&mut self, message: impl Into<String>) {
+        self.report(ValidationSeverity::Error, message.into());
+    }
+
+    fn report(&mut self, severity: ValidationSeverity, message: String) {
+        self.issues.push(ValidationIssue {
+            severity,
+            label: self.path.label().map(ToOwned::to_owned),
+            validator: self.validator.to_owned(),
+            message,
+        });
+    }
+}
+
+/// The severity of a [`ValidationIssue`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ValidationSeverity {
+    /// The asset can still be used.
+    Warning,
+    /// The asset is invalid.
+    Error,
+}
+
+/// A problem found while validating a processed asset.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    /// The severity of this issue.
+    pub severity: ValidationSeverity,
+    /// The label of the asset this issue was found in, if it is a labeled asset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// The type name of the [`AssetValidator`] that reported this issue.
+    pub validator: String,
+    /// A description of the issue.
+    pub message: String,
+}
+
+/// The issues found by the validation stage of the [`AssetProcessor`] during a processing run, grouped by asset path.
+///
+/// A new report is started whenever the processor starts processing assets, either on startup or after changes to
+/// source assets. Assets that are unchanged since the last run are not processed, and therefore not validated again.
+/// Assets without any issue are not included.
+///
+/// With the `asset_processor` feature, the report of each run is written as JSON to the
+/// [`AssetProcessor::validation_report_path`], which is next to the folder of the processed assets by default
+/// (`imported_assets/validation_report.json`).
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    assets: BTreeMap<String, Vec<ValidationIssue>>,
+}
+
+impl ValidationReport {
+    /// Returns the issues found in the asset at the given `path`.
+    pub fn get<'a>(&self, path: impl Into<AssetPath<'a>>) -> &[ValidationIssue] {
+        self.assets
+            .get(&path.into().to_string())
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Iterates over the paths of the assets with issues and their issues.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[ValidationIssue])> {
+        self.assets
+            .iter()
+            .map(|(path, issues)| (path.as_str(), issues.as_slice()))
+    }
+
+    /// Returns true if no issues were found.
+    pub fn is_empty(&self) -> bool {
+        self.assets.is_empty()
+    }
+
+    /// Returns the number of issues with the given `severity`.
+    pub fn count(&self, severity: ValidationSeverity) -> usize {
+        self.assets
+            .values()
+            .flatten()
+            .filter(|issue| issue.severity == severity)
+            .count()
+    }
+
+    /// Returns true if any asset is invalid.
+    pub fn has_errors(&self) -> bool {
+        self.count(ValidationSeverity::Error) > 0
+    }
+
+    /// Serializes this report to pretty-printed JSON.
+    #[cfg(feature = "asset_processor")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("validation reports are always serializable")
+    }
+
+    /// Replaces the issues of the asset at the given `path`.
+    pub(crate) fn set(&mut self, path: &AssetPath<'_>, issues: Vec<ValidationIssue>) {
+        if issues.is_empty() {
+            self.assets.remove(&path.to_string());
+        } else {
+            self.assets.insert(path.to_string(), issues);
+        }
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.assets.clear();
+    }
+
+    /// Returns the path of the report for processed assets written to `processed_file_path`, which is relative to
+    /// the base path of the app like the paths of the [`AssetPlugin`](crate::AssetPlugin).
+    pub(crate) fn path_for(processed_file_path: &Path) -> PathBuf {
+        #[cfg(not(target_arch = "wasm32"))]
+        let base_path = crate::io::file::get_base_path();
+        #[cfg(target_arch = "wasm32")]
+        let base_path = PathBuf::new();
+        let processed_path = base_path.join(processed_file_path);
+        processed_path
+            .parent()
+            .unwrap_or(&processed_path)
+            .join(VALIDATION_REPORT_FILE_NAME)
+    }
+
+    /// Writes this report to the given `path`.
+    #[cfg(feature = "asset_processor")]
+    pub(crate) async fn write(&self, path: &Path) -> Result<(), futures_io::Error> {
+        if let Some(parent) = path.parent() {
+            async_fs::create_dir_all(parent).await?;
+        }
+        async_fs::write(path, self.to_json()).await
+    }
+}
+
+impl AssetProcessor {
+    /// Registers the given `validator`, which will validate every processed asset of type [`AssetValidator::Asset`].
+    pub fn register_validator<V: AssetValidator>(&self, validator: V) {
+        self.data
+            .validators
+            .write()
+            .entry(core::any::TypeId::of::<V::Asset>())
+            .or_default()
+            .push(Arc::new(validator));
+    }
+
+    /// Returns the [`ValidationReport`] of the current (or last) processing run.
+    pub fn validation_report(&self) -> ValidationReport {
+        self.data.validation_report.read().clone()
+    }
+
+    /// Sets the path the [`ValidationReport`] is written to at the end of each processing run.
+    ///
+    /// The [`AssetPlugin`](crate::AssetPlugin) sets it next to its
+    /// [`processed_file_path`](crate::AssetPlugin::processed_file_path).
+    pub fn set_validation_report_path(&self, path: impl Into<PathBuf>) {
+        *self.data.validation_report_path.write() = path.into();
+    }
+
+    /// Returns the path the [`ValidationReport`] is written to. See [`AssetProcessor::set_validation_report_path`].
+    pub fn validation_report_path(&self) -> PathBuf {
+        self.data.validation_report_path.read().clone()
+    }
+
+    /// Loads the processed asset at `asset_path` and runs the registered validators on it and its labeled assets,
+    /// recording the issues in the [`ValidationReport`]. Returns [`ProcessError::InvalidAsset`] if any error is found.
+    ///
+    /// The dependencies of the asset are checked even if no validator is registered.
+    pub(crate) async fn validate_processed_asset(
+        &self,
+        source: &AssetSource,
+        asset_path: &AssetPath<'static>,
+    ) -> Result<(), ProcessError> {
+        let path = asset_path.path();
+        let reader = source.processed_reader()?;
+        let meta_bytes =
+            reader
+                .read_meta_bytes(path)
+                .await
+                .map_err(|err| ProcessError::ReadAssetMetaError {
+                    path: asset_path.clone(),
+                    err,
+                })?;
+        let minimal: AssetMetaMinimal = ron::de::from_bytes(&meta_bytes).map_err(|e| {
+            ProcessError::DeserializeMetaError(DeserializeMetaError::DeserializeMinimal(e))
+        })?;
+        let AssetActionMinimal::Load { loader } = minimal.asset else {
+            return Ok(());
+        };
+        let loader = self.server.get_asset_loader_with_type_name(&loader).await?;
+        let meta = loader.deserialize_meta(&meta_bytes)?;
+        let reader_err = |err| ProcessError::AssetReaderError {
+            path: asset_path.clone(),
+            err,
+        };
+        let mut asset_bytes = Vec::new();
+        crate::io::Reader::read_to_end(
+            &mut reader.read(path).await.map_err(reader_err)?,
+            &mut asset_bytes,
+        )
+        .await
+        .map_err(|err| reader_err(AssetReaderError::Io(err.into())))?;
+        let loaded_asset = self
+            .server
+            .load_with_meta_loader_and_reader(
+                asset_path,
+                &*meta,
+                &*loader,
+                &mut VecReader::new(asset_bytes),
+                false,
+                false,
+            )
+            .await?;
+
+        let mut issues = Vec::new();
+        self.run_validators(asset_path, &loaded_asset, &mut issues);
+        for label in loaded_asset.iter_labels() {
+            let labeled_asset = loaded_asset.get_labeled(label.to_owned()).unwrap();
+            let labeled_path = asset_path.clone().with_label(label.to_owned());
+            self.run_validators(&labeled_path, labeled_asset, &mut issues);
+        }
+
+        let missing_dependencies = self.check_dependencies(&loaded_asset, &mut issues).await;
+
+        let mut errors = 0;
+        for issue in &issues {
+            match issue.severity {
+                ValidationSeverity::Warning => {
+                    let label = issue.label.as_deref().map(|l| ["#", l].concat());
+                    warn!(
+                        "Validation of {asset_path}{} reported a warning ({}): {}",
+                        label.unwrap_or_default(),
+                        issue.validator,
+                        issue.message
+                    );
+                }
+                ValidationSeverity::Error => errors += 1,
+            }
+        }
+        self.data
+            .validation_report
+            .write()
+            .set(asset_path, issues.clone());
+
+        if errors > 0 {
+            return Err(ProcessError::InvalidAsset {
+                path: asset_path.clone(),
+                issues: issues
+                    .into_iter()
+                    .filter(|issue| issue.severity == ValidationSeverity::Error)
+                    .collect(),
+                missing_dependencies,
+            });
+        }
+        Ok(())
+    }
+
+    fn run_validators(
+        &self,
+        path: &AssetPath<'static>,
+        asset: &ErasedLoadedAsset,
+        issues: &mut Vec<ValidationIssue>,
+    ) {
+        let validators = self
+            .data
+            .validators
+            .read()
+            .get(&asset.asset_type_id())
+            .cloned()
+            .unwrap_or_default();
+        for validator in validators {
+            let mut context = ValidationContext {
+                path,
+                validator: validator.type_name(),
+                issues,
+            };
+            validator.validate(asset, &mut context);
+        }
+    }
+
+    /// Reports an error for every dependency of `loaded_asset` (and its labeled assets) whose source file does not
+    /// exist. Returns the paths of the missing dependencies.
+    async fn check_dependencies(
+        &self,
+        loaded_asset: &ErasedLoadedAsset,
+        issues: &mut Vec<ValidationIssue>,
+    ) -> Vec<AssetPath<'static>> {
+        let mut dependencies = HashSet::new();
+        let mut collect = |asset: &ErasedLoadedAsset| {
+            for id in &asset.dependencies {
+                if let Some(path) = self.server.get_path(*id) {
+                    dependencies.insert(path.without_label().into_owned());
+                }
+            }
+        };
+        collect(loaded_asset);
+        for label in loaded_asset.iter_labels() {
+            collect(loaded_asset.get_labeled(label.to_owned()).unwrap());
+        }
+
+        let mut dependencies = dependencies.into_iter().collect::<Vec<_>>();
+        dependencies.sort_by_key(ToString::to_string);
+        let mut missing_dependencies = Vec::new();
+        for dependency in dependencies {
+            let exists = match self.get_source(dependency.source()) {
+                Ok(source) => !matches!(
+                    source.reader().read(dependency.path()).await,
+                    Err(AssetReaderError::NotFound(_))
+                ),
+                Err(_) => false,
+            };
+            if !exists {
+                issues.push(ValidationIssue {
+                    severity: ValidationSeverity::Error,
+                    label: None,
+                    validator: MISSING_DEPENDENCY_VALIDATOR.to_owned(),
+                    message: alloc::format!("The dependency {dependency} does not exist"),
+                });
+                missing_dependencies.push(dependency);
+            }
+        }
+        missing_dependencies
+    }
+}
+
+#[cfg(all(test, feature = "asset_processor"))]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn validation_report() {
+        let path = AssetPath::from("models/ship.gltf");
+        let mut issues = Vec::new();
+        let mut context = ValidationContext {
+            path: &path,
+            validator: "NormalsValidator",
+            issues: &mut issues,
+        };
+        context.warn("mesh has no normals");
+        let labeled_path = path.clone().with_label("Mesh0");
+        let mut context = ValidationContext {
+            path: &labeled_path,
+            validator: "NormalsValidator",
+            issues: &mut issues,
+        };
+        context.error("mesh has no positions");
+
+        let mut report = ValidationReport::default();
+        report.set(&path, issues);
+        report.set(&AssetPath::from("valid.png"), vec![]);
+        assert!(report.has_errors());
+        assert_eq!(report.count(ValidationSeverity::Warning), 1);
+        assert_eq!(report.iter().count(), 1);
+        assert_eq!(report.get("valid.png"), &[]);
+        assert_eq!(report.get(&path)[1].label.as_deref(), Some("Mesh0"));
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "assets": {
+                    "models/ship.gltf": [
+                        {
+                            "severity": "warning",
+                            "validator": "NormalsValidator",
+                            "message": "mesh has no normals",
+                        },
+                        {
+                            "severity": "error",
+                            "label": "Mesh0",
+                            "validator": "NormalsValidator",
+                            "message": "mesh has no positions",
+                        },
+                    ]
+                }
+            })
+        );
+        assert_eq!(
+            serde_json::from_str::<ValidationReport>(&report.to_json()).unwrap(),
+            report
+        );
+
+        report.set(&path, vec![]);
+        assert!(report.is_empty());
+    }
+}
@@ -2,9 +2,15 @@ use alloc::boxed::Box;
 use core::any::{Any, TypeId};
 
 use bevy_ecs::world::{unsafe_world_cell::UnsafeWorldCell, World};
-use bevy_reflect::{FromReflect, FromType, PartialReflect, Reflect};
+use bevy_reflect::{
+    serde::{ReflectDeserializerProcessor, ReflectSerializerProcessor},
+    FromReflect, FromType, PartialReflect, Reflect, TypeRegistration, TypeRegistry,
+};
+use serde::{de::Error as _, ser::Error as _, Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::{Asset, AssetId, Assets, Handle, UntypedAssetId, UntypedHandle};
+use crate::{
+    Asset, AssetId, AssetPath, AssetServer, Assets, Handle, UntypedAssetId, UntypedHandle,
+};
 
 /// Type data for the [`TypeRegistry`](bevy_reflect::TypeRegistry) used to operate on reflected [`Asset`]s.
 ///
@@ -256,15 +262,165 @@ impl<A: Asset> FromType<Handle<A>> for ReflectHandle {
     }
 }
 
+/// A [`ReflectSerializerProcessor`] writing asset handles as the [`AssetPath`] of their asset.
+///
+/// Handles are found through their [`ReflectHandle`] type data, which is registered for the `Handle<A>` of every
+/// asset registered with [`register_asset_reflect`](crate::AssetApp::register_asset_reflect). Serializing a handle to
+/// an asset without a path, such as an asset added at runtime with [`Assets::add`], fails.
+///
+/// Use the [`HandleDeserializerProcessor`] to load the assets again when deserializing.
+///
+/// ```
+/// # use bevy_asset::{ron, Asset, Handle, HandleSerializerProcessor};
+/// # use bevy_reflect::{serde::ReflectSerializer, Reflect, TypeRegistry};
+/// # #[derive(Asset, Reflect)]
+/// # struct Image;
+/// #[derive(Reflect)]
+/// struct Sprite {
+///     image: Handle<Image>,
+/// }
+///
+/// fn save(sprite: &Sprite, registry: &TypeRegistry) -> String {
+///     let processor = HandleSerializerProcessor;
+///     let serializer = ReflectSerializer::with_processor(sprite, registry, &processor);
+///     ron::to_string(&serializer).unwrap()
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HandleSerializerProcessor;
+
+impl HandleSerializerProcessor {
+    /// Serializes `value` as the [`AssetPath`] of its asset if it is a handle, like
+    /// [`ReflectSerializerProcessor::try_serialize`], after passing the path through `map_path`.
+    ///
+    /// This allows other processors to change the written path of some handles, while keeping the same format.
+    pub fn try_serialize_mapped<S>(
+        value: &dyn PartialReflect,
+        registry: &TypeRegistry,
+        serializer: S,
+        map_path: impl FnOnce(&UntypedHandle, &AssetPath<'static>) -> AssetPath<'static>,
+    ) -> Result<Result<S::Ok, S>, S::Error>
+    where
+        S: Serializer,
+    {
+        let Some(value) = value.try_as_reflect() else {
+            return Ok(Err(serializer));
+        };
+        let Some(reflect_handle) =
+            registry.get_type_data::<ReflectHandle>(value.reflect_type_info().type_id())
+        else {
+            return Ok(Err(serializer));
+        };
+        let handle = reflect_handle
+            .downcast_handle_untyped(value.as_any())
+            .expect("`ReflectHandle` is only registered for handles");
+        let Some(path) = handle.path() else {
+            return Err(S::Error::custom(format_args!(
+                "the asset {} has no path and cannot be serialized by path",
+                handle.id()
+            )));
+        };
+        map_path(&handle, path).serialize(serializer).map(Ok)
+    }
+}
+
+impl ReflectSerializerProcessor for HandleSerializerProcessor {
+    fn try_serialize<S>(
+        &self,
+        value: &dyn PartialReflect,
+        registry: &TypeRegistry,
+        serializer: S,
+    ) -> Result<Result<S::Ok, S>, S::Error>
+    where
+        S: Serializer,
+    {
+        Self::try_serialize_mapped(value, registry, serializer, |_, path| path.clone())
+    }
+}
+
+/// A [`ReflectDeserializerProcessor`] reading asset handles written by the [`HandleSerializerProcessor`], loading their
+/// asset with the [`AssetServer`].
+///
+/// ```
+/// # use bevy_asset::{ron, Asset, AssetServer, Handle, HandleDeserializerProcessor};
+/// # use bevy_reflect::{serde::TypedReflectDeserializer, FromReflect, Reflect, TypeRegistry};
+/// # use serde::de::DeserializeSeed;
+/// # #[derive(Asset, Reflect)]
+/// # struct Image;
+/// #[derive(Reflect)]
+/// struct Sprite {
+///     image: Handle<Image>,
+/// }
+///
+/// fn load(ron: &str, registry: &TypeRegistry, asset_server: &AssetServer) -> Sprite {
+///     let mut processor = HandleDeserializerProcessor::new(asset_server);
+///     let deserializer = TypedReflectDeserializer::with_processor(
+///         registry.get(core::any::TypeId::of::<Sprite>()).unwrap(),
+///         registry,
+///         &mut processor,
+///     );
+///     let value = deserializer
+///         .deserialize(&mut ron::Deserializer::from_str(ron).unwrap())
+///         .unwrap();
+///     Sprite::from_reflect(value.as_partial_reflect()).unwrap()
+/// }
+/// ```
+#[derive(Clone)]
+pub struct HandleDeserializerProcessor {
+    asset_server: AssetServer,
+}
+
+impl HandleDeserializerProcessor {
+    /// Creates a [`HandleDeserializerProcessor`] loading assets with the given [`AssetServer`].
+    pub fn new(asset_server: &AssetServer) -> Self {
+        Self {
+            asset_server: asset_server.clone(),
+        }
+    }
+}
+
+impl ReflectDeserializerProcessor for HandleDeserializerProcessor {
+    fn try_deserialize<'de, D>(
+        &mut self,
+        registration: &TypeRegistration,
+        _registry: &TypeRegistry,
+        deserializer: D,
+    ) -> Result<Result<Box<dyn PartialReflect>, D>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let Some(reflect_handle) = registration.data::<ReflectHandle>() else {
+            return Ok(Err(deserializer));
+        };
+        let path = AssetPath::deserialize(deserializer)?;
+        let Some(handle) = self
+            .asset_server
+            .load_erased(path.clone(), reflect_handle.asset_type_id())
+        else {
+            return Err(D::Error::custom(format_args!(
+                "the asset path {path} is unapproved and cannot be loaded"
+            )));
+        };
+        Ok(Ok(reflect_handle.typed(handle).into_partial_reflect()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::{string::String, vec::Vec};
     use core::any::TypeId;
 
-    use crate::{Asset, AssetApp, AssetPlugin, ReflectAsset};
-    use bevy_app::App;
+    use crate::{
+        Asset, AssetApp, AssetPlugin, AssetServer, Assets, Handle, HandleDeserializerProcessor,
+        HandleSerializerProcessor, ReflectAsset,
+    };
+    use bevy_app::{App, TaskPoolPlugin};
     use bevy_ecs::reflect::AppTypeRegistry;
-    use bevy_reflect::Reflect;
+    use bevy_reflect::{
+        serde::{ReflectSerializer, TypedReflectDeserializer},
+        FromReflect, Reflect,
+    };
+    use serde::de::DeserializeSeed;
 
     #[derive(Asset, Reflect)]
     struct AssetType {
@@ -316,4 +472,64 @@ mod tests {
         reflect_asset.remove(app.world_mut(), id).unwrap();
         assert_eq!(reflect_asset.len(app.world()), 0);
     }
+
+    #[derive(Reflect)]
+    struct Holder {
+        handle: Handle<AssetType>,
+    }
+
+    #[test]
+    fn handles_serialize_as_paths() {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+            .init_asset::<AssetType>()
+            .register_asset_reflect::<AssetType>()
+            .register_type::<Holder>();
+
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let type_registry = app.world().resource::<AppTypeRegistry>().clone();
+        let type_registry = type_registry.read();
+        let serialize = |holder: &Holder| {
+            let serializer = ReflectSerializer::with_processor(
+                holder,
+                &type_registry,
+                &HandleSerializerProcessor,
+            );
+            ron::to_string(&serializer)
+        };
+        let deserialize = |input: &str| {
+            let mut processor = HandleDeserializerProcessor::new(&asset_server);
+            let deserializer = TypedReflectDeserializer::with_processor(
+                type_registry.get(TypeId::of::<Holder>()).unwrap(),
+                &type_registry,
+                &mut processor,
+            );
+            deserializer
+                .deserialize(&mut ron::Deserializer::from_str(input).unwrap())
+                .map(|value| Holder::from_reflect(value.as_partial_reflect()).unwrap())
+        };
+
+        let holder = Holder {
+            handle: asset_server.load("data/asset.txt"),
+        };
+        let serialized = serialize(&holder).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"bevy_asset::reflect::tests::Holder":(handle:"data/asset.txt")}"#
+        );
+
+        let deserialized = deserialize(r#"(handle:"data/asset.txt")"#).unwrap();
+        assert_eq!(deserialized.handle, holder.handle);
+        assert!(deserialize(r#"(handle:"../outside.txt")"#).is_err());
+
+        let holder = Holder {
+            handle: app
+                .world_mut()
+                .resource_mut::<Assets<AssetType>>()
+                .add(AssetType {
+                    field: "runtime".into(),
+                }),
+        };
+        assert!(serialize(&holder).is_err());
+    }
 }
@@ -0,0 +1,289 @@
+use crate::{AssetPath, AssetServer, LoadState, ReflectHandle, UntypedAssetId, UntypedHandle};
+use alloc::{collections::VecDeque, string::ToString, vec, vec::Vec};
+use bevy_ecs::{
+    component::ComponentInfo,
+    entity::Entity,
+    reflect::{AppTypeRegistry, ReflectComponent},
+    world::{EntityRef, World},
+};
+use bevy_platform::collections::HashMap;
+use bevy_reflect::{PartialReflect, ReflectRef, TypeRegistry};
+use serde::{Serialize, Serializer};
+
+/// A snapshot of the assets tracked by the [`AssetServer`] and of the dependencies between them, returned by
+/// [`AssetServer::dependency_graph`].
+///
+/// Every asset that was loaded, is loading or was added with [`AssetServer::add`] is a node of the graph. Nodes
+/// reference each other by their index in [`AssetDependencyGraph::nodes`], which is also how the graph is
+/// serialized. This can be used to build a report of the loaded assets and their size, and to answer "why is this
+/// asset loaded" with [`AssetDependencyGraph::why_loaded`].
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct AssetDependencyGraph {
+    /// The nodes of the graph, sorted by path. Assets without a path come last.
+    pub nodes: Vec<AssetDependencyNode>,
+    #[serde(skip)]
+    indices: HashMap<UntypedAssetId, usize>,
+}
+
+/// An asset in an [`AssetDependencyGraph`].
+#[derive(Clone, Debug, Serialize)]
+pub struct AssetDependencyNode {
+    /// The id of the asset.
+    #[serde(skip)]
+    pub id: UntypedAssetId,
+    /// The path of the asset, if it has one.
+    pub path: Option<AssetPath<'static>>,
+    /// The [`TypePath`](bevy_reflect::TypePath) of the asset type.
+    pub type_path: &'static str,
+    /// The load state of the asset.
+    #[serde(serialize_with = "serialize_load_state")]
+    pub load_state: LoadState,
+    /// The number of strong handles to the asset that were alive when the graph was created.
+    pub strong_handles: usize,
+    /// The number of bytes read from the asset source to load the asset, if it was loaded from a path.
+    ///
+    /// For labeled assets, this is counted on the asset at the path without the label.
+    pub loaded_bytes: Option<usize>,
+    /// The indices of the assets this asset depends on, and holds strong handles to.
+    pub dependencies: Vec<usize>,
+    /// The indices of the assets that depend on this asset, and hold strong handles to it.
+    pub dependents: Vec<usize>,
+    /// The entities with components holding strong handles to this asset.
+    ///
+    /// This is only populated by [`AssetDependencyGraph::collect_entity_holders`].
+    #[serde(serialize_with = "serialize_entities")]
+    pub entities: Vec<Entity>,
+}
+
+impl AssetDependencyGraph {
+    /// Returns the node of the asset with the given `id`, if it is tracked by the [`AssetServer`].
+    pub fn get(&self, id: impl Into<UntypedAssetId>) -> Option<&AssetDependencyNode> {
+        self.index_of(id).map(|index| &self.nodes[index])
+    }
+
+    /// Returns the index in [`AssetDependencyGraph::nodes`] of the asset with the given `id`.
+    pub fn index_of(&self, id: impl Into<UntypedAssetId>) -> Option<usize> {
+        self.indices.get(&id.into()).copied()
+    }
+
+    /// Returns the nodes of the assets at the given `path`, one for each type of asset loaded from it.
+    pub fn get_path<'a>(
+        &'a self,
+        path: &'a AssetPath<'_>,
+    ) -> impl Iterator<Item = &'a AssetDependencyNode> + 'a {
+        self.nodes
+            .iter()
+            .filter(move |node| node.path.as_ref() == Some(path))
+    }
+
+    /// Returns the total number of bytes read from asset sources to load the assets of this graph.
+    pub fn total_loaded_bytes(&self) -> usize {
+        self.nodes.iter().filter_map(|node| node.loaded_bytes).sum()
+    }
+
+    /// Explains why the asset with the given `id` is loaded, by returning the shortest chain of dependents from
+    /// the asset to an asset that no other asset depends on.
+    ///
+    /// The first node of the chain is the asset itself, and each following node depends on the previous one. The
+    /// last node is kept alive by handles held outside of the asset graph, such as by the
+    /// [entities](AssetDependencyNode::entities) of the last node. Returns an empty chain if the asset is not in the
+    /// graph.
+    pub fn why_loaded(&self, id: impl Into<UntypedAssetId>) -> Vec<&AssetDependencyNode> {
+        let Some(start) = self.index_of(id) else {
+            return Vec::new();
+        };
+        let mut previous = vec![None; self.nodes.len()];
+        let mut visited = vec![false; self.nodes.len()];
+        visited[start] = true;
+        let mut queue = VecDeque::from([start]);
+        let mut root = start;
+        while let Some(index) = queue.pop_front() {
+            let dependents = &self.nodes[index].dependents;
+            if dependents.is_empty() {
+                root = index;
+                break;
+            }
+            for &dependent in dependents {
+                if !visited[dependent] {
+                    visited[dependent] = true;
+                    previous[dependent] = Some(index);
+                    queue.push_back(dependent);
+                }
+            }
+        }
+
+        let mut chain = vec![&self.nodes[root]];
+        let mut index = root;
+        while let Some(next) = previous[index] {
+            chain.push(&self.nodes[next]);
+            index = next;
+        }
+        chain.reverse();
+        chain
+    }
+
+    /// Populates [`AssetDependencyNode::entities`] with the entities of the `world` that have components holding
+    /// strong handles to the assets of this graph.
+    ///
+    /// Components are inspected through reflection, so this only finds handles in components registered in the
+    /// [`AppTypeRegistry`], for asset types registered with
+    /// [`AssetApp::register_asset_reflect`](crate::AssetApp::register_asset_reflect).
+    pub fn collect_entity_holders(&mut self, world: &World) {
+        let Some(type_registry) = world.get_resource::<AppTypeRegistry>() else {
+            return;
+        };
+        let Some(mut query) = world.try_query::<EntityRef>() else {
+            return;
+        };
+        let type_registry = type_registry.read();
+        for node in &mut self.nodes {
+            node.entities.clear();
+        }
+        for entity in query.iter(world) {
+            for component_id in entity.archetype().components() {
+                let Some(reflect_component) = world
+                    .components()
+                    .get_info(component_id)
+                    .and_then(ComponentInfo::type_id)
+                    .and_then(|type_id| type_registry.get_type_data::<ReflectComponent>(type_id))
+                else {
+                    continue;
+                };
+                let Some(component) = reflect_component.reflect(entity) else {
+                    continue;
+                };
+                visit_handles(component.as_partial_reflect(), &type_registry, &mut |id| {
+                    if let Some(&index) = self.indices.get(&id) {
+                        let entities = &mut self.nodes[index].entities;
+                        if !entities.contains(&entity.id()) {
+                            entities.push(entity.id());
+                        }
+                    }
+                });
+            }
+        }
+    }
+}
+
+/// Calls `visit` with the id of every strong [`Handle`](crate::Handle) found in `value`.
+fn visit_handles(
+    value: &dyn PartialReflect,
+    type_registry: &TypeRegistry,
+    visit: &mut impl FnMut(UntypedAssetId),
+) {
+    if let Some(value) = value.try_as_reflect()
+        && let Some(reflect_handle) =
+            type_registry.get_type_data::<ReflectHandle>(value.as_any().type_id())
+    {
+        if let Some(handle) = reflect_handle.downcast_handle_untyped(value.as_any())
+            && matches!(handle, UntypedHandle::Strong(_))
+        {
+            visit(handle.id());
+        }
+        return;
+    }
+
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => value
+            .iter_fields()
+            .for_each(|field| visit_handles(field, type_registry, visit)),
+        ReflectRef::TupleStruct(value) => value
+            .iter_fields()
+            .for_each(|field| visit_handles(field, type_registry, visit)),
+        ReflectRef::Tuple(value) => value
+            .iter_fields()
+            .for_each(|field| visit_handles(field, type_registry, visit)),
+        ReflectRef::List(value) => value
+            .iter()
+            .for_each(|item| visit_handles(item, type_registry, visit)),
+        ReflectRef::Array(value) => value
+            .iter()
+            .for_each(|item| visit_handles(item, type_registry, visit)),
+        ReflectRef::Map(value) => value.iter().for_each(|(key, value)| {
+            visit_handles(key, type_registry, visit);
+            visit_handles(value, type_registry, visit);
+        }),
+        ReflectRef::Set(value) => value
+            .iter()
+            .for_each(|item| visit_handles(item, type_registry, visit)),
+        ReflectRef::Enum(value) => value
+            .iter_fields()
+            .for_each(|field| visit_handles(field.value(), type_registry, visit)),
+        _ => {}
+    }
+}
+
+fn serialize_load_state<S: Serializer>(
+    load_state: &LoadState,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(match load_state {
+        LoadState::NotLoaded => "NotLoaded",
+        LoadState::Loading => "Loading",
+        LoadState::Loaded => "Loaded",
+        LoadState::Failed(_) => "Failed",
+    })
+}
+
+fn serialize_entities<S: Serializer>(
+    entities: &[Entity],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(entities.iter().map(|entity| entity.to_bits()))
+}
+
+impl AssetServer {
+    /// Returns a snapshot of the assets tracked by this [`AssetServer`] and of the dependencies between them.
+    ///
+    /// Use [`AssetDependencyGraph::collect_entity_holders`] to also find the entities holding handles to the assets.
+    pub fn dependency_graph(&self) -> AssetDependencyGraph {
+        let infos = self.data.infos.read();
+        let mut nodes: Vec<_> = infos
+            .iter()
+            .map(|(id, info)| AssetDependencyNode {
+                id,
+                path: info.path.clone(),
+                type_path: infos
+                    .asset_type_paths
+                    .get(&id.type_id())
+                    .copied()
+                    .unwrap_or("unknown"),
+                load_state: info.load_state.clone(),
+                strong_handles: info.strong_count(),
+                loaded_bytes: info.loaded_bytes,
+                dependencies: Vec::new(),
+                dependents: Vec::new(),
+                entities: Vec::new(),
+            })
+            .collect();
+        nodes.sort_by_cached_key(|node| {
+            (
+                node.path.is_none(),
+                node.path.as_ref().map(ToString::to_string),
+            )
+        });
+
+        let indices: HashMap<_, _> = nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (node.id, index))
+            .collect();
+        for index in 0..nodes.len() {
+            let Some(info) = infos.get(nodes[index].id) else {
+                continue;
+            };
+            let mut dependencies: Vec<_> = info
+                .dependencies
+                .iter()
+                .filter_map(|id| indices.get(id).copied())
+                .collect();
+            dependencies.sort_unstable();
+            for &dependency in &dependencies {
+                nodes[dependency].dependents.push(index);
+            }
+            nodes[index].dependencies = dependencies;
+        }
+
+        AssetDependencyGraph { nodes, indices }
+    }
+}
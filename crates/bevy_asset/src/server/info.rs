@@ -40,6 +40,12 @@ pub(crate) struct AssetInfo {
     ///
     /// [`LoadedAsset`]: crate::loader::LoadedAsset
     loader_dependencies: HashMap<AssetPath<'static>, AssetHash>,
+    /// The assets this asset depends on, as reported by the [`LoadedAsset`] that loaded it.
+    ///
+    /// [`LoadedAsset`]: crate::loader::LoadedAsset
+    pub(crate) dependencies: HashSet<UntypedAssetId>,
+    /// The number of bytes read from the asset source to load this asset, if it was loaded from a path.
+    pub(crate) loaded_bytes: Option<usize>,
     /// The number of handle drops to skip for this asset.
     /// See usage (and comments) in `get_or_create_path_handle` for context.
     handle_drops_to_skip: usize,
@@ -48,6 +54,11 @@ pub(crate) struct AssetInfo {
 }
 
 impl AssetInfo {
+    /// Returns the number of strong handles to this asset that are currently alive.
+    pub(crate) fn strong_count(&self) -> usize {
+        self.weak_handle.strong_count()
+    }
+
     fn new(weak_handle: Weak<StrongHandle>, path: Option<AssetPath<'static>>) -> Self {
         Self {
             weak_handle,
@@ -60,6 +71,8 @@ impl AssetInfo {
             loading_rec_dependencies: HashSet::default(),
             failed_rec_dependencies: HashSet::default(),
             loader_dependencies: HashMap::default(),
+            dependencies: HashSet::default(),
+            loaded_bytes: None,
             dependents_waiting_on_load: HashSet::default(),
             dependents_waiting_on_recursive_dep_load: HashSet::default(),
             handle_drops_to_skip: 0,
@@ -82,6 +95,8 @@ pub(crate) struct AssetInfos {
     /// This should only be set when watching for changes to avoid unnecessary work.
     pub(crate) living_labeled_assets: HashMap<AssetPath<'static>, HashSet<Box<str>>>,
     pub(crate) handle_providers: TypeIdMap<AssetHandleProvider>,
+    /// The [`TypePath`](bevy_reflect::TypePath) of each registered asset type.
+    pub(crate) asset_type_paths: TypeIdMap<&'static str>,
     pub(crate) dependency_loaded_event_sender: TypeIdMap<fn(&mut World, UntypedAssetId)>,
     pub(crate) dependency_failed_event_sender:
         TypeIdMap<fn(&mut World, UntypedAssetId, AssetPath<'static>, AssetLoadError)>,
@@ -283,6 +298,10 @@ impl AssetInfos {
         self.infos.get(&id)
     }
 
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (UntypedAssetId, &AssetInfo)> {
+        self.infos.iter().map(|(id, info)| (*id, info))
+    }
+
     pub(crate) fn contains_key(&self, id: UntypedAssetId) -> bool {
         self.infos.contains_key(&id)
     }
@@ -392,6 +411,7 @@ impl AssetInfos {
         }
 
         loaded_asset.value.insert(loaded_asset_id, world);
+        let dependencies = loaded_asset.dependencies.clone();
         let mut loading_deps = loaded_asset.dependencies;
         let mut failed_deps = <HashSet<_>>::default();
         let mut dep_error = None;
@@ -488,6 +508,7 @@ impl AssetInfos {
             let info = self
                 .get_mut(loaded_asset_id)
                 .expect("Asset info should always exist at this point");
+            info.dependencies = dependencies;
             info.loading_dependencies = loading_deps;
             info.failed_dependencies = failed_deps;
             info.loading_rec_dependencies = loading_rec_deps;
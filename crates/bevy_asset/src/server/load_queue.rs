@@ -0,0 +1,212 @@
+use alloc::{collections::BTreeMap, sync::Arc};
+use core::{
+    cmp::Reverse,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use parking_lot::Mutex;
+
+/// The priority of an asset load, used to pick which load starts next when an
+/// [`AssetSource`](crate::io::AssetSource) has reached its
+/// [maximum number of concurrent loads](crate::io::AssetSourceBuilder::with_max_concurrent_loads).
+///
+/// Loads with a higher priority start first, and loads with the same priority start in the order
+/// they were requested. Set it with [`AssetServer::load_builder`](crate::AssetServer::load_builder)
+/// or [`NestedLoader::with_priority`](crate::NestedLoader::with_priority).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LoadPriority(pub i32);
+
+impl LoadPriority {
+    /// The priority of background loads, such as preloading assets that may be needed later.
+    pub const LOW: Self = Self(-100);
+    /// The default priority of asset loads.
+    pub const NORMAL: Self = Self(0);
+    /// The priority of loads that are needed as soon as possible.
+    pub const HIGH: Self = Self(100);
+}
+
+/// Limits the number of concurrent asset loads from an asset source, starting the pending loads
+/// by priority.
+pub(crate) struct LoadQueue {
+    max_concurrent_loads: usize,
+    state: Mutex<LoadQueueState>,
+}
+
+/// Key of a pending load, ordering the loads by descending priority and then by request order.
+type PendingKey = (Reverse<LoadPriority>, u64);
+
+#[derive(Default)]
+struct LoadQueueState {
+    running: usize,
+    next_ticket: u64,
+    pending: BTreeMap<PendingKey, Option<Waker>>,
+}
+
+impl LoadQueueState {
+    fn wake_next(&mut self, max_concurrent_loads: usize) {
+        if self.running < max_concurrent_loads
+            && let Some(waker) = self.pending.values_mut().next().and_then(Option::take)
+        {
+            waker.wake();
+        }
+    }
+}
+
+impl LoadQueue {
+    pub(crate) fn new(max_concurrent_loads: usize) -> Self {
+        Self {
+            max_concurrent_loads: max_concurrent_loads.max(1),
+            state: Mutex::default(),
+        }
+    }
+
+    pub(crate) fn max_concurrent_loads(&self) -> usize {
+        self.max_concurrent_loads
+    }
+
+    /// Waits until a load with the given `priority` can start. The load is considered running
+    /// until the returned [`LoadPermit`] is dropped.
+    pub(crate) fn acquire(self: &Arc<Self>, priority: LoadPriority) -> AcquireLoadPermit {
+        AcquireLoadPermit {
+            queue: self.clone(),
+            priority,
+            key: None,
+        }
+    }
+}
+
+/// Future returned by [`LoadQueue::acquire`].
+pub(crate) struct AcquireLoadPermit {
+    queue: Arc<LoadQueue>,
+    priority: LoadPriority,
+    key: Option<PendingKey>,
+}
+
+impl Future for AcquireLoadPermit {
+    type Output = LoadPermit;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let queue = self.queue.clone();
+        let mut state = queue.state.lock();
+        let key = match self.key {
+            Some(key) => key,
+            None => {
+                if state.running < queue.max_concurrent_loads && state.pending.is_empty() {
+                    state.running += 1;
+                    return Poll::Ready(LoadPermit {
+                        queue: queue.clone(),
+                    });
+                }
+                let key = (Reverse(self.priority), state.next_ticket);
+                state.next_ticket += 1;
+                state.pending.insert(key, None);
+                self.key = Some(key);
+                key
+            }
+        };
+
+        if state.running < queue.max_concurrent_loads
+            && state.pending.first_key_value().map(|(first, _)| *first) == Some(key)
+        {
+            state.pending.remove(&key);
+            state.running += 1;
+            self.key = None;
+            return Poll::Ready(LoadPermit {
+                queue: queue.clone(),
+            });
+        }
+
+        state.pending.insert(key, Some(cx.waker().clone()));
+        Poll::Pending
+    }
+}
+
+impl Drop for AcquireLoadPermit {
+    fn drop(&mut self) {
+        if let Some(key) = self.key {
+            // This load was cancelled. If it was woken up to start, let the next one start instead.
+            let mut state = self.queue.state.lock();
+            state.pending.remove(&key);
+            state.wake_next(self.queue.max_concurrent_loads);
+        }
+    }
+}
+
+/// Marks a load as running in its [`LoadQueue`], until dropped.
+pub(crate) struct LoadPermit {
+    queue: Arc<LoadQueue>,
+}
+
+impl Drop for LoadPermit {
+    fn drop(&mut self) {
+        let mut state = self.queue.state.lock();
+        state.running -= 1;
+        state.wake_next(self.queue.max_concurrent_loads);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LoadPriority, LoadQueue};
+    use alloc::{boxed::Box, sync::Arc, vec, vec::Vec};
+    use core::{
+        future::Future,
+        task::{Context, Poll, Waker},
+    };
+
+    #[test]
+    fn starts_pending_loads_by_priority() {
+        let queue = Arc::new(LoadQueue::new(1));
+        let mut cx = Context::from_waker(Waker::noop());
+
+        let Poll::Ready(mut permit) = Box::pin(queue.acquire(LoadPriority::NORMAL))
+            .as_mut()
+            .poll(&mut cx)
+        else {
+            panic!("the first load should start immediately");
+        };
+
+        let mut pending = vec![
+            (
+                LoadPriority::LOW,
+                Box::pin(queue.acquire(LoadPriority::LOW)),
+            ),
+            (
+                LoadPriority::NORMAL,
+                Box::pin(queue.acquire(LoadPriority::NORMAL)),
+            ),
+            (
+                LoadPriority::HIGH,
+                Box::pin(queue.acquire(LoadPriority::HIGH)),
+            ),
+        ];
+        for (_, acquire) in &mut pending {
+            assert!(acquire.as_mut().poll(&mut cx).is_pending());
+        }
+        let mut cancelled = Box::pin(queue.acquire(LoadPriority::HIGH));
+        assert!(cancelled.as_mut().poll(&mut cx).is_pending());
+        drop(cancelled);
+
+        let mut order = Vec::new();
+        while !pending.is_empty() {
+            drop(permit);
+            let mut next = None;
+            pending.retain_mut(|(priority, acquire)| {
+                if next.is_none()
+                    && let Poll::Ready(started) = acquire.as_mut().poll(&mut cx)
+                {
+                    next = Some(started);
+                    order.push(*priority);
+                    return false;
+                }
+                true
+            });
+            permit = next.expect("a pending load should start when a load finishes");
+        }
+        assert_eq!(
+            vec![LoadPriority::HIGH, LoadPriority::NORMAL, LoadPriority::LOW],
+            order
+        );
+    }
+}
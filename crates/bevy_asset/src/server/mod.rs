@@ -1,12 +1,14 @@
+mod dependency_graph;
 mod info;
+pub(crate) mod load_queue;
 mod loaders;
 
 use crate::{
     folder::LoadedFolder,
     io::{
         AssetReaderError, AssetSource, AssetSourceEvent, AssetSourceId, AssetSources,
-        AssetWriterError, ErasedAssetReader, MissingAssetSourceError, MissingAssetWriterError,
-        MissingProcessedAssetReaderError, Reader,
+        AssetWriterError, CountingReader, ErasedAssetReader, MissingAssetSourceError,
+        MissingAssetWriterError, MissingProcessedAssetReaderError, Reader,
     },
     loader::{AssetLoader, ErasedAssetLoader, LoadContext, LoadedAsset},
     meta::{
@@ -15,8 +17,8 @@ use crate::{
     },
     path::AssetPath,
     Asset, AssetEvent, AssetHandleProvider, AssetId, AssetLoadFailedEvent, AssetMetaCheck, Assets,
-    DeserializeMetaError, ErasedLoadedAsset, Handle, LoadedUntypedAsset, UnapprovedPathMode,
-    UntypedAssetId, UntypedAssetLoadFailedEvent, UntypedHandle,
+    DeserializeMetaError, ErasedLoadedAsset, Handle, LoadBuilder, LoadedUntypedAsset,
+    UnapprovedPathMode, UntypedAssetId, UntypedAssetLoadFailedEvent, UntypedHandle,
 };
 use alloc::{borrow::ToOwned, boxed::Box, vec, vec::Vec};
 use alloc::{
@@ -30,9 +32,12 @@ use bevy_platform::collections::HashSet;
 use bevy_tasks::IoTaskPool;
 use core::{any::TypeId, future::Future, panic::AssertUnwindSafe, task::Poll};
 use crossbeam_channel::{Receiver, Sender};
+pub use dependency_graph::*;
 use either::Either;
 use futures_lite::{FutureExt, StreamExt};
 use info::*;
+use load_queue::LoadPermit;
+pub use load_queue::LoadPriority;
 use loaders::*;
 use parking_lot::{RwLock, RwLockWriteGuard};
 use std::path::{Path, PathBuf};
@@ -193,6 +198,10 @@ impl AssetServer {
         infos
             .dependency_failed_event_sender
             .insert(TypeId::of::<A>(), failed_sender::<A>);
+
+        infos
+            .asset_type_paths
+            .insert(TypeId::of::<A>(), A::type_path());
     }
 
     pub(crate) fn register_handle_provider(&self, handle_provider: AssetHandleProvider) {
@@ -322,7 +331,13 @@ impl AssetServer {
     /// The asset load will fail and an error will be printed to the logs if the asset stored at `path` is not of type `A`.
     #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
     pub fn load<'a, A: Asset>(&self, path: impl Into<AssetPath<'a>>) -> Handle<A> {
-        self.load_with_meta_transform(path, None, (), false)
+        self.load_with_meta_transform(path, None, (), false, LoadPriority::NORMAL)
+    }
+
+    /// Returns a [`LoadBuilder`] to configure and begin the load of an asset, for example to set
+    /// its [`LoadPriority`].
+    pub fn load_builder(&self) -> LoadBuilder<'_> {
+        LoadBuilder::new(self)
     }
 
     /// Same as [`load`](AssetServer::load), but you can load assets from unaproved paths
@@ -331,11 +346,12 @@ impl AssetServer {
     ///
     /// See [`UnapprovedPathMode`] and [`AssetPath::is_unapproved`]
     pub fn load_override<'a, A: Asset>(&self, path: impl Into<AssetPath<'a>>) -> Handle<A> {
-        self.load_with_meta_transform(path, None, (), true)
+        self.load_with_meta_transform(path, None, (), true, LoadPriority::NORMAL)
     }
 
     /// Begins loading an [`Asset`] of type `A` stored at `path` while holding a guard item.
-    /// The guard item is dropped when either the asset is loaded or loading has failed.
+    /// The guard item is dropped when either the asset is loaded, loading has failed or loading was
+    /// cancelled because all handles to the asset were dropped.
     ///
     /// This function returns a "strong" [`Handle`]. When the [`Asset`] is loaded (and enters [`LoadState::Loaded`]), it will be added to the
     /// associated [`Assets`] resource.
@@ -355,7 +371,7 @@ impl AssetServer {
         path: impl Into<AssetPath<'a>>,
         guard: G,
     ) -> Handle<A> {
-        self.load_with_meta_transform(path, None, guard, false)
+        self.load_with_meta_transform(path, None, guard, false, LoadPriority::NORMAL)
     }
 
     /// Same as [`load`](AssetServer::load_acquire), but you can load assets from unaproved paths
@@ -368,7 +384,7 @@ impl AssetServer {
         path: impl Into<AssetPath<'a>>,
         guard: G,
     ) -> Handle<A> {
-        self.load_with_meta_transform(path, None, guard, true)
+        self.load_with_meta_transform(path, None, guard, true, LoadPriority::NORMAL)
     }
 
     /// Begins loading an [`Asset`] of type `A` stored at `path`. The given `settings` function will override the asset's
@@ -385,6 +401,7 @@ impl AssetServer {
             Some(loader_settings_meta_transform(settings)),
             (),
             false,
+            LoadPriority::NORMAL,
         )
     }
 
@@ -403,11 +420,13 @@ impl AssetServer {
             Some(loader_settings_meta_transform(settings)),
             (),
             true,
+            LoadPriority::NORMAL,
         )
     }
 
     /// Begins loading an [`Asset`] of type `A` stored at `path` while holding a guard item.
-    /// The guard item is dropped when either the asset is loaded or loading has failed.
+    /// The guard item is dropped when either the asset is loaded, loading has failed or loading was
+    /// cancelled because all handles to the asset were dropped.
     ///
     /// This function only guarantees the asset referenced by the [`Handle`] is loaded. If your asset is separated into
     /// multiple files, sub-assets referenced by the main asset might still be loading, depend on the implementation of the [`AssetLoader`].
@@ -427,6 +446,7 @@ impl AssetServer {
             Some(loader_settings_meta_transform(settings)),
             guard,
             false,
+            LoadPriority::NORMAL,
         )
     }
 
@@ -451,6 +471,7 @@ impl AssetServer {
             Some(loader_settings_meta_transform(settings)),
             guard,
             true,
+            LoadPriority::NORMAL,
         )
     }
 
@@ -460,6 +481,7 @@ impl AssetServer {
         meta_transform: Option<MetaTransform>,
         guard: G,
         override_unapproved: bool,
+        priority: LoadPriority,
     ) -> Handle<A> {
         let path = path.into().into_owned();
 
@@ -481,7 +503,7 @@ impl AssetServer {
         );
 
         if should_load {
-            self.spawn_load_task(handle.clone().untyped(), path, infos, guard);
+            self.spawn_load_task(handle.id().untyped(), path, infos, guard, priority);
         }
 
         handle
@@ -493,6 +515,7 @@ impl AssetServer {
         type_id: TypeId,
         meta_transform: Option<MetaTransform>,
         guard: G,
+        priority: LoadPriority,
     ) -> UntypedHandle {
         let path = path.into().into_owned();
         let mut infos = self.data.infos.write();
@@ -505,30 +528,48 @@ impl AssetServer {
         );
 
         if should_load {
-            self.spawn_load_task(handle.clone(), path, infos, guard);
+            self.spawn_load_task(handle.id(), path, infos, guard, priority);
         }
 
         handle
     }
 
+    /// Same as [`load`](AssetServer::load), with the type of the asset given by its [`TypeId`].
+    ///
+    /// Returns `None` if `path` is unapproved and [`AssetPlugin::unapproved_path_mode`](super::AssetPlugin::unapproved_path_mode)
+    /// is not [`Allow`](UnapprovedPathMode::Allow).
+    pub(crate) fn load_erased<'a>(
+        &self,
+        path: impl Into<AssetPath<'a>>,
+        type_id: TypeId,
+    ) -> Option<UntypedHandle> {
+        let path = path.into();
+        if path.is_unapproved()
+            && !matches!(self.data.unapproved_path_mode, UnapprovedPathMode::Allow)
+        {
+            return None;
+        }
+        Some(self.load_erased_with_meta_transform(path, type_id, None, (), LoadPriority::NORMAL))
+    }
+
     pub(crate) fn spawn_load_task<G: Send + Sync + 'static>(
         &self,
-        handle: UntypedHandle,
+        id: UntypedAssetId,
         path: AssetPath<'static>,
         infos: RwLockWriteGuard<AssetInfos>,
         guard: G,
+        priority: LoadPriority,
     ) {
         // drop the lock on `AssetInfos` before spawning a task that may block on it in single-threaded
         #[cfg(any(target_arch = "wasm32", not(feature = "multi_threaded")))]
         drop(infos);
 
-        let owned_handle = handle.clone();
+        // Only keep the id in the task, so that the load is cancelled if all handles are dropped
+        // while it is pending or running.
         let server = self.clone();
         let task = IoTaskPool::get().spawn(async move {
-            if let Err(err) = server
-                .load_internal(Some(owned_handle), path, false, None)
-                .await
-            {
+            let _permit = server.acquire_load_permit(path.source(), priority).await;
+            if let Err(err) = server.load_internal(Some(id), path, false, None).await {
                 error!("{}", err);
             }
             drop(guard);
@@ -537,13 +578,24 @@ impl AssetServer {
         #[cfg(not(any(target_arch = "wasm32", not(feature = "multi_threaded"))))]
         {
             let mut infos = infos;
-            infos.pending_tasks.insert(handle.id(), task);
+            infos.pending_tasks.insert(id, task);
         }
 
         #[cfg(any(target_arch = "wasm32", not(feature = "multi_threaded")))]
         task.detach();
     }
 
+    /// Waits until a load with the given `priority` can start from the given asset `source`, if
+    /// the source limits its number of concurrent loads.
+    async fn acquire_load_permit(
+        &self,
+        source: &AssetSourceId<'_>,
+        priority: LoadPriority,
+    ) -> Option<LoadPermit> {
+        let source = self.get_source(source.clone()).ok()?;
+        source.acquire_load_permit(priority).await
+    }
+
     /// Asynchronously load an asset that you do not know the type of statically. If you _do_ know the type of the asset,
     /// you should use [`AssetServer::load`]. If you don't know the type of the asset, but you can't use an async method,
     /// consider using [`AssetServer::load_untyped`].
@@ -562,6 +614,7 @@ impl AssetServer {
         &self,
         path: impl Into<AssetPath<'a>>,
         meta_transform: Option<MetaTransform>,
+        priority: LoadPriority,
     ) -> Handle<LoadedUntypedAsset> {
         let path = path.into().into_owned();
         let untyped_source = AssetSourceId::Name(match path.source() {
@@ -588,6 +641,7 @@ impl AssetServer {
 
         let server = self.clone();
         let task = IoTaskPool::get().spawn(async move {
+            let _permit = server.acquire_load_permit(path.source(), priority).await;
             let path_clone = path.clone();
             match server.load_untyped_async(path).await {
                 Ok(handle) => server.send_asset_event(InternalAssetEvent::Loaded {
@@ -640,7 +694,7 @@ impl AssetServer {
     /// required to figure out the asset type before a handle can be created.
     #[must_use = "not using the returned strong handle may result in the unexpected release of the assets"]
     pub fn load_untyped<'a>(&self, path: impl Into<AssetPath<'a>>) -> Handle<LoadedUntypedAsset> {
-        self.load_unknown_type_with_meta_transform(path, None)
+        self.load_unknown_type_with_meta_transform(path, None, LoadPriority::NORMAL)
     }
 
     /// Performs an async asset load.
@@ -653,12 +707,12 @@ impl AssetServer {
     /// [`None`].
     async fn load_internal<'a>(
         &self,
-        input_handle: Option<UntypedHandle>,
+        input_id: Option<UntypedAssetId>,
         path: AssetPath<'a>,
         force: bool,
         meta_transform: Option<MetaTransform>,
     ) -> Result<Option<UntypedHandle>, AssetLoadError> {
-        let input_handle_type_id = input_handle.as_ref().map(UntypedHandle::type_id);
+        let input_handle_type_id = input_id.map(|id| id.type_id());
 
         let path = path.into_owned();
         let path_clone = path.clone();
@@ -668,26 +722,32 @@ impl AssetServer {
             .inspect_err(|e| {
                 // if there was an input handle, a "load" operation has already started, so we must produce a "failure" event, if
                 // we cannot find the meta and loader
-                if let Some(handle) = &input_handle {
+                if let Some(id) = input_id {
                     self.send_asset_event(InternalAssetEvent::Failed {
-                        id: handle.id(),
+                        id,
                         path: path.clone_owned(),
                         error: e.clone(),
                     });
                 }
             })?;
 
-        if let Some(meta_transform) = input_handle.as_ref().and_then(|h| h.meta_transform()) {
-            (*meta_transform)(&mut *meta);
+        if let Some(id) = input_id {
+            // Only hold on to the handle while applying its meta transform, so that the load is
+            // cancelled if all handles are dropped (externally) before it finishes loading.
+            let Some(handle) = self.get_id_handle_untyped(id) else {
+                // All handles were dropped while reading the asset, so there is nothing to load.
+                return Ok(None);
+            };
+            if let Some(meta_transform) = handle.meta_transform() {
+                (*meta_transform)(&mut *meta);
+            }
         }
 
         let asset_id; // The asset ID of the asset we are trying to load.
         let fetched_handle; // The handle if one was looked up/created.
         let should_load; // Whether we need to load the asset.
-        if let Some(input_handle) = input_handle {
-            asset_id = Some(input_handle.id());
-            // In this case, we intentionally drop the input handle so we can cancel loading the
-            // asset if the handle gets dropped (externally) before it finishes loading.
+        if let Some(input_id) = input_id {
+            asset_id = Some(input_id);
             fetched_handle = None;
             // The handle was passed in, so the "should_load" check was already done.
             should_load = true;
@@ -766,18 +826,33 @@ impl AssetServer {
             (asset_id.unwrap(), None, path.clone())
         };
 
+        let mut reader = CountingReader::new(&mut *reader);
         match self
             .load_with_meta_loader_and_reader(
                 &base_path,
                 meta.as_ref(),
                 &*loader,
-                &mut *reader,
+                &mut reader,
                 true,
                 false,
             )
             .await
         {
             Ok(loaded_asset) => {
+                if let Some(id) = input_id
+                    && self
+                        .data
+                        .infos
+                        .read()
+                        .get(id)
+                        .is_none_or(|info| info.strong_count() == 0)
+                {
+                    // All handles were dropped while the asset was loading, so it is discarded.
+                    return Ok(None);
+                }
+                if let Some(info) = self.data.infos.write().get_mut(base_asset_id) {
+                    info.loaded_bytes = Some(reader.bytes_read());
+                }
                 let final_handle = if let Some(label) = path.label_cow() {
                     match loaded_asset.labeled_assets.get(&label) {
                         Some(labeled_asset) => Some(labeled_asset.handle.clone()),
@@ -829,6 +904,9 @@ impl AssetServer {
         let path = path.into().into_owned();
         IoTaskPool::get()
             .spawn(async move {
+                let _permit = server
+                    .acquire_load_permit(path.source(), LoadPriority::NORMAL)
+                    .await;
                 let mut reloaded = false;
 
                 let requests = server
@@ -836,7 +914,7 @@ impl AssetServer {
                     .infos
                     .read()
                     .get_path_handles(&path)
-                    .map(|handle| server.load_internal(Some(handle), path.clone(), true, None))
+                    .map(|handle| server.load_internal(Some(handle.id()), path.clone(), true, None))
                     .collect::<Vec<_>>();
 
                 for result in requests {
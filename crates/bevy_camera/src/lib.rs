@@ -4,12 +4,14 @@ mod clear_color;
 mod components;
 pub mod primitives;
 mod projection;
+mod spatial_index;
 pub mod visibility;
 
 pub use camera::*;
 pub use clear_color::*;
 pub use components::*;
 pub use projection::*;
+pub use spatial_index::*;
 
 use bevy_app::{App, Plugin};
 
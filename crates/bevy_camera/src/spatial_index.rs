@@ -0,0 +1,283 @@
+use crate::{primitives::Aabb, visibility::VisibilitySystems};
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_ecs::{entity::EntityHashMap, prelude::*, system::SystemParam};
+use bevy_math::{
+    bounding::{Aabb3d, BoundingSphere, RayCast3d, SpatialIndex, SpatialIndexKey},
+    Vec3A,
+};
+use bevy_transform::{components::GlobalTransform, TransformSystems};
+
+/// Keeps an [`EntitySpatialIndex`] of the world-space bounds of the entities with a [`GlobalTransform`] and an
+/// [`Aabb`], to find entities by location with the [`SpatialQuery`] system parameter.
+///
+/// The index is updated in [`PostUpdate`], in the [`SpatialIndexSystems`] set, so queries see the bounds of the
+/// entities as of the end of the previous frame.
+#[derive(Default)]
+pub struct SpatialIndexPlugin;
+
+impl Plugin for SpatialIndexPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EntitySpatialIndex>()
+            .configure_sets(
+                PostUpdate,
+                SpatialIndexSystems
+                    .after(TransformSystems::Propagate)
+                    .after(VisibilitySystems::CalculateBounds),
+            )
+            .add_systems(PostUpdate, update_spatial_index.in_set(SpatialIndexSystems));
+    }
+}
+
+/// Label for [`update_spatial_index`], updating the [`EntitySpatialIndex`].
+#[derive(SystemSet, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct SpatialIndexSystems;
+
+/// A [`SpatialIndex`] of the world-space bounds of the entities with a [`GlobalTransform`] and an [`Aabb`].
+///
+/// Added and updated by the [`SpatialIndexPlugin`]. Use the [`SpatialQuery`] system parameter to query it.
+#[derive(Resource, Default)]
+pub struct EntitySpatialIndex {
+    index: SpatialIndex<Entity>,
+    keys: EntityHashMap<SpatialIndexKey>,
+}
+
+impl EntitySpatialIndex {
+    /// The underlying [`SpatialIndex`].
+    pub fn index(&self) -> &SpatialIndex<Entity> {
+        &self.index
+    }
+
+    /// The key of the given entity in the [`index`](Self::index), if it is indexed.
+    pub fn key(&self, entity: Entity) -> Option<SpatialIndexKey> {
+        self.keys.get(&entity).copied()
+    }
+
+    fn insert_or_update(&mut self, entity: Entity, bounds: Aabb3d) {
+        match self.keys.get(&entity) {
+            Some(key) => {
+                self.index.update(*key, bounds);
+            }
+            None => {
+                let key = self.index.insert(bounds, entity);
+                self.keys.insert(entity, key);
+            }
+        }
+    }
+
+    fn remove(&mut self, entity: Entity) {
+        if let Some(key) = self.keys.remove(&entity) {
+            self.index.remove(key);
+        }
+    }
+}
+
+/// A [`SystemParam`] to find entities by the world-space bounds of their [`Aabb`], from the [`EntitySpatialIndex`].
+///
+/// Requires the [`SpatialIndexPlugin`].
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_camera::SpatialQuery;
+/// # use bevy_math::{bounding::BoundingSphere, Vec3};
+/// # use bevy_transform::components::GlobalTransform;
+/// #[derive(Component)]
+/// struct Explosion {
+///     radius: f32,
+/// }
+///
+/// fn explode(explosions: Query<(Entity, &Explosion, &GlobalTransform)>, spatial: SpatialQuery) {
+///     for (entity, explosion, transform) in &explosions {
+///         let blast = BoundingSphere::new(transform.translation(), explosion.radius);
+///         for hit in spatial.query_sphere(&blast).filter(|hit| *hit != entity) {
+///             // Damage `hit`...
+///         }
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(explode);
+/// ```
+#[derive(SystemParam)]
+pub struct SpatialQuery<'w> {
+    spatial_index: Res<'w, EntitySpatialIndex>,
+}
+
+impl SpatialQuery<'_> {
+    /// Returns the world-space bounds of the given entity, if it is indexed.
+    pub fn bounds(&self, entity: Entity) -> Option<Aabb3d> {
+        let key = self.spatial_index.key(entity)?;
+        self.spatial_index.index.bounds(key)
+    }
+
+    /// Returns an iterator over the entities whose bounds intersect `aabb`.
+    pub fn query_aabb(&self, aabb: &Aabb3d) -> impl Iterator<Item = Entity> {
+        self.spatial_index
+            .index
+            .query_aabb(aabb)
+            .map(|(_, entity)| *entity)
+    }
+
+    /// Returns an iterator over the entities whose bounds intersect `sphere`.
+    pub fn query_sphere(&self, sphere: &BoundingSphere) -> impl Iterator<Item = Entity> {
+        self.spatial_index
+            .index
+            .query_sphere(sphere)
+            .map(|(_, entity)| *entity)
+    }
+
+    /// Returns the entity whose bounds are first hit by `ray`, with the distance of the hit along the ray.
+    pub fn cast_ray(&self, ray: &RayCast3d) -> Option<(Entity, f32)> {
+        self.cast_ray_filtered(ray, |_| true)
+    }
+
+    /// Returns the entity passing `filter` whose bounds are first hit by `ray`, with the distance of the hit along
+    /// the ray.
+    pub fn cast_ray_filtered(
+        &self,
+        ray: &RayCast3d,
+        mut filter: impl FnMut(Entity) -> bool,
+    ) -> Option<(Entity, f32)> {
+        self.spatial_index
+            .index
+            .cast_ray_filtered(ray, |entity| filter(*entity))
+            .map(|(_, entity, distance)| (*entity, distance))
+    }
+
+    /// Returns the entity whose bounds are nearest to `point`, with the distance between them.
+    pub fn nearest(&self, point: impl Into<Vec3A>) -> Option<(Entity, f32)> {
+        self.nearest_filtered(point, |_| true)
+    }
+
+    /// Returns the entity passing `filter` whose bounds are nearest to `point`, with the distance between them.
+    pub fn nearest_filtered(
+        &self,
+        point: impl Into<Vec3A>,
+        mut filter: impl FnMut(Entity) -> bool,
+    ) -> Option<(Entity, f32)> {
+        self.spatial_index
+            .index
+            .nearest_filtered(point, |entity| filter(*entity))
+            .map(|(_, entity, distance)| (*entity, distance))
+    }
+}
+
+/// Updates the [`EntitySpatialIndex`] with the entities whose [`GlobalTransform`] or [`Aabb`] changed, and removes
+/// the entities that lost either.
+///
+/// This system is used in [`SpatialIndexPlugin`].
+pub fn update_spatial_index(
+    mut spatial_index: ResMut<EntitySpatialIndex>,
+    changed: Query<
+        (Entity, &GlobalTransform, &Aabb),
+        Or<(Changed<GlobalTransform>, Changed<Aabb>)>,
+    >,
+    indexed: Query<(), (With<GlobalTransform>, With<Aabb>)>,
+    mut removed_transforms: RemovedComponents<GlobalTransform>,
+    mut removed_aabbs: RemovedComponents<Aabb>,
+) {
+    for entity in removed_transforms.read().chain(removed_aabbs.read()) {
+        if !indexed.contains(entity) {
+            spatial_index.remove(entity);
+        }
+    }
+
+    for (entity, transform, aabb) in &changed {
+        let world_from_local = transform.affine();
+        let center = world_from_local.transform_point3a(aabb.center);
+        let half_extents = world_from_local.matrix3.abs() * aabb.half_extents;
+        let bounds = Aabb3d {
+            min: center - half_extents,
+            max: center + half_extents,
+        };
+        spatial_index.insert_or_update(entity, bounds);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::system::RunSystemOnce;
+    use bevy_math::{Dir3, Vec3};
+    use bevy_transform::components::Transform;
+
+    fn aabb() -> Aabb {
+        Aabb {
+            center: Vec3A::ZERO,
+            half_extents: Vec3A::splat(0.5),
+        }
+    }
+
+    #[test]
+    fn index_follows_entities() {
+        let mut app = App::new();
+        app.add_plugins(SpatialIndexPlugin);
+
+        let near = app
+            .world_mut()
+            .spawn((GlobalTransform::from_xyz(1.0, 0.0, 0.0), aabb()))
+            .id();
+        let far = app
+            .world_mut()
+            .spawn((
+                GlobalTransform::from(
+                    Transform::from_xyz(10.0, 0.0, 0.0).with_scale(Vec3::splat(4.0)),
+                ),
+                aabb(),
+            ))
+            .id();
+        app.world_mut().spawn(GlobalTransform::default());
+        app.update();
+
+        let bounds = app
+            .world_mut()
+            .run_system_once(move |spatial: SpatialQuery| spatial.bounds(far))
+            .unwrap();
+        assert_eq!(
+            bounds,
+            Some(Aabb3d::new(Vec3::new(10.0, 0.0, 0.0), Vec3::splat(2.0)))
+        );
+        let nearest = app
+            .world_mut()
+            .run_system_once(|spatial: SpatialQuery| spatial.nearest(Vec3::ZERO))
+            .unwrap();
+        assert_eq!(nearest, Some((near, 0.5)));
+        let hit = app
+            .world_mut()
+            .run_system_once(|spatial: SpatialQuery| {
+                spatial.cast_ray(&RayCast3d::new(Vec3::X * 20.0, -Dir3::X, 100.0))
+            })
+            .unwrap();
+        assert_eq!(hit, Some((far, 8.0)));
+
+        app.world_mut()
+            .entity_mut(near)
+            .insert(GlobalTransform::from_xyz(0.0, 20.0, 0.0));
+        app.world_mut().entity_mut(far).remove::<Aabb>();
+        app.update();
+
+        let found = app
+            .world_mut()
+            .run_system_once(|spatial: SpatialQuery| {
+                spatial
+                    .query_sphere(&BoundingSphere::new(Vec3::ZERO, 30.0))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap();
+        assert_eq!(found, [near]);
+        let found = app
+            .world_mut()
+            .run_system_once(|spatial: SpatialQuery| {
+                spatial
+                    .query_aabb(&Aabb3d::new(Vec3::Y * 20.0, Vec3::ONE))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap();
+        assert_eq!(found, [near]);
+
+        app.world_mut().despawn(near);
+        app.update();
+        assert!(app
+            .world()
+            .resource::<EntitySpatialIndex>()
+            .index()
+            .is_empty());
+    }
+}
@@ -0,0 +1,105 @@
+use alloc::{format, string::ToString};
+
+use bevy_app::prelude::*;
+use bevy_ecs::{
+    prelude::*,
+    schedule::{InternedScheduleLabel, SystemKey, SystemTimings},
+};
+use bevy_platform::{collections::HashMap, time::Instant};
+use core::time::Duration;
+
+use crate::{Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore};
+
+/// Adds a "system time" diagnostic for each system of the app, measuring the wall time it took each frame.
+///
+/// This inserts the [`SystemTimings`] resource, which makes every schedule executor record
+/// per-system timings. The raw [`SystemTimings`] can also be read directly, for example to find
+/// the systems that were skipped by their run conditions.
+///
+/// Diagnostics are added under [`SystemTimingDiagnosticsPlugin::system_time_path`] the first
+/// time a system is seen, in milliseconds. Systems of the same schedule sharing a name are summed.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+pub struct SystemTimingDiagnosticsPlugin {
+    /// The total number of values to keep.
+    pub max_history_length: usize,
+}
+
+impl Default for SystemTimingDiagnosticsPlugin {
+    fn default() -> Self {
+        Self::new(crate::DEFAULT_MAX_HISTORY_LENGTH)
+    }
+}
+
+impl SystemTimingDiagnosticsPlugin {
+    /// Creates a new `SystemTimingDiagnosticsPlugin` with the specified `max_history_length`.
+    pub fn new(max_history_length: usize) -> Self {
+        Self { max_history_length }
+    }
+}
+
+impl Plugin for SystemTimingDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        let max_history_length = self.max_history_length;
+        app.init_resource::<SystemTimings>().add_systems(
+            Last,
+            move |timings: Res<SystemTimings>,
+                  store: ResMut<DiagnosticsStore>,
+                  previous: Local<HashMap<(InternedScheduleLabel, SystemKey), Duration>>| {
+                Self::diagnostic_system(timings, store, previous, max_history_length);
+            },
+        );
+    }
+}
+
+impl SystemTimingDiagnosticsPlugin {
+    /// The prefix of every system time diagnostic path.
+    pub const SYSTEM_TIME: DiagnosticPath = DiagnosticPath::const_new("system_time");
+
+    /// Returns the path of the diagnostic measuring the time spent in the system named `system`
+    /// of the schedule `schedule`.
+    pub fn system_time_path(schedule: InternedScheduleLabel, system: &str) -> DiagnosticPath {
+        DiagnosticPath::from_components([
+            Self::SYSTEM_TIME.as_str(),
+            &format!("{schedule:?}"),
+            &system.replace('/', "_"),
+        ])
+    }
+
+    /// Adds the time spent in each system since the last frame as measurements.
+    fn diagnostic_system(
+        timings: Res<SystemTimings>,
+        mut store: ResMut<DiagnosticsStore>,
+        mut previous: Local<HashMap<(InternedScheduleLabel, SystemKey), Duration>>,
+        max_history_length: usize,
+    ) {
+        let mut frame_times = HashMap::<DiagnosticPath, Duration>::default();
+        for timing in timings.iter() {
+            let total = previous.entry((timing.schedule, timing.key)).or_default();
+            let delta = timing.total_time.saturating_sub(*total);
+            *total = timing.total_time;
+
+            let path = Self::system_time_path(timing.schedule, &timing.name.to_string());
+            *frame_times.entry(path).or_default() += delta;
+        }
+
+        let time = Instant::now();
+        for (path, frame_time) in frame_times {
+            if store.get(&path).is_none() {
+                store.add(
+                    Diagnostic::new(path.clone())
+                        .with_suffix("ms")
+                        .with_max_history_length(max_history_length),
+                );
+            }
+            if let Some(diagnostic) = store.get_mut(&path).filter(|d| d.is_enabled) {
+                diagnostic.add_measurement(DiagnosticMeasurement {
+                    time,
+                    value: frame_time.as_secs_f64() * 1000.0,
+                });
+            }
+        }
+    }
+}
@@ -752,6 +752,10 @@ pub struct Entities {
     /// [`flush`]: Entities::flush
     pending: Vec<EntityRow>,
     free_cursor: AtomicIdCursor,
+    /// The rows given back an earlier generation by [`alloc_at`](Entities::alloc_at), with the generation
+    /// they had reached before. [`free`](Entities::free) skips past it, so that the handles of those later
+    /// generations never become valid again.
+    rewound: Vec<(EntityRow, EntityGeneration)>,
 }
 
 impl Entities {
@@ -760,6 +764,7 @@ impl Entities {
             meta: Vec::new(),
             pending: Vec::new(),
             free_cursor: AtomicIdCursor::new(0),
+            rewound: Vec::new(),
         }
     }
 
@@ -869,6 +874,57 @@ impl Entities {
         }
     }
 
+    /// Allocate a specific entity ID, with its exact [`EntityGeneration`].
+    ///
+    /// Returns `false` without allocating anything if the row of `entity` is currently in use,
+    /// whatever its generation. Otherwise the row is taken out of the freelist and its generation
+    /// is set back to the one of `entity`. Handles to the generations the row went through since
+    /// then stay invalid: once `entity` is freed again, the row continues from the latest of them.
+    ///
+    /// This is used to bring despawned entities back with their original ids, for example when
+    /// restoring a [`WorldSnapshot`](crate::world::WorldSnapshot).
+    ///
+    /// Must not be called while reserved entities are awaiting `flush()`.
+    pub(crate) fn alloc_at(&mut self, entity: Entity) -> bool {
+        self.verify_flushed();
+
+        let index = entity.index() as usize;
+        if index >= self.meta.len() {
+            // Every row skipped to reach `entity` becomes free.
+            let start = self.meta.len() as u32;
+            self.pending.extend((start..entity.index()).map(|index| {
+                // SAFETY: `index` is lower than the index of `entity`, which is a `NonMaxU32`.
+                EntityRow::new(unsafe { NonMaxU32::new_unchecked(index) })
+            }));
+            self.meta.resize(index + 1, EntityMeta::EMPTY);
+        } else if self.meta[index].location.is_some() {
+            return false;
+        } else if let Some(position) = self.pending.iter().position(|row| *row == entity.row()) {
+            self.pending.swap_remove(position);
+        } else {
+            // The row is neither in use nor free: it is reserved.
+            return false;
+        }
+
+        *self.free_cursor.get_mut() = self.pending.len() as IdCursor;
+        let reached = mem::replace(&mut self.meta[index].generation, entity.generation());
+        if reached != entity.generation() {
+            match self
+                .rewound
+                .iter_mut()
+                .find(|(row, _)| *row == entity.row())
+            {
+                Some((_, latest)) => {
+                    if reached.cmp_approx(latest).is_gt() {
+                        *latest = reached;
+                    }
+                }
+                None => self.rewound.push((entity.row(), reached)),
+            }
+        }
+        true
+    }
+
     /// Destroy an entity, allowing it to be reused.
     ///
     /// Returns the `Option<EntityLocation>` of the entity or `None` if the `entity` was not present.
@@ -882,7 +938,18 @@ impl Entities {
             return None;
         }
 
-        let (new_generation, aliased) = meta.generation.after_versions_and_could_alias(1);
+        let (mut new_generation, aliased) = meta.generation.after_versions_and_could_alias(1);
+        if let Some(position) = self
+            .rewound
+            .iter()
+            .position(|(row, _)| *row == entity.row())
+        {
+            // The row was rewound by `alloc_at`, skip the generations it already went through.
+            let (_, reached) = self.rewound.swap_remove(position);
+            if reached.cmp_approx(&new_generation).is_gt() {
+                new_generation = reached;
+            }
+        }
         meta.generation = new_generation;
         if aliased {
             warn!(
@@ -933,6 +1000,7 @@ impl Entities {
     pub fn clear(&mut self) {
         self.meta.clear();
         self.pending.clear();
+        self.rewound.clear();
         *self.free_cursor.get_mut() = 0;
     }
 
@@ -1413,6 +1481,33 @@ mod tests {
             .is_gt());
     }
 
+    #[test]
+    fn alloc_at_rewinds_generation() {
+        let mut entities = Entities::new();
+        let entity = entities.alloc();
+        entities.free(entity);
+        let later = entities.alloc();
+        assert!(!entities.alloc_at(entity));
+        entities.free(later);
+
+        assert!(entities.alloc_at(entity));
+        assert!(entities.contains(entity));
+        assert!(!entities.contains(later));
+
+        // Once freed again, the row continues after the generation of `later`.
+        entities.free(entity);
+        let next = entities.alloc();
+        assert_eq!(next.index(), entity.index());
+        assert!(next.generation().cmp_approx(&later.generation()).is_gt());
+
+        // Rows past the allocated ones are allocated, and the rows in between are free.
+        let far = Entity::from_raw_u32(5).unwrap();
+        assert!(entities.alloc_at(far));
+        assert!(entities.contains(far));
+        assert_eq!(entities.len(), 2);
+        assert_eq!(entities.alloc().index(), 4);
+    }
+
     #[test]
     #[expect(
         clippy::nonminimal_bool,
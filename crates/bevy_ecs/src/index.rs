@@ -0,0 +1,359 @@
+//! Provides value indexes for [`Component`]s, allowing entities to be looked up by the value of
+//! one of their components in constant time.
+//!
+//! Finding "the entity whose `NetId` is 42" with a plain [`Query`] requires scanning every
+//! matching entity. An index instead keeps a map from a key derived from the component value
+//! to the set of entities holding that value, updated through the component's
+//! [`on_insert`](crate::lifecycle::ComponentHooks::on_insert) and
+//! [`on_replace`](crate::lifecycle::ComponentHooks::on_replace) lifecycle hooks.
+//!
+//! Because these hooks do not run when a component is mutated in place,
+//! only [immutable components](crate::component::Immutable) can be indexed.
+//! The only way to change the value of an indexed component is to insert a new one,
+//! which keeps the index in sync.
+//!
+//! # Usage
+//!
+//! 1. Implement [`IndexedComponent`] for an immutable component.
+//! 2. Register the index with [`World::register_component_index`] before the component is first spawned.
+//! 3. Look up entities with the [`QueryByIndex`] system parameter, or directly through the [`ComponentIndex`] resource.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! use bevy_ecs::index::{IndexedComponent, QueryByIndex};
+//!
+//! #[derive(Component, Clone, Copy, PartialEq, Eq, Hash)]
+//! #[component(immutable)]
+//! struct NetId(u32);
+//!
+//! impl IndexedComponent for NetId {
+//!     type Key = u32;
+//!
+//!     fn index_key(&self) -> u32 {
+//!         self.0
+//!     }
+//! }
+//!
+//! #[derive(Component)]
+//! struct Health(f32);
+//!
+//! fn damage_net_entity(mut by_net_id: QueryByIndex<NetId, &mut Health>) {
+//!     for mut health in by_net_id.iter_mut(&42) {
+//!         health.0 -= 10.0;
+//!     }
+//! }
+//!
+//! let mut world = World::new();
+//! world.register_component_index::<NetId>();
+//!
+//! let entity = world.spawn((NetId(42), Health(100.0))).id();
+//! world.spawn((NetId(7), Health(100.0)));
+//!
+//! world.run_system_once(damage_net_entity).unwrap();
+//! assert_eq!(world.get::<Health>(entity).unwrap().0, 90.0);
+//! # use bevy_ecs::system::RunSystemOnce;
+//! ```
+//!
+//! [`Query`]: crate::system::Query
+
+use crate::{
+    component::{Component, Immutable},
+    entity::{hash_set::EntityHashSet, Entity},
+    lifecycle::HookContext,
+    query::{QueryData, QueryFilter, QueryManyUniqueIter, With},
+    resource::Resource,
+    system::{Query, Res, SystemParam},
+    world::{DeferredWorld, World},
+};
+
+use bevy_platform::collections::HashMap;
+use core::{fmt::Debug, hash::Hash};
+
+/// A [`Component`] that can be indexed by a key derived from its value.
+///
+/// Indexed components must be [immutable](crate::component::Immutable),
+/// since in-place mutations would bypass the lifecycle hooks that keep the index up to date.
+///
+/// See the [module docs](crate::index) for more details.
+pub trait IndexedComponent: Component<Mutability = Immutable> {
+    /// The key entities are indexed by.
+    type Key: Eq + Hash + Clone + Send + Sync + 'static;
+
+    /// Returns the key this component value should be indexed under.
+    fn index_key(&self) -> Self::Key;
+}
+
+/// A [`Resource`] mapping each key of an [`IndexedComponent`] to the entities holding a component with that key.
+///
+/// This is kept up to date by the hooks installed with [`World::register_component_index`].
+/// For components that define their own `on_insert` or `on_replace` hooks, call
+/// [`ComponentIndex::on_insert`] and [`ComponentIndex::on_replace`] from within those hooks instead.
+#[derive(Resource)]
+pub struct ComponentIndex<C: IndexedComponent> {
+    entities: HashMap<C::Key, EntityHashSet>,
+}
+
+static EMPTY: EntityHashSet = EntityHashSet::new();
+
+impl<C: IndexedComponent> Default for ComponentIndex<C> {
+    fn default() -> Self {
+        Self {
+            entities: HashMap::default(),
+        }
+    }
+}
+
+impl<C: IndexedComponent> Debug for ComponentIndex<C>
+where
+    C::Key: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ComponentIndex")
+            .field("entities", &self.entities)
+            .finish()
+    }
+}
+
+impl<C: IndexedComponent> ComponentIndex<C> {
+    /// Returns the set of entities whose component is indexed under `key`.
+    ///
+    /// The returned set is empty if no entity matches.
+    pub fn get(&self, key: &C::Key) -> &EntityHashSet {
+        self.entities.get(key).unwrap_or(&EMPTY)
+    }
+
+    /// Returns an iterator over the entities whose component is indexed under `key`.
+    pub fn iter(&self, key: &C::Key) -> impl Iterator<Item = Entity> + '_ {
+        self.get(key).iter().copied()
+    }
+
+    /// Returns `true` if at least one entity has a component indexed under `key`.
+    pub fn contains_key(&self, key: &C::Key) -> bool {
+        self.entities.contains_key(key)
+    }
+
+    /// Returns an iterator over every key currently present in the index.
+    pub fn keys(&self) -> impl Iterator<Item = &C::Key> {
+        self.entities.keys()
+    }
+
+    /// Returns the number of distinct keys currently present in the index.
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Returns `true` if no entities are indexed.
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    fn insert(&mut self, key: C::Key, entity: Entity) {
+        self.entities.entry(key).or_default().insert(entity);
+    }
+
+    fn remove(&mut self, key: &C::Key, entity: Entity) {
+        if let Some(entities) = self.entities.get_mut(key) {
+            entities.remove(&entity);
+            if entities.is_empty() {
+                self.entities.remove(key);
+            }
+        }
+    }
+
+    /// The `on_insert` hook that adds an entity to the index.
+    ///
+    /// Does nothing if the [`ComponentIndex<C>`] resource does not exist.
+    pub fn on_insert(mut world: DeferredWorld, context: HookContext) {
+        let Some(key) = world.get::<C>(context.entity).map(C::index_key) else {
+            return;
+        };
+        if let Some(mut index) = world.get_resource_mut::<Self>() {
+            index.insert(key, context.entity);
+        }
+    }
+
+    /// The `on_replace` hook that removes an entity from the index.
+    ///
+    /// Does nothing if the [`ComponentIndex<C>`] resource does not exist.
+    pub fn on_replace(mut world: DeferredWorld, context: HookContext) {
+        let Some(key) = world.get::<C>(context.entity).map(C::index_key) else {
+            return;
+        };
+        if let Some(mut index) = world.get_resource_mut::<Self>() {
+            index.remove(&key, context.entity);
+        }
+    }
+}
+
+impl World {
+    /// Creates a [`ComponentIndex`] for the [`IndexedComponent`] `C`,
+    /// and installs the `on_insert` and `on_replace` hooks that keep it up to date.
+    ///
+    /// Calling this more than once for the same component has no effect.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `C` already exists in any archetype, or if `C` already defines an
+    /// `on_insert` or `on_replace` hook. In the latter case, call [`ComponentIndex::on_insert`]
+    /// and [`ComponentIndex::on_replace`] from those hooks and insert the [`ComponentIndex<C>`]
+    /// resource manually.
+    pub fn register_component_index<C: IndexedComponent>(&mut self) {
+        if self.contains_resource::<ComponentIndex<C>>() {
+            return;
+        }
+        self.register_component_hooks::<C>()
+            .on_insert(ComponentIndex::<C>::on_insert)
+            .on_replace(ComponentIndex::<C>::on_replace);
+        self.init_resource::<ComponentIndex<C>>();
+    }
+}
+
+/// A [`SystemParam`] that looks up entities by the value of an [`IndexedComponent`],
+/// then fetches query data `D` for them.
+///
+/// The underlying [`Query`] is filtered by `With<C>` and `F`, and the [`ComponentIndex<C>`] resource is read,
+/// so the scheduler accounts for this parameter like it would for the equivalent [`Query`] and [`Res`].
+///
+/// This requires the index to be registered with [`World::register_component_index`].
+///
+/// See the [module docs](crate::index) for an example.
+#[derive(SystemParam)]
+pub struct QueryByIndex<
+    'w,
+    's,
+    C: IndexedComponent,
+    D: QueryData + 'static,
+    F: QueryFilter + 'static = (),
+> {
+    index: Res<'w, ComponentIndex<C>>,
+    query: Query<'w, 's, D, (F, With<C>)>,
+}
+
+impl<'w, 's, C: IndexedComponent, D: QueryData, F: QueryFilter> QueryByIndex<'w, 's, C, D, F> {
+    /// Returns the set of entities whose component is indexed under `key`.
+    ///
+    /// These entities are not guaranteed to match the query filter `F`.
+    pub fn entities(&self, key: &C::Key) -> &EntityHashSet {
+        self.index.get(key)
+    }
+
+    /// Returns an iterator over the read-only query items of the entities whose component is indexed under `key`.
+    pub fn iter(
+        &self,
+        key: &C::Key,
+    ) -> QueryManyUniqueIter<
+        '_,
+        's,
+        D::ReadOnly,
+        (F, With<C>),
+        <&EntityHashSet as IntoIterator>::IntoIter,
+    > {
+        self.query.iter_many_unique(self.index.get(key))
+    }
+
+    /// Returns an iterator over the query items of the entities whose component is indexed under `key`.
+    pub fn iter_mut(
+        &mut self,
+        key: &C::Key,
+    ) -> QueryManyUniqueIter<'_, 's, D, (F, With<C>), <&EntityHashSet as IntoIterator>::IntoIter>
+    {
+        self.query.iter_many_unique_mut(self.index.get(key))
+    }
+
+    /// Returns the underlying [`ComponentIndex`].
+    pub fn index(&self) -> &ComponentIndex<C> {
+        &self.index
+    }
+
+    /// Returns the underlying [`Query`], for access that does not go through the index.
+    pub fn query(&self) -> &Query<'w, 's, D, (F, With<C>)> {
+        &self.query
+    }
+
+    /// Returns the underlying [`Query`] mutably, for access that does not go through the index.
+    pub fn query_mut(&mut self) -> &mut Query<'w, 's, D, (F, With<C>)> {
+        &mut self.query
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::*,
+        system::{assert_is_system, RunSystemOnce},
+    };
+
+    #[derive(Component, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+    #[component(immutable)]
+    struct Cell(i32, i32);
+
+    impl IndexedComponent for Cell {
+        type Key = Self;
+
+        fn index_key(&self) -> Self {
+            *self
+        }
+    }
+
+    #[derive(Component)]
+    struct Marker;
+
+    #[test]
+    fn index_tracks_insert_replace_and_despawn() {
+        let mut world = World::new();
+        world.register_component_index::<Cell>();
+
+        let a = world.spawn(Cell(0, 0)).id();
+        let b = world.spawn(Cell(0, 0)).id();
+        let c = world.spawn(Cell(1, 0)).id();
+
+        let index = world.resource::<ComponentIndex<Cell>>();
+        assert_eq!(index.get(&Cell(0, 0)).len(), 2);
+        assert!(index.get(&Cell(1, 0)).contains(&c));
+        assert!(index.get(&Cell(5, 5)).is_empty());
+
+        world.entity_mut(a).insert(Cell(1, 0));
+        world.entity_mut(b).remove::<Cell>();
+        world.despawn(c);
+
+        let index = world.resource::<ComponentIndex<Cell>>();
+        assert!(!index.contains_key(&Cell(0, 0)));
+        assert_eq!(index.iter(&Cell(1, 0)).collect::<alloc::vec::Vec<_>>(), [a]);
+        assert_eq!(index.len(), 1);
+    }
+
+    #[test]
+    fn query_by_index_respects_filter() {
+        let mut world = World::new();
+        world.register_component_index::<Cell>();
+
+        let a = world.spawn((Cell(2, 3), Marker)).id();
+        world.spawn(Cell(2, 3));
+        world.spawn((Cell(3, 2), Marker));
+
+        let found = world
+            .run_system_once(|query: QueryByIndex<Cell, Entity, With<Marker>>| {
+                assert_eq!(query.entities(&Cell(2, 3)).len(), 2);
+                query.iter(&Cell(2, 3)).collect::<alloc::vec::Vec<_>>()
+            })
+            .unwrap();
+        assert_eq!(found, [a]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn query_by_index_conflicts_with_mutable_query() {
+        fn system(_: QueryByIndex<Cell, &mut Marker>, _: Query<&Marker>) {}
+
+        let mut world = World::new();
+        world.register_component_index::<Cell>();
+        world.run_system_once(system).unwrap();
+    }
+
+    #[test]
+    fn query_by_index_is_system() {
+        fn system(_: QueryByIndex<Cell, &Marker>, _: Query<&mut Marker, Without<Cell>>) {}
+        assert_is_system(system);
+    }
+}
@@ -21,8 +21,8 @@ use crate::{
     prelude::{IntoSystemSet, SystemSet},
     query::FilteredAccessSet,
     schedule::{
-        ConditionWithAccess, InternedSystemSet, SystemKey, SystemSetKey, SystemTypeSet,
-        SystemWithAccess,
+        timings::SystemRunTiming, ConditionWithAccess, InternedSystemSet, SystemKey, SystemSetKey,
+        SystemTypeSet, SystemWithAccess,
     },
     system::{RunSystemError, System, SystemIn, SystemParamValidationError, SystemStateFlags},
     world::{unsafe_world_cell::UnsafeWorldCell, DeferredWorld, World},
@@ -38,6 +38,7 @@ pub(super) trait SystemExecutor: Send + Sync {
         world: &mut World,
         skip_systems: Option<&FixedBitSet>,
         error_handler: fn(BevyError, ErrorContext),
+        timings: Option<&mut [SystemRunTiming]>,
     );
     fn set_apply_final_deferred(&mut self, value: bool);
 }
@@ -316,8 +317,8 @@ mod __rust_begin_short_backtrace {
 #[cfg(test)]
 mod tests {
     use crate::{
-        prelude::{Component, In, IntoSystem, Resource, Schedule},
-        schedule::ExecutorKind,
+        prelude::{Component, In, IntoScheduleConfigs, IntoSystem, Resource, Schedule},
+        schedule::{ExecutorKind, SystemTimings},
         system::{Populated, Res, ResMut, Single},
         world::World,
     };
@@ -379,6 +380,85 @@ mod tests {
         }
     }
 
+    #[test]
+    fn system_timings_are_recorded() {
+        for executor in EXECUTORS {
+            let mut world = World::new();
+            world.init_resource::<TestState>();
+            world.init_resource::<SystemTimings>();
+
+            let mut schedule = Schedule::default();
+            schedule.set_executor_kind(executor);
+            schedule.add_systems((
+                set_single_state.run_if(|| false),
+                set_populated_state,
+                || {},
+            ));
+            schedule.run(&mut world);
+            schedule.run(&mut world);
+
+            let timings = world.resource::<SystemTimings>();
+            let label = schedule.label();
+            let mut counts = schedule
+                .systems()
+                .unwrap()
+                .map(|(key, _)| {
+                    let timing = timings.get(label, key).unwrap();
+                    (timing.run_count, timing.skip_count)
+                })
+                .collect::<alloc::vec::Vec<_>>();
+            counts.sort();
+            // `set_populated_state` is skipped by param validation,
+            // `set_single_state` by its run condition.
+            assert_eq!(counts, [(0, 2), (0, 2), (2, 0)], "{executor:?}");
+        }
+    }
+
+    #[test]
+    fn system_timings_are_opt_in() {
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(|| {});
+        schedule.run(&mut world);
+
+        world.init_resource::<SystemTimings>();
+        schedule.run(&mut world);
+        let timing = world.resource::<SystemTimings>().iter().next().unwrap();
+        assert_eq!(timing.run_count, 1);
+    }
+
+    #[test]
+    #[cfg(feature = "bevy_debug_stepping")]
+    fn system_timings_with_stepping() {
+        use crate::schedule::{ScheduleLabel, Stepping};
+
+        #[derive(ScheduleLabel, Debug, Hash, PartialEq, Eq, Clone)]
+        struct StepSchedule;
+
+        for executor in EXECUTORS {
+            let mut world = World::new();
+            world.init_resource::<SystemTimings>();
+
+            let mut schedule = Schedule::new(StepSchedule);
+            schedule.set_executor_kind(executor);
+            schedule.add_systems(|| {});
+
+            let mut stepping = Stepping::new();
+            stepping.add_schedule(StepSchedule).enable();
+            world.insert_resource(stepping);
+            world.run_system_cached(Stepping::begin_frame).unwrap();
+
+            // Waiting for a step, so the system is skipped by stepping.
+            schedule.run(&mut world);
+            world.resource_mut::<Stepping>().step_frame();
+            world.run_system_cached(Stepping::begin_frame).unwrap();
+            schedule.run(&mut world);
+
+            let timing = world.resource::<SystemTimings>().iter().next().unwrap();
+            assert_eq!(timing.run_count, 1, "{executor:?}");
+        }
+    }
+
     fn look_for_missing_resource(_res: Res<TestState>) {}
 
     #[test]
@@ -1,9 +1,10 @@
 use alloc::{boxed::Box, vec::Vec};
 use bevy_platform::cell::SyncUnsafeCell;
 use bevy_platform::sync::Arc;
+use bevy_platform::time::Instant;
 use bevy_tasks::{ComputeTaskPool, Scope, TaskPool, ThreadExecutor};
 use concurrent_queue::ConcurrentQueue;
-use core::{any::Any, panic::AssertUnwindSafe};
+use core::{any::Any, panic::AssertUnwindSafe, time::Duration};
 use fixedbitset::FixedBitSet;
 #[cfg(feature = "std")]
 use std::eprintln;
@@ -16,8 +17,8 @@ use crate::{
     error::{ErrorContext, ErrorHandler, Result},
     prelude::Resource,
     schedule::{
-        is_apply_deferred, ConditionWithAccess, ExecutorKind, SystemExecutor, SystemSchedule,
-        SystemWithAccess,
+        is_apply_deferred, timings::SystemRunTiming, ConditionWithAccess, ExecutorKind,
+        SystemExecutor, SystemSchedule, SystemWithAccess,
     },
     system::{RunSystemError, ScheduleSystem},
     world::{unsafe_world_cell::UnsafeWorldCell, World},
@@ -83,6 +84,8 @@ struct SystemTaskMetadata {
 /// The result of running a system that is sent across a channel.
 struct SystemResult {
     system_index: usize,
+    /// How long the system took to run, if timings are being recorded.
+    elapsed: Option<Duration>,
 }
 
 /// Runs the schedule using a thread pool. Non-conflicting systems can run in parallel.
@@ -96,6 +99,8 @@ pub struct MultiThreadedExecutor {
     /// When set, tells the executor that a thread has panicked.
     panic_payload: Mutex<Option<Box<dyn Any + Send>>>,
     starting_systems: FixedBitSet,
+    /// When true, the time taken by each system is measured.
+    record_timings: bool,
     /// Cached tracing span
     #[cfg(feature = "trace")]
     executor_span: Span,
@@ -129,6 +134,8 @@ pub struct ExecutorState {
     completed_systems: FixedBitSet,
     /// Systems that have run but have not had their buffers applied.
     unapplied_systems: FixedBitSet,
+    /// Timing information of each system during this run, empty when timings are not recorded.
+    timings: Vec<SystemRunTiming>,
 }
 
 /// References to data required by the executor.
@@ -241,12 +248,20 @@ impl SystemExecutor for MultiThreadedExecutor {
         world: &mut World,
         _skip_systems: Option<&FixedBitSet>,
         error_handler: ErrorHandler,
+        timings: Option<&mut [SystemRunTiming]>,
     ) {
         let state = self.state.get_mut().unwrap();
         // reset counts
         if schedule.systems.is_empty() {
             return;
         }
+        self.record_timings = timings.is_some();
+        state.timings.clear();
+        if self.record_timings {
+            state
+                .timings
+                .resize(schedule.systems.len(), SystemRunTiming::default());
+        }
         state.num_running_systems = 0;
         state
             .num_dependencies_remaining
@@ -313,6 +328,10 @@ impl SystemExecutor for MultiThreadedExecutor {
             std::panic::resume_unwind(payload);
         }
 
+        if let Some(timings) = timings {
+            timings.copy_from_slice(&state.timings);
+        }
+
         debug_assert!(state.ready_systems.is_clear());
         debug_assert!(state.running_systems.is_clear());
         state.evaluated_sets.clear();
@@ -331,12 +350,16 @@ impl<'scope, 'env: 'scope, 'sys> Context<'scope, 'env, 'sys> {
         system_index: usize,
         res: Result<(), Box<dyn Any + Send>>,
         system: &ScheduleSystem,
+        start: Option<Instant>,
     ) {
         // tell the executor that the system finished
         self.environment
             .executor
             .system_completion
-            .push(SystemResult { system_index })
+            .push(SystemResult {
+                system_index,
+                elapsed: start.map(|start| start.elapsed()),
+            })
             .unwrap_or_else(|error| unreachable!("{}", error));
         if let Err(payload) = res {
             #[cfg(feature = "std")]
@@ -396,6 +419,7 @@ impl MultiThreadedExecutor {
             starting_systems: FixedBitSet::new(),
             apply_final_deferred: true,
             panic_payload: Mutex::new(None),
+            record_timings: false,
             #[cfg(feature = "trace")]
             executor_span: info_span!("multithreaded executor"),
         }
@@ -418,6 +442,7 @@ impl ExecutorState {
             skipped_systems: FixedBitSet::new(),
             completed_systems: FixedBitSet::new(),
             unapplied_systems: FixedBitSet::new(),
+            timings: Vec::new(),
         }
     }
 
@@ -502,6 +527,9 @@ impl ExecutorState {
                         context.error_handler,
                     )
                 } {
+                    if let Some(timing) = self.timings.get_mut(system_index) {
+                        timing.skipped = true;
+                    }
                     self.skip_system_and_signal_dependents(system_index);
                     // signal_dependents may have set more systems to ready.
                     check_for_new_ready_systems = true;
@@ -674,6 +702,11 @@ impl ExecutorState {
         let system_meta = &self.system_task_metadata[system_index];
 
         let task = async move {
+            let start = context
+                .environment
+                .executor
+                .record_timings
+                .then(Instant::now);
             let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                 // SAFETY:
                 // - The caller ensures that we have permission to
@@ -696,7 +729,7 @@ impl ExecutorState {
                     }
                 };
             }));
-            context.system_completed(system_index, res, system);
+            context.system_completed(system_index, res, system, start);
         };
 
         if system_meta.is_send {
@@ -723,8 +756,13 @@ impl ExecutorState {
                 // SAFETY: `can_run` returned true for this system, which means
                 // that no other systems currently have access to the world.
                 let world = unsafe { context.environment.world_cell.world_mut() };
+                let start = context
+                    .environment
+                    .executor
+                    .record_timings
+                    .then(Instant::now);
                 let res = apply_deferred(&unapplied_systems, context.environment.systems, world);
-                context.system_completed(system_index, res, system);
+                context.system_completed(system_index, res, system, start);
             };
 
             context.scope.spawn_on_scope(task);
@@ -733,6 +771,11 @@ impl ExecutorState {
                 // SAFETY: `can_run` returned true for this system, which means
                 // that no other systems currently have access to the world.
                 let world = unsafe { context.environment.world_cell.world_mut() };
+                let start = context
+                    .environment
+                    .executor
+                    .record_timings
+                    .then(Instant::now);
                 let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                     if let Err(RunSystemError::Failed(err)) =
                         __rust_begin_short_backtrace::run(system, world)
@@ -746,7 +789,7 @@ impl ExecutorState {
                         );
                     }
                 }));
-                context.system_completed(system_index, res, system);
+                context.system_completed(system_index, res, system, start);
             };
 
             context.scope.spawn_on_scope(task);
@@ -757,7 +800,14 @@ impl ExecutorState {
     }
 
     fn finish_system_and_handle_dependents(&mut self, result: SystemResult) {
-        let SystemResult { system_index, .. } = result;
+        let SystemResult {
+            system_index,
+            elapsed,
+        } = result;
+
+        if let Some(timing) = self.timings.get_mut(system_index) {
+            timing.elapsed = elapsed;
+        }
 
         if self.system_task_metadata[system_index].is_exclusive {
             self.exclusive_running = false;
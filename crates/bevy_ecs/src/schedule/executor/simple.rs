@@ -1,5 +1,6 @@
 #![expect(deprecated, reason = "Everything here is deprecated")]
 
+use bevy_platform::time::Instant;
 use core::panic::AssertUnwindSafe;
 use fixedbitset::FixedBitSet;
 
@@ -14,8 +15,8 @@ use crate::{change_detection::DetectChanges, HotPatchChanges};
 use crate::{
     error::{ErrorContext, ErrorHandler},
     schedule::{
-        executor::is_apply_deferred, ConditionWithAccess, ExecutorKind, SystemExecutor,
-        SystemSchedule,
+        executor::is_apply_deferred, timings::SystemRunTiming, ConditionWithAccess, ExecutorKind,
+        SystemExecutor, SystemSchedule,
     },
     system::RunSystemError,
     world::World,
@@ -55,6 +56,7 @@ impl SystemExecutor for SimpleExecutor {
         world: &mut World,
         _skip_systems: Option<&FixedBitSet>,
         error_handler: ErrorHandler,
+        mut timings: Option<&mut [SystemRunTiming]>,
     ) {
         // If stepping is enabled, make sure we skip those systems that should
         // not be run.
@@ -121,6 +123,9 @@ impl SystemExecutor for SimpleExecutor {
             self.completed_systems.insert(system_index);
 
             if !should_run {
+                if let Some(timings) = timings.as_deref_mut() {
+                    timings[system_index].skipped = true;
+                }
                 continue;
             }
 
@@ -128,10 +133,10 @@ impl SystemExecutor for SimpleExecutor {
                 continue;
             }
 
-            let f = AssertUnwindSafe(|| {
-                if let Err(RunSystemError::Failed(err)) =
-                    __rust_begin_short_backtrace::run(system, world)
-                {
+            let start = timings.is_some().then(Instant::now);
+            let mut skipped = false;
+            let f = AssertUnwindSafe(|| match __rust_begin_short_backtrace::run(system, world) {
+                Err(RunSystemError::Failed(err)) => {
                     error_handler(
                         err,
                         ErrorContext::System {
@@ -140,6 +145,8 @@ impl SystemExecutor for SimpleExecutor {
                         },
                     );
                 }
+                Err(RunSystemError::Skipped(_)) => skipped = true,
+                Ok(()) => {}
             });
 
             #[cfg(feature = "std")]
@@ -155,6 +162,14 @@ impl SystemExecutor for SimpleExecutor {
             {
                 (f)();
             }
+
+            if let (Some(timings), Some(start)) = (timings.as_deref_mut(), start) {
+                if skipped {
+                    timings[system_index].skipped = true;
+                } else {
+                    timings[system_index].elapsed = Some(start.elapsed());
+                }
+            }
         }
 
         self.evaluated_sets.clear();
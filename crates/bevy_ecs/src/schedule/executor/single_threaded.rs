@@ -1,3 +1,4 @@
+use bevy_platform::time::Instant;
 use core::panic::AssertUnwindSafe;
 use fixedbitset::FixedBitSet;
 
@@ -10,7 +11,8 @@ use std::eprintln;
 use crate::{
     error::{ErrorContext, ErrorHandler},
     schedule::{
-        is_apply_deferred, ConditionWithAccess, ExecutorKind, SystemExecutor, SystemSchedule,
+        is_apply_deferred, timings::SystemRunTiming, ConditionWithAccess, ExecutorKind,
+        SystemExecutor, SystemSchedule,
     },
     system::RunSystemError,
     world::World,
@@ -57,6 +59,7 @@ impl SystemExecutor for SingleThreadedExecutor {
         world: &mut World,
         _skip_systems: Option<&FixedBitSet>,
         error_handler: ErrorHandler,
+        mut timings: Option<&mut [SystemRunTiming]>,
     ) {
         // If stepping is enabled, make sure we skip those systems that should
         // not be run.
@@ -123,25 +126,36 @@ impl SystemExecutor for SingleThreadedExecutor {
             self.completed_systems.insert(system_index);
 
             if !should_run {
+                if let Some(timings) = timings.as_deref_mut() {
+                    timings[system_index].skipped = true;
+                }
                 continue;
             }
 
+            let start = timings.is_some().then(Instant::now);
+
             if is_apply_deferred(&**system) {
                 self.apply_deferred(schedule, world);
+                if let (Some(timings), Some(start)) = (timings.as_deref_mut(), start) {
+                    timings[system_index].elapsed = Some(start.elapsed());
+                }
                 continue;
             }
 
+            let mut skipped = false;
             let f = AssertUnwindSafe(|| {
-                if let Err(RunSystemError::Failed(err)) =
-                    __rust_begin_short_backtrace::run_without_applying_deferred(system, world)
-                {
-                    error_handler(
-                        err,
-                        ErrorContext::System {
-                            name: system.name(),
-                            last_run: system.get_last_run(),
-                        },
-                    );
+                match __rust_begin_short_backtrace::run_without_applying_deferred(system, world) {
+                    Err(RunSystemError::Failed(err)) => {
+                        error_handler(
+                            err,
+                            ErrorContext::System {
+                                name: system.name(),
+                                last_run: system.get_last_run(),
+                            },
+                        );
+                    }
+                    Err(RunSystemError::Skipped(_)) => skipped = true,
+                    Ok(()) => {}
                 }
             });
 
@@ -159,6 +173,14 @@ impl SystemExecutor for SingleThreadedExecutor {
                 (f)();
             }
 
+            if let (Some(timings), Some(start)) = (timings.as_deref_mut(), start) {
+                if skipped {
+                    timings[system_index].skipped = true;
+                } else {
+                    timings[system_index].elapsed = Some(start.elapsed());
+                }
+            }
+
             self.unapplied_systems.insert(system_index);
         }
 
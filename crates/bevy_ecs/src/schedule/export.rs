@@ -0,0 +1,466 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_platform::collections::HashMap;
+use core::fmt::Write;
+
+use crate::{
+    component::Components,
+    schedule::{
+        graph::Direction, is_apply_deferred, NodeId, Schedule, ScheduleLabel,
+        ScheduleNotInitialized, Schedules, SystemKey,
+    },
+};
+
+/// A snapshot of the systems, system sets and edges of a [`Schedule`], meant for debugging tools.
+///
+/// This can be rendered as a [Graphviz] DOT document with [`ScheduleGraphExport::to_dot`],
+/// or serialized (for example to JSON) when the `serialize` feature is enabled.
+///
+/// Created with [`Schedule::export_graph`] or [`Schedules::export_graph`].
+///
+/// [Graphviz]: https://graphviz.org
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScheduleGraphExport {
+    /// The label of the exported schedule.
+    pub schedule: String,
+    /// Every system of the schedule, in the order the executor considers them.
+    pub systems: Vec<SystemExport>,
+    /// Every system set of the schedule.
+    pub sets: Vec<SystemSetExport>,
+    /// Edges from a set to the systems and sets it contains.
+    pub hierarchy: Vec<EdgeExport>,
+    /// Ordering edges declared with `before`, `after` and `chain`, from the node that runs first.
+    pub dependencies: Vec<EdgeExport>,
+    /// Ordering edges between systems once sets are flattened,
+    /// redundant edges are removed and sync points are inserted.
+    ///
+    /// These are the edges the executor actually uses.
+    pub flattened_dependencies: Vec<EdgeExport>,
+    /// Pairs of systems with conflicting data access and no ordering between them.
+    pub ambiguities: Vec<AmbiguityExport>,
+}
+
+/// A system in a [`ScheduleGraphExport`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct SystemExport {
+    /// The identifier of this node, unique within the export.
+    pub id: String,
+    /// The name of the system.
+    pub name: String,
+    /// The names of the run conditions of the system.
+    pub conditions: Vec<String>,
+    /// Whether the system has exclusive [`World`](crate::world::World) access.
+    pub is_exclusive: bool,
+    /// Whether the system is an [`ApplyDeferred`](crate::schedule::ApplyDeferred) sync point.
+    pub is_apply_deferred: bool,
+    /// Whether the system was inserted automatically by
+    /// [`AutoInsertApplyDeferredPass`](crate::schedule::passes::AutoInsertApplyDeferredPass).
+    pub is_auto_inserted: bool,
+}
+
+/// A system set in a [`ScheduleGraphExport`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct SystemSetExport {
+    /// The identifier of this node, unique within the export.
+    pub id: String,
+    /// The name of the set.
+    ///
+    /// Anonymous sets, created when configuring a tuple of systems, are named after their contents.
+    pub name: String,
+    /// The names of the run conditions of the set.
+    pub conditions: Vec<String>,
+    /// Whether the set was created implicitly when configuring a tuple of systems.
+    pub is_anonymous: bool,
+}
+
+/// A directed edge between two nodes of a [`ScheduleGraphExport`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct EdgeExport {
+    /// The identifier of the source node.
+    pub from: String,
+    /// The identifier of the target node.
+    pub to: String,
+}
+
+/// A pair of ambiguous systems in a [`ScheduleGraphExport`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct AmbiguityExport {
+    /// The identifier of the first system.
+    pub a: String,
+    /// The identifier of the second system.
+    pub b: String,
+    /// The names of the components and resources both systems access.
+    ///
+    /// Empty if the systems conflict on access to the whole [`World`](crate::world::World).
+    pub conflicts: Vec<String>,
+}
+
+impl Schedule {
+    /// Exports the systems, sets and edges of this schedule, including ambiguities and
+    /// automatically inserted sync points.
+    ///
+    /// The schedule must have been initialized, for example by running it once or calling
+    /// [`Schedule::initialize`]. `components` is used to name the data accessed by ambiguous systems.
+    pub fn export_graph(
+        &self,
+        components: &Components,
+    ) -> Result<ScheduleGraphExport, ScheduleNotInitialized> {
+        let graph = self.graph();
+        let executable = &self.executable;
+        let mut ids = HashMap::<NodeId, String>::default();
+        let mut export = ScheduleGraphExport {
+            schedule: format!("{:?}", self.label()),
+            ..Default::default()
+        };
+
+        for (index, (key, system)) in self.systems()?.enumerate() {
+            let node = NodeId::System(key);
+            let id = format!("system{index}");
+            ids.insert(node, id.clone());
+            export.systems.push(SystemExport {
+                id,
+                name: system.name().to_string(),
+                conditions: executable.system_conditions[index]
+                    .iter()
+                    .map(|condition| condition.condition.name().to_string())
+                    .collect(),
+                is_exclusive: system.is_exclusive(),
+                is_apply_deferred: is_apply_deferred(&**system),
+                is_auto_inserted: is_apply_deferred(&**system)
+                    && !graph.dependency().graph().contains_node(node),
+            });
+        }
+
+        let system_names = executable
+            .system_ids
+            .iter()
+            .zip(&export.systems)
+            .map(|(key, system)| (*key, system.name.clone()))
+            .collect::<HashMap<SystemKey, String>>();
+        let set_conditions = executable
+            .set_ids
+            .iter()
+            .zip(&executable.set_conditions)
+            .collect::<HashMap<_, _>>();
+        for (index, (key, set, _)) in graph.system_sets.iter().enumerate() {
+            let node = NodeId::Set(key);
+            let id = format!("set{index}");
+            ids.insert(node, id.clone());
+            let name = if set.is_anonymous() {
+                let members = graph
+                    .hierarchy()
+                    .graph()
+                    .neighbors_directed(node, Direction::Outgoing)
+                    .filter_map(|member| match member {
+                        NodeId::System(key) => system_names.get(&key).cloned(),
+                        NodeId::Set(key) => {
+                            graph.system_sets.get(key).map(|set| format!("{set:?}"))
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                format!("({})", members.join(", "))
+            } else {
+                format!("{set:?}")
+            };
+            export.sets.push(SystemSetExport {
+                id,
+                name,
+                conditions: set_conditions
+                    .get(&key)
+                    .into_iter()
+                    .flat_map(|conditions| conditions.iter())
+                    .map(|condition| condition.condition.name().to_string())
+                    .collect(),
+                is_anonymous: set.is_anonymous(),
+            });
+        }
+
+        let edge = |(from, to): (NodeId, NodeId)| {
+            Some(EdgeExport {
+                from: ids.get(&from)?.clone(),
+                to: ids.get(&to)?.clone(),
+            })
+        };
+        export.hierarchy = graph
+            .hierarchy()
+            .graph()
+            .all_edges()
+            .filter_map(edge)
+            .collect();
+        export.dependencies = graph
+            .dependency()
+            .graph()
+            .all_edges()
+            .filter_map(edge)
+            .collect();
+        export.flattened_dependencies = executable
+            .system_dependents
+            .iter()
+            .enumerate()
+            .flat_map(|(from, dependents)| dependents.iter().map(move |&to| (from, to)))
+            .map(|(from, to)| EdgeExport {
+                from: export.systems[from].id.clone(),
+                to: export.systems[to].id.clone(),
+            })
+            .collect();
+        export.ambiguities = graph
+            .conflicting_systems()
+            .iter()
+            .filter_map(|(a, b, conflicts)| {
+                Some(AmbiguityExport {
+                    a: ids.get(&NodeId::System(*a))?.clone(),
+                    b: ids.get(&NodeId::System(*b))?.clone(),
+                    conflicts: conflicts
+                        .iter()
+                        .filter_map(|id| components.get_name(*id))
+                        .map(|name| name.to_string())
+                        .collect(),
+                })
+            })
+            .collect();
+
+        Ok(export)
+    }
+}
+
+impl Schedules {
+    /// Exports the graph of the schedule with the given label.
+    ///
+    /// Returns `None` if there is no such schedule.
+    /// See [`Schedule::export_graph`] for more information.
+    pub fn export_graph(
+        &self,
+        label: impl ScheduleLabel,
+        components: &Components,
+    ) -> Option<Result<ScheduleGraphExport, ScheduleNotInitialized>> {
+        self.get(label)
+            .map(|schedule| schedule.export_graph(components))
+    }
+}
+
+impl ScheduleGraphExport {
+    /// Renders this export as a [Graphviz] DOT document.
+    ///
+    /// - Systems are boxes, sync points are octagons (dashed when automatically inserted)
+    ///   and sets are clusters-like rounded boxes.
+    /// - Set membership is drawn as dotted gray edges.
+    /// - Declared ordering dependencies are drawn as solid black edges,
+    ///   and the edges to and from automatically inserted sync points in blue.
+    /// - Ambiguities are drawn as dashed red undirected edges labelled with the conflicting data.
+    ///
+    /// [Graphviz]: https://graphviz.org
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        // Writing to a `String` cannot fail.
+        let _ = self.write_dot(&mut dot);
+        dot
+    }
+
+    fn write_dot(&self, dot: &mut String) -> core::fmt::Result {
+        writeln!(dot, "digraph {} {{", quote(&self.schedule))?;
+        writeln!(dot, "\trankdir=LR;")?;
+        writeln!(dot, "\tnode [fontname=\"Helvetica\"];")?;
+
+        for system in &self.systems {
+            let shape = if system.is_apply_deferred {
+                "octagon"
+            } else {
+                "box"
+            };
+            let style = if system.is_auto_inserted {
+                "dashed"
+            } else if system.is_exclusive {
+                "bold"
+            } else {
+                "solid"
+            };
+            writeln!(
+                dot,
+                "\t{} [label={}, shape={shape}, style={style}];",
+                quote(&system.id),
+                quote(&label_with_conditions(&system.name, &system.conditions)),
+            )?;
+        }
+        for set in &self.sets {
+            let style = if set.is_anonymous {
+                "\"rounded,dashed\""
+            } else {
+                "rounded"
+            };
+            writeln!(
+                dot,
+                "\t{} [label={}, shape=box, style={style}, color=gray40];",
+                quote(&set.id),
+                quote(&label_with_conditions(&set.name, &set.conditions)),
+            )?;
+        }
+
+        for edge in &self.hierarchy {
+            writeln!(
+                dot,
+                "\t{} -> {} [style=dotted, color=gray60, arrowhead=none];",
+                quote(&edge.from),
+                quote(&edge.to),
+            )?;
+        }
+        for edge in &self.dependencies {
+            writeln!(dot, "\t{} -> {};", quote(&edge.from), quote(&edge.to))?;
+        }
+        let auto_inserted = self
+            .systems
+            .iter()
+            .filter(|system| system.is_auto_inserted)
+            .map(|system| system.id.as_str())
+            .collect::<Vec<_>>();
+        for edge in self.flattened_dependencies.iter().filter(|edge| {
+            auto_inserted.contains(&edge.from.as_str()) || auto_inserted.contains(&edge.to.as_str())
+        }) {
+            writeln!(
+                dot,
+                "\t{} -> {} [color=blue];",
+                quote(&edge.from),
+                quote(&edge.to),
+            )?;
+        }
+        for ambiguity in &self.ambiguities {
+            writeln!(
+                dot,
+                "\t{} -> {} [dir=none, style=dashed, color=red, fontcolor=red, label={}];",
+                quote(&ambiguity.a),
+                quote(&ambiguity.b),
+                quote(&ambiguity.conflicts.join("\n")),
+            )?;
+        }
+
+        writeln!(dot, "}}")
+    }
+}
+
+fn label_with_conditions(name: &str, conditions: &[String]) -> String {
+    let mut label = name.to_string();
+    for condition in conditions {
+        label.push_str("\nrun_if: ");
+        label.push_str(condition);
+    }
+    label
+}
+
+/// Quotes a string as a DOT identifier.
+fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, schedule::ScheduleLabel};
+
+    #[derive(ScheduleLabel, Hash, PartialEq, Eq, Debug, Clone)]
+    struct TestSchedule;
+
+    #[derive(SystemSet, Hash, PartialEq, Eq, Debug, Clone)]
+    struct TestSet;
+
+    #[derive(Resource)]
+    struct Counter(u32);
+
+    fn spawn(mut commands: Commands) {
+        commands.spawn_empty();
+    }
+
+    fn count_a(mut counter: ResMut<Counter>) {
+        counter.0 += 1;
+    }
+
+    fn count_b(mut counter: ResMut<Counter>) {
+        counter.0 += 1;
+    }
+
+    #[test]
+    fn export_schedule_graph() {
+        let mut world = World::new();
+        world.insert_resource(Counter(0));
+
+        let mut schedule = Schedule::new(TestSchedule);
+        schedule.configure_sets(TestSet.run_if(|| true));
+        schedule.add_systems((spawn, count_a.in_set(TestSet)).chain());
+        schedule.add_systems(count_b);
+
+        assert!(schedule.export_graph(world.components()).is_err());
+        schedule.initialize(&mut world).unwrap();
+        let export = schedule.export_graph(world.components()).unwrap();
+
+        assert_eq!(export.schedule, "TestSchedule");
+        assert_eq!(export.systems.len(), 4);
+
+        // `spawn` uses `Commands` and is ordered before `count_a`, so a sync point is inserted.
+        let sync = export
+            .systems
+            .iter()
+            .find(|system| system.is_auto_inserted)
+            .unwrap();
+        assert!(sync.is_apply_deferred);
+        assert!(sync.is_exclusive);
+        let edge_from = |id: &str| {
+            export
+                .flattened_dependencies
+                .iter()
+                .find(|edge| edge.to == id)
+                .unwrap()
+                .from
+                .clone()
+        };
+        let spawn = edge_from(&sync.id);
+        let count_a = export
+            .flattened_dependencies
+            .iter()
+            .find(|edge| edge.from == sync.id)
+            .unwrap()
+            .to
+            .clone();
+        assert!(export
+            .dependencies
+            .iter()
+            .any(|edge| edge.from == spawn && edge.to == count_a));
+
+        let test_set = export
+            .sets
+            .iter()
+            .find(|set| set.name == "TestSet")
+            .unwrap();
+        assert_eq!(test_set.conditions.len(), 1);
+        assert!(export
+            .hierarchy
+            .iter()
+            .any(|edge| edge.from == test_set.id && edge.to == count_a));
+
+        // `count_a` and `count_b` both mutate `Counter` without being ordered.
+        assert_eq!(export.ambiguities.len(), 1);
+        let ambiguity = &export.ambiguities[0];
+        assert!([&ambiguity.a, &ambiguity.b].contains(&&count_a));
+        assert_eq!(ambiguity.conflicts.len(), 1);
+
+        let dot = export.to_dot();
+        assert!(dot.starts_with("digraph \"TestSchedule\" {"));
+        assert!(dot.contains(&alloc::format!("\"{spawn}\" -> \"{count_a}\";")));
+        assert!(dot.contains("color=red"));
+    }
+}
@@ -5,16 +5,21 @@ mod condition;
 mod config;
 mod error;
 mod executor;
+mod export;
 mod node;
 mod pass;
 mod schedule;
 mod set;
 mod stepping;
+mod timings;
 
 pub use self::graph::GraphInfo;
 use self::graph::*;
-pub use self::{condition::*, config::*, error::*, executor::*, node::*, schedule::*, set::*};
+pub use self::{
+    condition::*, config::*, error::*, executor::*, export::*, node::*, schedule::*, set::*,
+};
 pub use pass::ScheduleBuildPass;
+pub use timings::{SystemTiming, SystemTimings};
 
 /// An implementation of a graph data structure.
 pub mod graph;
@@ -329,10 +329,12 @@ impl Chain {
 pub struct Schedule {
     label: InternedScheduleLabel,
     graph: ScheduleGraph,
-    executable: SystemSchedule,
+    pub(super) executable: SystemSchedule,
     executor: Box<dyn SystemExecutor>,
     executor_initialized: bool,
     warnings: Vec<ScheduleBuildWarning>,
+    /// Per-system timings of the last run, only filled when [`SystemTimings`] exists.
+    timings: Vec<timings::SystemRunTiming>,
 }
 
 #[derive(ScheduleLabel, Hash, PartialEq, Eq, Debug, Clone)]
@@ -358,6 +360,7 @@ impl Schedule {
             executor: make_executor(ExecutorKind::default()),
             executor_initialized: false,
             warnings: Vec::new(),
+            timings: Vec::new(),
         };
         // Call `set_build_settings` to add any default build passes
         this.set_build_settings(Default::default());
@@ -488,9 +491,19 @@ impl Schedule {
 
         let error_handler = world.default_error_handler();
 
+        let record_timings = world.contains_resource::<SystemTimings>();
+        self.timings.clear();
+        if record_timings {
+            self.timings
+                .resize(self.executable.systems.len(), Default::default());
+        }
+
         #[cfg(not(feature = "bevy_debug_stepping"))]
-        self.executor
-            .run(&mut self.executable, world, None, error_handler);
+        {
+            let timings = record_timings.then_some(self.timings.as_mut_slice());
+            self.executor
+                .run(&mut self.executable, world, None, error_handler, timings);
+        }
 
         #[cfg(feature = "bevy_debug_stepping")]
         {
@@ -499,13 +512,29 @@ impl Schedule {
                 Some(mut stepping) => stepping.skipped_systems(self),
             };
 
+            // Borrowed only after `skipped_systems`, which needs all of `self`.
+            let timings = record_timings.then_some(self.timings.as_mut_slice());
             self.executor.run(
                 &mut self.executable,
                 world,
                 skip_systems.as_ref(),
                 error_handler,
+                timings,
             );
         }
+
+        if record_timings {
+            if let Some(mut system_timings) = world.get_resource_mut::<SystemTimings>() {
+                let systems = self
+                    .executable
+                    .system_ids
+                    .iter()
+                    .zip(&self.executable.systems)
+                    .zip(&self.timings)
+                    .map(|((key, system), timing)| (*key, system.system.name(), timing));
+                system_timings.record(self.label, systems);
+            }
+        }
     }
 
     /// Initializes any newly-added systems and conditions, rebuilds the executable schedule,
@@ -0,0 +1,137 @@
+use bevy_platform::collections::HashMap;
+use bevy_utils::prelude::DebugName;
+use core::time::Duration;
+
+use crate::{
+    resource::Resource,
+    schedule::{InternedScheduleLabel, SystemKey},
+};
+
+/// Per-system profiling data, collected by every executor when this resource is present in the [`World`].
+///
+/// Collecting timings is opt-in: insert this resource (for example with
+/// [`World::init_resource`]) to have each [`Schedule`] record the wall time, run count and skip count
+/// of its systems after every run. Removing the resource stops the collection.
+///
+/// The recorded values are cumulative. To get per-frame values, compare
+/// [`SystemTiming::total_time`] and [`SystemTiming::run_count`] between two frames.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::SystemTimings;
+/// let mut world = World::new();
+/// world.init_resource::<SystemTimings>();
+///
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems(|| {});
+/// schedule.run(&mut world);
+///
+/// let timings = world.resource::<SystemTimings>();
+/// let slowest = timings.iter().max_by_key(|timing| timing.last_run_time).unwrap();
+/// assert_eq!(slowest.run_count, 1);
+/// ```
+///
+/// [`World`]: crate::world::World
+/// [`World::init_resource`]: crate::world::World::init_resource
+/// [`Schedule`]: crate::schedule::Schedule
+#[derive(Resource, Default, Debug)]
+pub struct SystemTimings {
+    schedules: HashMap<InternedScheduleLabel, HashMap<SystemKey, SystemTiming>>,
+}
+
+/// Profiling data for a single system of a single schedule.
+///
+/// See [`SystemTimings`].
+#[derive(Clone, Debug)]
+pub struct SystemTiming {
+    /// The name of the system.
+    pub name: DebugName,
+    /// The label of the schedule the system belongs to.
+    pub schedule: InternedScheduleLabel,
+    /// The key of the system in its schedule.
+    pub key: SystemKey,
+    /// The wall time spent running the system the last time it ran.
+    pub last_run_time: Duration,
+    /// The total wall time spent running the system since timings started being recorded.
+    pub total_time: Duration,
+    /// How many times the system ran.
+    pub run_count: u64,
+    /// How many times the system was skipped, because its run conditions (or the ones of its sets)
+    /// returned `false` or because its parameters failed validation.
+    pub skip_count: u64,
+}
+
+impl SystemTiming {
+    /// Returns the average wall time of a run of this system, or [`Duration::ZERO`] if it never ran.
+    pub fn average_time(&self) -> Duration {
+        if self.run_count == 0 {
+            return Duration::ZERO;
+        }
+        self.total_time.div_f64(self.run_count as f64)
+    }
+}
+
+impl SystemTimings {
+    /// Returns an iterator over the timings of every system of every schedule that ran.
+    pub fn iter(&self) -> impl Iterator<Item = &SystemTiming> {
+        self.schedules.values().flat_map(HashMap::values)
+    }
+
+    /// Returns an iterator over the timings of the systems of the given schedule.
+    pub fn schedule(&self, label: InternedScheduleLabel) -> impl Iterator<Item = &SystemTiming> {
+        self.schedules
+            .get(&label)
+            .into_iter()
+            .flat_map(HashMap::values)
+    }
+
+    /// Returns the timing of a single system, if it has been recorded.
+    pub fn get(&self, label: InternedScheduleLabel, system: SystemKey) -> Option<&SystemTiming> {
+        self.schedules.get(&label)?.get(&system)
+    }
+
+    /// Clears all recorded timings.
+    pub fn clear(&mut self) {
+        self.schedules.clear();
+    }
+
+    /// Adds the timings recorded by an executor during a run of a schedule.
+    pub(super) fn record<'a>(
+        &mut self,
+        label: InternedScheduleLabel,
+        systems: impl Iterator<Item = (SystemKey, DebugName, &'a SystemRunTiming)>,
+    ) {
+        let schedule = self.schedules.entry(label).or_default();
+        for (key, name, run) in systems {
+            if run.elapsed.is_none() && !run.skipped {
+                continue;
+            }
+            let timing = schedule.entry(key).or_insert_with(|| SystemTiming {
+                name,
+                schedule: label,
+                key,
+                last_run_time: Duration::ZERO,
+                total_time: Duration::ZERO,
+                run_count: 0,
+                skip_count: 0,
+            });
+            if let Some(elapsed) = run.elapsed {
+                timing.last_run_time = elapsed;
+                timing.total_time += elapsed;
+                timing.run_count += 1;
+            }
+            if run.skipped {
+                timing.skip_count += 1;
+            }
+        }
+    }
+}
+
+/// What happened to a single system during one run of a schedule, as recorded by an executor.
+#[derive(Clone, Copy, Default, Debug)]
+pub(super) struct SystemRunTiming {
+    /// The wall time the system took, if it ran.
+    pub(super) elapsed: Option<Duration>,
+    /// Whether the system was skipped by its conditions or by parameter validation.
+    pub(super) skipped: bool,
+}
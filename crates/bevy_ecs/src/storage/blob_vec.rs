@@ -8,7 +8,7 @@ use core::{alloc::Layout, cell::UnsafeCell, num::NonZero, ptr::NonNull};
 /// Used to densely store homogeneous ECS data. A blob is usually just an arbitrary block of contiguous memory without any identity, and
 /// could be used to represent any arbitrary data (i.e. string, arrays, etc). This type is an extendable and re-allocatable blob, which makes
 /// it a blobby Vec, a `BlobVec`.
-pub(super) struct BlobVec {
+pub(crate) struct BlobVec {
     item_layout: Layout,
     capacity: usize,
     /// Number of elements, not bytes
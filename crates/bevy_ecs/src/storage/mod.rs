@@ -27,6 +27,7 @@ mod sparse_set;
 mod table;
 mod thin_array_ptr;
 
+pub(crate) use blob_vec::BlobVec;
 pub use resource::*;
 pub use sparse_set::*;
 pub use table::*;
@@ -7,6 +7,7 @@ mod entity_ref;
 pub mod error;
 mod filtered_resource;
 mod identifier;
+mod snapshot;
 mod spawn_batch;
 pub mod unsafe_world_cell;
 
@@ -34,6 +35,7 @@ pub use entity_ref::{
 };
 pub use filtered_resource::*;
 pub use identifier::WorldId;
+pub use snapshot::*;
 pub use spawn_batch::*;
 
 use crate::{
@@ -1094,6 +1096,20 @@ impl World {
         unsafe { self.spawn_at_empty_internal(entity, MaybeLocation::caller()) }
     }
 
+    /// Spawns a new empty entity with exactly the given [`Entity`] id, or returns `None` if its row
+    /// is already in use.
+    ///
+    /// See [`Entities::alloc_at`].
+    #[track_caller]
+    pub(crate) fn spawn_empty_at(&mut self, entity: Entity) -> Option<EntityWorldMut<'_>> {
+        self.flush();
+        if !self.entities.alloc_at(entity) {
+            return None;
+        }
+        // SAFETY: entity was just allocated
+        Some(unsafe { self.spawn_at_empty_internal(entity, MaybeLocation::caller()) })
+    }
+
     /// Spawns a new [`Entity`] with a given [`Bundle`] of [components](`Component`) and returns
     /// a corresponding [`EntityWorldMut`], which can be used to add components to the entity or
     /// retrieve its id. In case large batches of entities need to be spawned, consider using
@@ -0,0 +1,927 @@
+//! Capturing and restoring part of the state of a [`World`], for example for rollback netcode.
+
+use alloc::{boxed::Box, collections::VecDeque, vec::Vec};
+use bevy_platform::sync::Arc;
+use bevy_ptr::{OwningPtr, Ptr};
+use core::{alloc::Layout, any::TypeId};
+
+use crate::{
+    archetype::ArchetypeEntity,
+    component::{Component, ComponentId},
+    entity::{Entity, EntityHashSet},
+    resource::Resource,
+    storage::BlobVec,
+    world::{EntityWorldMut, World},
+};
+
+#[cfg(feature = "bevy_reflect")]
+use {
+    crate::reflect::{AppTypeRegistry, ReflectComponent},
+    bevy_reflect::{Reflect, ReflectFromPtr, ReflectFromReflect, TypeRegistration},
+    core::ptr::NonNull,
+};
+
+/// Describes which components and resources are recorded in a [`WorldSnapshot`].
+///
+/// Components are captured either through their [`Clone`] implementation
+/// ([`with_component`](Self::with_component)) or through reflection
+/// ([`with_reflect_component`](Self::with_reflect_component)). Resources are captured through [`Clone`].
+///
+/// The entities tracked by a snapshot are the ones that have at least one of the configured components.
+/// When a snapshot is restored, tracked entities that were spawned since then are despawned, and tracked
+/// entities that were despawned since then are spawned again with their original [`Entity`] id.
+/// To track the lifetime of entities independently of their other components, add a marker component
+/// to them and to the configuration.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::SnapshotConfig;
+/// #[derive(Component, Clone, PartialEq, Debug)]
+/// struct Position(f32);
+///
+/// let mut world = World::new();
+/// let entity = world.spawn(Position(0.0)).id();
+///
+/// let config = SnapshotConfig::new().with_component::<Position>();
+/// let snapshot = config.capture(&world);
+///
+/// world.despawn(entity);
+/// let spawned = world.spawn(Position(5.0)).id();
+///
+/// snapshot.restore(&mut world).unwrap();
+/// assert_eq!(world.get::<Position>(entity), Some(&Position(0.0)));
+/// assert!(world.get_entity(spawned).is_err());
+/// ```
+#[derive(Clone, Default)]
+pub struct SnapshotConfig {
+    components: Vec<(TypeId, CaptureComponentFn)>,
+    resources: Vec<(TypeId, CaptureResourceFn)>,
+}
+
+type CaptureComponentFn = Arc<dyn Fn(&World) -> ComponentColumn + Send + Sync>;
+type CaptureResourceFn = fn(&World) -> Box<dyn ResourceSnapshot>;
+
+impl SnapshotConfig {
+    /// Creates a configuration that doesn't record anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the component `C`, cloning it with its [`Clone`] implementation.
+    pub fn with_component<C: Component + Clone>(mut self) -> Self {
+        if !self.has_component(TypeId::of::<C>()) {
+            self.components
+                .push((TypeId::of::<C>(), Arc::new(capture_cloned_component::<C>)));
+        }
+        self
+    }
+
+    /// Records the component described by `registration`, cloning it through reflection.
+    ///
+    /// This is useful for components that are only known through the type registry. Restoring a snapshot
+    /// containing reflected components requires the [`AppTypeRegistry`] resource.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the type has no [`ReflectComponent`], [`ReflectFromReflect`] or [`ReflectFromPtr`] type data.
+    #[cfg(feature = "bevy_reflect")]
+    pub fn with_reflect_component(mut self, registration: &TypeRegistration) -> Self {
+        let type_id = registration.type_id();
+        let type_path = registration.type_info().type_path();
+        let Some(reflect_component) = registration.data::<ReflectComponent>() else {
+            panic!(
+                "Type {type_path} has no `ReflectComponent` type data, add `#[reflect(Component)]` to it",
+            );
+        };
+        let (Some(from_reflect), Some(from_ptr)) = (
+            registration.data::<ReflectFromReflect>(),
+            registration.data::<ReflectFromPtr>(),
+        ) else {
+            panic!(
+                "Type {type_path} has no `ReflectFromReflect` or `ReflectFromPtr` type data, which are needed to store its values",
+            );
+        };
+        if !self.has_component(type_id) {
+            let (reflect_component, from_ptr, from_reflect) = (
+                reflect_component.clone(),
+                from_ptr.clone(),
+                from_reflect.clone(),
+            );
+            self.components.push((
+                type_id,
+                Arc::new(move |world| {
+                    capture_reflected_component(
+                        world,
+                        type_id,
+                        &reflect_component,
+                        &from_ptr,
+                        &from_reflect,
+                    )
+                }),
+            ));
+        }
+        self
+    }
+
+    /// Records the resource `R`, cloning it with its [`Clone`] implementation.
+    ///
+    /// If the resource doesn't exist when the snapshot is captured, it is removed when the snapshot is restored.
+    pub fn with_resource<R: Resource + Clone>(mut self) -> Self {
+        if !self
+            .resources
+            .iter()
+            .any(|(id, _)| *id == TypeId::of::<R>())
+        {
+            self.resources
+                .push((TypeId::of::<R>(), capture_cloned_resource::<R>));
+        }
+        self
+    }
+
+    fn has_component(&self, type_id: TypeId) -> bool {
+        self.components.iter().any(|(id, _)| *id == type_id)
+    }
+
+    /// Records the configured components and resources of `world`.
+    pub fn capture(&self, world: &World) -> WorldSnapshot {
+        let components: Vec<_> = self
+            .components
+            .iter()
+            .map(|(_, capture)| capture(world))
+            .collect();
+        let entities = components
+            .iter()
+            .flat_map(|column| column.entities.iter().copied())
+            .collect();
+        let resources = self
+            .resources
+            .iter()
+            .map(|(_, capture)| capture(world))
+            .collect();
+        WorldSnapshot {
+            entities,
+            components,
+            resources,
+        }
+    }
+}
+
+/// The state of a filtered set of components and resources of a [`World`] at some point in time.
+///
+/// Created with [`SnapshotConfig::capture`], and usually stored in a [`SnapshotBuffer`].
+/// The values of each component type are stored next to each other, without any per-value allocation.
+pub struct WorldSnapshot {
+    entities: EntityHashSet,
+    components: Vec<ComponentColumn>,
+    resources: Vec<Box<dyn ResourceSnapshot>>,
+}
+
+impl WorldSnapshot {
+    /// Returns the entities tracked by this snapshot.
+    pub fn entities(&self) -> &EntityHashSet {
+        &self.entities
+    }
+
+    /// Returns `true` if `entity` is tracked by this snapshot.
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.entities.contains(&entity)
+    }
+
+    /// Restores `world` to the state recorded in this snapshot.
+    ///
+    /// - tracked entities that are not part of the snapshot are despawned,
+    /// - entities of the snapshot that no longer exist are spawned again with their original id,
+    /// - the recorded components are inserted again, overwriting the current values, and removed from
+    ///   the entities of the snapshot that didn't have them,
+    /// - the recorded resources are inserted again, or removed if they didn't exist.
+    ///
+    /// Entities spawned again get back the exact [`Entity`] they had, so handles to them stored anywhere
+    /// stay valid. Handles to the entities that used the same row in between never become valid again.
+    ///
+    /// Components are inserted normally, so hooks and observers run. The snapshot itself is left untouched
+    /// and can be restored again.
+    ///
+    /// Returns an error without changing `world` if an entity of the snapshot can't be spawned again,
+    /// because its row is now used by an entity that is not tracked, or if the snapshot contains reflected
+    /// components but `world` has no [`AppTypeRegistry`].
+    pub fn restore(&self, world: &mut World) -> Result<(), SnapshotRestoreError> {
+        world.flush();
+
+        let mut tracked = EntityHashSet::default();
+        for column in &self.components {
+            tracked.extend(entities_with(world, column.component_id(world)));
+        }
+
+        for &entity in &self.entities {
+            let Some(occupied_by) = world.entities().resolve_from_id(entity.row()) else {
+                continue;
+            };
+            if occupied_by != entity
+                && world.entities().get(occupied_by).is_some()
+                && !tracked.contains(&occupied_by)
+            {
+                return Err(SnapshotRestoreError::EntityRowOccupied {
+                    entity,
+                    occupied_by,
+                });
+            }
+        }
+        #[cfg(feature = "bevy_reflect")]
+        if self.components.iter().any(ComponentColumn::is_reflected)
+            && !world.contains_resource::<AppTypeRegistry>()
+        {
+            return Err(SnapshotRestoreError::MissingTypeRegistry);
+        }
+
+        for entity in tracked {
+            if !self.entities.contains(&entity) {
+                // The entity could already have been despawned along with a related entity.
+                world.try_despawn(entity).ok();
+            }
+        }
+        for &entity in &self.entities {
+            if world.entities().get(entity).is_none() && world.spawn_empty_at(entity).is_none() {
+                // Only possible if a despawn hook spawned an untracked entity in the freed row.
+                return Err(SnapshotRestoreError::EntityRowOccupied {
+                    entity,
+                    occupied_by: world
+                        .entities()
+                        .resolve_from_id(entity.row())
+                        .unwrap_or(entity),
+                });
+            }
+        }
+
+        for column in &self.components {
+            column.restore(world, &self.entities);
+        }
+        for resource in &self.resources {
+            resource.restore(world);
+        }
+        Ok(())
+    }
+}
+
+/// A ring buffer of the [`WorldSnapshot`]s of the last frames, for rollback.
+///
+/// Each call to [`save`](Self::save) captures a new snapshot with the buffer's [`SnapshotConfig`] and gives
+/// it the next frame number, dropping the oldest snapshot once the buffer is full. Rolling back to a frame
+/// restores its snapshot and discards the ones of the later frames, which will be simulated and saved again.
+///
+/// To record a snapshot every fixed tick, insert the buffer as a resource and add
+/// [`SnapshotBuffer::save_system`] at the end of the fixed timestep schedules, for example in `FixedPostUpdate`.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::{SnapshotBuffer, SnapshotConfig};
+/// #[derive(Component, Clone, PartialEq, Debug)]
+/// struct Position(f32);
+///
+/// let mut world = World::new();
+/// let entity = world.spawn(Position(0.0)).id();
+/// world.insert_resource(SnapshotBuffer::new(
+///     SnapshotConfig::new().with_component::<Position>(),
+///     8,
+/// ));
+///
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems((
+///     |mut positions: Query<&mut Position>| positions.iter_mut().for_each(|mut p| p.0 += 1.0),
+///     SnapshotBuffer::save_system,
+/// ).chain());
+/// for _ in 0..3 {
+///     schedule.run(&mut world);
+/// }
+/// assert_eq!(world.get::<Position>(entity), Some(&Position(3.0)));
+///
+/// SnapshotBuffer::rollback(&mut world, 0).unwrap();
+/// assert_eq!(world.get::<Position>(entity), Some(&Position(1.0)));
+/// assert_eq!(world.resource::<SnapshotBuffer>().next_frame(), 1);
+/// ```
+#[derive(Resource)]
+pub struct SnapshotBuffer {
+    config: SnapshotConfig,
+    capacity: usize,
+    frames: VecDeque<(u64, WorldSnapshot)>,
+    next_frame: u64,
+}
+
+impl SnapshotBuffer {
+    /// Creates an empty buffer keeping the snapshots of the last `capacity` frames.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(config: SnapshotConfig, capacity: usize) -> Self {
+        assert!(
+            capacity > 0,
+            "a `SnapshotBuffer` must hold at least one frame"
+        );
+        Self {
+            config,
+            capacity,
+            frames: VecDeque::with_capacity(capacity),
+            next_frame: 0,
+        }
+    }
+
+    /// Returns the configuration used to capture snapshots.
+    pub fn config(&self) -> &SnapshotConfig {
+        &self.config
+    }
+
+    /// Returns the maximum number of frames kept in the buffer.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of frames currently in the buffer.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns `true` if the buffer doesn't contain any frame.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Returns the frame number the next call to [`save`](Self::save) will use.
+    pub fn next_frame(&self) -> u64 {
+        self.next_frame
+    }
+
+    /// Captures a snapshot of `world` for the next frame, and returns the number of that frame.
+    pub fn save(&mut self, world: &World) -> u64 {
+        let frame = self.next_frame;
+        if self.frames.len() == self.capacity {
+            self.frames.pop_front();
+        }
+        self.frames.push_back((frame, self.config.capture(world)));
+        self.next_frame += 1;
+        frame
+    }
+
+    /// Returns the snapshot of the given frame, if it is still in the buffer.
+    pub fn get(&self, frame: u64) -> Option<&WorldSnapshot> {
+        self.frames
+            .get(self.index_of(frame)?)
+            .map(|(_, snapshot)| snapshot)
+    }
+
+    fn index_of(&self, frame: u64) -> Option<usize> {
+        let (first, _) = self.frames.front()?;
+        let index = usize::try_from(frame.checked_sub(*first)?).ok()?;
+        (index < self.frames.len()).then_some(index)
+    }
+
+    /// Returns the most recent frame and its snapshot.
+    pub fn latest(&self) -> Option<(u64, &WorldSnapshot)> {
+        self.frames
+            .back()
+            .map(|(frame, snapshot)| (*frame, snapshot))
+    }
+
+    /// Returns an iterator over the frames in the buffer and their snapshots, from the oldest to the newest.
+    pub fn iter(&self) -> impl Iterator<Item = (u64, &WorldSnapshot)> {
+        self.frames
+            .iter()
+            .map(|(frame, snapshot)| (*frame, snapshot))
+    }
+
+    /// Restores `world` to the snapshot of `frame`, and discards the snapshots of the later frames.
+    ///
+    /// The next call to [`save`](Self::save) will save `frame + 1`. See [`WorldSnapshot::restore`].
+    pub fn restore(&mut self, frame: u64, world: &mut World) -> Result<(), SnapshotRestoreError> {
+        let index = self
+            .index_of(frame)
+            .ok_or(SnapshotRestoreError::FrameNotFound(frame))?;
+        self.frames[index].1.restore(world)?;
+        while self.frames.back().is_some_and(|(last, _)| *last > frame) {
+            self.frames.pop_back();
+        }
+        self.next_frame = frame + 1;
+        Ok(())
+    }
+
+    /// An exclusive system saving a snapshot of the world in the [`SnapshotBuffer`] resource.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`SnapshotBuffer`] resource doesn't exist.
+    pub fn save_system(world: &mut World) {
+        world.resource_scope(|world, mut buffer: crate::change_detection::Mut<Self>| {
+            buffer.save(world);
+        });
+    }
+
+    /// Restores `world` to the snapshot of `frame` of its [`SnapshotBuffer`] resource.
+    ///
+    /// See [`SnapshotBuffer::restore`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`SnapshotBuffer`] resource doesn't exist.
+    pub fn rollback(world: &mut World, frame: u64) -> Result<(), SnapshotRestoreError> {
+        world.resource_scope(|world, mut buffer: crate::change_detection::Mut<Self>| {
+            buffer.restore(frame, world)
+        })
+    }
+}
+
+/// An error that occurs when restoring a [`WorldSnapshot`].
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotRestoreError {
+    /// The requested frame is not in the [`SnapshotBuffer`].
+    #[error("The frame {0} is not in the snapshot buffer")]
+    FrameNotFound(u64),
+    /// An entity of the snapshot can't be spawned again because its row is used by an untracked entity.
+    #[error("Could not spawn entity {entity} again, its row is used by the untracked entity {occupied_by}")]
+    EntityRowOccupied {
+        /// The entity of the snapshot.
+        entity: Entity,
+        /// The entity currently using the row of `entity`.
+        occupied_by: Entity,
+    },
+    /// The snapshot contains reflected components, but there is no [`AppTypeRegistry`] in the world.
+    #[cfg(feature = "bevy_reflect")]
+    #[error("The snapshot contains reflected components but the world has no `AppTypeRegistry`")]
+    MissingTypeRegistry,
+}
+
+/// The recorded values of a single component type, stored one after the other in a [`BlobVec`].
+struct ComponentColumn {
+    type_id: TypeId,
+    /// The entity of each value, in the same order.
+    entities: Vec<Entity>,
+    values: BlobVec,
+    restore: RestoreComponentFn,
+}
+
+// SAFETY: the column only stores values of a component, which are `Send + Sync`.
+unsafe impl Send for ComponentColumn {}
+// SAFETY: the column only stores values of a component, which are `Send + Sync`.
+unsafe impl Sync for ComponentColumn {}
+
+/// How the values of a [`ComponentColumn`] are inserted into entities again.
+#[derive(Clone)]
+enum RestoreComponentFn {
+    /// Inserts a clone of the value.
+    ///
+    /// The function must only be called with a value of the component of the column.
+    Cloned(unsafe fn(Ptr<'_>, &mut EntityWorldMut)),
+    /// Inserts the value through reflection.
+    #[cfg(feature = "bevy_reflect")]
+    Reflected {
+        reflect_component: ReflectComponent,
+        from_ptr: ReflectFromPtr,
+    },
+}
+
+impl ComponentColumn {
+    /// Creates an empty column for the component `type_id`, using its layout in `world`.
+    fn new(world: &World, type_id: TypeId, restore: RestoreComponentFn) -> Self {
+        let info = world
+            .components()
+            .get_id(type_id)
+            .and_then(|id| world.components().get_info(id));
+        // A component that isn't registered has no value to store.
+        let (layout, drop) = info.map_or((Layout::new::<()>(), None), |info| {
+            (info.layout(), info.drop())
+        });
+        Self {
+            type_id,
+            entities: Vec::new(),
+            // SAFETY: `drop` is the drop function of the component, the only type stored in the column.
+            values: unsafe { BlobVec::new(layout, drop, 0) },
+            restore,
+        }
+    }
+
+    /// Appends the value of the component for `entity`.
+    ///
+    /// # Safety
+    ///
+    /// `value` must point to a value of the component of the column.
+    unsafe fn push(&mut self, entity: Entity, value: OwningPtr<'_>) {
+        self.entities.push(entity);
+        // SAFETY: the caller ensures that `value` is a value of the component, which has the layout of the column.
+        unsafe { self.values.push(value) };
+    }
+
+    /// Returns the id of the component in `world`, if it is registered.
+    fn component_id(&self, world: &World) -> Option<ComponentId> {
+        world.components().get_id(self.type_id)
+    }
+
+    #[cfg(feature = "bevy_reflect")]
+    fn is_reflected(&self) -> bool {
+        matches!(self.restore, RestoreComponentFn::Reflected { .. })
+    }
+
+    /// Inserts the recorded values, and removes the component from the other `entities`.
+    fn restore(&self, world: &mut World, entities: &EntityHashSet) {
+        if let Some(id) = self.component_id(world) {
+            let recorded: EntityHashSet = self.entities.iter().copied().collect();
+            for &entity in entities.difference(&recorded) {
+                if let Ok(mut entity) = world.get_entity_mut(entity)
+                    && entity.contains_id(id)
+                {
+                    entity.remove_by_id(id);
+                }
+            }
+        }
+
+        #[cfg(feature = "bevy_reflect")]
+        let registry = world.get_resource::<AppTypeRegistry>().cloned();
+        #[cfg(feature = "bevy_reflect")]
+        let registry = registry.as_ref().map(|registry| registry.read());
+
+        for (index, &entity) in self.entities.iter().enumerate() {
+            let Ok(mut entity) = world.get_entity_mut(entity) else {
+                continue;
+            };
+            // SAFETY: there is one value per entity of the column.
+            let value = unsafe { self.values.get_unchecked(index) };
+            match &self.restore {
+                // SAFETY: `value` is a value of the component of the column.
+                RestoreComponentFn::Cloned(insert) => unsafe { insert(value, &mut entity) },
+                #[cfg(feature = "bevy_reflect")]
+                RestoreComponentFn::Reflected {
+                    reflect_component,
+                    from_ptr,
+                } => {
+                    let Some(registry) = &registry else {
+                        return;
+                    };
+                    // SAFETY: `value` is a value of the component of the column, the type of `from_ptr`.
+                    let value = unsafe { from_ptr.as_reflect(value) };
+                    reflect_component.insert(&mut entity, value.as_partial_reflect(), registry);
+                }
+            }
+        }
+    }
+}
+
+/// The recorded value of a single resource type.
+trait ResourceSnapshot: Send + Sync + 'static {
+    fn restore(&self, world: &mut World);
+}
+
+/// Returns all the entities that have the component `id`.
+fn entities_with(world: &World, id: Option<ComponentId>) -> impl Iterator<Item = Entity> + '_ {
+    world
+        .archetypes()
+        .iter()
+        .filter(move |archetype| id.is_some_and(|id| archetype.contains(id)))
+        .flat_map(|archetype| archetype.entities().iter().map(ArchetypeEntity::id))
+}
+
+fn capture_cloned_component<C: Component + Clone>(world: &World) -> ComponentColumn {
+    let mut column = ComponentColumn::new(
+        world,
+        TypeId::of::<C>(),
+        RestoreComponentFn::Cloned(insert_cloned::<C>),
+    );
+    for entity in entities_with(world, world.component_id::<C>()) {
+        if let Some(value) = world.get::<C>(entity) {
+            OwningPtr::make(value.clone(), |value| {
+                // SAFETY: `value` is a `C`, the component of the column.
+                unsafe { column.push(entity, value) };
+            });
+        }
+    }
+    column
+}
+
+/// Inserts a clone of `value` into `entity`.
+///
+/// # Safety
+///
+/// `value` must point to a `C`.
+unsafe fn insert_cloned<C: Component + Clone>(value: Ptr<'_>, entity: &mut EntityWorldMut) {
+    // SAFETY: the caller ensures that `value` is a `C`.
+    entity.insert(unsafe { value.deref::<C>() }.clone());
+}
+
+#[cfg(feature = "bevy_reflect")]
+fn capture_reflected_component(
+    world: &World,
+    type_id: TypeId,
+    reflect_component: &ReflectComponent,
+    from_ptr: &ReflectFromPtr,
+    from_reflect: &ReflectFromReflect,
+) -> ComponentColumn {
+    let restore = RestoreComponentFn::Reflected {
+        reflect_component: reflect_component.clone(),
+        from_ptr: from_ptr.clone(),
+    };
+    let mut column = ComponentColumn::new(world, type_id, restore);
+    for entity in entities_with(world, column.component_id(world)) {
+        let Some(value) = reflect_component.reflect(world.entity(entity)) else {
+            continue;
+        };
+        // The column stores values of the concrete component type, which `reflect_clone` may not return.
+        let value = value
+            .reflect_clone()
+            .ok()
+            .filter(|clone| clone.as_any().type_id() == type_id)
+            .or_else(|| from_reflect.from_reflect(value.as_partial_reflect()))
+            .unwrap_or_else(|| {
+                panic!(
+                    "Could not clone a value of {} through reflection",
+                    value.reflect_type_path()
+                )
+            });
+        let layout = Layout::for_value::<dyn Reflect>(&*value);
+        let value = Box::into_raw(value).cast::<u8>();
+        // SAFETY:
+        // - `value` is a value of the component of the column, moved out of its box.
+        // - the box is deallocated without dropping the value, which is owned by the column.
+        unsafe {
+            column.push(entity, OwningPtr::new(NonNull::new_unchecked(value)));
+            if layout.size() > 0 {
+                alloc::alloc::dealloc(value, layout);
+            }
+        }
+    }
+    column
+}
+
+struct ClonedResource<R> {
+    value: Option<R>,
+}
+
+fn capture_cloned_resource<R: Resource + Clone>(world: &World) -> Box<dyn ResourceSnapshot> {
+    Box::new(ClonedResource {
+        value: world.get_resource::<R>().cloned(),
+    })
+}
+
+impl<R: Resource + Clone> ResourceSnapshot for ClonedResource<R> {
+    fn restore(&self, world: &mut World) {
+        match &self.value {
+            Some(value) => world.insert_resource(value.clone()),
+            None => {
+                world.remove_resource::<R>();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hierarchy::ChildOf, prelude::*};
+    use alloc::{string::String, vec};
+    use bevy_platform::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Component, Clone, PartialEq, Debug)]
+    struct Position(i32);
+
+    #[derive(Component, Clone, PartialEq, Debug)]
+    struct Velocity(i32);
+
+    #[derive(Component)]
+    struct Untracked;
+
+    #[derive(Resource, Clone, PartialEq, Debug)]
+    struct Score(u32);
+
+    fn config() -> SnapshotConfig {
+        SnapshotConfig::new()
+            .with_component::<Position>()
+            .with_component::<Velocity>()
+            .with_resource::<Score>()
+    }
+
+    #[test]
+    fn restore_values_and_resources() {
+        let mut world = World::new();
+        let a = world.spawn((Position(0), Velocity(1))).id();
+        let b = world.spawn(Position(5)).id();
+        world.insert_resource(Score(1));
+
+        let snapshot = config().capture(&world);
+        assert_eq!(snapshot.entities().len(), 2);
+
+        world
+            .entity_mut(a)
+            .insert(Position(10))
+            .remove::<Velocity>();
+        world.entity_mut(b).insert(Velocity(3));
+        world.insert_resource(Score(7));
+
+        snapshot.restore(&mut world).unwrap();
+        assert_eq!(world.get::<Position>(a), Some(&Position(0)));
+        assert_eq!(world.get::<Velocity>(a), Some(&Velocity(1)));
+        assert_eq!(world.get::<Position>(b), Some(&Position(5)));
+        assert_eq!(world.get::<Velocity>(b), None);
+        assert_eq!(world.resource::<Score>(), &Score(1));
+
+        world.remove_resource::<Score>();
+        let snapshot = config().capture(&world);
+        world.insert_resource(Score(2));
+        snapshot.restore(&mut world).unwrap();
+        assert!(!world.contains_resource::<Score>());
+    }
+
+    #[test]
+    fn restore_spawned_and_despawned_entities() {
+        let mut world = World::new();
+        let kept = world.spawn(Position(0)).id();
+        let despawned = world.spawn((Position(1), Velocity(1))).id();
+        let untracked = world.spawn(Untracked).id();
+
+        let snapshot = config().capture(&world);
+        assert!(!snapshot.contains(untracked));
+
+        world.despawn(despawned);
+        let spawned = world.spawn(Position(2)).id();
+        // The freed row of `despawned` is reused by `spawned`.
+        assert_eq!(spawned.index(), despawned.index());
+        let other = world.spawn(Velocity(2)).id();
+
+        snapshot.restore(&mut world).unwrap();
+        assert!(world.get_entity(spawned).is_err());
+        assert!(world.get_entity(other).is_err());
+        assert_eq!(world.get::<Position>(despawned), Some(&Position(1)));
+        assert_eq!(world.get::<Velocity>(despawned), Some(&Velocity(1)));
+        assert_eq!(world.get::<Position>(kept), Some(&Position(0)));
+        assert!(world.get_entity(untracked).is_ok());
+
+        // New entities don't collide with the restored ones.
+        let new = world.spawn(Position(3)).id();
+        assert_ne!(new, despawned);
+        assert_eq!(world.query::<&Position>().iter(&world).count(), 3);
+    }
+
+    #[test]
+    fn restore_never_revives_stale_entities() {
+        let mut world = World::new();
+        let despawned = world.spawn(Position(1)).id();
+        let snapshot = config().capture(&world);
+
+        world.despawn(despawned);
+        let spawned = world.spawn(Position(2)).id();
+        assert_eq!(spawned.index(), despawned.index());
+
+        snapshot.restore(&mut world).unwrap();
+        assert!(world.get_entity(despawned).is_ok());
+        assert!(world.get_entity(spawned).is_err());
+
+        // Once the restored entity is despawned, its row doesn't go back to the generation of `spawned`.
+        world.despawn(despawned);
+        let new = world.spawn(Untracked).id();
+        assert_eq!(new.index(), despawned.index());
+        assert_ne!(new, despawned);
+        assert_ne!(new, spawned);
+        assert!(world.get_entity(spawned).is_err());
+    }
+
+    #[test]
+    fn restore_fails_on_untracked_entity() {
+        let mut world = World::new();
+        let despawned = world.spawn(Position(1)).id();
+        let snapshot = config().capture(&world);
+
+        world.despawn(despawned);
+        let untracked = world.spawn(Untracked).id();
+        assert_eq!(untracked.index(), despawned.index());
+
+        assert_eq!(
+            snapshot.restore(&mut world),
+            Err(SnapshotRestoreError::EntityRowOccupied {
+                entity: despawned,
+                occupied_by: untracked,
+            })
+        );
+        assert!(world.get_entity(untracked).is_ok());
+    }
+
+    #[test]
+    fn restore_relationships() {
+        let mut world = World::new();
+        let parent = world.spawn(Position(0)).id();
+        let child = world.spawn((Position(1), ChildOf(parent))).id();
+        let config = config().with_component::<ChildOf>();
+        let snapshot = config.capture(&world);
+
+        world.despawn(child);
+        assert!(world.get::<Children>(parent).is_none());
+
+        snapshot.restore(&mut world).unwrap();
+        assert_eq!(world.get::<ChildOf>(child), Some(&ChildOf(parent)));
+        assert_eq!(&**world.get::<Children>(parent).unwrap(), &[child]);
+
+        // Despawning the parent despawns its children, both are spawned again with their ids.
+        world.despawn(parent);
+        snapshot.restore(&mut world).unwrap();
+        assert_eq!(world.get::<ChildOf>(child), Some(&ChildOf(parent)));
+        assert_eq!(&**world.get::<Children>(parent).unwrap(), &[child]);
+    }
+
+    #[test]
+    fn drop_recorded_values() {
+        static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+        #[derive(Component, Clone)]
+        struct Name(#[expect(dead_code, reason = "only dropped")] String);
+
+        impl Drop for Name {
+            fn drop(&mut self) {
+                DROPPED.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let mut world = World::new();
+        let entity = world.spawn(Name("a".into())).id();
+        let snapshot = SnapshotConfig::new()
+            .with_component::<Name>()
+            .capture(&world);
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 0);
+
+        // The recorded value is cloned again, the replaced value is dropped.
+        snapshot.restore(&mut world).unwrap();
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 1);
+
+        drop(snapshot);
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 2);
+        world.despawn(entity);
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn buffer_rollback() {
+        let mut world = World::new();
+        let entity = world.spawn(Position(0)).id();
+        world.insert_resource(SnapshotBuffer::new(config(), 3));
+
+        for i in 0..5 {
+            world.entity_mut(entity).insert(Position(i));
+            SnapshotBuffer::save_system(&mut world);
+        }
+        let buffer = world.resource::<SnapshotBuffer>();
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(buffer.next_frame(), 5);
+        assert_eq!(
+            buffer.iter().map(|(frame, _)| frame).collect::<Vec<_>>(),
+            [2, 3, 4]
+        );
+        assert!(buffer.get(1).is_none());
+
+        assert_eq!(
+            SnapshotBuffer::rollback(&mut world, 1),
+            Err(SnapshotRestoreError::FrameNotFound(1))
+        );
+        SnapshotBuffer::rollback(&mut world, 3).unwrap();
+        assert_eq!(world.get::<Position>(entity), Some(&Position(3)));
+
+        let buffer = world.resource::<SnapshotBuffer>();
+        assert_eq!(buffer.latest().map(|(frame, _)| frame), Some(3));
+        assert_eq!(buffer.next_frame(), 4);
+
+        // An entity despawned after a frame is spawned again with the same id.
+        world.despawn(entity);
+        SnapshotBuffer::rollback(&mut world, 2).unwrap();
+        assert_eq!(world.get::<Position>(entity), Some(&Position(2)));
+    }
+
+    #[cfg(feature = "bevy_reflect")]
+    #[test]
+    fn reflected_components() {
+        #[derive(Component, Reflect, PartialEq, Debug)]
+        #[reflect(Component)]
+        struct Health(u32, Vec<u32>);
+
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Health>();
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let entity = world.spawn(Health(10, vec![1, 2])).id();
+
+        let config = {
+            let registry = world.resource::<AppTypeRegistry>().read();
+            SnapshotConfig::new()
+                .with_reflect_component(registry.get(TypeId::of::<Health>()).unwrap())
+        };
+        let snapshot = config.capture(&world);
+
+        world.entity_mut(entity).insert(Health(3, vec![]));
+        let spawned = world.spawn(Health(1, vec![])).id();
+
+        world.remove_resource::<AppTypeRegistry>();
+        assert_eq!(
+            snapshot.restore(&mut world),
+            Err(SnapshotRestoreError::MissingTypeRegistry)
+        );
+        assert!(world.get_entity(spawned).is_ok());
+        world.insert_resource(registry);
+
+        snapshot.restore(&mut world).unwrap();
+        assert_eq!(world.get::<Health>(entity), Some(&Health(10, vec![1, 2])));
+        assert!(world.get_entity(spawned).is_err());
+    }
+}
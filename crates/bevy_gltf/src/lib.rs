@@ -94,6 +94,7 @@ mod assets;
 mod convert_coordinates;
 mod label;
 mod loader;
+mod scene_processor;
 mod vertex_attributes;
 
 extern crate alloc;
@@ -106,9 +107,10 @@ use bevy_platform::collections::HashMap;
 
 use bevy_app::prelude::*;
 use bevy_asset::AssetApp;
-use bevy_ecs::prelude::Resource;
+use bevy_ecs::{prelude::Resource, world::FromWorld};
 use bevy_image::{CompressedImageFormatSupport, CompressedImageFormats, ImageSamplerDescriptor};
 use bevy_mesh::MeshVertexAttribute;
+use bevy_scene::{BinarySceneSaver, DynamicSceneSaver};
 
 /// The glTF prelude.
 ///
@@ -118,7 +120,7 @@ pub mod prelude {
     pub use crate::{assets::Gltf, assets::GltfExtras, label::GltfAssetLabel};
 }
 
-pub use {assets::*, label::GltfAssetLabel, loader::*};
+pub use {assets::*, label::GltfAssetLabel, loader::*, scene_processor::*};
 
 // Has to store an Arc<Mutex<...>> as there is no other way to mutate fields of asset loaders.
 /// Stores default [`ImageSamplerDescriptor`] in main world.
@@ -243,5 +245,16 @@ impl Plugin for GltfPlugin {
             default_sampler,
             default_convert_coordinates: self.convert_coordinates,
         });
+
+        let gltf_scene_processor = GltfSceneProcessor::new(
+            GltfSceneTransformer::from_world(app.world_mut()),
+            DynamicSceneSaver::from_world(app.world_mut()),
+        );
+        let gltf_binary_scene_processor = GltfBinarySceneProcessor::new(
+            GltfSceneTransformer::from_world(app.world_mut()),
+            BinarySceneSaver::from_world(app.world_mut()),
+        );
+        app.register_asset_processor(gltf_scene_processor)
+            .register_asset_processor(gltf_binary_scene_processor);
     }
 }
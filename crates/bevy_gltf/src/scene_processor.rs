@@ -0,0 +1,238 @@
+use crate::{Gltf, GltfLoader};
+use bevy_asset::{
+    processor::LoadTransformAndSave,
+    transformer::{AssetTransformer, TransformedAsset},
+    AssetPath,
+};
+use bevy_ecs::{
+    reflect::AppTypeRegistry,
+    world::{FromWorld, World},
+};
+use bevy_reflect::TypeRegistryArc;
+use bevy_scene::{BinarySceneSaver, DynamicScene, DynamicSceneSaver, Scene};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// An asset processor converting a scene of a glTF file into a [`DynamicScene`] in the RON scene format, to be loaded
+/// with the [`SceneLoader`](bevy_scene::SceneLoader).
+///
+/// The processed scene can be edited by hand or with tools working on [`DynamicScene`]s. See
+/// [`GltfSceneTransformer`] for how the meshes, materials and other assets of the glTF file are referenced.
+pub type GltfSceneProcessor =
+    LoadTransformAndSave<GltfLoader, GltfSceneTransformer, DynamicSceneSaver>;
+
+/// An asset processor converting a scene of a glTF file into a [`DynamicScene`] in the binary scene format, to be
+/// loaded with the [`BinarySceneLoader`](bevy_scene::BinarySceneLoader).
+pub type GltfBinarySceneProcessor =
+    LoadTransformAndSave<GltfLoader, GltfSceneTransformer, BinarySceneSaver>;
+
+/// Settings of the [`GltfSceneTransformer`].
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct GltfSceneTransformerSettings {
+    /// The index of the scene to convert. If `None`, the default scene of the glTF file is converted, or its first
+    /// scene if it has no default scene.
+    pub scene: Option<usize>,
+}
+
+/// Transforms a [`Gltf`] into the [`DynamicScene`] of one of its scenes.
+///
+/// The entities of the scene keep their handles to the meshes, materials and other labeled assets of the glTF file,
+/// which are saved as asset paths by the [`DynamicSceneSaver`] and the [`BinarySceneSaver`]. When processing, the glTF
+/// file is replaced by the scene, so set
+/// [`DynamicSceneSaverSettings::labeled_asset_source`](bevy_scene::DynamicSceneSaverSettings::labeled_asset_source)
+/// to an unprocessed [`AssetSource`](bevy_asset::io::AssetSource) reading the same files to load these assets from the
+/// original glTF file.
+pub struct GltfSceneTransformer {
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for GltfSceneTransformer {
+    fn from_world(world: &mut World) -> Self {
+        let type_registry = world.resource::<AppTypeRegistry>();
+        GltfSceneTransformer {
+            type_registry: type_registry.0.clone(),
+        }
+    }
+}
+
+/// An error that occurs when transforming a [`Gltf`] with the [`GltfSceneTransformer`].
+#[derive(Error, Debug)]
+pub enum GltfSceneTransformerError {
+    /// The glTF file has no scene.
+    #[error("The glTF file has no scene")]
+    NoScene,
+    /// The glTF file has no scene with the given index.
+    #[error("The glTF file has no scene {0}")]
+    MissingScene(usize),
+    /// The selected scene is not a labeled asset of the glTF file, so it can't be converted.
+    #[error("The scene {0} is not a labeled asset of the glTF file")]
+    UnlabeledScene(String),
+}
+
+impl AssetTransformer for GltfSceneTransformer {
+    type AssetInput = Gltf;
+    type AssetOutput = DynamicScene;
+    type Settings = GltfSceneTransformerSettings;
+    type Error = GltfSceneTransformerError;
+
+    async fn transform<'a>(
+        &'a self,
+        mut asset: TransformedAsset<Gltf>,
+        settings: &'a Self::Settings,
+    ) -> Result<TransformedAsset<DynamicScene>, Self::Error> {
+        let handle = match settings.scene {
+            Some(index) => asset
+                .scenes
+                .get(index)
+                .ok_or(GltfSceneTransformerError::MissingScene(index))?,
+            None => asset
+                .default_scene
+                .as_ref()
+                .or(asset.scenes.first())
+                .ok_or(GltfSceneTransformerError::NoScene)?,
+        }
+        .clone();
+        let unlabeled = || {
+            GltfSceneTransformerError::UnlabeledScene(match handle.path() {
+                Some(path) => path.to_string(),
+                None => format!("{:?}", handle.id()),
+            })
+        };
+        let label = handle
+            .path()
+            .and_then(AssetPath::label)
+            .ok_or_else(unlabeled)?
+            .to_owned();
+        let mut scene = asset
+            .get_labeled::<Scene, str>(&label)
+            .ok_or_else(unlabeled)?;
+        let scene = scene.get_mut();
+        scene
+            .world
+            .insert_resource(AppTypeRegistry(self.type_registry.clone()));
+        let dynamic_scene = DynamicScene::from_scene(scene);
+        Ok(asset.replace_asset(dynamic_scene))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GltfSceneTransformer, GltfSceneTransformerError, GltfSceneTransformerSettings};
+    use crate::Gltf;
+    use bevy_app::{App, TaskPoolPlugin};
+    use bevy_asset::{
+        transformer::{AssetTransformer, TransformedAsset},
+        AssetApp, AssetPlugin, AssetServer, Handle, LoadedAsset,
+    };
+    use bevy_ecs::{
+        component::Component,
+        reflect::{AppTypeRegistry, ReflectComponent},
+        world::{FromWorld, World},
+    };
+    use bevy_reflect::Reflect;
+    use bevy_scene::Scene;
+    use bevy_tasks::block_on;
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct Marker;
+
+    fn gltf(scenes: Vec<Handle<Scene>>, default_scene: Option<Handle<Scene>>) -> Gltf {
+        Gltf {
+            scenes,
+            named_scenes: Default::default(),
+            meshes: Vec::new(),
+            named_meshes: Default::default(),
+            materials: Vec::new(),
+            named_materials: Default::default(),
+            nodes: Vec::new(),
+            named_nodes: Default::default(),
+            skins: Vec::new(),
+            named_skins: Default::default(),
+            default_scene,
+            #[cfg(feature = "bevy_animation")]
+            animations: Vec::new(),
+            #[cfg(feature = "bevy_animation")]
+            named_animations: Default::default(),
+            source: None,
+        }
+    }
+
+    /// Transforms a glTF file whose scenes are labeled `Scene{index}` and contain `index + 1` entities,
+    /// returning the number of entities of the converted scene.
+    fn transform(
+        scenes: Vec<Handle<Scene>>,
+        default_scene: Option<Handle<Scene>>,
+        settings: GltfSceneTransformerSettings,
+    ) -> Result<usize, GltfSceneTransformerError> {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Marker>();
+        let transformer = GltfSceneTransformer::from_world(&mut world);
+
+        let mut asset = TransformedAsset::<Gltf>::from_loaded(
+            LoadedAsset::from(gltf(scenes.clone(), default_scene)).into(),
+        )
+        .unwrap();
+        for (index, handle) in scenes.into_iter().enumerate() {
+            let mut scene_world = World::new();
+            for _ in 0..=index {
+                scene_world.spawn(Marker);
+            }
+            asset.insert_labeled(
+                format!("Scene{index}"),
+                handle,
+                LoadedAsset::from(Scene::new(scene_world)),
+            );
+        }
+        block_on(transformer.transform(asset, &settings)).map(|scene| scene.get().entities.len())
+    }
+
+    #[test]
+    fn transform_gltf_scenes() {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+            .init_asset::<Scene>();
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let scenes: Vec<Handle<Scene>> = (0..2)
+            .map(|index| asset_server.load(format!("model.gltf#Scene{index}")))
+            .collect();
+
+        assert_eq!(
+            1,
+            transform(scenes.clone(), None, Default::default()).unwrap()
+        );
+        let default_scene = Some(scenes[1].clone());
+        assert_eq!(
+            2,
+            transform(scenes.clone(), default_scene, Default::default()).unwrap()
+        );
+        let settings = GltfSceneTransformerSettings { scene: Some(1) };
+        assert_eq!(2, transform(scenes.clone(), None, settings).unwrap());
+
+        let settings = GltfSceneTransformerSettings { scene: Some(2) };
+        assert!(matches!(
+            transform(scenes.clone(), None, settings),
+            Err(GltfSceneTransformerError::MissingScene(2))
+        ));
+        assert!(matches!(
+            transform(Vec::new(), None, Default::default()),
+            Err(GltfSceneTransformerError::NoScene)
+        ));
+
+        // Scenes that are not labeled assets of the glTF file can't be converted.
+        let unlabeled = asset_server.load("other.gltf#Missing");
+        assert!(matches!(
+            transform(scenes.clone(), Some(unlabeled), Default::default()),
+            Err(GltfSceneTransformerError::UnlabeledScene(path)) if path == "other.gltf#Missing"
+        ));
+        let weak = Handle::<Scene>::default();
+        assert!(matches!(
+            transform(scenes, Some(weak), Default::default()),
+            Err(GltfSceneTransformerError::UnlabeledScene(_))
+        ));
+    }
+}
@@ -115,3 +115,8 @@ mod raycast2d;
 pub use raycast2d::*;
 mod raycast3d;
 pub use raycast3d::*;
+
+#[cfg(feature = "alloc")]
+mod spatial_index;
+#[cfg(feature = "alloc")]
+pub use spatial_index::*;
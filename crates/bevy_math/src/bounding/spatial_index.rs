@@ -0,0 +1,672 @@
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::cmp::Reverse;
+
+use super::{Aabb3d, BoundingSphere, BoundingVolume, IntersectsVolume, RayCast3d};
+use crate::{FloatOrd, Vec3A};
+
+/// Index of a missing node.
+const NULL: u32 = u32::MAX;
+
+/// A key to a value stored in a [`SpatialIndex`], returned by [`SpatialIndex::insert`].
+///
+/// Keys are not reused: once its value is removed, a key is invalid and is ignored by the [`SpatialIndex`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SpatialIndexKey {
+    index: u32,
+    generation: u32,
+}
+
+/// An acceleration structure for broad-phase queries on values bounded by an [`Aabb3d`].
+///
+/// The index is a dynamic bounding volume hierarchy: a balanced binary tree of [`Aabb3d`]s whose leaves are the bounds
+/// of the stored values, and whose branches enclose their children. Values can be inserted, moved and removed at any
+/// time, and the index can be queried for the values:
+/// - intersecting an [`Aabb3d`] with [`query_aabb`](Self::query_aabb),
+/// - intersecting a [`BoundingSphere`] with [`query_sphere`](Self::query_sphere),
+/// - first hit by a [`RayCast3d`] with [`cast_ray`](Self::cast_ray),
+/// - nearest to a point with [`nearest`](Self::nearest).
+///
+/// The leaves are enlarged by a [margin](Self::with_margin), so that values moving by less than the margin do not
+/// need to be reinserted in the tree when their bounds are [updated](Self::update).
+///
+/// ```
+/// # use bevy_math::{bounding::{Aabb3d, BoundingSphere, SpatialIndex}, Vec3};
+/// let mut index = SpatialIndex::new();
+/// let a = index.insert(Aabb3d::new(Vec3::ZERO, Vec3::ONE), "a");
+/// let b = index.insert(Aabb3d::new(Vec3::X * 10.0, Vec3::ONE), "b");
+///
+/// let found: Vec<_> = index
+///     .query_sphere(&BoundingSphere::new(Vec3::X * 1.5, 1.0))
+///     .collect();
+/// assert_eq!(found, [(a, &"a")]);
+///
+/// index.update(b, Aabb3d::new(Vec3::X * 3.0, Vec3::ONE));
+/// assert_eq!(index.nearest(Vec3::X * 5.0).map(|(_, value, _)| *value), Some("b"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct SpatialIndex<T> {
+    nodes: Vec<Node<T>>,
+    root: u32,
+    free: u32,
+    len: usize,
+    margin: f32,
+}
+
+#[derive(Clone, Debug)]
+struct Node<T> {
+    /// The bounds of the node, enclosing its children, or the enlarged bounds of its value for leaves.
+    aabb: Aabb3d,
+    /// The parent of the node, or the next free node for free nodes.
+    parent: u32,
+    /// The children of branches, `[NULL, NULL]` for leaves.
+    children: [u32; 2],
+    /// The height of the node in the tree, 0 for leaves.
+    height: u32,
+    generation: u32,
+    leaf: Option<Leaf<T>>,
+}
+
+#[derive(Clone, Debug)]
+struct Leaf<T> {
+    bounds: Aabb3d,
+    value: T,
+}
+
+impl<T> Node<T> {
+    fn is_leaf(&self) -> bool {
+        self.children[0] == NULL
+    }
+}
+
+impl<T> Default for SpatialIndex<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SpatialIndex<T> {
+    /// The default margin by which the bounds of the values are enlarged in the tree.
+    pub const DEFAULT_MARGIN: f32 = 0.1;
+
+    /// Creates an empty [`SpatialIndex`] with the [default margin](Self::DEFAULT_MARGIN).
+    pub const fn new() -> Self {
+        Self::with_margin(Self::DEFAULT_MARGIN)
+    }
+
+    /// Creates an empty [`SpatialIndex`] enlarging the bounds of its values by `margin` in each direction.
+    ///
+    /// A larger margin lets values move further before being reinserted in the tree, but makes the queries visit more
+    /// nodes.
+    pub const fn with_margin(margin: f32) -> Self {
+        Self {
+            nodes: Vec::new(),
+            root: NULL,
+            free: NULL,
+            len: 0,
+            margin,
+        }
+    }
+
+    /// Returns the number of values in the index.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the index contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes all the values from the index, invalidating their keys.
+    pub fn clear(&mut self) {
+        let keys: Vec<_> = self.iter().map(|(key, _)| key).collect();
+        for key in keys {
+            self.remove(key);
+        }
+    }
+
+    /// Inserts a `value` bounded by `bounds` and returns its key.
+    pub fn insert(&mut self, bounds: Aabb3d, value: T) -> SpatialIndexKey {
+        let index = self.allocate();
+        let node = &mut self.nodes[index as usize];
+        node.aabb = bounds.grow(Vec3A::splat(self.margin));
+        node.leaf = Some(Leaf { bounds, value });
+        let key = SpatialIndexKey {
+            index,
+            generation: node.generation,
+        };
+        self.insert_leaf(index);
+        self.len += 1;
+        key
+    }
+
+    /// Removes the value with the given key and returns it, or `None` if the key is invalid.
+    pub fn remove(&mut self, key: SpatialIndexKey) -> Option<T> {
+        let index = self.leaf_index(key)?;
+        self.remove_leaf(index);
+        let leaf = self.nodes[index as usize].leaf.take()?;
+        self.deallocate(index);
+        self.len -= 1;
+        Some(leaf.value)
+    }
+
+    /// Moves the value with the given key to new `bounds`.
+    ///
+    /// Returns `false` if the key is invalid.
+    pub fn update(&mut self, key: SpatialIndexKey, bounds: Aabb3d) -> bool {
+        let Some(index) = self.leaf_index(key) else {
+            return false;
+        };
+        let margin = Vec3A::splat(self.margin);
+        let node = &mut self.nodes[index as usize];
+        if let Some(leaf) = &mut node.leaf {
+            leaf.bounds = bounds;
+        }
+        // Keep the node in place if it still encloses the bounds and is not much larger than them.
+        if node.aabb.contains(&bounds) && bounds.grow(margin * 4.0).contains(&node.aabb) {
+            return true;
+        }
+        self.remove_leaf(index);
+        self.nodes[index as usize].aabb = bounds.grow(margin);
+        self.insert_leaf(index);
+        true
+    }
+
+    /// Returns the value with the given key, or `None` if the key is invalid.
+    pub fn get(&self, key: SpatialIndexKey) -> Option<&T> {
+        self.leaf(key).map(|leaf| &leaf.value)
+    }
+
+    /// Returns a mutable reference to the value with the given key, or `None` if the key is invalid.
+    pub fn get_mut(&mut self, key: SpatialIndexKey) -> Option<&mut T> {
+        let index = self.leaf_index(key)?;
+        self.nodes[index as usize]
+            .leaf
+            .as_mut()
+            .map(|leaf| &mut leaf.value)
+    }
+
+    /// Returns the bounds of the value with the given key, or `None` if the key is invalid.
+    pub fn bounds(&self, key: SpatialIndexKey) -> Option<Aabb3d> {
+        self.leaf(key).map(|leaf| leaf.bounds)
+    }
+
+    /// Returns an iterator over the keys and values of the index, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (SpatialIndexKey, &T)> {
+        self.nodes.iter().enumerate().filter_map(|(index, node)| {
+            let leaf = node.leaf.as_ref()?;
+            Some((
+                SpatialIndexKey {
+                    index: index as u32,
+                    generation: node.generation,
+                },
+                &leaf.value,
+            ))
+        })
+    }
+
+    /// Returns an iterator over the values whose bounds intersect `aabb`.
+    pub fn query_aabb(&self, aabb: &Aabb3d) -> impl Iterator<Item = (SpatialIndexKey, &T)> {
+        let aabb = *aabb;
+        self.query(move |bounds| bounds.intersects(&aabb))
+    }
+
+    /// Returns an iterator over the values whose bounds intersect `sphere`.
+    pub fn query_sphere(
+        &self,
+        sphere: &BoundingSphere,
+    ) -> impl Iterator<Item = (SpatialIndexKey, &T)> {
+        let sphere = *sphere;
+        self.query(move |bounds| bounds.intersects(&sphere))
+    }
+
+    /// Returns an iterator over the values whose bounds pass `test`.
+    ///
+    /// `test` must also pass for any [`Aabb3d`] enclosing bounds that pass it, as it is used to skip the branches of
+    /// the tree.
+    pub fn query(
+        &self,
+        mut test: impl FnMut(&Aabb3d) -> bool,
+    ) -> impl Iterator<Item = (SpatialIndexKey, &T)> {
+        let mut stack = if self.root == NULL {
+            Vec::new()
+        } else {
+            vec![self.root]
+        };
+        core::iter::from_fn(move || {
+            while let Some(index) = stack.pop() {
+                let node = &self.nodes[index as usize];
+                if !test(&node.aabb) {
+                    continue;
+                }
+                match &node.leaf {
+                    Some(leaf) if test(&leaf.bounds) => {
+                        let key = SpatialIndexKey {
+                            index,
+                            generation: node.generation,
+                        };
+                        return Some((key, &leaf.value));
+                    }
+                    Some(_) => {}
+                    None => stack.extend(node.children),
+                }
+            }
+            None
+        })
+    }
+
+    /// Returns the value whose bounds are first hit by `ray`, with the distance of the hit along the ray.
+    pub fn cast_ray(&self, ray: &RayCast3d) -> Option<(SpatialIndexKey, &T, f32)> {
+        self.cast_ray_filtered(ray, |_| true)
+    }
+
+    /// Returns the value passing `filter` whose bounds are first hit by `ray`, with the distance of the hit along the
+    /// ray.
+    pub fn cast_ray_filtered(
+        &self,
+        ray: &RayCast3d,
+        mut filter: impl FnMut(&T) -> bool,
+    ) -> Option<(SpatialIndexKey, &T, f32)> {
+        let mut ray = ray.clone();
+        let mut hit = None;
+        let mut stack = Vec::new();
+        if self.root != NULL {
+            stack.push(self.root);
+        }
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index as usize];
+            // `ray.max` is shortened to the closest hit so far, skipping the nodes behind it.
+            if ray.aabb_intersection_at(&node.aabb).is_none() {
+                continue;
+            }
+            match &node.leaf {
+                Some(leaf) => {
+                    if let Some(distance) = ray.aabb_intersection_at(&leaf.bounds)
+                        && filter(&leaf.value)
+                    {
+                        ray.max = distance;
+                        let key = SpatialIndexKey {
+                            index,
+                            generation: node.generation,
+                        };
+                        hit = Some((key, &leaf.value, distance));
+                    }
+                }
+                None => stack.extend(node.children),
+            }
+        }
+        hit
+    }
+
+    /// Returns the value whose bounds are nearest to `point`, with the distance between them.
+    ///
+    /// The distance is 0 for values whose bounds contain `point`.
+    pub fn nearest(&self, point: impl Into<Vec3A>) -> Option<(SpatialIndexKey, &T, f32)> {
+        self.nearest_filtered(point, |_| true)
+    }
+
+    /// Returns the value passing `filter` whose bounds are nearest to `point`, with the distance between them.
+    ///
+    /// The distance is 0 for values whose bounds contain `point`.
+    pub fn nearest_filtered(
+        &self,
+        point: impl Into<Vec3A>,
+        mut filter: impl FnMut(&T) -> bool,
+    ) -> Option<(SpatialIndexKey, &T, f32)> {
+        let point = point.into();
+        // Nodes are visited from the nearest, so the first leaf popped from the heap is the nearest value.
+        let mut heap = BinaryHeap::new();
+        let mut push = |heap: &mut BinaryHeap<_>, index: u32| {
+            let node = &self.nodes[index as usize];
+            let aabb = match &node.leaf {
+                Some(leaf) if filter(&leaf.value) => &leaf.bounds,
+                Some(_) => return,
+                None => &node.aabb,
+            };
+            let distance = aabb.closest_point(point).distance(point);
+            heap.push(Reverse((FloatOrd(distance), index)));
+        };
+        if self.root != NULL {
+            push(&mut heap, self.root);
+        }
+        while let Some(Reverse((FloatOrd(distance), index))) = heap.pop() {
+            let node = &self.nodes[index as usize];
+            match &node.leaf {
+                Some(leaf) => {
+                    let key = SpatialIndexKey {
+                        index,
+                        generation: node.generation,
+                    };
+                    return Some((key, &leaf.value, distance));
+                }
+                None => {
+                    for child in node.children {
+                        push(&mut heap, child);
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    fn leaf_index(&self, key: SpatialIndexKey) -> Option<u32> {
+        let node = self.nodes.get(key.index as usize)?;
+        (node.generation == key.generation && node.leaf.is_some()).then_some(key.index)
+    }
+
+    fn leaf(&self, key: SpatialIndexKey) -> Option<&Leaf<T>> {
+        let index = self.leaf_index(key)?;
+        self.nodes[index as usize].leaf.as_ref()
+    }
+
+    fn allocate(&mut self) -> u32 {
+        if self.free == NULL {
+            self.nodes.push(Node {
+                aabb: Aabb3d {
+                    min: Vec3A::ZERO,
+                    max: Vec3A::ZERO,
+                },
+                parent: NULL,
+                children: [NULL; 2],
+                height: 0,
+                generation: 0,
+                leaf: None,
+            });
+            return (self.nodes.len() - 1) as u32;
+        }
+        let index = self.free;
+        let node = &mut self.nodes[index as usize];
+        self.free = node.parent;
+        node.parent = NULL;
+        node.children = [NULL; 2];
+        node.height = 0;
+        index
+    }
+
+    fn deallocate(&mut self, index: u32) {
+        let node = &mut self.nodes[index as usize];
+        node.generation = node.generation.wrapping_add(1);
+        node.leaf = None;
+        node.parent = self.free;
+        self.free = index;
+    }
+
+    /// Inserts the leaf at `leaf` in the tree, next to the sibling minimizing the surface area of the branches.
+    fn insert_leaf(&mut self, leaf: u32) {
+        if self.root == NULL {
+            self.root = leaf;
+            self.nodes[leaf as usize].parent = NULL;
+            return;
+        }
+
+        let leaf_aabb = self.nodes[leaf as usize].aabb;
+        let mut index = self.root;
+        while !self.nodes[index as usize].is_leaf() {
+            let node = &self.nodes[index as usize];
+            let area = node.aabb.visible_area();
+            let combined_area = node.aabb.merge(&leaf_aabb).visible_area();
+
+            // The cost of making the leaf a sibling of this node.
+            let cost = 2.0 * combined_area;
+            // The cost of pushing the leaf further down the tree, growing this node.
+            let inheritance_cost = 2.0 * (combined_area - area);
+            let child_cost = |child: u32| {
+                let child = &self.nodes[child as usize];
+                let merged_area = child.aabb.merge(&leaf_aabb).visible_area();
+                if child.is_leaf() {
+                    merged_area + inheritance_cost
+                } else {
+                    merged_area - child.aabb.visible_area() + inheritance_cost
+                }
+            };
+            let [left, right] = node.children;
+            let (left_cost, right_cost) = (child_cost(left), child_cost(right));
+
+            if cost < left_cost && cost < right_cost {
+                break;
+            }
+            index = if left_cost < right_cost { left } else { right };
+        }
+
+        let sibling = index;
+        let old_parent = self.nodes[sibling as usize].parent;
+        let new_parent = self.allocate();
+        let sibling_node = &self.nodes[sibling as usize];
+        let aabb = sibling_node.aabb.merge(&leaf_aabb);
+        let height = sibling_node.height + 1;
+        let node = &mut self.nodes[new_parent as usize];
+        node.parent = old_parent;
+        node.aabb = aabb;
+        node.height = height;
+        node.children = [sibling, leaf];
+
+        if old_parent == NULL {
+            self.root = new_parent;
+        } else {
+            self.replace_child(old_parent, sibling, new_parent);
+        }
+        self.nodes[sibling as usize].parent = new_parent;
+        self.nodes[leaf as usize].parent = new_parent;
+
+        self.refit(new_parent);
+    }
+
+    /// Removes the leaf at `leaf` from the tree, replacing its parent by its sibling.
+    fn remove_leaf(&mut self, leaf: u32) {
+        if leaf == self.root {
+            self.root = NULL;
+            return;
+        }
+
+        let parent = self.nodes[leaf as usize].parent;
+        let grandparent = self.nodes[parent as usize].parent;
+        let [left, right] = self.nodes[parent as usize].children;
+        let sibling = if left == leaf { right } else { left };
+
+        if grandparent == NULL {
+            self.root = sibling;
+            self.nodes[sibling as usize].parent = NULL;
+        } else {
+            self.replace_child(grandparent, parent, sibling);
+            self.nodes[sibling as usize].parent = grandparent;
+        }
+        self.deallocate(parent);
+        self.nodes[leaf as usize].parent = NULL;
+
+        if grandparent != NULL {
+            self.refit(grandparent);
+        }
+    }
+
+    fn replace_child(&mut self, parent: u32, old_child: u32, new_child: u32) {
+        let children = &mut self.nodes[parent as usize].children;
+        if children[0] == old_child {
+            children[0] = new_child;
+        } else {
+            children[1] = new_child;
+        }
+    }
+
+    /// Balances and recomputes the bounds and heights of `index` and its ancestors.
+    fn refit(&mut self, mut index: u32) {
+        while index != NULL {
+            index = self.balance(index);
+            self.update_branch(index);
+            index = self.nodes[index as usize].parent;
+        }
+    }
+
+    fn update_branch(&mut self, index: u32) {
+        let [left, right] = self.nodes[index as usize].children;
+        let (left, right) = (&self.nodes[left as usize], &self.nodes[right as usize]);
+        let aabb = left.aabb.merge(&right.aabb);
+        let height = 1 + left.height.max(right.height);
+        let node = &mut self.nodes[index as usize];
+        node.aabb = aabb;
+        node.height = height;
+    }
+
+    /// Rotates the higher child of the branch at `index` up if the heights of its children differ by more than one,
+    /// and returns the index of the branch now at its place.
+    fn balance(&mut self, index: u32) -> u32 {
+        let node = &self.nodes[index as usize];
+        if node.is_leaf() || node.height < 2 {
+            return index;
+        }
+        let [left, right] = node.children;
+        let left_height = self.nodes[left as usize].height;
+        let right_height = self.nodes[right as usize].height;
+        if right_height > left_height + 1 {
+            self.rotate_up(index, 1);
+            right
+        } else if left_height > right_height + 1 {
+            self.rotate_up(index, 0);
+            left
+        } else {
+            index
+        }
+    }
+
+    /// Swaps the branch at `index` with its child `side`, which takes its place in the tree. The higher child of the
+    /// rotated child stays under it, and its lower child replaces it under `index`.
+    fn rotate_up(&mut self, index: u32, side: usize) {
+        let child = self.nodes[index as usize].children[side];
+        let [grandchild_a, grandchild_b] = self.nodes[child as usize].children;
+        let (kept, moved) = if self.nodes[grandchild_a as usize].height
+            > self.nodes[grandchild_b as usize].height
+        {
+            (grandchild_a, grandchild_b)
+        } else {
+            (grandchild_b, grandchild_a)
+        };
+
+        let parent = self.nodes[index as usize].parent;
+        self.nodes[child as usize].parent = parent;
+        if parent == NULL {
+            self.root = child;
+        } else {
+            self.replace_child(parent, index, child);
+        }
+
+        self.nodes[child as usize].children = [index, kept];
+        self.nodes[index as usize].parent = child;
+        self.nodes[index as usize].children[side] = moved;
+        self.nodes[moved as usize].parent = index;
+
+        self.update_branch(index);
+        self.update_branch(child);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Dir3, Vec3};
+
+    /// Checks the structure of the tree and returns the number of leaves.
+    fn validate<T>(index: &SpatialIndex<T>, node: u32, parent: u32) -> usize {
+        if node == NULL {
+            return 0;
+        }
+        let n = &index.nodes[node as usize];
+        assert_eq!(n.parent, parent);
+        if n.is_leaf() {
+            assert!(n.aabb.contains(&n.leaf.as_ref().unwrap().bounds));
+            return 1;
+        }
+        let [left, right] = n.children;
+        let (l, r) = (&index.nodes[left as usize], &index.nodes[right as usize]);
+        assert!(n.aabb.contains(&l.aabb) && n.aabb.contains(&r.aabb));
+        assert_eq!(n.height, 1 + l.height.max(r.height));
+        assert!(l.height.abs_diff(r.height) <= 1);
+        validate(index, left, node) + validate(index, right, node)
+    }
+
+    fn cube(center: Vec3) -> Aabb3d {
+        Aabb3d::new(center, Vec3::splat(0.5))
+    }
+
+    fn grid() -> (SpatialIndex<usize>, Vec<SpatialIndexKey>) {
+        let mut index = SpatialIndex::new();
+        let keys = (0..100)
+            .map(|i| {
+                let center = Vec3::new((i % 10) as f32 * 2.0, (i / 10) as f32 * 2.0, 0.0);
+                index.insert(cube(center), i)
+            })
+            .collect();
+        (index, keys)
+    }
+
+    #[test]
+    fn insert_update_remove() {
+        let (mut index, keys) = grid();
+        assert_eq!(index.len(), 100);
+        assert_eq!(validate(&index, index.root, NULL), 100);
+        assert!(index.nodes[index.root as usize].height <= 10);
+
+        for (i, key) in keys.iter().enumerate() {
+            let center = Vec3::new(i as f32 * 3.0, 50.0, 0.0);
+            assert!(index.update(*key, cube(center)));
+            assert_eq!(index.bounds(*key), Some(cube(center)));
+        }
+        assert_eq!(validate(&index, index.root, NULL), 100);
+
+        for key in keys.iter().step_by(2) {
+            assert!(index.remove(*key).is_some());
+            assert!(index.remove(*key).is_none());
+            assert!(index.get(*key).is_none());
+            assert!(!index.update(*key, cube(Vec3::ZERO)));
+        }
+        assert_eq!(index.len(), 50);
+        assert_eq!(validate(&index, index.root, NULL), 50);
+
+        // Removed keys are not valid for the values reusing their nodes.
+        let key = index.insert(cube(Vec3::ZERO), 1000);
+        assert!(keys
+            .iter()
+            .step_by(2)
+            .all(|removed| index.get(*removed).is_none()));
+        assert_eq!(index.get(key), Some(&1000));
+
+        index.clear();
+        assert!(index.is_empty());
+        assert_eq!(index.iter().count(), 0);
+    }
+
+    #[test]
+    fn queries() {
+        let (index, _) = grid();
+
+        let mut found: Vec<_> = index
+            .query_aabb(&Aabb3d::new(Vec3::new(3.0, 3.0, 0.0), Vec3::splat(1.0)))
+            .map(|(_, value)| *value)
+            .collect();
+        found.sort_unstable();
+        assert_eq!(found, [11, 12, 21, 22]);
+
+        let mut found: Vec<_> = index
+            .query_sphere(&BoundingSphere::new(Vec3::new(4.0, 4.0, 0.0), 1.6))
+            .map(|(_, value)| *value)
+            .collect();
+        found.sort_unstable();
+        assert_eq!(found, [12, 21, 22, 23, 32]);
+
+        let ray = RayCast3d::new(Vec3::new(-10.0, 6.0, 0.0), Dir3::X, 100.0);
+        let (_, value, distance) = index.cast_ray(&ray).unwrap();
+        assert_eq!((*value, distance), (30, 9.5));
+        let (_, value, _) = index.cast_ray_filtered(&ray, |value| *value > 32).unwrap();
+        assert_eq!(*value, 33);
+        let ray = RayCast3d::new(Vec3::new(-10.0, 6.0, 0.0), -Dir3::X, 100.0);
+        assert!(index.cast_ray(&ray).is_none());
+
+        let (_, value, distance) = index.nearest(Vec3::new(8.2, 30.0, 0.0)).unwrap();
+        assert_eq!((*value, distance), (94, 11.5));
+        let (_, value, distance) = index.nearest(Vec3::new(2.1, 2.2, 0.0)).unwrap();
+        assert_eq!((*value, distance), (11, 0.0));
+        let (_, value, _) = index
+            .nearest_filtered(Vec3::new(2.1, 2.2, 0.0), |value| value % 2 == 0)
+            .unwrap();
+        assert_eq!(*value, 12);
+        assert!(SpatialIndex::<()>::new().nearest(Vec3::ZERO).is_none());
+    }
+}
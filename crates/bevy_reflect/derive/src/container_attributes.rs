@@ -14,7 +14,7 @@ use proc_macro2::{Ident, Span};
 use quote::quote_spanned;
 use syn::{
     ext::IdentExt, parenthesized, parse::ParseStream, spanned::Spanned, token, Expr, LitBool,
-    MetaList, MetaNameValue, Path, Token, WhereClause,
+    LitInt, MetaList, MetaNameValue, Path, Token, WhereClause,
 };
 
 mod kw {
@@ -26,6 +26,8 @@ mod kw {
     syn::custom_keyword!(Clone);
     syn::custom_keyword!(no_field_bounds);
     syn::custom_keyword!(opaque);
+    syn::custom_keyword!(version);
+    syn::custom_keyword!(methods);
 }
 
 // The "special" trait idents that are used internally for reflection.
@@ -44,6 +46,12 @@ const FROM_REFLECT_ATTR: &str = "from_reflect";
 // Attributes for `TypePath` implementation
 const TYPE_PATH_ATTR: &str = "type_path";
 
+// Attributes for the `ReflectVersion` registration
+const VERSION_ATTR: &str = "version";
+
+// The `#[reflect(methods(...))]` attribute name.
+const METHODS_ATTR: &str = "methods";
+
 // The error message to show when a trait/type is specified multiple times
 const CONFLICTING_TYPE_DATA_MESSAGE: &str = "conflicting type data registration";
 
@@ -186,6 +194,8 @@ pub(crate) struct ContainerAttributes {
     no_field_bounds: bool,
     custom_attributes: CustomAttributes,
     is_opaque: bool,
+    version: Option<LitInt>,
+    methods: Vec<Ident>,
     idents: Vec<Ident>,
 }
 
@@ -211,6 +221,8 @@ impl ContainerAttributes {
     /// # Example
     /// - `#[reflect(Hash, Debug(custom_debug), MyTrait)]`
     /// - `#[reflect(no_field_bounds)]`
+    /// - `#[reflect(version = 2)]`
+    /// - `#[reflect(methods(length, normalize))]`
     pub fn parse_meta_list(
         &mut self,
         meta: &MetaList,
@@ -238,6 +250,10 @@ impl ContainerAttributes {
             self.parse_opaque(input)
         } else if lookahead.peek(kw::no_field_bounds) {
             self.parse_no_field_bounds(input)
+        } else if lookahead.peek(kw::version) {
+            self.parse_version(input)
+        } else if lookahead.peek(kw::methods) {
+            self.parse_methods(input)
         } else if lookahead.peek(kw::Clone) {
             self.parse_clone(input)
         } else if lookahead.peek(kw::Debug) {
@@ -378,6 +394,68 @@ impl ContainerAttributes {
         Ok(())
     }
 
+    /// Parse `version` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(version = 2)]`
+    fn parse_version(&mut self, input: ParseStream) -> syn::Result<()> {
+        let pair = input.parse::<MetaNameValue>()?;
+        let version = match &pair.value {
+            Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(lit),
+                ..
+            }) => {
+                lit.base10_parse::<u32>()?;
+                lit.clone()
+            }
+            _ => {
+                return Err(syn::Error::new(
+                    pair.value.span(),
+                    "Expected an unsigned integer value",
+                ))
+            }
+        };
+
+        if self.version.is_some() {
+            return Err(syn::Error::new(
+                version.span(),
+                format!("`{VERSION_ATTR}` already set"),
+            ));
+        }
+
+        self.version = Some(version);
+        Ok(())
+    }
+
+    /// Parse `methods` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(methods(length, normalize))]`
+    fn parse_methods(&mut self, input: ParseStream) -> syn::Result<()> {
+        let ident = input.parse::<kw::methods>()?;
+
+        if !cfg!(feature = "functions") {
+            return Err(syn::Error::new(
+                ident.span,
+                format!("the `{METHODS_ATTR}` attribute requires the `functions` feature of `bevy_reflect`"),
+            ));
+        }
+
+        let content;
+        parenthesized!(content in input);
+        for method in content.parse_terminated(Ident::parse_any, Token![,])? {
+            if self.methods.contains(&method) {
+                return Err(syn::Error::new(
+                    method.span(),
+                    format!("method `{method}` already registered in `{METHODS_ATTR}`"),
+                ));
+            }
+            self.methods.push(method);
+        }
+
+        Ok(())
+    }
+
     /// Parse `where` attribute.
     ///
     /// Examples:
@@ -587,6 +665,16 @@ impl ContainerAttributes {
     pub fn is_opaque(&self) -> bool {
         self.is_opaque
     }
+
+    /// The version set by the `version` attribute on this type, if any.
+    pub fn version(&self) -> Option<&LitInt> {
+        self.version.as_ref()
+    }
+
+    /// The methods listed in the `methods` attribute on this type.
+    pub fn methods(&self) -> &[Ident] {
+        &self.methods
+    }
 }
 
 /// Adds an identifier to a vector of identifiers if it is not already present.
@@ -209,6 +209,23 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
 /// This is useful for when a type can't or shouldn't implement `TypePath`,
 /// or if a manual implementation is desired.
 ///
+/// ## `#[reflect(version = N)]`
+///
+/// This attribute registers `ReflectVersion` type data with the given version of the type.
+///
+/// Versioned types are serialized along with their version by the reflection serializers,
+/// and the data written by older versions is migrated during deserialization
+/// using the migrations registered with `TypeRegistry::register_migration`.
+///
+/// ## `#[reflect(methods(...))]`
+///
+/// This attribute registers `ReflectMethods` type data containing the listed methods of the type,
+/// such as `#[reflect(methods(length, normalize))]`.
+/// Each method is converted into a `DynamicFunction` whose first argument is its receiver,
+/// and may then be called by name on a `dyn PartialReflect` using `TypeRegistry::reflect_call`.
+///
+/// This attribute requires the `functions` feature.
+///
 /// ## `#[reflect(no_field_bounds)]`
 ///
 /// This attribute will opt-out of the default trait bounds added to all field types
@@ -534,10 +551,33 @@ pub fn derive_type_path(input: TokenStream) -> TokenStream {
 /// assert_eq!("Hello, World!", reflected_my_trait.print());
 /// ```
 ///
+/// # `#[reflect_trait(boxed)]`
+///
+/// For traits with `Reflect` as a supertrait, the `boxed` argument makes `Box<dyn MyTrait>` reflectable,
+/// by using `ReflectBox<dyn MyTrait>` as its remote wrapper.
+/// This implements `TypePath` and `BoxedReflect` for `dyn MyTrait`, and adds a hidden supertrait to the trait,
+/// which is automatically implemented for all its implementors and allows boxed values to be cloned.
+///
+/// Boxed values are serialized along with the type path of their underlying type,
+/// and deserialized into the registered type with the `ReflectMyTrait` type data.
+///
+/// ```ignore (bevy_reflect is not accessible from this crate)
+/// #[reflect_trait(boxed)]
+/// trait Behaviour: Reflect {
+///   fn speed(&self) -> f32;
+/// }
+///
+/// #[derive(Reflect)]
+/// struct Npc {
+///   #[reflect(remote = ReflectBox<dyn Behaviour>)]
+///   behaviour: Box<dyn Behaviour>,
+/// }
+/// ```
+///
 /// [object-safe]: https://doc.rust-lang.org/reference/items/traits.html#object-safety
 #[proc_macro_attribute]
 pub fn reflect_trait(args: TokenStream, input: TokenStream) -> TokenStream {
-    trait_reflection::reflect_trait(&args, input)
+    trait_reflection::reflect_trait(args, input)
 }
 
 /// Generates a wrapper type that can be used to "derive `Reflect`" for remote types.
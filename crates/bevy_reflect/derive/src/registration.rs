@@ -42,6 +42,23 @@ pub(crate) fn impl_get_type_registration<'a>(
         }
     });
 
+    let version_data = meta.attrs().version().map(|version| {
+        quote! {
+            registration.insert::<#bevy_reflect_path::serde::ReflectVersion>(#bevy_reflect_path::serde::ReflectVersion::new(#version));
+        }
+    });
+
+    let methods = meta.attrs().methods();
+    let methods_data = (!methods.is_empty()).then(|| {
+        let names = methods.iter().map(ToString::to_string);
+        quote! {
+            registration.insert::<#bevy_reflect_path::func::ReflectMethods>(
+                #bevy_reflect_path::func::ReflectMethods::new()
+                    #(.with_method(#names, Self::#methods))*
+            );
+        }
+    });
+
     quote! {
         impl #impl_generics #bevy_reflect_path::GetTypeRegistration for #type_path #ty_generics #where_reflect_clause {
             fn get_type_registration() -> #bevy_reflect_path::TypeRegistration {
@@ -49,6 +66,8 @@ pub(crate) fn impl_get_type_registration<'a>(
                 registration.insert::<#bevy_reflect_path::ReflectFromPtr>(#bevy_reflect_path::FromType::<Self>::from_type());
                 #from_reflect_data
                 #serialization_data
+                #version_data
+                #methods_data
                 #(registration.insert::<#registration_data>(#bevy_reflect_path::FromType::<Self>::from_type());)*
                 registration
             }
@@ -1,7 +1,14 @@
 use bevy_macro_utils::fq_std::{FQClone, FQOption, FQResult};
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse::Parse, parse_macro_input, Attribute, ItemTrait, Token};
+use quote::{format_ident, quote};
+use syn::{
+    parse::Parse, parse_macro_input, parse_quote, Attribute, ItemTrait, Token,
+    TypeParamBound,
+};
+
+mod kw {
+    syn::custom_keyword!(boxed);
+}
 
 pub(crate) struct TraitInfo {
     item_trait: ItemTrait,
@@ -21,12 +28,44 @@ impl Parse for TraitInfo {
     }
 }
 
+/// The arguments of the `#[reflect_trait]` attribute.
+#[derive(Default)]
+struct TraitArgs {
+    /// Whether `Box<dyn MyTrait>` should be reflectable with `ReflectBox`.
+    boxed: bool,
+}
+
+impl Parse for TraitArgs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut args = TraitArgs::default();
+        if !input.is_empty() {
+            input.parse::<kw::boxed>()?;
+            args.boxed = true;
+        }
+        Ok(args)
+    }
+}
+
 /// A trait attribute macro that allows a reflected type to be downcast to a trait object.
 ///
 /// This generates a struct that takes the form `ReflectMyTrait`. An instance of this struct can then be
 /// used to perform the conversion.
-pub(crate) fn reflect_trait(_args: &TokenStream, input: TokenStream) -> TokenStream {
-    let trait_info = parse_macro_input!(input as TraitInfo);
+///
+/// With `#[reflect_trait(boxed)]`, this also implements `BoxedReflect` for `dyn MyTrait`,
+/// so that `Box<dyn MyTrait>` can be reflected with `ReflectBox`.
+pub(crate) fn reflect_trait(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args as TraitArgs);
+    let mut trait_info = parse_macro_input!(input as TraitInfo);
+
+    let boxed_impls = if args.boxed {
+        match impl_boxed_reflect(&mut trait_info.item_trait) {
+            Ok(impls) => impls,
+            Err(err) => return err.into_compile_error().into(),
+        }
+    } else {
+        proc_macro2::TokenStream::new()
+    };
+
     let item_trait = &trait_info.item_trait;
     let trait_ident = &item_trait.ident;
     let trait_vis = &item_trait.vis;
@@ -89,5 +128,75 @@ pub(crate) fn reflect_trait(_args: &TokenStream, input: TokenStream) -> TokenStr
                 }
             }
         }
+
+        #boxed_impls
     })
 }
+
+/// Generates the implementations of `TypePath` and `BoxedReflect` for `dyn MyTrait`.
+///
+/// Cloning a `dyn MyTrait` requires knowing its concrete type,
+/// so a hidden supertrait with a blanket implementation is added to the trait to provide the clone.
+fn impl_boxed_reflect(item_trait: &mut ItemTrait) -> syn::Result<proc_macro2::TokenStream> {
+    if !item_trait.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &item_trait.generics,
+            "`#[reflect_trait(boxed)]` does not support generic traits",
+        ));
+    }
+
+    let trait_ident = &item_trait.ident;
+    let trait_vis = &item_trait.vis;
+    let clone_trait_ident = format_ident!("__ReflectBoxed{}", trait_ident);
+    let reflect_trait_ident = crate::ident::get_reflect_ident(&trait_ident.to_string());
+    let bevy_reflect_path = crate::meta::get_bevy_reflect_path();
+    let box_path = quote!(#bevy_reflect_path::__macro_exports::alloc_utils::Box);
+
+    let tokens = quote! {
+        #[doc(hidden)]
+        #trait_vis trait #clone_trait_ident {
+            #[doc(hidden)]
+            fn __reflect_clone_boxed(&self) -> #FQResult<#box_path<dyn #trait_ident>, #bevy_reflect_path::ReflectCloneError>;
+        }
+
+        impl<T: #trait_ident> #clone_trait_ident for T {
+            fn __reflect_clone_boxed(&self) -> #FQResult<#box_path<dyn #trait_ident>, #bevy_reflect_path::ReflectCloneError> {
+                #bevy_reflect_path::__macro_exports::reflect_clone_boxed::<T>(self)
+                    .map(|value| value as #box_path<dyn #trait_ident>)
+            }
+        }
+
+        impl #bevy_reflect_path::TypePath for dyn #trait_ident {
+            fn type_path() -> &'static str {
+                ::core::concat!("dyn ", ::core::module_path!(), "::", ::core::stringify!(#trait_ident))
+            }
+
+            fn short_type_path() -> &'static str {
+                ::core::concat!("dyn ", ::core::stringify!(#trait_ident))
+            }
+        }
+
+        impl #bevy_reflect_path::BoxedReflect for dyn #trait_ident {
+            fn reflect_clone_boxed(&self) -> #FQResult<#box_path<Self>, #bevy_reflect_path::ReflectCloneError> {
+                #clone_trait_ident::__reflect_clone_boxed(self)
+            }
+
+            fn from_boxed_reflect(
+                value: #box_path<dyn #bevy_reflect_path::Reflect>,
+                registry: &#bevy_reflect_path::TypeRegistry,
+            ) -> #FQResult<#box_path<Self>, #box_path<dyn #bevy_reflect_path::Reflect>> {
+                let type_id = #bevy_reflect_path::Reflect::as_any(&*value).type_id();
+                match registry.get_type_data::<#reflect_trait_ident>(type_id) {
+                    #FQOption::Some(reflect_trait) => reflect_trait.get_boxed(value),
+                    #FQOption::None => #FQResult::Err(value),
+                }
+            }
+        }
+    };
+
+    let bound: TypeParamBound = parse_quote!(#clone_trait_ident);
+    item_trait.colon_token.get_or_insert_with(Default::default);
+    item_trait.supertraits.push(bound);
+
+    Ok(tokens)
+}
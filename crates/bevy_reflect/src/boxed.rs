@@ -0,0 +1,545 @@
+use crate::{
+    serde::{
+        DeserializeWithRegistry, ReflectDeserializeWithRegistry, ReflectDeserializer,
+        ReflectSerializeWithRegistry, ReflectSerializer, SerializeWithRegistry,
+    },
+    utility::GenericTypeInfoCell,
+    ApplyError, FromReflect, FromType, Generics, GetTypeRegistration, OpaqueInfo, PartialReflect,
+    Reflect, ReflectCloneError, ReflectFromPtr, ReflectFromReflect, ReflectKind, ReflectMut,
+    ReflectOwned, ReflectRef, ReflectRemote, TypeInfo, TypeParamInfo, TypePath, TypeRegistration,
+    TypeRegistry, Typed,
+};
+use alloc::boxed::Box;
+use bevy_reflect_derive::impl_type_path;
+use core::{
+    any::Any,
+    fmt::{Debug, Formatter},
+};
+use serde::{de::DeserializeSeed, de::Error, Deserializer, Serialize, Serializer};
+
+/// A trait object type, such as `dyn Reflect`, whose boxed values can be reflected with a [`ReflectBox`].
+///
+/// This is implemented for `dyn PartialReflect` and `dyn Reflect`,
+/// and for the traits marked with [`#[reflect_trait(boxed)]`](crate::reflect_trait).
+pub trait BoxedReflect: PartialReflect + TypePath {
+    /// Clones the value into a new box, using [`PartialReflect::reflect_clone`].
+    fn reflect_clone_boxed(&self) -> Result<Box<Self>, ReflectCloneError>;
+
+    /// Converts a boxed value of a concrete type into this trait object,
+    /// using the type data registered for its type in the given registry.
+    ///
+    /// If the type cannot be converted, returns the value back.
+    fn from_boxed_reflect(
+        value: Box<dyn Reflect>,
+        registry: &TypeRegistry,
+    ) -> Result<Box<Self>, Box<dyn Reflect>>;
+}
+
+impl BoxedReflect for dyn PartialReflect {
+    fn reflect_clone_boxed(&self) -> Result<Box<Self>, ReflectCloneError> {
+        match self.reflect_clone() {
+            Ok(value) => Ok(value.into_partial_reflect()),
+            // Dynamic types cannot be cloned as themselves, but can still be copied as dynamic types
+            Err(_)
+                if self.try_as_reflect().is_none()
+                    && self.reflect_kind() != ReflectKind::Opaque =>
+            {
+                Ok(self.to_dynamic())
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    fn from_boxed_reflect(
+        value: Box<dyn Reflect>,
+        _registry: &TypeRegistry,
+    ) -> Result<Box<Self>, Box<dyn Reflect>> {
+        Ok(value.into_partial_reflect())
+    }
+}
+
+impl BoxedReflect for dyn Reflect {
+    fn reflect_clone_boxed(&self) -> Result<Box<Self>, ReflectCloneError> {
+        self.reflect_clone()
+    }
+
+    fn from_boxed_reflect(
+        value: Box<dyn Reflect>,
+        _registry: &TypeRegistry,
+    ) -> Result<Box<Self>, Box<dyn Reflect>> {
+        Ok(value)
+    }
+}
+
+/// A reflectable [`Box`] of a trait object, such as `Box<dyn Reflect>`.
+///
+/// The boxed value is reflected as an [opaque] value of this type.
+/// It is serialized along with the type path of its underlying type, like with a [`ReflectSerializer`],
+/// so that it can be deserialized with the [`TypeRegistry`] into any registered type.
+///
+/// This type is the [remote] wrapper of `Box<dyn Trait>` fields, which makes them reflectable
+/// so that they can be used in reflected components, scenes, and the like.
+/// Like other remote wrappers, its values are represented by the remote `Box<dyn Trait>`,
+/// which is the type to downcast them to.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{
+/// #     reflect_trait, serde::{ReflectDeserializer, ReflectSerializer},
+/// #     FromReflect, Reflect, ReflectBox, TypeRegistry,
+/// # };
+/// # use serde::de::DeserializeSeed;
+/// #[reflect_trait(boxed)]
+/// trait Behaviour: Reflect {
+///     fn speed(&self) -> f32;
+/// }
+///
+/// #[derive(Reflect)]
+/// #[reflect(Behaviour)]
+/// struct Wander {
+///     speed: f32,
+/// }
+///
+/// impl Behaviour for Wander {
+///     fn speed(&self) -> f32 {
+///         self.speed
+///     }
+/// }
+///
+/// #[derive(Reflect)]
+/// struct Npc {
+///     #[reflect(remote = ReflectBox<dyn Behaviour>)]
+///     behaviour: Box<dyn Behaviour>,
+///     #[reflect(remote = ReflectBox<dyn Reflect>)]
+///     item: Box<dyn Reflect>,
+/// }
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Npc>();
+/// registry.register::<Wander>();
+///
+/// let npc = Npc {
+///     behaviour: Box::new(Wander { speed: 2.0 }),
+///     item: Box::new(5_u32),
+/// };
+///
+/// // The boxed values are serialized along with their type path
+/// let serialized = ron::to_string(&ReflectSerializer::new(&npc, &registry)).unwrap();
+/// assert!(serialized.contains(r#"item:{"u32":5}"#));
+///
+/// let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
+/// let deserialized = ReflectDeserializer::new(&registry)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+/// let npc = Npc::from_reflect(&*deserialized).unwrap();
+/// assert_eq!(npc.behaviour.speed(), 2.0);
+/// ```
+///
+/// [opaque]: ReflectKind::Opaque
+/// [remote]: ReflectRemote
+#[repr(transparent)]
+pub struct ReflectBox<T: ?Sized>(pub Box<T>);
+
+impl<T: ?Sized + BoxedReflect> Debug for ReflectBox<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        (*self.0).debug(f)
+    }
+}
+
+impl<T: ?Sized + BoxedReflect> PartialReflect for ReflectBox<T> {
+    fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(<Self as Typed>::type_info())
+    }
+
+    #[inline]
+    fn into_partial_reflect(self: Box<Self>) -> Box<dyn PartialReflect> {
+        self
+    }
+
+    fn as_partial_reflect(&self) -> &dyn PartialReflect {
+        self
+    }
+
+    fn as_partial_reflect_mut(&mut self) -> &mut dyn PartialReflect {
+        self
+    }
+
+    fn try_into_reflect(self: Box<Self>) -> Result<Box<dyn Reflect>, Box<dyn PartialReflect>> {
+        Ok(self)
+    }
+
+    fn try_as_reflect(&self) -> Option<&dyn Reflect> {
+        Some(self)
+    }
+
+    fn try_as_reflect_mut(&mut self) -> Option<&mut dyn Reflect> {
+        Some(self)
+    }
+
+    fn try_apply(&mut self, value: &dyn PartialReflect) -> Result<(), ApplyError> {
+        let Some(value) = value.try_downcast_ref::<Box<T>>() else {
+            // Values of the boxed type can be applied directly
+            return (*self.0).try_apply(value);
+        };
+
+        if (**value).reflect_type_path() == (*self.0).reflect_type_path() {
+            (*self.0).try_apply((**value).as_partial_reflect())
+        } else {
+            self.0 = (**value)
+                .reflect_clone_boxed()
+                .map_err(|_| ApplyError::MismatchedTypes {
+                    from_type: (**value).reflect_type_path().into(),
+                    to_type: (*self.0).reflect_type_path().into(),
+                })?;
+            Ok(())
+        }
+    }
+
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::Opaque
+    }
+
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::Opaque(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Opaque(self)
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Opaque(self)
+    }
+
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(Self((*self.0).reflect_clone_boxed()?)))
+    }
+
+    fn reflect_hash(&self) -> Option<u64> {
+        (*self.0).reflect_hash()
+    }
+
+    fn reflect_partial_eq(&self, value: &dyn PartialReflect) -> Option<bool> {
+        match value.try_downcast_ref::<Box<T>>() {
+            Some(value) => (*self.0).reflect_partial_eq((**value).as_partial_reflect()),
+            None => (*self.0).reflect_partial_eq(value),
+        }
+    }
+
+    fn debug(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        (*self.0).debug(f)
+    }
+}
+
+// Like other remote wrappers, the value is represented by its remote type
+impl<T: ?Sized + BoxedReflect> Reflect for ReflectBox<T> {
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        Box::new(self.0)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        &mut self.0
+    }
+
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        self.0 = value.take()?;
+        Ok(())
+    }
+}
+
+impl<T: ?Sized + BoxedReflect> Typed for ReflectBox<T> {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            TypeInfo::Opaque(
+                OpaqueInfo::new::<Self>()
+                    .with_generics(Generics::from_iter([TypeParamInfo::new::<T>("T")])),
+            )
+        })
+    }
+}
+
+impl_type_path!((in bevy_reflect) ReflectBox<T: ?Sized + BoxedReflect>);
+
+impl<T: ?Sized + BoxedReflect> FromReflect for ReflectBox<T> {
+    fn from_reflect(reflect: &dyn PartialReflect) -> Option<Self> {
+        let value = reflect.try_downcast_ref::<Box<T>>()?;
+        (**value).reflect_clone_boxed().ok().map(Self)
+    }
+}
+
+impl<T: ?Sized + BoxedReflect> GetTypeRegistration for ReflectBox<T> {
+    fn get_type_registration() -> TypeRegistration {
+        let mut registration = TypeRegistration::of::<Self>();
+        registration.insert::<ReflectFromPtr>(FromType::<Self>::from_type());
+        registration.insert::<ReflectFromReflect>(FromType::<Self>::from_type());
+        registration.insert(ReflectSerializeWithRegistry::from_remote::<Self>());
+        registration.insert::<ReflectDeserializeWithRegistry>(FromType::<Self>::from_type());
+        registration
+    }
+}
+
+#[expect(
+    unsafe_code,
+    reason = "`ReflectBox` is `#[repr(transparent)]`, so references to its remote type can be cast to it."
+)]
+impl<T: ?Sized + BoxedReflect> ReflectRemote for ReflectBox<T> {
+    type Remote = Box<T>;
+
+    fn as_remote(&self) -> &Self::Remote {
+        &self.0
+    }
+
+    fn as_remote_mut(&mut self) -> &mut Self::Remote {
+        &mut self.0
+    }
+
+    fn into_remote(self) -> Self::Remote {
+        self.0
+    }
+
+    fn as_wrapper(remote: &Self::Remote) -> &Self {
+        // SAFETY: `ReflectBox` is `#[repr(transparent)]` over `Box<T>`.
+        unsafe { &*core::ptr::from_ref(remote).cast::<Self>() }
+    }
+
+    fn as_wrapper_mut(remote: &mut Self::Remote) -> &mut Self {
+        // SAFETY: `ReflectBox` is `#[repr(transparent)]` over `Box<T>`.
+        unsafe { &mut *core::ptr::from_mut(remote).cast::<Self>() }
+    }
+
+    fn into_wrapper(remote: Self::Remote) -> Self {
+        Self(remote)
+    }
+}
+
+impl<T: ?Sized + BoxedReflect> SerializeWithRegistry for ReflectBox<T> {
+    fn serialize<S>(&self, serializer: S, registry: &TypeRegistry) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ReflectSerializer::new((*self.0).as_partial_reflect(), registry).serialize(serializer)
+    }
+}
+
+impl<'de, T: ?Sized + BoxedReflect> DeserializeWithRegistry<'de> for ReflectBox<T> {
+    fn deserialize<D>(deserializer: D, registry: &TypeRegistry) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = ReflectDeserializer::new(registry).deserialize(deserializer)?;
+
+        // Values are usually deserialized as dynamic types, which need to be converted to their concrete type
+        let value = match value.try_into_reflect() {
+            Ok(value) => value,
+            Err(value) => {
+                let type_path = value.reflect_type_path();
+                value
+                    .get_represented_type_info()
+                    .and_then(|info| registry.get_type_data::<ReflectFromReflect>(info.type_id()))
+                    .ok_or_else(|| {
+                        Error::custom(format_args!(
+                            "type `{type_path}` did not register the `ReflectFromReflect` type data needed to box it as `{}`",
+                            T::type_path(),
+                        ))
+                    })?
+                    .from_reflect(&*value)
+                    .ok_or_else(|| {
+                        Error::custom(format_args!(
+                            "failed to convert the data of type `{type_path}` to the type",
+                        ))
+                    })?
+            }
+        };
+
+        T::from_boxed_reflect(value, registry)
+            .map(Self)
+            .map_err(|value| {
+                Error::custom(format_args!(
+                    "type `{}` cannot be boxed as `{}`, make sure the type data of the trait is registered for it",
+                    value.reflect_type_path(),
+                    T::type_path(),
+                ))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        reflect_trait,
+        serde::{ReflectDeserializer, ReflectSerializer},
+        DynamicStruct, FromReflect, PartialReflect, Reflect, ReflectBox, Struct, TypeRegistry,
+        Typed,
+    };
+    use alloc::{boxed::Box, string::String, string::ToString};
+    use serde::de::DeserializeSeed;
+
+    #[reflect_trait(boxed)]
+    trait Behaviour: Reflect {
+        fn speed(&self) -> f32;
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(Behaviour)]
+    struct Wander {
+        speed: f32,
+    }
+
+    impl Behaviour for Wander {
+        fn speed(&self) -> f32 {
+            self.speed
+        }
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(Behaviour)]
+    struct Idle;
+
+    impl Behaviour for Idle {
+        fn speed(&self) -> f32 {
+            0.0
+        }
+    }
+
+    // Does not implement `Behaviour`
+    #[derive(Reflect)]
+    struct Unregistered;
+
+    #[derive(Reflect)]
+    struct Npc {
+        name: String,
+        #[reflect(remote = ReflectBox<dyn Behaviour>)]
+        behaviour: Box<dyn Behaviour>,
+        #[reflect(remote = ReflectBox<dyn Reflect>)]
+        item: Box<dyn Reflect>,
+        #[reflect(remote = ReflectBox<dyn PartialReflect>)]
+        data: Box<dyn PartialReflect>,
+    }
+
+    fn get_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Npc>();
+        registry.register::<Wander>();
+        registry.register::<Idle>();
+        registry.register::<Unregistered>();
+        registry
+    }
+
+    fn npc() -> Npc {
+        let mut dynamic_wander = DynamicStruct::default();
+        dynamic_wander.insert("speed", 1.0_f32);
+        dynamic_wander.set_represented_type(Some(Wander::type_info()));
+
+        Npc {
+            name: String::from("Bob"),
+            behaviour: Box::new(Wander { speed: 2.0 }),
+            item: Box::new(Idle),
+            data: Box::new(dynamic_wander),
+        }
+    }
+
+    #[test]
+    fn should_reflect_boxed_values() {
+        let npc = npc();
+
+        assert_eq!(2.0, npc.behaviour.speed());
+        let clone = npc.reflect_clone().unwrap().take::<Npc>().unwrap();
+        assert_eq!(2.0, clone.behaviour.speed());
+        assert!(clone.item.is::<Idle>());
+        assert!(npc.reflect_partial_eq(&clone).unwrap());
+
+        let dynamic = npc.to_dynamic();
+        let from_reflect = Npc::from_reflect(&*dynamic).unwrap();
+        assert_eq!(2.0, from_reflect.behaviour.speed());
+        assert!(npc.reflect_partial_eq(&from_reflect).unwrap());
+    }
+
+    #[test]
+    fn should_apply_boxed_values() {
+        let mut npc = npc();
+
+        // Values of the same type are applied to the boxed value
+        let mut patch = DynamicStruct::default();
+        patch.insert("speed", 3.0_f32);
+        npc.field_mut("behaviour").unwrap().apply(&patch);
+        assert_eq!(3.0, npc.behaviour.speed());
+
+        // Values of other types replace the boxed value
+        let mut patch = DynamicStruct::default();
+        patch.insert(
+            "behaviour",
+            ReflectBox(Box::new(Idle) as Box<dyn Behaviour>),
+        );
+        npc.apply(&patch);
+        assert!(npc.behaviour.as_reflect().is::<Idle>());
+
+        // Boxed values are downcast to their remote type
+        let behaviour = npc.field("behaviour").unwrap();
+        assert!(behaviour.try_downcast_ref::<Box<dyn Behaviour>>().is_some());
+    }
+
+    #[test]
+    fn should_roundtrip_boxed_values() {
+        let registry = get_registry();
+        let npc = npc();
+
+        let serializer = ReflectSerializer::new(&npc, &registry);
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!(
+            r#"{"bevy_reflect::boxed::tests::Npc":(name:"Bob",behaviour:{"bevy_reflect::boxed::tests::Wander":(speed:2.0)},item:{"bevy_reflect::boxed::tests::Idle":()},data:{"bevy_reflect::boxed::tests::Wander":(speed:1.0)})}"#,
+            serialized
+        );
+
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let output = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        let output = Npc::from_reflect(&*output).unwrap();
+        assert_eq!(2.0, output.behaviour.speed());
+        assert!(output.item.is::<Idle>());
+        // Dynamic values are deserialized as their represented type
+        assert_eq!(
+            Some(&Wander { speed: 1.0 }),
+            output.data.try_downcast_ref::<Wander>()
+        );
+    }
+
+    #[test]
+    fn should_not_deserialize_types_without_trait() {
+        let registry = get_registry();
+
+        let input = r#"{
+            "bevy_reflect::boxed::tests::Npc": (
+                name: "Bob",
+                behaviour: { "bevy_reflect::boxed::tests::Unregistered": () },
+                item: { "bevy_reflect::boxed::tests::Idle": () },
+                data: { "u32": 5 },
+            ),
+        }"#;
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let error = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("type `bevy_reflect::boxed::tests::Unregistered` cannot be boxed as `dyn bevy_reflect::boxed::tests::Behaviour`"));
+    }
+}
@@ -0,0 +1,801 @@
+//! Structural diffing and patching of reflected values.
+//!
+//! [`Patch::diff`] walks two values of the same type and records the differences between them as a list of
+//! [`PatchOp`]s, each addressed by a [`ParsedPath`] from the root of the value. The patch can later be
+//! [applied](Patch::apply) to the old value, or to another value of the same type, to turn it into the new value.
+//!
+//! The [`Diff`] trait provides both operations as methods on any [`PartialReflect`] value:
+//!
+//! ```
+//! # use bevy_reflect::{diff::Diff, Reflect};
+//! #[derive(Reflect, Clone, PartialEq, Debug)]
+//! struct Player {
+//!     name: String,
+//!     health: u32,
+//!     inventory: Vec<String>,
+//! }
+//!
+//! let old = Player {
+//!     name: "Alice".to_string(),
+//!     health: 100,
+//!     inventory: vec!["sword".to_string()],
+//! };
+//! let new = Player {
+//!     health: 80,
+//!     inventory: vec!["sword".to_string(), "shield".to_string()],
+//!     ..old.clone()
+//! };
+//!
+//! let patch = old.diff(&new).unwrap();
+//! // One operation sets `.health`, the other inserts `.inventory[1]`.
+//! assert_eq!(patch.len(), 2);
+//!
+//! let mut value = old.clone();
+//! value.apply_patch(&patch).unwrap();
+//! assert_eq!(value, new);
+//! ```
+//!
+//! Patches can be serialized with a [`TypeRegistry`](crate::TypeRegistry) using the [`PatchSerializer`] and the
+//! [`PatchDeserializer`].
+
+mod serde;
+
+pub use serde::{PatchDeserializer, PatchSerializer};
+
+use alloc::{borrow::Cow, boxed::Box, string::ToString, vec::Vec};
+use thiserror::Error;
+
+use crate::{
+    access::Access, ApplyError, OffsetAccess, ParsedPath, PartialReflect, ReflectCloneError,
+    ReflectKind, ReflectMut, ReflectPath, ReflectPathError, ReflectRef, VariantType,
+};
+
+/// A single change recorded in a [`Patch`].
+///
+/// Every operation targets the value at its `path`, relative to the root of the patched value.
+#[derive(Debug)]
+pub enum PatchOp {
+    /// Replaces the value at `path` with `value`.
+    Set {
+        /// The path of the replaced value.
+        path: ParsedPath,
+        /// The new value.
+        value: Box<dyn PartialReflect>,
+    },
+    /// Replaces the enum at `path` with `value`, which is of another variant.
+    ChangeVariant {
+        /// The path of the enum.
+        path: ParsedPath,
+        /// The new value of the enum.
+        value: Box<dyn PartialReflect>,
+    },
+    /// Inserts `value` at `index` in the list at `path`.
+    ListInsert {
+        /// The path of the list.
+        path: ParsedPath,
+        /// The index of the inserted item.
+        index: usize,
+        /// The inserted item.
+        value: Box<dyn PartialReflect>,
+    },
+    /// Removes the item at `index` from the list at `path`.
+    ListRemove {
+        /// The path of the list.
+        path: ParsedPath,
+        /// The index of the removed item.
+        index: usize,
+    },
+    /// Inserts or replaces the entry with the given `key` in the map at `path`.
+    MapInsert {
+        /// The path of the map.
+        path: ParsedPath,
+        /// The key of the entry.
+        key: Box<dyn PartialReflect>,
+        /// The new value of the entry.
+        value: Box<dyn PartialReflect>,
+    },
+    /// Removes the entry with the given `key` from the map at `path`.
+    MapRemove {
+        /// The path of the map.
+        path: ParsedPath,
+        /// The key of the removed entry.
+        key: Box<dyn PartialReflect>,
+    },
+    /// Inserts `value` in the set at `path`.
+    SetInsert {
+        /// The path of the set.
+        path: ParsedPath,
+        /// The inserted value.
+        value: Box<dyn PartialReflect>,
+    },
+    /// Removes `value` from the set at `path`.
+    SetRemove {
+        /// The path of the set.
+        path: ParsedPath,
+        /// The removed value.
+        value: Box<dyn PartialReflect>,
+    },
+}
+
+impl PatchOp {
+    /// The path of the value targeted by this operation.
+    pub fn path(&self) -> &ParsedPath {
+        match self {
+            PatchOp::Set { path, .. }
+            | PatchOp::ChangeVariant { path, .. }
+            | PatchOp::ListInsert { path, .. }
+            | PatchOp::ListRemove { path, .. }
+            | PatchOp::MapInsert { path, .. }
+            | PatchOp::MapRemove { path, .. }
+            | PatchOp::SetInsert { path, .. }
+            | PatchOp::SetRemove { path, .. } => path,
+        }
+    }
+
+    /// Applies this operation to `target`.
+    pub fn apply<'a>(&'a self, target: &mut dyn PartialReflect) -> Result<(), ApplyPatchError<'a>> {
+        let path = self.path();
+        let target = path.reflect_element_mut(target)?;
+        let mismatched_kinds = |expected, actual| ApplyPatchError::MismatchedKinds {
+            path: path.clone(),
+            expected,
+            actual,
+        };
+        let missing_entry = || ApplyPatchError::MissingEntry { path: path.clone() };
+
+        if let PatchOp::Set { value, .. } | PatchOp::ChangeVariant { value, .. } = self {
+            target.try_apply(value.as_ref())?;
+            return Ok(());
+        }
+        match (self, target.reflect_mut()) {
+            (PatchOp::Set { .. } | PatchOp::ChangeVariant { .. }, _) => {}
+            (PatchOp::ListInsert { index, value, .. }, ReflectMut::List(list)) => {
+                if *index > list.len() {
+                    return Err(ApplyPatchError::IndexOutOfBounds {
+                        path: path.clone(),
+                        index: *index,
+                        len: list.len(),
+                    });
+                }
+                list.insert(*index, clone_value(value.as_ref())?);
+            }
+            (PatchOp::ListRemove { index, .. }, ReflectMut::List(list)) => {
+                if *index >= list.len() {
+                    return Err(ApplyPatchError::IndexOutOfBounds {
+                        path: path.clone(),
+                        index: *index,
+                        len: list.len(),
+                    });
+                }
+                list.remove(*index);
+            }
+            (PatchOp::MapInsert { key, value, .. }, ReflectMut::Map(map)) => {
+                map.insert_boxed(clone_value(key.as_ref())?, clone_value(value.as_ref())?);
+            }
+            (PatchOp::MapRemove { key, .. }, ReflectMut::Map(map)) => {
+                map.remove(key.as_ref()).ok_or_else(missing_entry)?;
+            }
+            (PatchOp::SetInsert { value, .. }, ReflectMut::Set(set)) => {
+                set.insert_boxed(clone_value(value.as_ref())?);
+            }
+            (PatchOp::SetRemove { value, .. }, ReflectMut::Set(set)) => {
+                if !set.remove(value.as_ref()) {
+                    return Err(missing_entry());
+                }
+            }
+            (PatchOp::ListInsert { .. } | PatchOp::ListRemove { .. }, actual) => {
+                return Err(mismatched_kinds(ReflectKind::List, actual.kind()));
+            }
+            (PatchOp::MapInsert { .. } | PatchOp::MapRemove { .. }, actual) => {
+                return Err(mismatched_kinds(ReflectKind::Map, actual.kind()));
+            }
+            (PatchOp::SetInsert { .. } | PatchOp::SetRemove { .. }, actual) => {
+                return Err(mismatched_kinds(ReflectKind::Set, actual.kind()));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A list of [`PatchOp`]s turning a reflected value into another, computed with [`Patch::diff`].
+///
+/// See the [module documentation](self) for more details.
+#[derive(Debug, Default)]
+pub struct Patch {
+    ops: Vec<PatchOp>,
+}
+
+impl Patch {
+    /// Creates an empty [`Patch`].
+    pub const fn new() -> Self {
+        Self { ops: Vec::new() }
+    }
+
+    /// Computes the [`Patch`] turning `old` into `new`.
+    ///
+    /// Structs, tuples, tuple structs, arrays and enums of the same variant are compared field by field. Items
+    /// inserted or removed in lists, maps and sets are recorded as such, and enums changing variant are replaced
+    /// entirely. Other values are compared with [`PartialReflect::reflect_partial_eq`] and replaced if they differ.
+    ///
+    /// Returns an error if `old` and `new` are not of the same type, or if a value of the patch cannot be cloned.
+    pub fn diff(old: &dyn PartialReflect, new: &dyn PartialReflect) -> Result<Self, DiffError> {
+        if !same_type(old, new) {
+            return Err(DiffError::MismatchedTypes {
+                old: old.reflect_type_path().into(),
+                new: new.reflect_type_path().into(),
+            });
+        }
+        let mut differ = Differ {
+            path: Vec::new(),
+            ops: Vec::new(),
+        };
+        differ.diff(old, new)?;
+        Ok(Self { ops: differ.ops })
+    }
+
+    /// Applies the operations of this patch to `target`, in order.
+    ///
+    /// If an operation fails, the error is returned and the following operations are not applied, leaving `target`
+    /// partially patched.
+    pub fn apply<'a>(&'a self, target: &mut dyn PartialReflect) -> Result<(), ApplyPatchError<'a>> {
+        for op in &self.ops {
+            op.apply(target)?;
+        }
+        Ok(())
+    }
+
+    /// Appends an operation to the patch.
+    pub fn push(&mut self, op: PatchOp) {
+        self.ops.push(op);
+    }
+
+    /// The operations of the patch.
+    pub fn ops(&self) -> &[PatchOp] {
+        &self.ops
+    }
+
+    /// Returns an iterator over the operations of the patch.
+    pub fn iter(&self) -> core::slice::Iter<'_, PatchOp> {
+        self.ops.iter()
+    }
+
+    /// Returns the number of operations in the patch.
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    /// Returns `true` if the patch has no operations, meaning the compared values were equal.
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+}
+
+impl From<Vec<PatchOp>> for Patch {
+    fn from(ops: Vec<PatchOp>) -> Self {
+        Self { ops }
+    }
+}
+
+impl IntoIterator for Patch {
+    type Item = PatchOp;
+    type IntoIter = alloc::vec::IntoIter<PatchOp>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ops.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Patch {
+    type Item = &'a PatchOp;
+    type IntoIter = core::slice::Iter<'a, PatchOp>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ops.iter()
+    }
+}
+
+/// An error returned by [`Patch::diff`].
+#[derive(Error, Debug)]
+pub enum DiffError {
+    /// The compared values are not of the same type.
+    #[error("cannot diff a `{old}` with a `{new}`")]
+    MismatchedTypes {
+        /// The type path of the old value.
+        old: Box<str>,
+        /// The type path of the new value.
+        new: Box<str>,
+    },
+    /// A value recorded in the patch could not be cloned.
+    #[error(transparent)]
+    Clone(#[from] ReflectCloneError),
+}
+
+/// An error returned by [`Patch::apply`].
+#[derive(Error, Debug)]
+pub enum ApplyPatchError<'a> {
+    /// The path of an operation does not exist in the patched value.
+    #[error("{0}")]
+    Path(ReflectPathError<'a>),
+    /// An operation targets a value of another kind, e.g. a list insertion on a map.
+    #[error("expected a {expected} at `{path}`, found a {actual}")]
+    MismatchedKinds {
+        /// The path of the operation.
+        path: ParsedPath,
+        /// The kind of value expected by the operation.
+        expected: ReflectKind,
+        /// The kind of the value found at the path.
+        actual: ReflectKind,
+    },
+    /// A list operation targets an index outside of the list.
+    #[error("index {index} is out of bounds of the list at `{path}` of length {len}")]
+    IndexOutOfBounds {
+        /// The path of the list.
+        path: ParsedPath,
+        /// The index targeted by the operation.
+        index: usize,
+        /// The length of the list.
+        len: usize,
+    },
+    /// A removal targets an entry that does not exist in the map or set.
+    #[error("the removed entry does not exist in `{path}`")]
+    MissingEntry {
+        /// The path of the map or set.
+        path: ParsedPath,
+    },
+    /// A value could not be applied.
+    #[error(transparent)]
+    Apply(#[from] ApplyError),
+    /// A value of the patch could not be cloned into the patched value.
+    #[error(transparent)]
+    Clone(#[from] ReflectCloneError),
+}
+
+impl<'a> From<ReflectPathError<'a>> for ApplyPatchError<'a> {
+    fn from(error: ReflectPathError<'a>) -> Self {
+        ApplyPatchError::Path(error)
+    }
+}
+
+/// Methods to [diff](Patch::diff) and [patch](Patch::apply) reflected values.
+///
+/// This trait is automatically implemented for all [`PartialReflect`] types.
+pub trait Diff: PartialReflect {
+    /// Computes the [`Patch`] turning `self` into `new`. See [`Patch::diff`].
+    fn diff(&self, new: &dyn PartialReflect) -> Result<Patch, DiffError> {
+        Patch::diff(self.as_partial_reflect(), new)
+    }
+
+    /// Applies `patch` to `self`. See [`Patch::apply`].
+    fn apply_patch<'a>(&mut self, patch: &'a Patch) -> Result<(), ApplyPatchError<'a>> {
+        patch.apply(self.as_partial_reflect_mut())
+    }
+}
+
+impl<T: PartialReflect + ?Sized> Diff for T {}
+
+/// Clones `value`, preferring a concrete clone to a dynamic one.
+fn clone_value(value: &dyn PartialReflect) -> Result<Box<dyn PartialReflect>, ReflectCloneError> {
+    match value.reflect_clone() {
+        Ok(value) => Ok(value.into_partial_reflect()),
+        Err(error) if value.reflect_kind() == ReflectKind::Opaque => Err(error),
+        Err(_) => Ok(value.to_dynamic()),
+    }
+}
+
+fn same_type(a: &dyn PartialReflect, b: &dyn PartialReflect) -> bool {
+    match (a.get_represented_type_info(), b.get_represented_type_info()) {
+        (Some(a), Some(b)) => a.type_id() == b.type_id(),
+        (None, None) => a.reflect_kind() == b.reflect_kind(),
+        _ => false,
+    }
+}
+
+fn equal(a: &dyn PartialReflect, b: &dyn PartialReflect) -> bool {
+    a.reflect_partial_eq(b) == Some(true)
+}
+
+struct Differ {
+    path: Vec<OffsetAccess>,
+    ops: Vec<PatchOp>,
+}
+
+impl Differ {
+    fn path(&self) -> ParsedPath {
+        ParsedPath(self.path.clone())
+    }
+
+    fn set(&mut self, new: &dyn PartialReflect) -> Result<(), DiffError> {
+        let op = PatchOp::Set {
+            path: self.path(),
+            value: clone_value(new)?,
+        };
+        self.ops.push(op);
+        Ok(())
+    }
+
+    fn diff_at(
+        &mut self,
+        access: Access<'static>,
+        old: &dyn PartialReflect,
+        new: &dyn PartialReflect,
+    ) -> Result<(), DiffError> {
+        self.path.push(access.into());
+        let result = self.diff(old, new);
+        self.path.pop();
+        result
+    }
+
+    fn diff(
+        &mut self,
+        old: &dyn PartialReflect,
+        new: &dyn PartialReflect,
+    ) -> Result<(), DiffError> {
+        if !same_type(old, new) {
+            return self.set(new);
+        }
+
+        match (old.reflect_ref(), new.reflect_ref()) {
+            (ReflectRef::Struct(old), ReflectRef::Struct(new)) => {
+                if old.field_len() != new.field_len() {
+                    return self.set(new.as_partial_reflect());
+                }
+                for index in 0..new.field_len() {
+                    let name = new.name_at(index).unwrap_or_default();
+                    let (Some(old_field), Some(new_field)) = (old.field(name), new.field_at(index))
+                    else {
+                        return self.set(new.as_partial_reflect());
+                    };
+                    self.diff_at(
+                        Access::Field(Cow::Owned(name.to_string())),
+                        old_field,
+                        new_field,
+                    )?;
+                }
+            }
+            (ReflectRef::TupleStruct(old), ReflectRef::TupleStruct(new)) => {
+                if old.field_len() != new.field_len() {
+                    return self.set(new.as_partial_reflect());
+                }
+                for (index, (old, new)) in old.iter_fields().zip(new.iter_fields()).enumerate() {
+                    self.diff_at(Access::TupleIndex(index), old, new)?;
+                }
+            }
+            (ReflectRef::Tuple(old), ReflectRef::Tuple(new)) => {
+                if old.field_len() != new.field_len() {
+                    return self.set(new.as_partial_reflect());
+                }
+                for (index, (old, new)) in old.iter_fields().zip(new.iter_fields()).enumerate() {
+                    self.diff_at(Access::TupleIndex(index), old, new)?;
+                }
+            }
+            (ReflectRef::Array(old), ReflectRef::Array(new)) => {
+                if old.len() != new.len() {
+                    return self.set(new.as_partial_reflect());
+                }
+                for (index, (old, new)) in old.iter().zip(new.iter()).enumerate() {
+                    self.diff_at(Access::ListIndex(index), old, new)?;
+                }
+            }
+            (ReflectRef::Enum(old_enum), ReflectRef::Enum(new_enum)) => {
+                if old_enum.variant_name() != new_enum.variant_name()
+                    || old_enum.field_len() != new_enum.field_len()
+                {
+                    let op = PatchOp::ChangeVariant {
+                        path: self.path(),
+                        value: clone_value(new)?,
+                    };
+                    self.ops.push(op);
+                    return Ok(());
+                }
+                for index in 0..new_enum.field_len() {
+                    let (Some(old_field), Some(new_field)) =
+                        (old_enum.field_at(index), new_enum.field_at(index))
+                    else {
+                        return self.set(new);
+                    };
+                    let access = match new_enum.variant_type() {
+                        VariantType::Struct => {
+                            let name = new_enum.name_at(index).unwrap_or_default();
+                            Access::Field(Cow::Owned(name.to_string()))
+                        }
+                        VariantType::Tuple | VariantType::Unit => Access::TupleIndex(index),
+                    };
+                    self.diff_at(access, old_field, new_field)?;
+                }
+            }
+            (ReflectRef::List(old), ReflectRef::List(new)) => {
+                let (old_len, new_len) = (old.len(), new.len());
+                fn items(list: &dyn crate::List, index: usize) -> &dyn PartialReflect {
+                    list.get(index).unwrap()
+                }
+
+                // Skip the items left untouched at the start and the end of the list.
+                let prefix = (0..old_len.min(new_len))
+                    .take_while(|&i| equal(items(old, i), items(new, i)))
+                    .count();
+                let suffix = (0..old_len.min(new_len) - prefix)
+                    .take_while(|&i| {
+                        equal(items(old, old_len - 1 - i), items(new, new_len - 1 - i))
+                    })
+                    .count();
+                let old_end = old_len - suffix;
+                let new_end = new_len - suffix;
+                let common = (old_end - prefix).min(new_end - prefix);
+
+                for index in prefix..prefix + common {
+                    self.diff_at(
+                        Access::ListIndex(index),
+                        items(old, index),
+                        items(new, index),
+                    )?;
+                }
+                for index in (prefix + common..old_end).rev() {
+                    let op = PatchOp::ListRemove {
+                        path: self.path(),
+                        index,
+                    };
+                    self.ops.push(op);
+                }
+                for index in prefix + common..new_end {
+                    let op = PatchOp::ListInsert {
+                        path: self.path(),
+                        index,
+                        value: clone_value(items(new, index))?,
+                    };
+                    self.ops.push(op);
+                }
+            }
+            (ReflectRef::Map(old), ReflectRef::Map(new)) => {
+                for (key, _) in old.iter() {
+                    if new.get(key).is_none() {
+                        let op = PatchOp::MapRemove {
+                            path: self.path(),
+                            key: clone_value(key)?,
+                        };
+                        self.ops.push(op);
+                    }
+                }
+                for (key, value) in new.iter() {
+                    if old
+                        .get(key)
+                        .is_some_and(|old_value| equal(old_value, value))
+                    {
+                        continue;
+                    }
+                    let op = PatchOp::MapInsert {
+                        path: self.path(),
+                        key: clone_value(key)?,
+                        value: clone_value(value)?,
+                    };
+                    self.ops.push(op);
+                }
+            }
+            (ReflectRef::Set(old), ReflectRef::Set(new)) => {
+                for value in old.iter() {
+                    if !new.contains(value) {
+                        let op = PatchOp::SetRemove {
+                            path: self.path(),
+                            value: clone_value(value)?,
+                        };
+                        self.ops.push(op);
+                    }
+                }
+                for value in new.iter() {
+                    if !old.contains(value) {
+                        let op = PatchOp::SetInsert {
+                            path: self.path(),
+                            value: clone_value(value)?,
+                        };
+                        self.ops.push(op);
+                    }
+                }
+            }
+            _ => {
+                if !equal(old, new) {
+                    return self.set(new);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Reflect;
+    use alloc::{
+        string::{String, ToString},
+        vec,
+    };
+    use bevy_platform::collections::{HashMap, HashSet};
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    enum Shape {
+        Circle { radius: f32 },
+        Rect(f32, f32),
+        Point,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Inner(u32, String);
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Data {
+        value: i32,
+        inner: Inner,
+        shape: Shape,
+        list: Vec<u32>,
+        array: [u8; 3],
+        tuple: (bool, f32),
+        map: HashMap<String, u32>,
+        set: HashSet<u32>,
+        option: Option<Inner>,
+    }
+
+    fn data() -> Data {
+        Data {
+            value: 1,
+            inner: Inner(2, "inner".to_string()),
+            shape: Shape::Circle { radius: 1.0 },
+            list: vec![1, 2, 3, 4],
+            array: [1, 2, 3],
+            tuple: (false, 0.5),
+            map: [("a".to_string(), 1), ("b".to_string(), 2)]
+                .into_iter()
+                .collect(),
+            set: [1, 2].into_iter().collect(),
+            option: None,
+        }
+    }
+
+    fn paths(patch: &Patch) -> Vec<String> {
+        patch.iter().map(|op| op.path().to_string()).collect()
+    }
+
+    fn assert_round_trip(old: &Data, new: &Data) -> Patch {
+        let patch = old.diff(new).unwrap();
+        let mut patched = old.clone();
+        patched.apply_patch(&patch).unwrap();
+        assert_eq!(&patched, new);
+        patch
+    }
+
+    #[test]
+    fn equal_values_have_empty_patch() {
+        assert!(data().diff(&data()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn fields() {
+        let old = data();
+        let mut new = data();
+        new.value = 5;
+        new.inner.1 = "changed".to_string();
+        new.shape = Shape::Circle { radius: 2.0 };
+        new.array[1] = 7;
+        new.tuple.0 = true;
+
+        let patch = assert_round_trip(&old, &new);
+        assert_eq!(
+            paths(&patch),
+            [
+                ".value",
+                ".inner.1",
+                ".shape.radius",
+                ".array[1]",
+                ".tuple.0"
+            ]
+        );
+        assert!(patch.iter().all(|op| matches!(op, PatchOp::Set { .. })));
+    }
+
+    #[test]
+    fn variants() {
+        let old = data();
+        let mut new = data();
+        new.shape = Shape::Rect(1.0, 2.0);
+        new.option = Some(Inner(1, "some".to_string()));
+
+        let patch = assert_round_trip(&old, &new);
+        assert_eq!(paths(&patch), [".shape", ".option"]);
+        assert!(patch
+            .iter()
+            .all(|op| matches!(op, PatchOp::ChangeVariant { .. })));
+
+        let mut newer = new.clone();
+        newer.shape = Shape::Point;
+        newer.option.as_mut().unwrap().0 = 2;
+        let patch = assert_round_trip(&new, &newer);
+        assert_eq!(paths(&patch), [".shape", ".option.0.0"]);
+    }
+
+    #[test]
+    fn lists() {
+        let old = data();
+        let cases: [(&[u32], usize); 6] = [
+            (&[1, 2, 9, 3, 4], 1),
+            (&[0, 1, 2, 3, 4], 1),
+            (&[1, 2, 4], 1),
+            (&[1, 5, 6, 4], 2),
+            (&[], 4),
+            (&[1, 2, 3, 4, 5, 6], 2),
+        ];
+        for (list, len) in cases {
+            let mut new = data();
+            new.list = list.to_vec();
+            let patch = assert_round_trip(&old, &new);
+            assert_eq!(patch.len(), len, "{list:?}: {patch:?}");
+        }
+
+        let mut new = data();
+        new.list.insert(1, 9);
+        let patch = old.diff(&new).unwrap();
+        assert!(matches!(
+            patch.ops(),
+            [PatchOp::ListInsert { index: 1, .. }]
+        ));
+    }
+
+    #[test]
+    fn maps_and_sets() {
+        let old = data();
+        let mut new = data();
+        new.map.remove("a");
+        new.map.insert("b".to_string(), 3);
+        new.map.insert("c".to_string(), 4);
+        new.set.remove(&1);
+        new.set.insert(3);
+
+        let patch = assert_round_trip(&old, &new);
+        let count = |f: fn(&PatchOp) -> bool| patch.iter().filter(|op| f(op)).count();
+        assert_eq!(count(|op| matches!(op, PatchOp::MapRemove { .. })), 1);
+        assert_eq!(count(|op| matches!(op, PatchOp::MapInsert { .. })), 2);
+        assert_eq!(count(|op| matches!(op, PatchOp::SetRemove { .. })), 1);
+        assert_eq!(count(|op| matches!(op, PatchOp::SetInsert { .. })), 1);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            1_u32.diff(&1_i32),
+            Err(DiffError::MismatchedTypes { .. })
+        ));
+
+        let mut new = data();
+        new.list.push(5);
+        new.map.remove("a");
+        let patch = data().diff(&new).unwrap();
+
+        let mut target = data();
+        target.list.clear();
+        assert!(matches!(
+            target.apply_patch(&patch),
+            Err(ApplyPatchError::IndexOutOfBounds {
+                index: 4,
+                len: 0,
+                ..
+            })
+        ));
+
+        let mut target = data();
+        target.map.clear();
+        assert!(matches!(
+            target.apply_patch(&patch),
+            Err(ApplyPatchError::MissingEntry { .. })
+        ));
+
+        let patch = Patch::from(vec![PatchOp::ListRemove {
+            path: ParsedPath::parse(".value").unwrap(),
+            index: 0,
+        }]);
+        assert!(matches!(
+            data().apply_patch(&patch),
+            Err(ApplyPatchError::MismatchedKinds {
+                expected: ReflectKind::List,
+                actual: ReflectKind::Opaque,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,517 @@
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+use serde::{
+    de::{self, DeserializeSeed, EnumAccess, MapAccess, SeqAccess, VariantAccess, Visitor},
+    ser::{SerializeSeq, SerializeStructVariant},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use super::{Patch, PatchOp};
+use crate::{
+    serde::{ReflectDeserializer, ReflectSerializer},
+    ParsedPath, PartialReflect, TypeRegistry,
+};
+
+const VARIANTS: &[&str] = &[
+    "Set",
+    "ChangeVariant",
+    "ListInsert",
+    "ListRemove",
+    "MapInsert",
+    "MapRemove",
+    "SetInsert",
+    "SetRemove",
+];
+
+/// The fields of each variant of [`PatchOp`], in the order of [`VARIANTS`].
+const FIELDS: &[&[&str]] = &[
+    &["path", "value"],
+    &["path", "value"],
+    &["path", "index", "value"],
+    &["path", "index"],
+    &["path", "key", "value"],
+    &["path", "key"],
+    &["path", "value"],
+    &["path", "value"],
+];
+
+/// A serializer for a [`Patch`].
+///
+/// Each operation is serialized as a struct variant of [`PatchOp`]: paths are written as strings, and values as in
+/// the [`ReflectSerializer`], so their types must be registered in the [`TypeRegistry`].
+///
+/// ```
+/// # use bevy_reflect::{diff::{Diff, PatchSerializer}, Reflect, TypeRegistry};
+/// #[derive(Reflect)]
+/// struct Health(u32);
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Health>();
+///
+/// let patch = Health(10).diff(&Health(5)).unwrap();
+/// let serializer = PatchSerializer::new(&patch, &registry);
+/// let ron = ron::to_string(&serializer).unwrap();
+/// assert_eq!(ron, r#"[Set(path:".0",value:{"u32":5})]"#);
+/// ```
+pub struct PatchSerializer<'a> {
+    patch: &'a Patch,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> PatchSerializer<'a> {
+    /// Creates a serializer for `patch`, with the types of its values registered in `registry`.
+    pub fn new(patch: &'a Patch, registry: &'a TypeRegistry) -> Self {
+        Self { patch, registry }
+    }
+}
+
+impl Serialize for PatchSerializer<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.patch.len()))?;
+        for op in self.patch {
+            seq.serialize_element(&PatchOpSerializer {
+                op,
+                registry: self.registry,
+            })?;
+        }
+        seq.end()
+    }
+}
+
+struct PatchOpSerializer<'a> {
+    op: &'a PatchOp,
+    registry: &'a TypeRegistry,
+}
+
+impl PatchOpSerializer<'_> {
+    fn value<'v>(&'v self, value: &'v dyn PartialReflect) -> ReflectSerializer<'v> {
+        ReflectSerializer::new(value, self.registry)
+    }
+}
+
+impl Serialize for PatchOpSerializer<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let variant_index = match self.op {
+            PatchOp::Set { .. } => 0,
+            PatchOp::ChangeVariant { .. } => 1,
+            PatchOp::ListInsert { .. } => 2,
+            PatchOp::ListRemove { .. } => 3,
+            PatchOp::MapInsert { .. } => 4,
+            PatchOp::MapRemove { .. } => 5,
+            PatchOp::SetInsert { .. } => 6,
+            PatchOp::SetRemove { .. } => 7,
+        };
+        let fields = FIELDS[variant_index];
+        let mut state = serializer.serialize_struct_variant(
+            "PatchOp",
+            variant_index as u32,
+            VARIANTS[variant_index],
+            fields.len(),
+        )?;
+        state.serialize_field("path", &self.op.path().to_string())?;
+        match self.op {
+            PatchOp::Set { value, .. }
+            | PatchOp::ChangeVariant { value, .. }
+            | PatchOp::SetInsert { value, .. }
+            | PatchOp::SetRemove { value, .. } => {
+                state.serialize_field("value", &self.value(value.as_ref()))?;
+            }
+            PatchOp::ListInsert { index, value, .. } => {
+                state.serialize_field("index", index)?;
+                state.serialize_field("value", &self.value(value.as_ref()))?;
+            }
+            PatchOp::ListRemove { index, .. } => {
+                state.serialize_field("index", index)?;
+            }
+            PatchOp::MapInsert { key, value, .. } => {
+                state.serialize_field("key", &self.value(key.as_ref()))?;
+                state.serialize_field("value", &self.value(value.as_ref()))?;
+            }
+            PatchOp::MapRemove { key, .. } => {
+                state.serialize_field("key", &self.value(key.as_ref()))?;
+            }
+        }
+        state.end()
+    }
+}
+
+/// A deserializer for a [`Patch`] serialized by the [`PatchSerializer`].
+///
+/// The types of the values of the patch must be registered in the [`TypeRegistry`].
+///
+/// ```
+/// # use bevy_reflect::{diff::{Diff, PatchDeserializer}, Reflect, TypeRegistry};
+/// # use serde::de::DeserializeSeed;
+/// #[derive(Reflect, PartialEq, Debug)]
+/// struct Health(u32);
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Health>();
+///
+/// let mut deserializer = ron::Deserializer::from_str(r#"[Set(path: ".0", value: {"u32": 5})]"#).unwrap();
+/// let patch = PatchDeserializer::new(&registry).deserialize(&mut deserializer).unwrap();
+///
+/// let mut health = Health(10);
+/// health.apply_patch(&patch).unwrap();
+/// assert_eq!(health, Health(5));
+/// ```
+pub struct PatchDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> PatchDeserializer<'a> {
+    /// Creates a deserializer for a [`Patch`], with the types of its values registered in `registry`.
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for PatchDeserializer<'_> {
+    type Value = Patch;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct PatchVisitor<'a> {
+            registry: &'a TypeRegistry,
+        }
+
+        impl<'de> Visitor<'de> for PatchVisitor<'_> {
+            type Value = Patch;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a list of patch operations")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut ops = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+                while let Some(op) = seq.next_element_seed(PatchOpDeserializer {
+                    registry: self.registry,
+                })? {
+                    ops.push(op);
+                }
+                Ok(Patch::from(ops))
+            }
+        }
+
+        deserializer.deserialize_seq(PatchVisitor {
+            registry: self.registry,
+        })
+    }
+}
+
+struct PatchOpDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'de> DeserializeSeed<'de> for PatchOpDeserializer<'_> {
+    type Value = PatchOp;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_enum("PatchOp", VARIANTS, self)
+    }
+}
+
+impl<'de> Visitor<'de> for PatchOpDeserializer<'_> {
+    type Value = PatchOp;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a patch operation")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (VariantIndex(variant_index), access) = data.variant()?;
+        let fields = access.struct_variant(
+            FIELDS[variant_index],
+            PatchOpFieldsVisitor {
+                registry: self.registry,
+                fields: FIELDS[variant_index],
+            },
+        )?;
+        fields.into_op(variant_index)
+    }
+}
+
+/// The index of a variant of [`PatchOp`] in [`VARIANTS`], deserialized from its name or its index.
+struct VariantIndex(usize);
+
+impl<'de> Deserialize<'de> for VariantIndex {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct VariantIndexVisitor;
+
+        impl<'de> Visitor<'de> for VariantIndexVisitor {
+            type Value = VariantIndex;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a patch operation variant")
+            }
+
+            fn visit_u64<E: de::Error>(self, index: u64) -> Result<Self::Value, E> {
+                match usize::try_from(index) {
+                    Ok(index) if index < VARIANTS.len() => Ok(VariantIndex(index)),
+                    _ => Err(E::invalid_value(
+                        de::Unexpected::Unsigned(index),
+                        &"a patch operation variant index",
+                    )),
+                }
+            }
+
+            fn visit_str<E: de::Error>(self, name: &str) -> Result<Self::Value, E> {
+                VARIANTS
+                    .iter()
+                    .position(|variant| *variant == name)
+                    .map(VariantIndex)
+                    .ok_or_else(|| E::unknown_variant(name, VARIANTS))
+            }
+        }
+
+        deserializer.deserialize_identifier(VariantIndexVisitor)
+    }
+}
+
+/// The fields of a [`PatchOp`], collected before building the operation of the deserialized variant.
+#[derive(Default)]
+struct PatchOpFields {
+    path: Option<String>,
+    index: Option<usize>,
+    key: Option<Box<dyn PartialReflect>>,
+    value: Option<Box<dyn PartialReflect>>,
+}
+
+impl PatchOpFields {
+    fn into_op<E: de::Error>(self, variant_index: usize) -> Result<PatchOp, E> {
+        let path = self.path.ok_or_else(|| E::missing_field("path"))?;
+        let path = ParsedPath::parse(&path).map_err(E::custom)?;
+        let index = || self.index.ok_or_else(|| E::missing_field("index"));
+        let key = self.key.ok_or_else(|| E::missing_field("key"));
+        let value = self.value.ok_or_else(|| E::missing_field("value"));
+        Ok(match variant_index {
+            0 => PatchOp::Set {
+                path,
+                value: value?,
+            },
+            1 => PatchOp::ChangeVariant {
+                path,
+                value: value?,
+            },
+            2 => PatchOp::ListInsert {
+                path,
+                index: index()?,
+                value: value?,
+            },
+            3 => PatchOp::ListRemove {
+                path,
+                index: index()?,
+            },
+            4 => PatchOp::MapInsert {
+                path,
+                key: key?,
+                value: value?,
+            },
+            5 => PatchOp::MapRemove { path, key: key? },
+            6 => PatchOp::SetInsert {
+                path,
+                value: value?,
+            },
+            _ => PatchOp::SetRemove {
+                path,
+                value: value?,
+            },
+        })
+    }
+}
+
+struct PatchOpFieldsVisitor<'a> {
+    registry: &'a TypeRegistry,
+    fields: &'static [&'static str],
+}
+
+impl PatchOpFieldsVisitor<'_> {
+    fn read_field<'de, F>(
+        &self,
+        fields: &mut PatchOpFields,
+        name: &str,
+        mut read: F,
+    ) -> Result<(), F::Error>
+    where
+        F: FieldReader<'de>,
+    {
+        match name {
+            "path" => fields.path = Some(read.read()?),
+            "index" => fields.index = Some(read.read()?),
+            "key" => fields.key = Some(read.read_seed(ReflectDeserializer::new(self.registry))?),
+            "value" => {
+                fields.value = Some(read.read_seed(ReflectDeserializer::new(self.registry))?);
+            }
+            _ => return Err(de::Error::unknown_field(name, self.fields)),
+        }
+        Ok(())
+    }
+}
+
+/// Reads a single field from either a [`SeqAccess`] or a [`MapAccess`].
+trait FieldReader<'de> {
+    type Error: de::Error;
+
+    fn read<T: Deserialize<'de>>(&mut self) -> Result<T, Self::Error>;
+
+    fn read_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<T::Value, Self::Error>;
+}
+
+impl<'de, A: SeqAccess<'de>> FieldReader<'de> for &mut A {
+    type Error = A::Error;
+
+    fn read<T: Deserialize<'de>>(&mut self) -> Result<T, Self::Error> {
+        self.read_seed(core::marker::PhantomData)
+    }
+
+    fn read_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<T::Value, Self::Error> {
+        self.next_element_seed(seed)?
+            .ok_or_else(|| de::Error::custom("missing field of patch operation"))
+    }
+}
+
+struct MapValueReader<'a, A>(&'a mut A);
+
+impl<'de, A: MapAccess<'de>> FieldReader<'de> for MapValueReader<'_, A> {
+    type Error = A::Error;
+
+    fn read<T: Deserialize<'de>>(&mut self) -> Result<T, Self::Error> {
+        self.0.next_value()
+    }
+
+    fn read_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<T::Value, Self::Error> {
+        self.0.next_value_seed(seed)
+    }
+}
+
+impl<'de> Visitor<'de> for PatchOpFieldsVisitor<'_> {
+    type Value = PatchOpFields;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("the fields of a patch operation")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut fields = PatchOpFields::default();
+        for name in self.fields {
+            self.read_field(&mut fields, name, &mut seq)?;
+        }
+        Ok(fields)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut fields = PatchOpFields::default();
+        while let Some(name) = map.next_key::<String>()? {
+            self.read_field(&mut fields, &name, MapValueReader(&mut map))?;
+        }
+        Ok(fields)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diff::Diff, Reflect};
+    use alloc::{string::ToString, vec};
+    use bevy_platform::collections::HashMap;
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    enum Mode {
+        Idle,
+        Moving { speed: f32 },
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Unit {
+        name: String,
+        mode: Mode,
+        path: Vec<(i32, i32)>,
+        stats: HashMap<String, u32>,
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Unit>();
+        registry.register::<(i32, i32)>();
+        registry
+    }
+
+    #[test]
+    fn round_trip() {
+        let old = Unit {
+            name: "scout".to_string(),
+            mode: Mode::Idle,
+            path: vec![(0, 0), (1, 0)],
+            stats: [("hp".to_string(), 10), ("mp".to_string(), 5)]
+                .into_iter()
+                .collect(),
+        };
+        let mut new = old.clone();
+        new.name = "ranger".to_string();
+        new.mode = Mode::Moving { speed: 2.0 };
+        new.path.remove(0);
+        new.path.push((2, 1));
+        new.stats.insert("hp".to_string(), 7);
+        new.stats.remove("mp");
+
+        let registry = registry();
+        let patch = old.diff(&new).unwrap();
+        let ron = ron::to_string(&PatchSerializer::new(&patch, &registry)).unwrap();
+        let mut deserializer = ron::Deserializer::from_str(&ron).unwrap();
+        let deserialized = PatchDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(deserialized.len(), patch.len());
+
+        let mut patched = old.clone();
+        patched.apply_patch(&deserialized).unwrap();
+        assert_eq!(patched, new);
+
+        // Non-self-describing formats deserialize the fields of the operations in order.
+        let bytes = bincode::serde::encode_to_vec(
+            PatchSerializer::new(&patch, &registry),
+            bincode::config::standard(),
+        )
+        .unwrap();
+        let (deserialized, _) = bincode::serde::seed_decode_from_slice(
+            PatchDeserializer::new(&registry),
+            &bytes,
+            bincode::config::standard(),
+        )
+        .unwrap();
+        let mut patched = old;
+        patched.apply_patch(&deserialized).unwrap();
+        assert_eq!(patched, new);
+    }
+}
@@ -3,6 +3,7 @@ use crate::func::{
     args::{ArgCount, ArgError},
     Return,
 };
+use crate::ReflectCloneError;
 use alloc::borrow::Cow;
 use bevy_platform::collections::HashSet;
 use thiserror::Error;
@@ -79,3 +80,29 @@ pub enum FunctionRegistrationError {
     #[error("function name is missing")]
     MissingName,
 }
+
+/// An error that occurs when calling a [reflected method] by name.
+///
+/// [reflected method]: crate::func::ReflectMethods
+#[derive(Debug, Error, PartialEq)]
+pub enum MethodCallError {
+    /// The type of the receiver is not registered in the [`TypeRegistry`].
+    ///
+    /// [`TypeRegistry`]: crate::TypeRegistry
+    #[error("type `{0}` is not registered in the type registry")]
+    UnregisteredType(Cow<'static, str>),
+    /// The type of the receiver has no method with the given name.
+    #[error("type `{type_path}` has no reflected method named `{name}`")]
+    MissingMethod {
+        /// The type path of the receiver.
+        type_path: Cow<'static, str>,
+        /// The name of the method.
+        name: Cow<'static, str>,
+    },
+    /// The receiver could not be cloned for a method taking `self` by value.
+    #[error(transparent)]
+    CloneError(#[from] ReflectCloneError),
+    /// An error occurred while calling the method.
+    #[error(transparent)]
+    FunctionError(#[from] FunctionError),
+}
@@ -0,0 +1,404 @@
+use alloc::{borrow::Cow, string::ToString};
+use bevy_platform::collections::HashMap;
+use core::fmt::Debug;
+
+use crate::{
+    func::{
+        args::{ArgInfo, ArgValue, Ownership},
+        ArgList, DynamicFunction, FunctionRegistrationError, IntoFunction, MethodCallError, Return,
+    },
+    PartialReflect,
+};
+
+/// Type data containing the [reflected methods] of a type.
+///
+/// Methods are stored as [`DynamicFunction`]s whose first argument is the receiver,
+/// such as `self`, `&self`, or `&mut self`,
+/// and are mapped by name so that they may be called dynamically on a `dyn PartialReflect`.
+///
+/// This type data can be registered with the `#[reflect(methods(...))]` attribute,
+/// which takes a list of the type's methods:
+///
+/// ```
+/// # use bevy_reflect::{func::ArgList, Reflect, TypeRegistry};
+/// #[derive(Reflect)]
+/// #[reflect(methods(area, scale))]
+/// struct Rect {
+///     width: f32,
+///     height: f32,
+/// }
+///
+/// impl Rect {
+///     fn area(&self) -> f32 {
+///         self.width * self.height
+///     }
+///
+///     fn scale(&mut self, factor: f32) {
+///         self.width *= factor;
+///         self.height *= factor;
+///     }
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Rect>();
+///
+/// let mut rect = Rect { width: 2.0, height: 3.0 };
+///
+/// let args = ArgList::new().with_owned(2.0_f32);
+/// registry.reflect_call_mut(&mut rect, "scale", args).unwrap();
+///
+/// let area = registry.reflect_call(&rect, "area", ArgList::new()).unwrap();
+/// assert_eq!(area.unwrap_owned().try_take::<f32>().unwrap(), 24.0);
+/// ```
+///
+/// Methods may also be registered manually,
+/// either with [`TypeRegistry::register_method`] or directly on this type data.
+///
+/// [reflected methods]: crate::func
+/// [`TypeRegistry::register_method`]: crate::TypeRegistry::register_method
+#[derive(Clone, Default)]
+pub struct ReflectMethods {
+    /// Maps method names to their respective [`DynamicFunction`].
+    methods: HashMap<Cow<'static, str>, DynamicFunction<'static>>,
+}
+
+impl ReflectMethods {
+    /// Creates an empty set of reflected methods.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the given method under the given name.
+    ///
+    /// The first argument of the method is its receiver.
+    /// The stored [`DynamicFunction`] is renamed to the given name.
+    ///
+    /// If a method with the same name already exists,
+    /// it will not be registered again and an error will be returned.
+    /// To register the method anyway, overwriting any existing registration,
+    /// use [`overwrite_registration`] instead.
+    ///
+    /// [`overwrite_registration`]: Self::overwrite_registration
+    pub fn register<F, Marker>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        method: F,
+    ) -> Result<&mut Self, FunctionRegistrationError>
+    where
+        F: IntoFunction<'static, Marker> + 'static,
+    {
+        let name = name.into();
+        if self.methods.contains_key(&name) {
+            return Err(FunctionRegistrationError::DuplicateName(name));
+        }
+
+        let method = method.into_function().with_name(name.clone());
+        self.methods.insert(name, method);
+        Ok(self)
+    }
+
+    /// Registers the given method under the given name,
+    /// overwriting any existing method with that name.
+    ///
+    /// Returns the previous method with the same name, if any.
+    pub fn overwrite_registration<F, Marker>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        method: F,
+    ) -> Option<DynamicFunction<'static>>
+    where
+        F: IntoFunction<'static, Marker> + 'static,
+    {
+        let name = name.into();
+        let method = method.into_function().with_name(name.clone());
+        self.methods.insert(name, method)
+    }
+
+    /// Registers the given method under the given name, returning the updated set of methods.
+    ///
+    /// This is used by the `#[reflect(methods(...))]` attribute.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a method with the same name has already been registered.
+    pub fn with_method<F, Marker>(mut self, name: impl Into<Cow<'static, str>>, method: F) -> Self
+    where
+        F: IntoFunction<'static, Marker> + 'static,
+    {
+        if let Err(err) = self.register(name, method) {
+            panic!("{err}");
+        }
+        self
+    }
+
+    /// Calls the method with the given name on an immutable receiver.
+    ///
+    /// Methods taking `self` by value are called on a [clone] of the receiver,
+    /// while methods taking `&mut self` will return an error.
+    ///
+    /// [clone]: PartialReflect::reflect_clone
+    pub fn call<'a>(
+        &self,
+        receiver: &'a dyn PartialReflect,
+        name: &str,
+        args: ArgList<'a>,
+    ) -> Result<Return<'a>, MethodCallError> {
+        let method = self.get_or_error(receiver, name)?;
+        let receiver = match receiver_ownership(method) {
+            Ownership::Owned => ArgValue::Owned(receiver.reflect_clone()?.into_partial_reflect()),
+            Ownership::Ref | Ownership::Mut => ArgValue::Ref(receiver),
+        };
+
+        Ok(method.call(with_receiver(receiver, args))?)
+    }
+
+    /// Calls the method with the given name on a mutable receiver.
+    ///
+    /// Methods taking `self` by value are called on a [clone] of the receiver.
+    ///
+    /// [clone]: PartialReflect::reflect_clone
+    pub fn call_mut<'a>(
+        &self,
+        receiver: &'a mut dyn PartialReflect,
+        name: &str,
+        args: ArgList<'a>,
+    ) -> Result<Return<'a>, MethodCallError> {
+        let method = self.get_or_error(receiver, name)?;
+        let receiver = match receiver_ownership(method) {
+            Ownership::Owned => ArgValue::Owned(receiver.reflect_clone()?.into_partial_reflect()),
+            Ownership::Ref => ArgValue::Ref(receiver),
+            Ownership::Mut => ArgValue::Mut(receiver),
+        };
+
+        Ok(method.call(with_receiver(receiver, args))?)
+    }
+
+    /// Get a reference to a registered method by name.
+    pub fn get(&self, name: &str) -> Option<&DynamicFunction<'static>> {
+        self.methods.get(name)
+    }
+
+    /// Returns `true` if a method with the given name is registered.
+    pub fn contains(&self, name: &str) -> bool {
+        self.methods.contains_key(name)
+    }
+
+    /// Returns an iterator over the names and functions of all registered methods.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&str, &DynamicFunction<'static>)> {
+        self.methods
+            .iter()
+            .map(|(name, method)| (name.as_ref(), method))
+    }
+
+    /// Returns the number of registered methods.
+    pub fn len(&self) -> usize {
+        self.methods.len()
+    }
+
+    /// Returns `true` if no methods are registered.
+    pub fn is_empty(&self) -> bool {
+        self.methods.is_empty()
+    }
+
+    fn get_or_error(
+        &self,
+        receiver: &dyn PartialReflect,
+        name: &str,
+    ) -> Result<&DynamicFunction<'static>, MethodCallError> {
+        self.get(name)
+            .ok_or_else(|| MethodCallError::MissingMethod {
+                type_path: Cow::Owned(receiver.reflect_type_path().to_string()),
+                name: Cow::Owned(name.to_string()),
+            })
+    }
+}
+
+impl Debug for ReflectMethods {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.methods.values()).finish()
+    }
+}
+
+/// Returns the expected [`Ownership`] of the receiver of the given method.
+///
+/// For overloaded methods, the receiver of the first signature is used.
+fn receiver_ownership(method: &DynamicFunction) -> Ownership {
+    method
+        .info()
+        .base()
+        .args()
+        .first()
+        .map_or(Ownership::Ref, ArgInfo::ownership)
+}
+
+/// Prepends the receiver to the given list of arguments.
+fn with_receiver<'a>(receiver: ArgValue<'a>, mut args: ArgList<'a>) -> ArgList<'a> {
+    let mut list = ArgList::new().with_arg(receiver);
+    while let Ok(arg) = args.take_arg() {
+        list.push_arg(arg.take_value());
+    }
+    list
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::func::{args::ArgError, FunctionError};
+    use crate::{Reflect, TypePath, TypeRegistry};
+    use alloc::string::String;
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(Clone, methods(area, scale, scaled, name))]
+    struct Rect {
+        width: f32,
+        height: f32,
+        name: String,
+    }
+
+    impl Rect {
+        fn area(&self) -> f32 {
+            self.width * self.height
+        }
+
+        fn scale(&mut self, factor: f32) {
+            self.width *= factor;
+            self.height *= factor;
+        }
+
+        fn scaled(mut self, factor: f32) -> Self {
+            self.scale(factor);
+            self
+        }
+
+        fn name(&self) -> &String {
+            &self.name
+        }
+    }
+
+    fn rect() -> Rect {
+        Rect {
+            width: 2.0,
+            height: 3.0,
+            name: String::from("rect"),
+        }
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Rect>();
+        registry
+    }
+
+    #[test]
+    fn should_register_methods_from_attribute() {
+        let registry = registry();
+        let methods = registry
+            .get_type_data::<ReflectMethods>(core::any::TypeId::of::<Rect>())
+            .unwrap();
+
+        assert_eq!(methods.len(), 4);
+        assert!(methods.contains("area"));
+        assert_eq!(
+            methods.get("scale").unwrap().name().map(AsRef::as_ref),
+            Some("scale")
+        );
+    }
+
+    #[test]
+    fn should_call_methods_by_name() {
+        let registry = registry();
+        let mut value = rect();
+
+        let area = registry
+            .reflect_call(&value, "area", ArgList::new())
+            .unwrap();
+        assert_eq!(area.unwrap_owned().try_take::<f32>().unwrap(), 6.0);
+
+        let name = registry
+            .reflect_call(&value, "name", ArgList::new())
+            .unwrap();
+        assert_eq!(
+            name.unwrap_ref().try_downcast_ref::<String>().unwrap(),
+            "rect"
+        );
+
+        registry
+            .reflect_call_mut(&mut value, "scale", ArgList::new().with_owned(2.0_f32))
+            .unwrap();
+        assert_eq!(value.area(), 24.0);
+    }
+
+    #[test]
+    fn should_clone_receiver_for_owned_methods() {
+        let registry = registry();
+        let value = rect();
+
+        let scaled = registry
+            .reflect_call(&value, "scaled", ArgList::new().with_owned(2.0_f32))
+            .unwrap();
+        assert_eq!(
+            scaled.unwrap_owned().try_take::<Rect>().unwrap().area(),
+            24.0
+        );
+        assert_eq!(value, rect());
+    }
+
+    #[test]
+    fn should_register_methods_manually() {
+        let mut registry = registry();
+        registry
+            .register_method::<Rect, _, _>("perimeter", |rect: &Rect| {
+                2.0 * (rect.width + rect.height)
+            })
+            .unwrap();
+
+        let result = registry.register_method::<Rect, _, _>("area", Rect::area);
+        assert_eq!(
+            result.unwrap_err(),
+            FunctionRegistrationError::DuplicateName(Cow::Borrowed("area"))
+        );
+
+        let value = rect();
+        let perimeter = registry
+            .reflect_call(&value, "perimeter", ArgList::new())
+            .unwrap();
+        assert_eq!(perimeter.unwrap_owned().try_take::<f32>().unwrap(), 10.0);
+    }
+
+    #[test]
+    fn should_error_on_invalid_method_calls() {
+        let registry = registry();
+        let value = rect();
+
+        assert_eq!(
+            registry
+                .reflect_call(&value, "volume", ArgList::new())
+                .unwrap_err(),
+            MethodCallError::MissingMethod {
+                type_path: Cow::Borrowed(Rect::type_path()),
+                name: Cow::Borrowed("volume"),
+            }
+        );
+
+        assert_eq!(
+            registry
+                .reflect_call(&value, "scale", ArgList::new().with_owned(2.0_f32))
+                .unwrap_err(),
+            MethodCallError::FunctionError(FunctionError::ArgError(ArgError::InvalidOwnership {
+                index: 0,
+                expected: Ownership::Mut,
+                received: Ownership::Ref,
+            }))
+        );
+
+        #[derive(Reflect)]
+        struct Unregistered;
+
+        assert_eq!(
+            registry
+                .reflect_call(&Unregistered, "area", ArgList::new())
+                .unwrap_err(),
+            MethodCallError::UnregisteredType(Cow::Borrowed(Unregistered::type_path()))
+        );
+    }
+}
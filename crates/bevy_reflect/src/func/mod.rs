@@ -149,8 +149,38 @@
 //! assert_eq!(value.unwrap_owned().try_downcast_ref::<i32>(), Some(&50));
 //! ```
 //!
+//! # Method Registration
+//!
+//! Methods can be attached to a type with the `#[reflect(methods(...))]` attribute
+//! or [`TypeRegistry::register_method`], which store them in the [`ReflectMethods`] type data.
+//! They can then be called by name on any `dyn PartialReflect` of that type.
+//!
+//! ```
+//! # use bevy_reflect::{func::ArgList, Reflect, TypeRegistry};
+//! #[derive(Reflect)]
+//! #[reflect(methods(greet))]
+//! struct Player {
+//!     name: String,
+//! }
+//!
+//! impl Player {
+//!     fn greet(&self, greeting: String) -> String {
+//!         format!("{greeting}, {}!", self.name)
+//!     }
+//! }
+//!
+//! let mut registry = TypeRegistry::default();
+//! registry.register::<Player>();
+//!
+//! let player = Player { name: String::from("Alice") };
+//! let args = ArgList::default().with_owned(String::from("Hello"));
+//! let value = registry.reflect_call(&player, "greet", args).unwrap();
+//! assert_eq!(value.unwrap_owned().try_take::<String>().unwrap(), "Hello, Alice!");
+//! ```
+//!
 //! [`PartialReflect`]: crate::PartialReflect
 //! [`Reflect`]: crate::Reflect
+//! [`TypeRegistry::register_method`]: crate::TypeRegistry::register_method
 //! [lack of variadic generics]: https://poignardazur.github.io/2024/05/25/report-on-rustnl-variadics/
 //! [coherence issues]: https://doc.rust-lang.org/rustc/lints/listing/warn-by-default.html#coherence-leak-check
 //! [monomorphized]: https://en.wikipedia.org/wiki/Monomorphization
@@ -166,6 +196,7 @@ pub use function::*;
 pub use info::*;
 pub use into_function::*;
 pub use into_function_mut::*;
+pub use methods::*;
 pub use reflect_fn::*;
 pub use reflect_fn_mut::*;
 pub use registry::*;
@@ -181,6 +212,7 @@ mod info;
 mod into_function;
 mod into_function_mut;
 pub(crate) mod macros;
+mod methods;
 mod reflect_fn;
 mod reflect_fn_mut;
 mod registry;
@@ -380,6 +380,9 @@
 //! See the [dynamic types example](https://github.com/bevyengine/bevy/blob/latest/examples/reflection/dynamic_types.rs)
 //! for more information and usage details.
 //!
+//! Fields holding trait objects, such as `Box<dyn Reflect>` or `Box<dyn MyTrait>`
+//! for traits marked with `#[reflect_trait(boxed)]`, can be reflected and serialized with [`ReflectBox`].
+//!
 //! # Serialization
 //!
 //! By using reflection, we are also able to get serialization capabilities for free.
@@ -567,6 +570,7 @@ extern crate alloc;
 extern crate self as bevy_reflect;
 
 mod array;
+mod boxed;
 mod error;
 mod fields;
 mod from_reflect;
@@ -613,6 +617,7 @@ mod impls {
 }
 
 pub mod attributes;
+pub mod diff;
 mod enums;
 mod generics;
 pub mod serde;
@@ -639,6 +644,7 @@ pub mod prelude {
 }
 
 pub use array::*;
+pub use boxed::*;
 pub use enums::*;
 pub use error::*;
 pub use fields::*;
@@ -723,6 +729,21 @@ pub mod __macro_exports {
     impl RegisterForReflection for DynamicArray {}
 
     impl RegisterForReflection for DynamicTuple {}
+
+    /// Clones a value with [`PartialReflect::reflect_clone`](crate::PartialReflect::reflect_clone),
+    /// as a value of its own type.
+    ///
+    /// This is used by the `#[reflect_trait(boxed)]` attribute macro to clone boxed trait objects.
+    pub fn reflect_clone_boxed<T: crate::Reflect>(
+        value: &T,
+    ) -> Result<alloc_utils::Box<T>, crate::ReflectCloneError> {
+        value.reflect_clone()?.downcast::<T>().map_err(|received| {
+            crate::ReflectCloneError::FailedDowncast {
+                expected: alloc_utils::Cow::Owned(value.reflect_type_path().into()),
+                received: alloc_utils::Cow::Owned(received.reflect_type_path().into()),
+            }
+        })
+    }
 }
 
 #[cfg(test)]
@@ -15,6 +15,7 @@ pub(super) struct ArrayVisitor<'a, P> {
     pub array_info: &'static ArrayInfo,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a mut P>,
+    pub self_describing: bool,
 }
 
 impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for ArrayVisitor<'_, P> {
@@ -34,6 +35,7 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for ArrayVisitor<'_, P>
             registration,
             self.registry,
             self.processor.as_deref_mut(),
+            self.self_describing,
         ))? {
             vec.push(value);
         }
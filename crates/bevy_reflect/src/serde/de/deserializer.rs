@@ -7,8 +7,9 @@ use crate::{
             arrays::ArrayVisitor, enums::EnumVisitor, error_utils::make_custom_error,
             lists::ListVisitor, maps::MapVisitor, options::OptionVisitor, sets::SetVisitor,
             structs::StructVisitor, tuple_structs::TupleStructVisitor, tuples::TupleVisitor,
+            versions::VersionedVisitor,
         },
-        TypeRegistrationDeserializer,
+        ReflectVersion, TypeRegistrationDeserializer,
     },
     PartialReflect, ReflectDeserialize, TypeInfo, TypePath, TypeRegistration, TypeRegistry,
 };
@@ -105,6 +106,7 @@ use super::ReflectDeserializerProcessor;
 pub struct ReflectDeserializer<'a, P: ReflectDeserializerProcessor = ()> {
     registry: &'a TypeRegistry,
     processor: Option<&'a mut P>,
+    self_describing: bool,
 }
 
 impl<'a> ReflectDeserializer<'a, ()> {
@@ -118,6 +120,7 @@ impl<'a> ReflectDeserializer<'a, ()> {
         Self {
             registry,
             processor: None,
+            self_describing: true,
         }
     }
 }
@@ -133,8 +136,17 @@ impl<'a, P: ReflectDeserializerProcessor> ReflectDeserializer<'a, P> {
         Self {
             registry,
             processor: Some(processor),
+            self_describing: true,
         }
     }
+
+    /// Sets whether the input format is self-describing, which is the default.
+    ///
+    /// See [`TypedReflectDeserializer::with_self_describing`] for more details.
+    pub fn with_self_describing(mut self, self_describing: bool) -> Self {
+        self.self_describing = self_describing;
+        self
+    }
 }
 
 impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de> for ReflectDeserializer<'_, P> {
@@ -147,6 +159,7 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de> for ReflectDeser
         struct UntypedReflectDeserializerVisitor<'a, P> {
             registry: &'a TypeRegistry,
             processor: Option<&'a mut P>,
+            self_describing: bool,
         }
 
         impl<'de, P: ReflectDeserializerProcessor> Visitor<'de>
@@ -171,6 +184,7 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de> for ReflectDeser
                     registration,
                     self.registry,
                     self.processor,
+                    self.self_describing,
                 ))?;
 
                 if map.next_key::<IgnoredAny>()?.is_some() {
@@ -184,6 +198,7 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de> for ReflectDeser
         deserializer.deserialize_map(UntypedReflectDeserializerVisitor {
             registry: self.registry,
             processor: self.processor,
+            self_describing: self.self_describing,
         })
     }
 }
@@ -197,6 +212,9 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de> for ReflectDeser
 /// # Input
 ///
 /// Since the type is already known, the input is just the serialized data.
+/// Values of types with a [`ReflectVersion`] are input as a map of their version and their data,
+/// and the data of older versions is migrated.
+/// In [self-describing](Self::with_self_describing) formats, any other input is read as version 0 of the type.
 ///
 /// # Output
 ///
@@ -269,10 +287,12 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de> for ReflectDeser
 /// [`FromReflect`]: crate::FromReflect
 /// [`ReflectFromReflect`]: crate::ReflectFromReflect
 /// [`with_processor`]: Self::with_processor
+/// [`ReflectVersion`]: crate::serde::ReflectVersion
 pub struct TypedReflectDeserializer<'a, P: ReflectDeserializerProcessor = ()> {
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
     processor: Option<&'a mut P>,
+    self_describing: bool,
 }
 
 impl<'a> TypedReflectDeserializer<'a, ()> {
@@ -290,6 +310,7 @@ impl<'a> TypedReflectDeserializer<'a, ()> {
             registration,
             registry,
             processor: None,
+            self_describing: true,
         }
     }
 
@@ -308,6 +329,7 @@ impl<'a> TypedReflectDeserializer<'a, ()> {
             registration,
             registry,
             processor: None,
+            self_describing: true,
         }
     }
 }
@@ -331,19 +353,36 @@ impl<'a, P: ReflectDeserializerProcessor> TypedReflectDeserializer<'a, P> {
             registration,
             registry,
             processor: Some(processor),
+            self_describing: true,
         }
     }
 
+    /// Sets whether the input format is self-describing, which is the default.
+    ///
+    /// Values of types with a [`ReflectVersion`] written before the type had a version can only be told apart
+    /// from versioned values by inspecting the input, which requires [`Deserializer::deserialize_any`].
+    /// This must be disabled for formats that don't support it, such as `bincode` or `postcard`,
+    /// which then only read versioned values.
+    ///
+    /// [`ReflectVersion`]: crate::serde::ReflectVersion
+    /// [`Deserializer::deserialize_any`]: serde::Deserializer::deserialize_any
+    pub fn with_self_describing(mut self, self_describing: bool) -> Self {
+        self.self_describing = self_describing;
+        self
+    }
+
     /// An internal constructor for creating a deserializer without resetting the type info stack.
     pub(super) fn new_internal(
         registration: &'a TypeRegistration,
         registry: &'a TypeRegistry,
         processor: Option<&'a mut P>,
+        self_describing: bool,
     ) -> Self {
         Self {
             registration,
             registry,
             processor,
+            self_describing,
         }
     }
 }
@@ -374,158 +413,204 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
                 deserializer
             };
 
-            let type_path = self.registration.type_info().type_path();
-
-            // Handle both Value case and types that have a custom `ReflectDeserialize`
-            if let Some(deserialize_reflect) = self.registration.data::<ReflectDeserialize>() {
-                let value = deserialize_reflect.deserialize(deserializer)?;
-                return Ok(value.into_partial_reflect());
+            // Versioned types are input along with their version, so that older data can be migrated
+            if let Some(version) = self.registration.data::<ReflectVersion>() {
+                let visitor = VersionedVisitor {
+                    version,
+                    registration: self.registration,
+                    registry: self.registry,
+                    processor: self.processor,
+                    self_describing: self.self_describing,
+                };
+                // Self-describing formats can also read data written before the type had a version
+                return if self.self_describing {
+                    deserializer.deserialize_any(visitor)
+                } else {
+                    deserializer.deserialize_map(visitor)
+                };
             }
 
-            if let Some(deserialize_reflect) =
-                self.registration.data::<ReflectDeserializeWithRegistry>()
-            {
-                let value = deserialize_reflect.deserialize(deserializer, self.registry)?;
-                return Ok(value);
-            }
+            TypedReflectDeserializer::new_internal(
+                self.registration,
+                self.registry,
+                self.processor,
+                self.self_describing,
+            )
+            .deserialize_unversioned(deserializer)
+        };
+
+        #[cfg(feature = "debug_stack")]
+        TYPE_INFO_STACK.with_borrow_mut(|stack| stack.push(self.registration.type_info()));
+
+        let output = deserialize_internal();
+
+        #[cfg(feature = "debug_stack")]
+        TYPE_INFO_STACK.with_borrow_mut(crate::type_info_stack::TypeInfoStack::pop);
 
-            match self.registration.type_info() {
-                TypeInfo::Struct(struct_info) => {
-                    let mut dynamic_struct = deserializer.deserialize_struct(
-                        struct_info.type_path_table().ident().unwrap(),
-                        struct_info.field_names(),
-                        StructVisitor {
-                            struct_info,
+        output
+    }
+}
+
+impl<'a, P: ReflectDeserializerProcessor> TypedReflectDeserializer<'a, P> {
+    /// Deserializes the data of the type, without checking for a processor or a version.
+    pub(super) fn deserialize_unversioned<'de, D>(
+        self,
+        deserializer: D,
+    ) -> Result<Box<dyn PartialReflect>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let type_path = self.registration.type_info().type_path();
+
+        // Handle both Value case and types that have a custom `ReflectDeserialize`
+        if let Some(deserialize_reflect) = self.registration.data::<ReflectDeserialize>() {
+            let value = deserialize_reflect.deserialize(deserializer)?;
+            return Ok(value.into_partial_reflect());
+        }
+
+        if let Some(deserialize_reflect) =
+            self.registration.data::<ReflectDeserializeWithRegistry>()
+        {
+            let value = deserialize_reflect.deserialize(deserializer, self.registry)?;
+            return Ok(value);
+        }
+
+        match self.registration.type_info() {
+            TypeInfo::Struct(struct_info) => {
+                let mut dynamic_struct = deserializer.deserialize_struct(
+                    struct_info.type_path_table().ident().unwrap(),
+                    struct_info.field_names(),
+                    StructVisitor {
+                        struct_info,
+                        registration: self.registration,
+                        registry: self.registry,
+                        processor: self.processor,
+                        self_describing: self.self_describing,
+                    },
+                )?;
+                dynamic_struct.set_represented_type(Some(self.registration.type_info()));
+                Ok(Box::new(dynamic_struct))
+            }
+            TypeInfo::TupleStruct(tuple_struct_info) => {
+                let mut dynamic_tuple_struct = if tuple_struct_info.field_len() == 1
+                    && self.registration.data::<SerializationData>().is_none()
+                {
+                    deserializer.deserialize_newtype_struct(
+                        tuple_struct_info.type_path_table().ident().unwrap(),
+                        TupleStructVisitor {
+                            tuple_struct_info,
                             registration: self.registration,
                             registry: self.registry,
                             processor: self.processor,
+                            self_describing: self.self_describing,
                         },
-                    )?;
-                    dynamic_struct.set_represented_type(Some(self.registration.type_info()));
-                    Ok(Box::new(dynamic_struct))
-                }
-                TypeInfo::TupleStruct(tuple_struct_info) => {
-                    let mut dynamic_tuple_struct = if tuple_struct_info.field_len() == 1
-                        && self.registration.data::<SerializationData>().is_none()
-                    {
-                        deserializer.deserialize_newtype_struct(
-                            tuple_struct_info.type_path_table().ident().unwrap(),
-                            TupleStructVisitor {
-                                tuple_struct_info,
-                                registration: self.registration,
-                                registry: self.registry,
-                                processor: self.processor,
-                            },
-                        )?
-                    } else {
-                        deserializer.deserialize_tuple_struct(
-                            tuple_struct_info.type_path_table().ident().unwrap(),
-                            tuple_struct_info.field_len(),
-                            TupleStructVisitor {
-                                tuple_struct_info,
-                                registration: self.registration,
-                                registry: self.registry,
-                                processor: self.processor,
-                            },
-                        )?
-                    };
-                    dynamic_tuple_struct.set_represented_type(Some(self.registration.type_info()));
-                    Ok(Box::new(dynamic_tuple_struct))
-                }
-                TypeInfo::List(list_info) => {
-                    let mut dynamic_list = deserializer.deserialize_seq(ListVisitor {
-                        list_info,
-                        registry: self.registry,
-                        processor: self.processor,
-                    })?;
-                    dynamic_list.set_represented_type(Some(self.registration.type_info()));
-                    Ok(Box::new(dynamic_list))
-                }
-                TypeInfo::Array(array_info) => {
-                    let mut dynamic_array = deserializer.deserialize_tuple(
-                        array_info.capacity(),
-                        ArrayVisitor {
-                            array_info,
+                    )?
+                } else {
+                    deserializer.deserialize_tuple_struct(
+                        tuple_struct_info.type_path_table().ident().unwrap(),
+                        tuple_struct_info.field_len(),
+                        TupleStructVisitor {
+                            tuple_struct_info,
+                            registration: self.registration,
                             registry: self.registry,
                             processor: self.processor,
+                            self_describing: self.self_describing,
                         },
-                    )?;
-                    dynamic_array.set_represented_type(Some(self.registration.type_info()));
-                    Ok(Box::new(dynamic_array))
-                }
-                TypeInfo::Map(map_info) => {
-                    let mut dynamic_map = deserializer.deserialize_map(MapVisitor {
-                        map_info,
+                    )?
+                };
+                dynamic_tuple_struct.set_represented_type(Some(self.registration.type_info()));
+                Ok(Box::new(dynamic_tuple_struct))
+            }
+            TypeInfo::List(list_info) => {
+                let mut dynamic_list = deserializer.deserialize_seq(ListVisitor {
+                    list_info,
+                    registry: self.registry,
+                    processor: self.processor,
+                    self_describing: self.self_describing,
+                })?;
+                dynamic_list.set_represented_type(Some(self.registration.type_info()));
+                Ok(Box::new(dynamic_list))
+            }
+            TypeInfo::Array(array_info) => {
+                let mut dynamic_array = deserializer.deserialize_tuple(
+                    array_info.capacity(),
+                    ArrayVisitor {
+                        array_info,
                         registry: self.registry,
                         processor: self.processor,
-                    })?;
-                    dynamic_map.set_represented_type(Some(self.registration.type_info()));
-                    Ok(Box::new(dynamic_map))
-                }
-                TypeInfo::Set(set_info) => {
-                    let mut dynamic_set = deserializer.deserialize_seq(SetVisitor {
-                        set_info,
+                        self_describing: self.self_describing,
+                    },
+                )?;
+                dynamic_array.set_represented_type(Some(self.registration.type_info()));
+                Ok(Box::new(dynamic_array))
+            }
+            TypeInfo::Map(map_info) => {
+                let mut dynamic_map = deserializer.deserialize_map(MapVisitor {
+                    map_info,
+                    registry: self.registry,
+                    processor: self.processor,
+                    self_describing: self.self_describing,
+                })?;
+                dynamic_map.set_represented_type(Some(self.registration.type_info()));
+                Ok(Box::new(dynamic_map))
+            }
+            TypeInfo::Set(set_info) => {
+                let mut dynamic_set = deserializer.deserialize_seq(SetVisitor {
+                    set_info,
+                    registry: self.registry,
+                    processor: self.processor,
+                    self_describing: self.self_describing,
+                })?;
+                dynamic_set.set_represented_type(Some(self.registration.type_info()));
+                Ok(Box::new(dynamic_set))
+            }
+            TypeInfo::Tuple(tuple_info) => {
+                let mut dynamic_tuple = deserializer.deserialize_tuple(
+                    tuple_info.field_len(),
+                    TupleVisitor {
+                        tuple_info,
+                        registration: self.registration,
                         registry: self.registry,
                         processor: self.processor,
-                    })?;
-                    dynamic_set.set_represented_type(Some(self.registration.type_info()));
-                    Ok(Box::new(dynamic_set))
-                }
-                TypeInfo::Tuple(tuple_info) => {
-                    let mut dynamic_tuple = deserializer.deserialize_tuple(
-                        tuple_info.field_len(),
-                        TupleVisitor {
-                            tuple_info,
+                        self_describing: self.self_describing,
+                    },
+                )?;
+                dynamic_tuple.set_represented_type(Some(self.registration.type_info()));
+                Ok(Box::new(dynamic_tuple))
+            }
+            TypeInfo::Enum(enum_info) => {
+                let mut dynamic_enum = if enum_info.type_path_table().module_path()
+                    == Some("core::option")
+                    && enum_info.type_path_table().ident() == Some("Option")
+                {
+                    deserializer.deserialize_option(OptionVisitor {
+                        enum_info,
+                        registry: self.registry,
+                        processor: self.processor,
+                        self_describing: self.self_describing,
+                    })?
+                } else {
+                    deserializer.deserialize_enum(
+                        enum_info.type_path_table().ident().unwrap(),
+                        enum_info.variant_names(),
+                        EnumVisitor {
+                            enum_info,
                             registration: self.registration,
                             registry: self.registry,
                             processor: self.processor,
+                            self_describing: self.self_describing,
                         },
-                    )?;
-                    dynamic_tuple.set_represented_type(Some(self.registration.type_info()));
-                    Ok(Box::new(dynamic_tuple))
-                }
-                TypeInfo::Enum(enum_info) => {
-                    let mut dynamic_enum = if enum_info.type_path_table().module_path()
-                        == Some("core::option")
-                        && enum_info.type_path_table().ident() == Some("Option")
-                    {
-                        deserializer.deserialize_option(OptionVisitor {
-                            enum_info,
-                            registry: self.registry,
-                            processor: self.processor,
-                        })?
-                    } else {
-                        deserializer.deserialize_enum(
-                            enum_info.type_path_table().ident().unwrap(),
-                            enum_info.variant_names(),
-                            EnumVisitor {
-                                enum_info,
-                                registration: self.registration,
-                                registry: self.registry,
-                                processor: self.processor,
-                            },
-                        )?
-                    };
-                    dynamic_enum.set_represented_type(Some(self.registration.type_info()));
-                    Ok(Box::new(dynamic_enum))
-                }
-                TypeInfo::Opaque(_) => {
-                    // This case should already be handled
-                    Err(make_custom_error(format_args!(
+                    )?
+                };
+                dynamic_enum.set_represented_type(Some(self.registration.type_info()));
+                Ok(Box::new(dynamic_enum))
+            }
+            TypeInfo::Opaque(_) => {
+                // This case should already be handled
+                Err(make_custom_error(format_args!(
                         "type `{type_path}` did not register the `ReflectDeserialize` type data. For certain types, this may need to be registered manually using `register_type_data`",
                     )))
-                }
             }
-        };
-
-        #[cfg(feature = "debug_stack")]
-        TYPE_INFO_STACK.with_borrow_mut(|stack| stack.push(self.registration.type_info()));
-
-        let output = deserialize_internal();
-
-        #[cfg(feature = "debug_stack")]
-        TYPE_INFO_STACK.with_borrow_mut(crate::type_info_stack::TypeInfoStack::pop);
-
-        output
+        }
     }
 }
@@ -25,6 +25,7 @@ pub(super) struct EnumVisitor<'a, P> {
     pub registration: &'a TypeRegistration,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a mut P>,
+    pub self_describing: bool,
 }
 
 impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for EnumVisitor<'_, P> {
@@ -53,6 +54,7 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for EnumVisitor<'_, P> {
                         registration: self.registration,
                         registry: self.registry,
                         processor: self.processor,
+                        self_describing: self.self_describing,
                     },
                 )?
                 .into(),
@@ -66,6 +68,7 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for EnumVisitor<'_, P> {
                         registration,
                         self.registry,
                         self.processor,
+                        self.self_describing,
                     ))?;
                 let mut dynamic_tuple = DynamicTuple::default();
                 dynamic_tuple.insert_boxed(value);
@@ -79,6 +82,7 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for EnumVisitor<'_, P> {
                         registration: self.registration,
                         registry: self.registry,
                         processor: self.processor,
+                        self_describing: self.self_describing,
                     },
                 )?
                 .into(),
@@ -150,6 +154,7 @@ struct StructVariantVisitor<'a, P> {
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
     processor: Option<&'a mut P>,
+    self_describing: bool,
 }
 
 impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for StructVariantVisitor<'_, P> {
@@ -169,6 +174,7 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for StructVariantVisitor
             self.registration,
             self.registry,
             self.processor,
+            self.self_describing,
         )
     }
 
@@ -182,6 +188,7 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for StructVariantVisitor
             self.registration,
             self.registry,
             self.processor,
+            self.self_describing,
         )
     }
 }
@@ -191,6 +198,7 @@ struct TupleVariantVisitor<'a, P> {
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
     processor: Option<&'a mut P>,
+    self_describing: bool,
 }
 
 impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for TupleVariantVisitor<'_, P> {
@@ -210,6 +218,7 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for TupleVariantVisitor<
             self.registration,
             self.registry,
             self.processor,
+            self.self_describing,
         )
     }
 }
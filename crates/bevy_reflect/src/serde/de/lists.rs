@@ -14,6 +14,7 @@ pub(super) struct ListVisitor<'a, P> {
     pub list_info: &'static ListInfo,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a mut P>,
+    pub self_describing: bool,
 }
 
 impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for ListVisitor<'_, P> {
@@ -33,6 +34,7 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for ListVisitor<'_, P> {
             registration,
             self.registry,
             self.processor.as_deref_mut(),
+            self.self_describing,
         ))? {
             list.push_box(value);
         }
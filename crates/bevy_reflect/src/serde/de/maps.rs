@@ -14,6 +14,7 @@ pub(super) struct MapVisitor<'a, P> {
     pub map_info: &'static MapInfo,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a mut P>,
+    pub self_describing: bool,
 }
 
 impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for MapVisitor<'_, P> {
@@ -34,11 +35,13 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for MapVisitor<'_, P> {
             key_registration,
             self.registry,
             self.processor.as_deref_mut(),
+            self.self_describing,
         ))? {
             let value = map.next_value_seed(TypedReflectDeserializer::new_internal(
                 value_registration,
                 self.registry,
                 self.processor.as_deref_mut(),
+                self.self_describing,
             ))?;
             dynamic_map.insert_boxed(key, value);
         }
@@ -21,6 +21,7 @@ mod structs;
 mod tuple_structs;
 mod tuple_utils;
 mod tuples;
+mod versions;
 
 #[cfg(test)]
 mod tests {
@@ -15,6 +15,7 @@ pub(super) struct OptionVisitor<'a, P> {
     pub enum_info: &'static EnumInfo,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a mut P>,
+    pub self_describing: bool,
 }
 
 impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for OptionVisitor<'_, P> {
@@ -47,6 +48,7 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for OptionVisitor<'_, P>
                     registration,
                     self.registry,
                     self.processor,
+                    self.self_describing,
                 );
                 let mut value = DynamicTuple::default();
                 value.insert_boxed(de.deserialize(deserializer)?);
@@ -14,6 +14,7 @@ pub(super) struct SetVisitor<'a, P> {
     pub set_info: &'static SetInfo,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a mut P>,
+    pub self_describing: bool,
 }
 
 impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for SetVisitor<'_, P> {
@@ -33,6 +34,7 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for SetVisitor<'_, P> {
             value_registration,
             self.registry,
             self.processor.as_deref_mut(),
+            self.self_describing,
         ))? {
             dynamic_set.insert_boxed(value);
         }
@@ -92,6 +92,7 @@ pub(super) fn visit_struct<'de, T, V, P>(
     registration: &TypeRegistration,
     registry: &TypeRegistry,
     mut processor: Option<&mut P>,
+    self_describing: bool,
 ) -> Result<DynamicStruct, V::Error>
 where
     T: StructLikeInfo,
@@ -113,6 +114,7 @@ where
             registration,
             registry,
             processor.as_deref_mut(),
+            self_describing,
         ))?;
         dynamic_struct.insert_boxed(&key, value);
     }
@@ -141,6 +143,7 @@ pub(super) fn visit_struct_seq<'de, T, V, P>(
     registration: &TypeRegistration,
     registry: &TypeRegistry,
     mut processor: Option<&mut P>,
+    self_describing: bool,
 ) -> Result<DynamicStruct, V::Error>
 where
     T: StructLikeInfo,
@@ -176,6 +179,7 @@ where
                 try_get_registration(*info.field_at(index)?.ty(), registry)?,
                 registry,
                 processor.as_deref_mut(),
+                self_describing,
             ))?
             .ok_or_else(|| Error::invalid_length(index, &len.to_string().as_str()))?;
         dynamic_struct.insert_boxed(name, value);
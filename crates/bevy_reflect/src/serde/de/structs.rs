@@ -15,6 +15,7 @@ pub(super) struct StructVisitor<'a, P> {
     pub registration: &'a TypeRegistration,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a mut P>,
+    pub self_describing: bool,
 }
 
 impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for StructVisitor<'_, P> {
@@ -34,6 +35,7 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for StructVisitor<'_, P>
             self.registration,
             self.registry,
             self.processor,
+            self.self_describing,
         )
     }
 
@@ -47,6 +49,7 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for StructVisitor<'_, P>
             self.registration,
             self.registry,
             self.processor,
+            self.self_describing,
         )
     }
 }
@@ -17,6 +17,7 @@ pub(super) struct TupleStructVisitor<'a, P> {
     pub registration: &'a TypeRegistration,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a mut P>,
+    pub self_describing: bool,
 }
 
 impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for TupleStructVisitor<'_, P> {
@@ -36,6 +37,7 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for TupleStructVisitor<'
             self.registration,
             self.registry,
             self.processor,
+            self.self_describing,
         )
         .map(DynamicTupleStruct::from)
     }
@@ -60,8 +62,12 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for TupleStructVisitor<'
                 .ty(),
             self.registry,
         )?;
-        let reflect_deserializer =
-            TypedReflectDeserializer::new_internal(registration, self.registry, self.processor);
+        let reflect_deserializer = TypedReflectDeserializer::new_internal(
+            registration,
+            self.registry,
+            self.processor,
+            self.self_describing,
+        );
         let value = reflect_deserializer.deserialize(deserializer)?;
 
         tuple.insert_boxed(value.into_partial_reflect());
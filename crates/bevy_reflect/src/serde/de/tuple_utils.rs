@@ -73,6 +73,7 @@ pub(super) fn visit_tuple<'de, T, V, P>(
     registration: &TypeRegistration,
     registry: &TypeRegistry,
     mut processor: Option<&mut P>,
+    self_describing: bool,
 ) -> Result<DynamicTuple, V::Error>
 where
     T: TupleLikeInfo,
@@ -101,6 +102,7 @@ where
                 try_get_registration(*info.field_at(index)?.ty(), registry)?,
                 registry,
                 processor.as_deref_mut(),
+                self_describing,
             ))?
             .ok_or_else(|| Error::invalid_length(index, &len.to_string().as_str()))?;
         tuple.insert_boxed(value);
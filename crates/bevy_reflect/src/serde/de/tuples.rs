@@ -14,6 +14,7 @@ pub(super) struct TupleVisitor<'a, P> {
     pub registration: &'a TypeRegistration,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a mut P>,
+    pub self_describing: bool,
 }
 
 impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for TupleVisitor<'_, P> {
@@ -33,6 +34,7 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for TupleVisitor<'_, P>
             self.registration,
             self.registry,
             self.processor,
+            self.self_describing,
         )
     }
 }
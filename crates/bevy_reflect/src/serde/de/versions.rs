@@ -0,0 +1,300 @@
+use crate::{
+    serde::{
+        migration::{DATA_KEY, VERSION_KEY},
+        ReflectVersion, TypedReflectDeserializer,
+    },
+    PartialReflect, ReflectFromReflect, TypeRegistration, TypeRegistry,
+};
+use alloc::{boxed::Box, string::String};
+use core::{fmt, fmt::Formatter};
+use serde::de::{
+    value::{
+        EnumAccessDeserializer, MapAccessDeserializer, SeqAccessDeserializer, StringDeserializer,
+        U64Deserializer,
+    },
+    DeserializeSeed, Deserializer, EnumAccess, Error, IgnoredAny, IntoDeserializer, MapAccess,
+    SeqAccess, Visitor,
+};
+
+use super::ReflectDeserializerProcessor;
+
+const EXPECTING: &str = "reflected versioned value";
+
+/// A [`Visitor`] for deserializing the version and data of types with a [`ReflectVersion`],
+/// migrating the data of older versions.
+///
+/// Versioned values are a map with a `"@version"` and a `"@data"` entry.
+/// In self-describing formats, any other input is read as the data of version 0, written before the type had a version.
+pub(super) struct VersionedVisitor<'a, P> {
+    pub version: &'a ReflectVersion,
+    pub registration: &'a TypeRegistration,
+    pub registry: &'a TypeRegistry,
+    pub processor: Option<&'a mut P>,
+    pub self_describing: bool,
+}
+
+impl<'a, P: ReflectDeserializerProcessor> VersionedVisitor<'a, P> {
+    /// Returns the seed reading the data of the given `version`,
+    /// with the schema of that version if it differs from the current layout.
+    fn data_seed<E: Error>(&mut self, version: u32) -> Result<DataDeserializer<'a, P>, E> {
+        let type_path = self.registration.type_info().type_path();
+        let current_version = self.version.version();
+
+        if version > current_version {
+            return Err(Error::custom(format_args!(
+                "cannot deserialize version {version} of type `{type_path}`, which is at version {current_version}",
+            )));
+        }
+
+        // Older data is read with the schema of its version, if it differs from the current layout
+        match self.version.schema(version) {
+            Some(schema) if version < current_version => {
+                let schema = self.registry.get(schema).ok_or_else(|| {
+                    Error::custom(format_args!(
+                        "the schema of version {version} of type `{type_path}` is not registered in the type registry",
+                    ))
+                })?;
+                Ok(DataDeserializer(TypedReflectDeserializer::new_internal(
+                    schema,
+                    self.registry,
+                    self.processor.take(),
+                    self.self_describing,
+                )))
+            }
+            _ => Ok(DataDeserializer(TypedReflectDeserializer::new_internal(
+                self.registration,
+                self.registry,
+                self.processor.take(),
+                self.self_describing,
+            ))),
+        }
+    }
+
+    /// Migrates the data read for the given `version` to the current version of the type.
+    fn migrate<E: Error>(
+        &self,
+        version: u32,
+        value: Box<dyn PartialReflect>,
+    ) -> Result<Box<dyn PartialReflect>, E> {
+        let type_path = self.registration.type_info().type_path();
+
+        if version == self.version.version() {
+            return Ok(value);
+        }
+
+        let value = self.version.migrate(version, value).map_err(|err| {
+            Error::custom(format_args!(
+                "failed to migrate version {version} of type `{type_path}`: {err}",
+            ))
+        })?;
+
+        let from_reflect = self
+            .registration
+            .data::<ReflectFromReflect>()
+            .ok_or_else(|| {
+                Error::custom(format_args!(
+                    "type `{type_path}` did not register the `ReflectFromReflect` type data needed to migrate older versions",
+                ))
+            })?;
+
+        from_reflect
+            .from_reflect(value.as_partial_reflect())
+            .map(PartialReflect::into_partial_reflect)
+            .ok_or_else(|| {
+                Error::custom(format_args!(
+                    "the data migrated from version {version} of type `{type_path}` does not match the type",
+                ))
+            })
+    }
+
+    /// Reads data written before the type had a version as the data of version 0.
+    fn visit_unversioned<'de, D>(
+        mut self,
+        deserializer: D,
+    ) -> Result<Box<dyn PartialReflect>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = self.data_seed(0)?.deserialize(deserializer)?;
+        self.migrate(0, value)
+    }
+}
+
+impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for VersionedVisitor<'_, P> {
+    type Value = Box<dyn PartialReflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str(EXPECTING)
+    }
+
+    fn visit_map<V>(mut self, mut map: V) -> Result<Self::Value, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        let key_seed = MapKeySeed {
+            self_describing: self.self_describing,
+        };
+
+        match map.next_key_seed(key_seed)? {
+            Some(MapKey::Str(key)) if key == VERSION_KEY => {}
+            // Maps without a version, such as structs, were written before the type had a version
+            Some(key) if self.self_describing => {
+                return self.visit_unversioned(MapAccessDeserializer::new(FirstKeyMapAccess {
+                    key: Some(key),
+                    map,
+                }));
+            }
+            None if self.self_describing => {
+                return self.visit_unversioned(MapAccessDeserializer::new(map));
+            }
+            _ => return Err(Error::missing_field(VERSION_KEY)),
+        }
+
+        let version: u32 = map.next_value()?;
+        match map.next_key_seed(key_seed)? {
+            Some(MapKey::Str(key)) if key == DATA_KEY => {}
+            _ => return Err(Error::missing_field(DATA_KEY)),
+        }
+        let value = map.next_value_seed(self.data_seed(version)?)?;
+
+        if map.next_key::<IgnoredAny>()?.is_some() {
+            return Err(Error::invalid_length(3, &EXPECTING));
+        }
+
+        self.migrate(version, value)
+    }
+
+    fn visit_seq<V>(self, seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        self.visit_unversioned(SeqAccessDeserializer::new(seq))
+    }
+
+    fn visit_enum<V>(self, data: V) -> Result<Self::Value, V::Error>
+    where
+        V: EnumAccess<'de>,
+    {
+        self.visit_unversioned(EnumAccessDeserializer::new(data))
+    }
+
+    fn visit_bool<E: Error>(self, v: bool) -> Result<Self::Value, E> {
+        self.visit_unversioned(v.into_deserializer())
+    }
+
+    fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+        self.visit_unversioned(v.into_deserializer())
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+        self.visit_unversioned(v.into_deserializer())
+    }
+
+    fn visit_f64<E: Error>(self, v: f64) -> Result<Self::Value, E> {
+        self.visit_unversioned(v.into_deserializer())
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        self.visit_unversioned(v.into_deserializer())
+    }
+}
+
+/// Deserializes the data of a version of a versioned value,
+/// either with the schema of that version or with the current layout of the type.
+///
+/// The data is read without checking for a processor or a version, as the [`TypedReflectDeserializer`] that read
+/// the version already did.
+struct DataDeserializer<'a, P: ReflectDeserializerProcessor>(TypedReflectDeserializer<'a, P>);
+
+impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de> for DataDeserializer<'_, P> {
+    type Value = Box<dyn PartialReflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.0.deserialize_unversioned(deserializer)
+    }
+}
+
+/// The first key of a map, which is read again as the data of version 0 if it isn't the version of a versioned value.
+///
+/// Only string and integer keys can be read again, which covers the fields of structs and the variants of enums.
+enum MapKey {
+    Str(String),
+    U64(u64),
+}
+
+#[derive(Clone, Copy)]
+struct MapKeySeed {
+    self_describing: bool,
+}
+
+impl<'de> DeserializeSeed<'de> for MapKeySeed {
+    type Value = MapKey;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if self.self_describing {
+            deserializer.deserialize_any(self)
+        } else {
+            deserializer.deserialize_str(self)
+        }
+    }
+}
+
+impl<'de> Visitor<'de> for MapKeySeed {
+    type Value = MapKey;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("string or integer map key")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(MapKey::Str(v.into()))
+    }
+
+    fn visit_string<E: Error>(self, v: String) -> Result<Self::Value, E> {
+        Ok(MapKey::Str(v))
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(MapKey::U64(v))
+    }
+}
+
+/// A [`MapAccess`] returning an already read key before the remaining entries of the map.
+struct FirstKeyMapAccess<V> {
+    key: Option<MapKey>,
+    map: V,
+}
+
+impl<'de, V: MapAccess<'de>> MapAccess<'de> for FirstKeyMapAccess<V> {
+    type Error = V::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        match self.key.take() {
+            Some(MapKey::Str(key)) => seed.deserialize(StringDeserializer::new(key)).map(Some),
+            Some(MapKey::U64(key)) => seed.deserialize(U64Deserializer::new(key)).map(Some),
+            None => self.map.next_key_seed(seed),
+        }
+    }
+
+    fn next_value_seed<T>(&mut self, seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        self.map.next_value_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.map
+            .size_hint()
+            .map(|len| len + usize::from(self.key.is_some()))
+    }
+}
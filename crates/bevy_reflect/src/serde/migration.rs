@@ -0,0 +1,599 @@
+use crate::{
+    DynamicEnum, DynamicStruct, PartialReflect, Reflect, ReflectKindMismatchError, TypePath,
+};
+use alloc::{borrow::Cow, boxed::Box, vec::Vec};
+use bevy_platform::sync::Arc;
+use core::{
+    any::TypeId,
+    fmt::{Debug, Formatter},
+};
+use thiserror::Error;
+
+/// The key of the version in the map that versioned values are serialized as.
+pub(super) const VERSION_KEY: &str = "@version";
+
+/// The key of the data in the map that versioned values are serialized as.
+pub(super) const DATA_KEY: &str = "@data";
+
+/// Type data for types with a `#[reflect(version = N)]` attribute, which holds the current version of the type and
+/// the [migrations] used to deserialize data written by its older versions.
+///
+/// Versioned types are serialized by the [`TypedReflectSerializer`] as a map with their version under the `"@version"`
+/// key and their data under the `"@data"` key.
+/// When the [`TypedReflectDeserializer`] reads data of an older version, it reads the data with the [schema] of that
+/// version, runs the migrations from that version on the resulting dynamic value, then converts the migrated value
+/// to the type with [`ReflectFromReflect`].
+///
+/// Migrations are usually added with [`TypeRegistry::register_migration`].
+///
+/// Data written before the type had a version is read as version 0 by [self-describing] formats such as RON or JSON,
+/// except for enums in formats that don't keep their variant name when reading unknown data, such as RON.
+/// Other formats, such as `bincode`, can't read such data, even with migrations,
+/// and must be read with [`with_self_describing(false)`](crate::serde::TypedReflectDeserializer::with_self_describing).
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{DynamicStruct, Reflect, TypeRegistry, serde::{TypedReflectDeserializer, TypeMigration}};
+/// # use serde::de::DeserializeSeed;
+/// // The previous layout of `Player`, to read the data written by version 0.
+/// #[derive(Reflect)]
+/// struct PlayerV0 {
+///     hp: u32,
+/// }
+///
+/// #[derive(Reflect, PartialEq, Debug)]
+/// #[reflect(version = 1)]
+/// struct Player {
+///     health: u32,
+/// }
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<PlayerV0>();
+/// registry.register::<Player>();
+/// registry.register_migration::<Player>(
+///     TypeMigration::for_struct(0, |player: &mut DynamicStruct| {
+///         let hp = player.remove("hp").ok_or("missing `hp`")?;
+///         player.insert_boxed("health", hp);
+///         Ok(())
+///     })
+///     .with_schema::<PlayerV0>(),
+/// );
+///
+/// let registration = registry.get(core::any::TypeId::of::<Player>()).unwrap();
+/// let mut deserializer = ron::Deserializer::from_str(r#"{"@version": 0, "@data": (hp: 10)}"#).unwrap();
+/// let player = TypedReflectDeserializer::new(registration, &registry)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+/// assert_eq!(player.try_downcast_ref::<Player>(), Some(&Player { health: 10 }));
+/// ```
+///
+/// [migrations]: TypeMigration
+/// [`TypedReflectSerializer`]: crate::serde::TypedReflectSerializer
+/// [`TypedReflectDeserializer`]: crate::serde::TypedReflectDeserializer
+/// [schema]: TypeMigration::with_schema
+/// [self-describing]: serde::Deserializer::deserialize_any
+/// [`ReflectFromReflect`]: crate::ReflectFromReflect
+/// [`TypeRegistry::register_migration`]: crate::TypeRegistry::register_migration
+#[derive(Clone, Debug)]
+pub struct ReflectVersion {
+    version: u32,
+    migrations: Vec<TypeMigration>,
+}
+
+impl ReflectVersion {
+    /// Creates the type data of a type at the given version, without migrations.
+    pub fn new(version: u32) -> Self {
+        Self {
+            version,
+            migrations: Vec::new(),
+        }
+    }
+
+    /// The current version of the type.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Adds a migration, replacing any migration from the same version.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the migration is not from a version older than the [current version](Self::version).
+    pub fn add_migration(&mut self, migration: TypeMigration) {
+        assert!(
+            migration.from_version < self.version,
+            "attempted to add a migration from version {} to a type at version {}",
+            migration.from_version,
+            self.version
+        );
+
+        match self
+            .migrations
+            .binary_search_by_key(&migration.from_version, TypeMigration::from_version)
+        {
+            Ok(index) => self.migrations[index] = migration,
+            Err(index) => self.migrations.insert(index, migration),
+        }
+    }
+
+    /// Returns the migration from the given version, if there is one.
+    pub fn migration(&self, from_version: u32) -> Option<&TypeMigration> {
+        self.migrations
+            .binary_search_by_key(&from_version, TypeMigration::from_version)
+            .ok()
+            .map(|index| &self.migrations[index])
+    }
+
+    /// Returns an iterator over the migrations, ordered by the version they migrate from.
+    pub fn migrations(&self) -> impl ExactSizeIterator<Item = &TypeMigration> {
+        self.migrations.iter()
+    }
+
+    /// Returns the [`TypeId`] of the type whose layout matches the data written by the given version,
+    /// or `None` if it matches the current layout of the type.
+    ///
+    /// This is the [schema](TypeMigration::with_schema) of the first migration from that version or a later one
+    /// that declares a schema.
+    pub fn schema(&self, version: u32) -> Option<TypeId> {
+        self.migrations
+            .iter()
+            .filter(|migration| migration.from_version >= version)
+            .find_map(|migration| migration.schema.map(|(type_id, _)| type_id))
+    }
+
+    /// Runs the migrations from the given version or later on `value`, in order.
+    pub fn migrate(
+        &self,
+        from_version: u32,
+        value: Box<dyn PartialReflect>,
+    ) -> Result<Box<dyn PartialReflect>, MigrationError> {
+        self.migrations
+            .iter()
+            .filter(|migration| migration.from_version >= from_version)
+            .try_fold(value, |value, migration| migration.migrate(value))
+    }
+}
+
+/// A migration of the data written by an older version of a type to the layout of the next version.
+///
+/// Migrations operate on the dynamic value deserialized from the older data,
+/// e.g. a [`DynamicStruct`] for a struct or a [`DynamicEnum`] for an enum.
+///
+/// See [`ReflectVersion`] for more details.
+#[derive(Clone)]
+pub struct TypeMigration {
+    from_version: u32,
+    schema: Option<(TypeId, &'static str)>,
+    migrate_fn: Arc<MigrateFn>,
+}
+
+type MigrateFn = dyn Fn(Box<dyn PartialReflect>) -> Result<Box<dyn PartialReflect>, MigrationError>
+    + Send
+    + Sync;
+
+impl TypeMigration {
+    /// Creates a migration of the data written by the given version, running `migrate_fn` on its dynamic value.
+    pub fn new(
+        from_version: u32,
+        migrate_fn: impl Fn(Box<dyn PartialReflect>) -> Result<Box<dyn PartialReflect>, MigrationError>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        Self {
+            from_version,
+            schema: None,
+            migrate_fn: Arc::new(migrate_fn),
+        }
+    }
+
+    /// Creates a migration of the struct data written by the given version, running `migrate_fn` on its
+    /// [`DynamicStruct`].
+    pub fn for_struct(
+        from_version: u32,
+        migrate_fn: impl Fn(&mut DynamicStruct) -> Result<(), MigrationError> + Send + Sync + 'static,
+    ) -> Self {
+        Self::new(from_version, move |value| {
+            let mut value = value.reflect_ref().as_struct()?.to_dynamic_struct();
+            migrate_fn(&mut value)?;
+            Ok(Box::new(value))
+        })
+    }
+
+    /// Creates a migration of the enum data written by the given version, running `migrate_fn` on its
+    /// [`DynamicEnum`].
+    pub fn for_enum(
+        from_version: u32,
+        migrate_fn: impl Fn(&mut DynamicEnum) -> Result<(), MigrationError> + Send + Sync + 'static,
+    ) -> Self {
+        Self::new(from_version, move |value| {
+            let mut value = value.reflect_ref().as_enum()?.to_dynamic_enum();
+            migrate_fn(&mut value)?;
+            Ok(Box::new(value))
+        })
+    }
+
+    /// Sets the type whose layout matches the data written by the version this migration is from.
+    ///
+    /// The data is deserialized with the [type info] of this type before it is migrated,
+    /// which allows reading data whose fields or variants were renamed, removed or changed since.
+    /// The type only describes the old layout and must be registered in the [`TypeRegistry`].
+    ///
+    /// Without a schema, the data is read with the layout of the next migration with a schema,
+    /// or of the current version of the type if there is none.
+    ///
+    /// [type info]: crate::TypeInfo
+    /// [`TypeRegistry`]: crate::TypeRegistry
+    pub fn with_schema<T: Reflect + TypePath>(mut self) -> Self {
+        self.schema = Some((TypeId::of::<T>(), T::type_path()));
+        self
+    }
+
+    /// The version this migration migrates data from.
+    pub fn from_version(&self) -> u32 {
+        self.from_version
+    }
+
+    /// The [`TypeId`] of the type whose layout matches the data written by the version this migration is from,
+    /// if set with [`with_schema`](Self::with_schema).
+    pub fn schema(&self) -> Option<TypeId> {
+        self.schema.map(|(type_id, _)| type_id)
+    }
+
+    /// Runs this migration on the given dynamic value.
+    pub fn migrate(
+        &self,
+        value: Box<dyn PartialReflect>,
+    ) -> Result<Box<dyn PartialReflect>, MigrationError> {
+        (self.migrate_fn)(value)
+    }
+}
+
+impl Debug for TypeMigration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TypeMigration")
+            .field("from_version", &self.from_version)
+            .field("schema", &self.schema.map(|(_, type_path)| type_path))
+            .finish_non_exhaustive()
+    }
+}
+
+/// An error returned by a [`TypeMigration`].
+#[derive(Debug, Error)]
+pub enum MigrationError {
+    /// The migrated value was not of the expected kind.
+    #[error(transparent)]
+    MismatchedKind(#[from] ReflectKindMismatchError),
+    /// A custom error from the migration function.
+    #[error("{0}")]
+    Custom(Cow<'static, str>),
+}
+
+impl From<&'static str> for MigrationError {
+    fn from(message: &'static str) -> Self {
+        Self::Custom(Cow::Borrowed(message))
+    }
+}
+
+impl From<alloc::string::String> for MigrationError {
+    fn from(message: alloc::string::String) -> Self {
+        Self::Custom(Cow::Owned(message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        serde::{
+            ReflectDeserializer, ReflectSerializer, TypedReflectDeserializer,
+            TypedReflectSerializer,
+        },
+        DynamicVariant, Enum, FromReflect, GetField, TypeRegistry,
+    };
+    use alloc::{format, string::String, vec, vec::Vec};
+    use serde::de::DeserializeSeed;
+
+    mod v0 {
+        use crate::Reflect;
+
+        // Versioned fields are migrated on their own, so old layouts refer to their current type
+        #[derive(Reflect)]
+        pub struct Player {
+            pub hp: u32,
+            pub mode: super::Mode,
+        }
+
+        #[derive(Reflect)]
+        pub enum Mode {
+            Walk,
+            Fly { height: f32 },
+        }
+    }
+
+    #[derive(Reflect, PartialEq, Debug)]
+    #[reflect(version = 2)]
+    struct Player {
+        health: u32,
+        mode: Mode,
+    }
+
+    #[derive(Reflect, PartialEq, Debug)]
+    #[reflect(version = 1)]
+    enum Mode {
+        Walking,
+        Flying { height: f32 },
+    }
+
+    #[derive(Reflect, PartialEq, Debug)]
+    #[reflect(version = 1)]
+    struct Position(f32, f32);
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Player>();
+        registry.register::<v0::Player>();
+        registry.register::<v0::Mode>();
+        registry.register_migration::<Player>(
+            TypeMigration::for_struct(0, |player| {
+                let hp = player.remove("hp").ok_or("missing `hp`")?;
+                player.insert_boxed("health", hp);
+                Ok(())
+            })
+            .with_schema::<v0::Player>(),
+        );
+        // Version 1 had the same layout, but stored half the health
+        registry.register_migration::<Player>(TypeMigration::for_struct(1, |player| {
+            let health = player
+                .get_field_mut::<u32>("health")
+                .ok_or("missing `health`")?;
+            *health *= 2;
+            Ok(())
+        }));
+        registry.register_migration::<Mode>(
+            TypeMigration::for_enum(0, |mode| {
+                match mode.variant_name() {
+                    "Walk" => mode.set_variant("Walking", DynamicVariant::Unit),
+                    "Fly" => {
+                        let variant = mode.variant().clone();
+                        mode.set_variant("Flying", variant);
+                    }
+                    name => return Err(format!("unknown variant `{name}`").into()),
+                }
+                Ok(())
+            })
+            .with_schema::<v0::Mode>(),
+        );
+        registry.register::<Position>();
+        // Version 0 stored the coordinates the other way around
+        registry.register_migration::<Position>(TypeMigration::new(0, |mut value| {
+            let position = value.reflect_mut().as_tuple_struct()?;
+            let x = position.field(1).ok_or("missing `x`")?.to_dynamic();
+            let y = position.field(0).ok_or("missing `y`")?.to_dynamic();
+            position.field_mut(0).ok_or("missing `x`")?.apply(&*x);
+            position.field_mut(1).ok_or("missing `y`")?.apply(&*y);
+            Ok(value)
+        }));
+        registry.register::<Vec<u32>>();
+        registry
+            .get_mut(TypeId::of::<Vec<u32>>())
+            .unwrap()
+            .insert(ReflectVersion::new(1));
+        // Version 0 stored half the values
+        registry.register_migration::<Vec<u32>>(TypeMigration::new(0, |mut value| {
+            let values = value.reflect_mut().as_list()?;
+            for index in 0..values.len() {
+                let value = values
+                    .get_mut(index)
+                    .and_then(|value| value.try_downcast_mut::<u32>())
+                    .ok_or("expected a `u32`")?;
+                *value *= 2;
+            }
+            Ok(value)
+        }));
+        registry
+    }
+
+    fn deserialize<T: FromReflect>(input: &str, registry: &TypeRegistry) -> Result<T, String> {
+        let mut deserializer = ron::Deserializer::from_str(input).unwrap();
+        let registration = registry.get(TypeId::of::<T>()).unwrap();
+        let value = TypedReflectDeserializer::new(registration, registry)
+            .deserialize(&mut deserializer)
+            .map_err(|err| format!("{err}"))?;
+        Ok(T::from_reflect(&*value).unwrap())
+    }
+
+    fn deserialize_json<T: FromReflect>(input: &str, registry: &TypeRegistry) -> Result<T, String> {
+        let mut deserializer = serde_json::Deserializer::from_str(input);
+        let registration = registry.get(TypeId::of::<T>()).unwrap();
+        let value = TypedReflectDeserializer::new(registration, registry)
+            .deserialize(&mut deserializer)
+            .map_err(|err| format!("{err}"))?;
+        Ok(T::from_reflect(&*value).unwrap())
+    }
+
+    #[test]
+    fn should_serialize_version() {
+        let registry = registry();
+        let player = Player {
+            health: 10,
+            mode: Mode::Flying { height: 2.0 },
+        };
+
+        let serializer = ReflectSerializer::new(&player, &registry);
+        let output = ron::to_string(&serializer).unwrap();
+        assert_eq!(
+            output,
+            r#"{"bevy_reflect::serde::migration::tests::Player":{"@version":2,"@data":(health:10,mode:{"@version":1,"@data":Flying(height:2.0)})}}"#
+        );
+
+        let mut deserializer = ron::Deserializer::from_str(&output).unwrap();
+        let value = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(Player::from_reflect(&*value), Some(player));
+    }
+
+    #[test]
+    fn should_migrate_older_versions() {
+        let registry = registry();
+
+        let player = deserialize::<Player>(
+            r#"{"@version": 0, "@data": (hp: 5, mode: {"@version": 0, "@data": Walk})}"#,
+            &registry,
+        )
+        .unwrap();
+        assert_eq!(
+            player,
+            Player {
+                health: 10,
+                mode: Mode::Walking,
+            }
+        );
+
+        let player = deserialize::<Player>(
+            r#"{"@version": 1, "@data": (health: 10, mode: {"@version": 0, "@data": Fly(height: 2.0)})}"#,
+            &registry,
+        )
+        .unwrap();
+        assert_eq!(
+            player,
+            Player {
+                health: 20,
+                mode: Mode::Flying { height: 2.0 },
+            }
+        );
+    }
+
+    #[test]
+    fn should_migrate_unversioned_data() {
+        let registry = registry();
+
+        // `Player` was written before it had a version, but after `Mode` did
+        let player = deserialize::<Player>(
+            r#"(hp: 5, mode: {"@version": 1, "@data": Walking})"#,
+            &registry,
+        )
+        .unwrap();
+        assert_eq!(
+            player,
+            Player {
+                health: 10,
+                mode: Mode::Walking,
+            }
+        );
+    }
+
+    #[test]
+    fn should_migrate_unversioned_sequences() {
+        let registry = registry();
+
+        let position = deserialize::<Position>("(2.0, 1.0)", &registry).unwrap();
+        assert_eq!(position, Position(1.0, 2.0));
+        let position = deserialize_json::<Position>("[2.0, 1.0]", &registry).unwrap();
+        assert_eq!(position, Position(1.0, 2.0));
+
+        let values = deserialize::<Vec<u32>>("[1, 2]", &registry).unwrap();
+        assert_eq!(values, [2, 4]);
+        let values = deserialize_json::<Vec<u32>>("[1, 2]", &registry).unwrap();
+        assert_eq!(values, [2, 4]);
+    }
+
+    #[test]
+    fn should_not_migrate_current_sequences() {
+        let registry = registry();
+
+        let position = Position(1.0, 2.0);
+        let serializer = TypedReflectSerializer::new(&position, &registry);
+        let output = serde_json::to_string(&serializer).unwrap();
+        assert_eq!(output, r#"{"@version":1,"@data":[1.0,2.0]}"#);
+        assert_eq!(
+            deserialize_json::<Position>(&output, &registry),
+            Ok(position)
+        );
+
+        let values = deserialize::<Vec<u32>>(r#"{"@version": 1, "@data": [1, 2]}"#, &registry);
+        assert_eq!(values, Ok(vec![1, 2]));
+    }
+
+    #[test]
+    fn should_migrate_in_non_self_describing_formats() {
+        let registry = registry();
+
+        #[derive(serde::Serialize)]
+        enum ModeV0 {
+            Walk,
+        }
+
+        struct Versioned<T>(u32, T);
+
+        impl<T: serde::Serialize> serde::Serialize for Versioned<T> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use serde::ser::SerializeMap;
+
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry(VERSION_KEY, &self.0)?;
+                map.serialize_entry(DATA_KEY, &self.1)?;
+                map.end()
+            }
+        }
+
+        let input = bincode::serde::encode_to_vec(
+            Versioned(0, (5_u32, Versioned(0, ModeV0::Walk))),
+            bincode::config::standard(),
+        )
+        .unwrap();
+        let registration = registry.get(TypeId::of::<Player>()).unwrap();
+        let (value, _) = bincode::serde::seed_decode_from_slice(
+            TypedReflectDeserializer::new(registration, &registry).with_self_describing(false),
+            &input,
+            bincode::config::standard(),
+        )
+        .unwrap();
+        assert_eq!(
+            Player::from_reflect(&*value),
+            Some(Player {
+                health: 10,
+                mode: Mode::Walking,
+            })
+        );
+    }
+
+    #[test]
+    fn should_not_deserialize_newer_versions() {
+        let registry = registry();
+
+        let error = deserialize::<Player>(
+            r#"{"@version": 3, "@data": (health: 10, mode: {"@version": 1, "@data": Walking})}"#,
+            &registry,
+        )
+        .unwrap_err();
+        assert!(
+            error.contains("cannot deserialize version 3 of type `bevy_reflect::serde::migration::tests::Player`, which is at version 2"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn should_return_migration_errors() {
+        let mut registry = registry();
+        registry.register_migration::<Mode>(
+            TypeMigration::for_enum(0, |_| Err("unsupported".into())).with_schema::<v0::Mode>(),
+        );
+
+        let error =
+            deserialize::<Mode>(r#"{"@version": 0, "@data": Walk}"#, &registry).unwrap_err();
+        assert!(
+            error.contains("failed to migrate version 0 of type `bevy_reflect::serde::migration::tests::Mode`: unsupported"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to add a migration from version 2 to a type at version 2")]
+    fn should_panic_on_migration_from_current_version() {
+        let mut registry = registry();
+        registry.register_migration::<Player>(TypeMigration::new(2, Ok));
+    }
+}
@@ -1,10 +1,12 @@
 //! Serde integration for reflected types.
 
 mod de;
+mod migration;
 mod ser;
 mod type_data;
 
 pub use de::*;
+pub use migration::*;
 pub use ser::*;
 pub use type_data::*;
 
@@ -1,4 +1,4 @@
-use crate::{FromType, Reflect, TypeRegistry};
+use crate::{FromType, Reflect, ReflectRemote, TypeRegistry};
 use alloc::boxed::Box;
 use serde::{Serialize, Serializer};
 
@@ -89,6 +89,35 @@ impl<T: Reflect + SerializeWithRegistry> FromType<T> for ReflectSerializeWithReg
     }
 }
 
+impl ReflectSerializeWithRegistry {
+    /// Creates the type data of a [remote wrapper] with a custom [`SerializeWithRegistry`] implementation.
+    ///
+    /// Unlike with [`FromType`], the reflected values are downcast to the remote type before being wrapped,
+    /// as remote wrappers are represented by their remote type.
+    ///
+    /// [remote wrapper]: ReflectRemote
+    pub(crate) fn from_remote<T: ReflectRemote + SerializeWithRegistry>() -> Self
+    where
+        T::Remote: 'static,
+    {
+        Self {
+            serialize: |value: &dyn Reflect, registry| {
+                let value = value.downcast_ref::<T::Remote>().unwrap_or_else(|| {
+                    panic!(
+                        "Expected value to be of type {} but received {}",
+                        core::any::type_name::<T::Remote>(),
+                        value.reflect_type_path()
+                    )
+                });
+                Box::new(SerializableWithRegistry {
+                    value: T::as_wrapper(value),
+                    registry,
+                })
+            },
+        }
+    }
+}
+
 struct SerializableWithRegistry<'a, T: SerializeWithRegistry> {
     value: &'a T,
     registry: &'a TypeRegistry,
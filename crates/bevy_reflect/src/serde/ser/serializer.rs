@@ -7,6 +7,10 @@ use crate::{
         sets::SetSerializer, structs::StructSerializer, tuple_structs::TupleStructSerializer,
         tuples::TupleSerializer,
     },
+    serde::{
+        migration::{DATA_KEY, VERSION_KEY},
+        ReflectVersion,
+    },
     PartialReflect, ReflectRef, TypeRegistry,
 };
 use serde::{ser::SerializeMap, Serialize, Serializer};
@@ -138,6 +142,8 @@ impl<P: ReflectSerializerProcessor> Serialize for ReflectSerializer<'_, P> {
 /// such as the [type path].
 ///
 /// Instead, it will output just the serialized data.
+/// Values of types with a [`ReflectVersion`] are output as a map of their version and their data,
+/// with the `"@version"` and `"@data"` keys.
 ///
 /// If you want to override serialization for specific values, you can pass in
 /// a reference to a [`ReflectSerializerProcessor`] which will take priority
@@ -167,6 +173,7 @@ impl<P: ReflectSerializerProcessor> Serialize for ReflectSerializer<'_, P> {
 ///
 /// [`TypedReflectDeserializer`]: crate::serde::TypedReflectDeserializer
 /// [type path]: crate::TypePath::type_path
+/// [`ReflectVersion`]: crate::serde::ReflectVersion
 /// [`with_processor`]: Self::with_processor
 pub struct TypedReflectSerializer<'a, P = ()> {
     value: &'a dyn PartialReflect,
@@ -257,6 +264,31 @@ impl<P: ReflectSerializerProcessor> Serialize for TypedReflectSerializer<'_, P>
             serializer
         };
 
+        // Versioned types are output along with their version, so that older data can be migrated
+        if let Some(version) = self.version() {
+            let mut state = serializer.serialize_map(Some(2))?;
+            state.serialize_entry(VERSION_KEY, &version)?;
+            state.serialize_entry(DATA_KEY, &UnversionedSerializer(self))?;
+            return state.end();
+        }
+
+        self.serialize_unversioned(serializer)
+    }
+}
+
+impl<P: ReflectSerializerProcessor> TypedReflectSerializer<'_, P> {
+    /// The version of the serialized type, if it has a [`ReflectVersion`].
+    fn version(&self) -> Option<u32> {
+        let info = self.value.get_represented_type_info()?;
+        self.registry
+            .get_type_data::<ReflectVersion>(info.type_id())
+            .map(ReflectVersion::version)
+    }
+
+    fn serialize_unversioned<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
         // Handle both Value case and types that have a custom `Serialize`
         let (serializer, error) = match try_custom_serialize(self.value, self.registry, serializer)
         {
@@ -324,3 +356,15 @@ impl<P: ReflectSerializerProcessor> Serialize for TypedReflectSerializer<'_, P>
         output
     }
 }
+
+/// Serializes the data of a versioned value, without its version.
+struct UnversionedSerializer<'a, 'b, P>(&'b TypedReflectSerializer<'a, P>);
+
+impl<P: ReflectSerializerProcessor> Serialize for UnversionedSerializer<'_, '_, P> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize_unversioned(serializer)
+    }
+}
@@ -327,6 +327,18 @@ impl DynamicStruct {
         self.insert_boxed(name, Box::new(value));
     }
 
+    /// Removes the field named `name` from the struct, returning its value.
+    ///
+    /// The fields after it are shifted down by one index.
+    pub fn remove(&mut self, name: &str) -> Option<Box<dyn PartialReflect>> {
+        let index = self.field_indices.remove(name)?;
+        self.field_names.remove(index);
+        for index in self.field_indices.values_mut().filter(|i| **i > index) {
+            *index -= 1;
+        }
+        Some(self.fields.remove(index))
+    }
+
     /// Gets the index of the field with the given name.
     pub fn index_of(&self, name: &str) -> Option<usize> {
         self.field_indices.get(name).copied()
@@ -596,4 +608,20 @@ mod tests {
         assert!(iter.next().is_none());
         assert_eq!(prev_index, iter.index);
     }
+
+    #[test]
+    fn dynamic_struct_remove() {
+        let mut dynamic = DynamicStruct::default();
+        dynamic.insert("a", 1_u32);
+        dynamic.insert("b", 2_u32);
+        dynamic.insert("c", 3_u32);
+
+        let removed = dynamic.remove("b").unwrap();
+        assert_eq!(removed.try_downcast_ref::<u32>(), Some(&2));
+        assert!(dynamic.remove("b").is_none());
+        assert_eq!(dynamic.field_len(), 2);
+        assert_eq!(dynamic.name_at(1), Some("c"));
+        assert_eq!(dynamic.index_of("c"), Some(1));
+        assert_eq!(dynamic.get_field::<u32>("c"), Some(&3));
+    }
 }
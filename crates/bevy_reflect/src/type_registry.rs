@@ -1,4 +1,7 @@
-use crate::{serde::Serializable, FromReflect, Reflect, TypeInfo, TypePath, Typed};
+use crate::{
+    serde::{ReflectVersion, Serializable, TypeMigration},
+    FromReflect, Reflect, TypeInfo, TypePath, Typed,
+};
 use alloc::{boxed::Box, string::String};
 use bevy_platform::{
     collections::{HashMap, HashSet},
@@ -314,6 +317,149 @@ impl TypeRegistry {
         data.insert(D::from_type());
     }
 
+    /// Registers a [`TypeMigration`] for the versioned type `T`.
+    ///
+    /// The migration is used when deserializing the data written by an older version of `T`.
+    /// See [`ReflectVersion`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` is not registered, if it has no `#[reflect(version = N)]` attribute,
+    /// or if the migration is not from an older version.
+    ///
+    pub fn register_migration<T: Reflect + TypePath>(&mut self, migration: TypeMigration) {
+        let version = self
+            .get_type_data_mut::<ReflectVersion>(TypeId::of::<T>())
+            .unwrap_or_else(|| {
+                panic!(
+                    "attempted to call `TypeRegistry::register_migration` for type `{T}` without a registered version. \
+                    Register `{T}` first and make sure it has a `#[reflect(version = N)]` attribute",
+                    T = T::type_path(),
+                )
+            });
+        version.add_migration(migration);
+    }
+
+    /// Registers a [reflected method] of type `T` under the given name.
+    ///
+    /// The first argument of the method is its receiver.
+    /// If `T` does not have [`ReflectMethods`] yet, they will be inserted into its registration.
+    ///
+    /// Most of the time, methods can be registered with the `#[reflect(methods(...))]` attribute instead.
+    /// This method can be used for types that don't derive [`Reflect`] themselves,
+    /// or to register closures as additional methods.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::{func::ArgList, TypeRegistry};
+    /// let mut registry = TypeRegistry::default();
+    /// registry.register::<String>();
+    /// registry.register_method::<String, _, _>("len", String::len).unwrap();
+    ///
+    /// let value = String::from("hello");
+    /// let len = registry.reflect_call(&value, "len", ArgList::new()).unwrap();
+    /// assert_eq!(len.unwrap_owned().try_take::<usize>().unwrap(), 5);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` is not registered.
+    ///
+    /// [reflected method]: crate::func::ReflectMethods
+    /// [`ReflectMethods`]: crate::func::ReflectMethods
+    #[cfg(feature = "functions")]
+    pub fn register_method<T: Reflect + TypePath, F, Marker>(
+        &mut self,
+        name: impl Into<alloc::borrow::Cow<'static, str>>,
+        method: F,
+    ) -> Result<(), crate::func::FunctionRegistrationError>
+    where
+        F: crate::func::IntoFunction<'static, Marker> + 'static,
+    {
+        let registration = self.get_mut(TypeId::of::<T>()).unwrap_or_else(|| {
+            panic!(
+                "attempted to call `TypeRegistry::register_method` for type `{T}` without registering `{T}` first",
+                T = T::type_path(),
+            )
+        });
+
+        if registration.data::<crate::func::ReflectMethods>().is_none() {
+            registration.insert(crate::func::ReflectMethods::new());
+        }
+
+        registration
+            .data_mut::<crate::func::ReflectMethods>()
+            .unwrap()
+            .register(name, method)?;
+        Ok(())
+    }
+
+    /// Calls the [reflected method] with the given name on the given receiver.
+    ///
+    /// The method is looked up in the [`ReflectMethods`] registered for the receiver's type,
+    /// or for the type it represents if the receiver is a dynamic value.
+    /// See [`ReflectMethods::call`] for how the receiver is passed to the method.
+    ///
+    /// To call methods taking `&mut self`, use [`reflect_call_mut`] instead.
+    ///
+    /// [reflected method]: crate::func::ReflectMethods
+    /// [`ReflectMethods`]: crate::func::ReflectMethods
+    /// [`ReflectMethods::call`]: crate::func::ReflectMethods::call
+    /// [`reflect_call_mut`]: Self::reflect_call_mut
+    #[cfg(feature = "functions")]
+    pub fn reflect_call<'a>(
+        &self,
+        receiver: &'a dyn crate::PartialReflect,
+        name: &str,
+        args: crate::func::ArgList<'a>,
+    ) -> Result<crate::func::Return<'a>, crate::func::MethodCallError> {
+        self.get_methods(receiver, name)?.call(receiver, name, args)
+    }
+
+    /// Calls the [reflected method] with the given name on the given mutable receiver.
+    ///
+    /// See [`reflect_call`] for more details.
+    ///
+    /// [reflected method]: crate::func::ReflectMethods
+    /// [`reflect_call`]: Self::reflect_call
+    #[cfg(feature = "functions")]
+    pub fn reflect_call_mut<'a>(
+        &self,
+        receiver: &'a mut dyn crate::PartialReflect,
+        name: &str,
+        args: crate::func::ArgList<'a>,
+    ) -> Result<crate::func::Return<'a>, crate::func::MethodCallError> {
+        self.get_methods(receiver, name)?
+            .call_mut(receiver, name, args)
+    }
+
+    /// Returns the [`ReflectMethods`] of the receiver's type.
+    ///
+    /// [`ReflectMethods`]: crate::func::ReflectMethods
+    #[cfg(feature = "functions")]
+    fn get_methods(
+        &self,
+        receiver: &dyn crate::PartialReflect,
+        name: &str,
+    ) -> Result<&crate::func::ReflectMethods, crate::func::MethodCallError> {
+        use crate::func::MethodCallError;
+        use alloc::{borrow::Cow, string::ToString};
+
+        let type_path = || Cow::Owned(receiver.reflect_type_path().to_string());
+        let registration = receiver
+            .get_represented_type_info()
+            .and_then(|info| self.get(info.type_id()))
+            .ok_or_else(|| MethodCallError::UnregisteredType(type_path()))?;
+
+        registration
+            .data::<crate::func::ReflectMethods>()
+            .ok_or_else(|| MethodCallError::MissingMethod {
+                type_path: type_path(),
+                name: Cow::Owned(name.to_string()),
+            })
+    }
+
     /// Whether the type with given [`TypeId`] has been registered in this registry.
     pub fn contains(&self, type_id: TypeId) -> bool {
         self.registrations.contains_key(&type_id)
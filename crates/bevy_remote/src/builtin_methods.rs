@@ -4,14 +4,18 @@ use core::any::TypeId;
 
 use anyhow::{anyhow, Result as AnyhowResult};
 use bevy_ecs::{
+    archetype::{Archetype, ArchetypeEntity},
     component::ComponentId,
-    entity::Entity,
+    entity::{Entity, EntityHashMap, EntityHashSet},
+    entity_disabling::{DefaultQueryFilters, Internal},
     event::EventCursor,
     hierarchy::ChildOf,
     lifecycle::RemovedComponentEntity,
-    query::QueryBuilder,
+    name::Name,
+    query::{Allows, QueryBuilder, QueryState, With},
     reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
-    system::{In, Local},
+    schedule::{ScheduleGraphExport, Schedules},
+    system::{In, Local, SystemId, SystemIdMarker},
     world::{EntityRef, EntityWorldMut, FilteredEntityRef, World},
 };
 use bevy_log::warn_once;
@@ -23,6 +27,16 @@ use bevy_reflect::{
 use serde::{de::DeserializeSeed as _, Deserialize, Serialize};
 use serde_json::{Map, Value};
 
+#[cfg(feature = "reflect_functions")]
+use {
+    bevy_ecs::reflect::AppFunctionRegistry,
+    bevy_reflect::{
+        func::{args::Ownership, ArgList, Return, SignatureInfo},
+        serde::TypedReflectSerializer,
+        ReflectFromReflect,
+    },
+};
+
 use crate::{
     error_codes,
     schemas::{
@@ -32,6 +46,9 @@ use crate::{
     BrpError, BrpResult,
 };
 
+#[cfg(feature = "bevy_asset")]
+use bevy_asset::{AssetDependencyGraph, AssetPath, AssetServer};
+
 #[cfg(all(feature = "http", not(target_family = "wasm")))]
 use {crate::schemas::open_rpc::ServerObject, bevy_utils::default};
 
@@ -68,6 +85,9 @@ pub const BRP_GET_AND_WATCH_METHOD: &str = "bevy/get+watch";
 /// The method path for a `bevy/list+watch` request.
 pub const BRP_LIST_AND_WATCH_METHOD: &str = "bevy/list+watch";
 
+/// The method path for a `bevy/query+watch` request.
+pub const BRP_QUERY_AND_WATCH_METHOD: &str = "bevy/query+watch";
+
 /// The method path for a `bevy/get_resource` request.
 pub const BRP_GET_RESOURCE_METHOD: &str = "bevy/get_resource";
 
@@ -86,6 +106,20 @@ pub const BRP_LIST_RESOURCES_METHOD: &str = "bevy/list_resources";
 /// The method path for a `bevy/registry/schema` request.
 pub const BRP_REGISTRY_SCHEMA_METHOD: &str = "bevy/registry/schema";
 
+/// The method path for a `bevy/schedule_graph` request.
+pub const BRP_SCHEDULE_GRAPH_METHOD: &str = "bevy/schedule_graph";
+
+/// The method path for a `bevy/run_system` request.
+pub const BRP_RUN_SYSTEM_METHOD: &str = "bevy/run_system";
+
+/// The method path for a `bevy/asset_graph` request.
+#[cfg(feature = "bevy_asset")]
+pub const BRP_ASSET_GRAPH_METHOD: &str = "bevy/asset_graph";
+
+/// The method path for a `bevy/call_function` request.
+#[cfg(feature = "reflect_functions")]
+pub const BRP_CALL_FUNCTION_METHOD: &str = "bevy/call_function";
+
 /// The method path for a `rpc.discover` request.
 pub const RPC_DISCOVER_METHOD: &str = "rpc.discover";
 
@@ -127,7 +161,7 @@ pub struct BrpGetResourceParams {
 /// and component values that match.
 ///
 /// The server responds with a [`BrpQueryResponse`].
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct BrpQueryParams {
     /// The components to select.
     pub data: BrpQuery,
@@ -438,6 +472,102 @@ pub type BrpListResponse = Vec<String>;
 /// The response to a `bevy/list_resources` request.
 pub type BrpListResourcesResponse = Vec<String>;
 
+/// `bevy/schedule_graph`: Exports the graphs of one or all schedules.
+///
+/// The server responds with a [`BrpScheduleGraphResponse`].
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpScheduleGraphParams {
+    /// The debug name of the schedule to export, e.g. `Update`.
+    ///
+    /// If omitted, every schedule that is not currently running and has been initialized is exported.
+    #[serde(default)]
+    pub schedule: Option<String>,
+
+    /// The format of the exported graphs.
+    #[serde(default)]
+    pub format: BrpScheduleGraphFormat,
+}
+
+/// The format of the graphs returned by `bevy/schedule_graph`.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BrpScheduleGraphFormat {
+    /// A JSON object per schedule, see [`ScheduleGraphExport`].
+    #[default]
+    Json,
+    /// A [Graphviz](https://graphviz.org) DOT document per schedule.
+    Dot,
+}
+
+/// The response to a `bevy/schedule_graph` request.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum BrpScheduleGraphResponse {
+    /// The exported graphs, when the `json` format was requested.
+    Json(Vec<ScheduleGraphExport>),
+    /// The exported DOT documents, when the `dot` format was requested.
+    Dot(Vec<String>),
+}
+
+/// `bevy/asset_graph`: Exports the assets tracked by the [`AssetServer`] and the dependencies
+/// between them.
+///
+/// The server responds with a [`BrpAssetGraphResponse`].
+#[cfg(feature = "bevy_asset")]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpAssetGraphParams {
+    /// The path of an asset to explain with [`AssetDependencyGraph::why_loaded`].
+    #[serde(default)]
+    pub path: Option<String>,
+}
+
+/// The response to a `bevy/asset_graph` request.
+#[cfg(feature = "bevy_asset")]
+#[derive(Debug, Serialize, Clone)]
+pub struct BrpAssetGraphResponse {
+    /// The assets tracked by the [`AssetServer`] and the dependencies between them.
+    #[serde(flatten)]
+    pub graph: AssetDependencyGraph,
+
+    /// If a path was given, the [`AssetDependencyGraph::why_loaded`] chain of each asset at that
+    /// path, as indices into the nodes of the graph.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub why_loaded: Option<Vec<Vec<usize>>>,
+}
+
+/// `bevy/run_system`: Runs a registered one-shot system.
+///
+/// The system is identified by either the entity holding it or the [`Name`] of that entity.
+///
+/// The server responds with a null.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpRunSystemParams {
+    /// The entity holding the system, see [`SystemId::entity`].
+    #[serde(default)]
+    pub entity: Option<Entity>,
+
+    /// The [`Name`] of the entity holding the system.
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+/// `bevy/call_function`: Calls a function registered in the [`AppFunctionRegistry`].
+///
+/// The server responds with the serialized return value of the function.
+///
+/// [`AppFunctionRegistry`]: bevy_ecs::reflect::AppFunctionRegistry
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpCallFunctionParams {
+    /// The name the function was registered under.
+    pub function: String,
+
+    /// The arguments to pass to the function, in order.
+    ///
+    /// Each argument is deserialized as the type of the corresponding function parameter.
+    #[serde(default)]
+    pub args: Vec<Value>,
+}
+
 /// A single response from a `bevy/list+watch` request.
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct BrpListWatchingResponse {
@@ -445,6 +575,45 @@ pub struct BrpListWatchingResponse {
     removed: Vec<String>,
 }
 
+/// A single response from a `bevy/query+watch` request.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpQueryWatchingResponse {
+    /// The entities that started matching the query in the last tick, with all their requested
+    /// components.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub added: Vec<BrpQueryRow>,
+
+    /// The entities that still match the query and had some of their requested components changed
+    /// or removed in the last tick.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub changed: Vec<BrpQueryWatchingRow>,
+
+    /// The entities that stopped matching the query in the last tick, or were despawned.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub removed: Vec<Entity>,
+}
+
+/// An entity whose requested components changed, in a `bevy/query+watch` response.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpQueryWatchingRow {
+    /// The ID of the entity.
+    pub entity: Entity,
+
+    /// The serialized values of the requested components that were added or changed.
+    #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+    pub components: HashMap<String, Value>,
+
+    /// The [full paths] of the optional components that were removed.
+    ///
+    /// [full paths]: bevy_reflect::TypePath::type_path
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub removed: Vec<String>,
+
+    /// The boolean-only containment query results, if any of them changed.
+    #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+    pub has: HashMap<String, Value>,
+}
+
 /// The response to a `bevy/query` request.
 pub type BrpQueryResponse = Vec<BrpQueryRow>;
 
@@ -736,111 +905,433 @@ impl Default for ComponentSelector {
 
 /// Handles a `bevy/query` request coming from a client.
 pub fn process_remote_query_request(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
-    let BrpQueryParams {
-        data: BrpQuery {
-            components,
-            option,
-            has,
-        },
-        filter,
-        strict,
-    } = match params {
+    let params = match params {
         Some(params) => parse_some(Some(params))?,
-        None => BrpQueryParams {
-            data: BrpQuery {
-                components: Vec::new(),
-                option: ComponentSelector::default(),
-                has: Vec::new(),
-            },
-            filter: BrpQueryFilter::default(),
-            strict: false,
-        },
+        None => BrpQueryParams::default(),
     };
 
     let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = app_type_registry.read();
 
-    // Required components: must be present
-    let (required, unregistered_in_required) =
-        get_component_ids(&type_registry, world, components.clone(), strict)
-            .map_err(BrpError::component_error)?;
+    let Some(resolved) = ResolvedQuery::new(params, world, &type_registry)? else {
+        return serde_json::to_value(BrpQueryResponse::default()).map_err(BrpError::internal);
+    };
 
-    // Optional components: Option<&T> or all reflectable if "all"
-    let (optional, _) = match &option {
-        ComponentSelector::Paths(paths) => {
-            get_component_ids(&type_registry, world, paths.clone(), strict)
-                .map_err(BrpError::component_error)?
-        }
-        ComponentSelector::All => (Vec::new(), Vec::new()),
+    // Prepare has reflect info
+    let has_paths_and_reflect_components = resolved.has_reflect_components(&type_registry)?;
+
+    let mut response = BrpQueryResponse::default();
+    let mut query = resolved.build(world);
+
+    for row in query.iter(world) {
+        let entity_ref = world.get_entity(row.id()).expect("Entity should exist");
+
+        let query_row = BrpQueryRow {
+            entity: row.id(),
+            components: resolved.serialize_components(world, entity_ref, &type_registry, |_| true),
+            // The map of boolean-valued component presences:
+            has: build_has_map(
+                row,
+                has_paths_and_reflect_components.iter().copied(),
+                &resolved.unregistered_in_has,
+            ),
+        };
+
+        response.push(query_row);
+    }
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/query+watch` request coming from a client.
+pub fn process_remote_query_watching_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult<Option<Value>> {
+    let params = match params {
+        Some(params) => parse_some(Some(params))?,
+        None => BrpQueryParams::default(),
     };
 
-    // Has components: presence check
-    let (has_ids, unregistered_in_has) =
-        get_component_ids(&type_registry, world, has, strict).map_err(BrpError::component_error)?;
+    let app_type_registry = world.resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry.read();
 
-    // Filters
-    let (without, _) = get_component_ids(&type_registry, world, filter.without.clone(), strict)
-        .map_err(BrpError::component_error)?;
-    let (with, unregistered_in_with) =
-        get_component_ids(&type_registry, world, filter.with.clone(), strict)
-            .map_err(BrpError::component_error)?;
+    let Some(resolved) = ResolvedQuery::new(params, world, &type_registry)? else {
+        return Ok(None);
+    };
+    let has_paths_and_reflect_components = resolved.has_reflect_components(&type_registry)?;
 
-    // When "strict" is false:
-    // - Unregistered components in "option" and "without" are ignored.
-    // - Unregistered components in "has" are considered absent from the entity.
-    // - Unregistered components in "components" and "with" result in an empty
-    // response since they specify hard requirements.
-    // If strict, fail if any required or with components are unregistered
-    if !unregistered_in_required.is_empty() || !unregistered_in_with.is_empty() {
-        return serde_json::to_value(BrpQueryResponse::default()).map_err(BrpError::internal);
+    // This handler is shared by every ongoing `bevy/query+watch` request, so it can't rely on
+    // its own last run: changes are detected since the last tick of the world instead.
+    let last_change_tick = world.last_change_tick();
+    let this_run = world.read_change_tick();
+
+    // The components that decide whether an entity matches the query.
+    let membership: Vec<ComponentId> = resolved
+        .required
+        .iter()
+        .chain(&resolved.with)
+        .map(|(_, component_id)| *component_id)
+        .collect();
+    let filtered_out = resolved.filtered_out(world);
+    let matches = |archetype: &Archetype| {
+        membership
+            .iter()
+            .all(|component_id| archetype.contains(*component_id))
+            && !filtered_out
+                .iter()
+                .any(|component_id| archetype.contains(*component_id))
+    };
+
+    // The components removed from each entity in the last tick, including despawned entities.
+    let mut removals = EntityHashMap::<Vec<ComponentId>>::default();
+    for (component_id, events) in world.removed_components().iter() {
+        for event in events.iter_current_update_events() {
+            removals
+                .entry(Entity::from(event.clone()))
+                .or_default()
+                .push(*component_id);
+        }
     }
 
-    let mut query = QueryBuilder::<FilteredEntityRef>::new(world);
-    for (_, component) in &required {
-        query.ref_id(*component);
+    let mut response = BrpQueryWatchingResponse::default();
+
+    for archetype in world
+        .archetypes()
+        .iter()
+        .filter(|archetype| matches(archetype))
+    {
+        // Like with `Changed` and `Added` filters, only the entities for which a watched component
+        // changed, or that lost a component, are processed further.
+        let watched: Vec<ComponentId> = archetype
+            .components()
+            .filter(|component_id| resolved.watches(*component_id))
+            .collect();
+        for entity in archetype.entities().iter().map(ArchetypeEntity::id) {
+            let entity_ref = world.entity(entity);
+            let removed_components = removals.get(&entity).map(Vec::as_slice).unwrap_or_default();
+            if removed_components.is_empty()
+                && !entity_ref
+                    .spawned_at()
+                    .is_newer_than(last_change_tick, this_run)
+                && !watched.iter().any(|component_id| {
+                    entity_ref
+                        .get_change_ticks_by_id(*component_id)
+                        .is_some_and(|ticks| ticks.is_changed(last_change_tick, this_run))
+                })
+            {
+                continue;
+            }
+
+            let is_added = |component_id: ComponentId| {
+                entity_ref
+                    .get_change_ticks_by_id(component_id)
+                    .is_some_and(|ticks| ticks.is_added(last_change_tick, this_run))
+            };
+
+            let started_matching = entity_ref
+                .spawned_at()
+                .is_newer_than(last_change_tick, this_run)
+                || membership
+                    .iter()
+                    .any(|component_id| is_added(*component_id))
+                || filtered_out
+                    .iter()
+                    .any(|component_id| removed_components.contains(component_id));
+            if started_matching {
+                response.added.push(BrpQueryRow {
+                    entity,
+                    components: resolved.serialize_components(
+                        world,
+                        entity_ref,
+                        &type_registry,
+                        |_| true,
+                    ),
+                    has: build_has_map(
+                        entity_ref.into(),
+                        has_paths_and_reflect_components.iter().copied(),
+                        &resolved.unregistered_in_has,
+                    ),
+                });
+                continue;
+            }
+
+            let components =
+                resolved.serialize_components(world, entity_ref, &type_registry, |component_id| {
+                    entity_ref
+                        .get_change_ticks_by_id(component_id)
+                        .is_some_and(|ticks| ticks.is_changed(last_change_tick, this_run))
+                });
+            let removed = removed_components
+                .iter()
+                .filter(|component_id| {
+                    !entity_ref.contains_id(**component_id)
+                        && resolved.selects_optional(**component_id)
+                })
+                .filter_map(|component_id| {
+                    let type_id = world.components().get_info(*component_id)?.type_id()?;
+                    Some(
+                        type_registry
+                            .get(type_id)?
+                            .type_info()
+                            .type_path()
+                            .to_owned(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let has_changed = resolved.has.iter().any(|(_, component_id)| {
+                is_added(*component_id) || removed_components.contains(component_id)
+            });
+
+            if !components.is_empty() || !removed.is_empty() || has_changed {
+                response.changed.push(BrpQueryWatchingRow {
+                    entity,
+                    components,
+                    removed,
+                    has: if has_changed {
+                        build_has_map(
+                            entity_ref.into(),
+                            has_paths_and_reflect_components.iter().copied(),
+                            &resolved.unregistered_in_has,
+                        )
+                    } else {
+                        HashMap::default()
+                    },
+                });
+            }
+        }
     }
-    for (_, option) in &optional {
-        query.optional(|query| {
-            query.ref_id(*option);
-        });
+
+    // Entities that may have stopped matching the query: despawned entities and entities that lost
+    // a required component or gained a filtered out one.
+    let mut candidates = removals
+        .iter()
+        .filter(|(entity, component_ids)| {
+            if membership.is_empty() {
+                world.get_entity(**entity).is_err()
+            } else {
+                component_ids
+                    .iter()
+                    .any(|component_id| membership.contains(component_id))
+            }
+        })
+        .map(|(entity, _)| *entity)
+        .collect::<EntityHashSet>();
+    for component_id in &filtered_out {
+        for archetype in world
+            .archetypes()
+            .iter()
+            .filter(|archetype| archetype.contains(*component_id))
+        {
+            candidates.extend(archetype.entities().iter().map(ArchetypeEntity::id).filter(
+                |entity| {
+                    world
+                        .entity(*entity)
+                        .get_change_ticks_by_id(*component_id)
+                        .is_some_and(|ticks| ticks.is_added(last_change_tick, this_run))
+                },
+            ));
+        }
     }
-    for (_, has) in &has_ids {
-        query.optional(|query| {
-            query.ref_id(*has);
-        });
+    response.removed = candidates
+        .into_iter()
+        .filter(|entity| {
+            world
+                .get_entity(*entity)
+                .map_or(true, |entity_ref| !matches(entity_ref.archetype()))
+        })
+        .collect();
+    response.removed.sort();
+
+    if response.added.is_empty() && response.changed.is_empty() && response.removed.is_empty() {
+        return Ok(None);
     }
-    for (_, without) in without {
-        query.without_id(without);
+    Ok(Some(
+        serde_json::to_value(response).map_err(BrpError::internal)?,
+    ))
+}
+
+/// The components of a [`BrpQueryParams`], resolved against the world.
+///
+/// Shared by `bevy/query` and `bevy/query+watch`.
+struct ResolvedQuery {
+    required: Vec<(TypeId, ComponentId)>,
+    option: ComponentSelector,
+    optional: Vec<(TypeId, ComponentId)>,
+    has: Vec<(TypeId, ComponentId)>,
+    unregistered_in_has: Vec<String>,
+    without: Vec<(TypeId, ComponentId)>,
+    with: Vec<(TypeId, ComponentId)>,
+}
+
+impl ResolvedQuery {
+    /// Resolves the components of `params`.
+    ///
+    /// Returns `None` if the query can't match any entity because some of the components it requires
+    /// are not registered.
+    fn new(
+        params: BrpQueryParams,
+        world: &World,
+        type_registry: &TypeRegistry,
+    ) -> BrpResult<Option<Self>> {
+        let BrpQueryParams {
+            data:
+                BrpQuery {
+                    components,
+                    option,
+                    has,
+                },
+            filter,
+            strict,
+        } = params;
+
+        // Required components: must be present
+        let (required, unregistered_in_required) =
+            get_component_ids(type_registry, world, components, strict)
+                .map_err(BrpError::component_error)?;
+
+        // Optional components: Option<&T> or all reflectable if "all"
+        let (optional, _) = match &option {
+            ComponentSelector::Paths(paths) => {
+                get_component_ids(type_registry, world, paths.clone(), strict)
+                    .map_err(BrpError::component_error)?
+            }
+            ComponentSelector::All => (Vec::new(), Vec::new()),
+        };
+
+        // Has components: presence check
+        let (has, unregistered_in_has) = get_component_ids(type_registry, world, has, strict)
+            .map_err(BrpError::component_error)?;
+
+        // Filters
+        let (without, _) = get_component_ids(type_registry, world, filter.without, strict)
+            .map_err(BrpError::component_error)?;
+        let (with, unregistered_in_with) =
+            get_component_ids(type_registry, world, filter.with, strict)
+                .map_err(BrpError::component_error)?;
+
+        // When "strict" is false:
+        // - Unregistered components in "option" and "without" are ignored.
+        // - Unregistered components in "has" are considered absent from the entity.
+        // - Unregistered components in "components" and "with" result in an empty
+        // response since they specify hard requirements.
+        // If strict, fail if any required or with components are unregistered
+        if !unregistered_in_required.is_empty() || !unregistered_in_with.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Self {
+            required,
+            option,
+            optional,
+            has,
+            unregistered_in_has,
+            without,
+            with,
+        }))
+    }
+
+    /// Builds the query matching the entities selected by the request.
+    fn build(&self, world: &mut World) -> QueryState<FilteredEntityRef<'static, 'static>> {
+        let mut query = QueryBuilder::<FilteredEntityRef>::new(world);
+        for (_, component) in &self.required {
+            query.ref_id(*component);
+        }
+        for (_, option) in &self.optional {
+            query.optional(|query| {
+                query.ref_id(*option);
+            });
+        }
+        for (_, has) in &self.has {
+            query.optional(|query| {
+                query.ref_id(*has);
+            });
+        }
+        for (_, without) in &self.without {
+            query.without_id(*without);
+        }
+        for (_, with) in &self.with {
+            query.with_id(*with);
+        }
+        query.build()
     }
-    for (_, with) in with {
-        query.with_id(with);
+
+    /// Returns the type paths and [`ReflectComponent`]s of the `has` components.
+    fn has_reflect_components<'a>(
+        &self,
+        type_registry: &'a TypeRegistry,
+    ) -> BrpResult<Vec<(&'a str, &'a ReflectComponent)>> {
+        self.has
+            .iter()
+            .map(|(type_id, _)| reflect_component_from_id(*type_id, type_registry))
+            .collect::<AnyhowResult<Vec<(&str, &ReflectComponent)>>>()
+            .map_err(BrpError::component_error)
     }
 
-    // Prepare has reflect info
-    let has_paths_and_reflect_components: Vec<(&str, &ReflectComponent)> = has_ids
-        .iter()
-        .map(|(type_id, _)| reflect_component_from_id(*type_id, &type_registry))
-        .collect::<AnyhowResult<Vec<(&str, &ReflectComponent)>>>()
-        .map_err(BrpError::component_error)?;
+    /// Returns the components that exclude an entity from the query: the `without` components,
+    /// and the disabling components of the [`DefaultQueryFilters`] that the request doesn't mention.
+    fn filtered_out(&self, world: &World) -> Vec<ComponentId> {
+        let mut filtered_out: Vec<ComponentId> = self
+            .without
+            .iter()
+            .map(|(_, component_id)| *component_id)
+            .collect();
+        if let Some(default_filters) = world.get_resource::<DefaultQueryFilters>() {
+            filtered_out.extend(default_filters.disabling_ids().filter(|component_id| {
+                !self
+                    .required
+                    .iter()
+                    .chain(&self.optional)
+                    .chain(&self.has)
+                    .chain(&self.without)
+                    .chain(&self.with)
+                    .any(|(_, id)| id == component_id)
+            }));
+        }
+        filtered_out
+    }
 
-    let mut response = BrpQueryResponse::default();
-    let mut query = query.build();
+    /// Returns `true` if changes to `component_id` can affect the result of the request.
+    fn watches(&self, component_id: ComponentId) -> bool {
+        self.selects_optional(component_id)
+            || self
+                .required
+                .iter()
+                .chain(&self.has)
+                .chain(&self.with)
+                .any(|(_, id)| *id == component_id)
+    }
 
-    for row in query.iter(world) {
-        let entity_id = row.id();
-        let entity_ref = world.get_entity(entity_id).expect("Entity should exist");
+    /// Returns `true` if `component_id` is one of the optional components of the request.
+    fn selects_optional(&self, component_id: ComponentId) -> bool {
+        match self.option {
+            ComponentSelector::All => true,
+            ComponentSelector::Paths(_) => self.optional.iter().any(|(_, id)| *id == component_id),
+        }
+    }
+
+    /// Serializes the required and optional components of an entity for which `filter` returns `true`.
+    fn serialize_components(
+        &self,
+        world: &World,
+        entity_ref: EntityRef,
+        type_registry: &TypeRegistry,
+        filter: impl Fn(ComponentId) -> bool,
+    ) -> HashMap<String, Value> {
+        let required = &self.required;
 
         // Required components
         let mut components_map = serialize_components(
             entity_ref,
-            &type_registry,
+            type_registry,
             required
                 .iter()
+                .filter(|(_, component_id)| filter(*component_id))
                 .map(|(type_id, component_id)| (*type_id, Some(*component_id))),
         );
 
         // Optional components
-        match &option {
+        match &self.option {
             ComponentSelector::All => {
                 // Add all reflectable components present on the entity (as Option<&T>)
                 let all_optionals =
@@ -854,47 +1345,30 @@ pub fn process_remote_query_request(In(params): In<Option<Value>>, world: &mut W
                             if required.iter().any(|(_, cid)| cid == &component_id) {
                                 return None;
                             }
-                            Some((type_id, Some(component_id)))
+                            filter(component_id).then_some((type_id, Some(component_id)))
                         });
                 components_map.extend(serialize_components(
                     entity_ref,
-                    &type_registry,
+                    type_registry,
                     all_optionals,
                 ));
             }
             ComponentSelector::Paths(_) => {
                 // Add only the requested optional components (as Option<&T>)
-                let optionals = optional.iter().filter(|(_, component_id)| {
+                let optionals = self.optional.iter().filter(|(_, component_id)| {
                     // Skip required components (already included)
-                    !required.iter().any(|(_, cid)| cid == component_id)
+                    !required.iter().any(|(_, cid)| cid == component_id) && filter(*component_id)
                 });
                 components_map.extend(serialize_components(
                     entity_ref,
-                    &type_registry,
-                    optionals
-                        .clone()
-                        .map(|(type_id, component_id)| (*type_id, Some(*component_id))),
+                    type_registry,
+                    optionals.map(|(type_id, component_id)| (*type_id, Some(*component_id))),
                 ));
             }
         }
 
-        // The map of boolean-valued component presences:
-        let has_map = build_has_map(
-            row,
-            has_paths_and_reflect_components.iter().copied(),
-            &unregistered_in_has,
-        );
-
-        let query_row = BrpQueryRow {
-            entity: row.id(),
-            components: components_map,
-            has: has_map,
-        };
-
-        response.push(query_row);
+        components_map
     }
-
-    serde_json::to_value(response).map_err(BrpError::internal)
 }
 
 /// Serializes the specified components for an entity.
@@ -1297,6 +1771,195 @@ pub fn process_remote_list_resources_request(
     serde_json::to_value(response).map_err(BrpError::internal)
 }
 
+/// Handles a `bevy/schedule_graph` request coming from a client.
+pub fn process_remote_schedule_graph_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let BrpScheduleGraphParams { schedule, format } = match params {
+        Some(params) => parse(params)?,
+        None => BrpScheduleGraphParams::default(),
+    };
+
+    let schedules = world
+        .get_resource::<Schedules>()
+        .ok_or_else(|| BrpError::resource_not_present("Schedules"))?;
+
+    let mut exports = Vec::new();
+    match schedule {
+        Some(name) => {
+            let (_, schedule) = schedules
+                .iter()
+                .find(|(label, _)| format!("{label:?}") == name)
+                .ok_or_else(|| BrpError::schedule_not_present(&name))?;
+            exports.push(
+                schedule
+                    .export_graph(world.components())
+                    .map_err(BrpError::schedule_error)?,
+            );
+        }
+        None => {
+            exports.extend(
+                schedules
+                    .iter()
+                    .filter_map(|(_, schedule)| schedule.export_graph(world.components()).ok()),
+            );
+            exports.sort_by(|a, b| a.schedule.cmp(&b.schedule));
+        }
+    }
+
+    let response = match format {
+        BrpScheduleGraphFormat::Json => BrpScheduleGraphResponse::Json(exports),
+        BrpScheduleGraphFormat::Dot => {
+            BrpScheduleGraphResponse::Dot(exports.iter().map(ScheduleGraphExport::to_dot).collect())
+        }
+    };
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/asset_graph` request coming from a client.
+#[cfg(feature = "bevy_asset")]
+pub fn process_remote_asset_graph_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let BrpAssetGraphParams { path } = match params {
+        Some(params) => parse(params)?,
+        None => BrpAssetGraphParams::default(),
+    };
+
+    let asset_server = world
+        .get_resource::<AssetServer>()
+        .ok_or_else(|| BrpError::resource_not_present("AssetServer"))?;
+    let mut graph = asset_server.dependency_graph();
+    graph.collect_entity_holders(world);
+
+    let why_loaded = match path {
+        Some(path) => {
+            let asset_path = AssetPath::try_parse(&path).map_err(|err| BrpError {
+                code: error_codes::INVALID_PARAMS,
+                message: err.to_string(),
+                data: None,
+            })?;
+            let why_loaded: Vec<Vec<usize>> = graph
+                .get_path(&asset_path)
+                .map(|node| {
+                    graph
+                        .why_loaded(node.id)
+                        .into_iter()
+                        .filter_map(|node| graph.index_of(node.id))
+                        .collect()
+                })
+                .collect();
+            if why_loaded.is_empty() {
+                return Err(BrpError::asset_not_present(&path));
+            }
+            Some(why_loaded)
+        }
+        None => None,
+    };
+
+    serde_json::to_value(BrpAssetGraphResponse { graph, why_loaded }).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/run_system` request coming from a client.
+pub fn process_remote_run_system_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpRunSystemParams { entity, name } = parse_some(params)?;
+
+    let entity = match (entity, name) {
+        (Some(entity), None) => {
+            if !world
+                .get_entity(entity)
+                .is_ok_and(|entity_ref| entity_ref.contains::<SystemIdMarker>())
+            {
+                return Err(BrpError::system_not_present(&entity.to_string()));
+            }
+            entity
+        }
+        (None, Some(name)) => world
+            .query_filtered::<(Entity, &Name), (With<SystemIdMarker>, Allows<Internal>)>()
+            .iter(world)
+            .find_map(|(entity, entity_name)| (entity_name.as_str() == name).then_some(entity))
+            .ok_or_else(|| BrpError::system_not_present(&name))?,
+        _ => {
+            return Err(BrpError {
+                code: error_codes::INVALID_PARAMS,
+                message: String::from("Exactly one of `entity` and `name` must be provided"),
+                data: None,
+            })
+        }
+    };
+
+    world
+        .run_system(SystemId::<(), ()>::from_entity(entity))
+        .map_err(BrpError::system_error)?;
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/call_function` request coming from a client.
+#[cfg(feature = "reflect_functions")]
+pub fn process_remote_call_function_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let BrpCallFunctionParams {
+        function: function_name,
+        args,
+    } = parse_some(params)?;
+
+    let function_registry = world.resource::<AppFunctionRegistry>().read();
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+
+    let function = function_registry
+        .get(&function_name)
+        .ok_or_else(|| BrpError::function_not_present(&function_name))?;
+
+    // Overloaded functions may have several signatures taking the same number of arguments,
+    // so use the first one the arguments can be deserialized as.
+    let mut values = Err(anyhow!(
+        "Function `{}` does not take {} argument(s)",
+        function_name,
+        args.len()
+    ));
+    for signature in function.info().signatures() {
+        if signature.arg_count() == args.len() {
+            values = deserialize_function_args(&type_registry, signature, &args);
+            if values.is_ok() {
+                break;
+            }
+        }
+    }
+    let mut values = values.map_err(BrpError::function_error)?;
+
+    let mut arg_list = ArgList::new();
+    for (ownership, value) in &mut values {
+        match ownership {
+            // Move owned arguments out, leaving a placeholder behind.
+            Ownership::Owned => arg_list.push_boxed(core::mem::replace(value, Box::new(()))),
+            Ownership::Ref => arg_list.push_ref(&**value),
+            Ownership::Mut => arg_list.push_mut(&mut **value),
+        }
+    }
+
+    let returned = function.call(arg_list).map_err(BrpError::function_error)?;
+    if returned.is_unit() {
+        return Ok(Value::Null);
+    }
+
+    let returned: &dyn PartialReflect = match &returned {
+        Return::Owned(value) => &**value,
+        Return::Ref(value) => *value,
+        Return::Mut(value) => &**value,
+    };
+    serde_json::to_value(TypedReflectSerializer::new(returned, &type_registry))
+        .map_err(BrpError::function_error)
+}
+
 /// Handles a `bevy/list+watch` request coming from a client.
 pub fn process_remote_list_watching_request(
     In(params): In<Option<Value>>,
@@ -1523,6 +2186,55 @@ fn deserialize_components(
     Ok(reflect_components)
 }
 
+/// Given the `signature` of a function and a collection of serialized arguments (`args`),
+/// return the deserialized arguments along with how the function takes each of them.
+#[cfg(feature = "reflect_functions")]
+fn deserialize_function_args(
+    type_registry: &TypeRegistry,
+    signature: &SignatureInfo,
+    args: &[Value],
+) -> AnyhowResult<Vec<(Ownership, Box<dyn PartialReflect>)>> {
+    signature
+        .args()
+        .iter()
+        .zip(args)
+        .map(|(arg_info, arg)| {
+            // Reference parameters carry the type path of the reference, not of the referenced type.
+            let type_path = arg_info.type_path();
+            let type_path = match arg_info.ownership() {
+                Ownership::Owned => Some(type_path),
+                Ownership::Ref => type_path.strip_prefix('&'),
+                Ownership::Mut => type_path.strip_prefix("&mut "),
+            }
+            .unwrap_or(type_path);
+            let Some(arg_type) = type_registry.get_with_type_path(type_path) else {
+                return Err(anyhow!("Unknown argument type: `{}`", type_path));
+            };
+            let reflected: Box<dyn PartialReflect> =
+                TypedReflectDeserializer::new(arg_type, type_registry)
+                    .deserialize(arg)
+                    .map_err(|err| anyhow!("Argument {} is invalid: {err}", arg_info.index()))?;
+
+            // Functions only accept concrete values, which complex types don't deserialize to.
+            let reflected = match arg_type.data::<ReflectFromReflect>() {
+                Some(reflect_from_reflect) => reflect_from_reflect
+                    .from_reflect(&*reflected)
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "Argument {} could not be converted to `{}`",
+                            arg_info.index(),
+                            type_path
+                        )
+                    })?
+                    .into_partial_reflect(),
+                None => reflected,
+            };
+
+            Ok((arg_info.ownership(), reflected))
+        })
+        .collect()
+}
+
 /// Given a resource path and an associated serialized value (`value`), return the
 /// deserialized value.
 fn deserialize_resource(
@@ -1635,6 +2347,7 @@ mod tests {
             has: Default::default(),
         });
         test_serialize_deserialize(BrpListWatchingResponse::default());
+        test_serialize_deserialize(BrpQueryWatchingResponse::default());
         test_serialize_deserialize(BrpQuery::default());
         test_serialize_deserialize(BrpJsonSchemaQueryFilter::default());
         test_serialize_deserialize(BrpJsonSchemaQueryFilter {
@@ -1647,5 +2360,263 @@ mod tests {
         test_serialize_deserialize(BrpListParams {
             entity: Entity::from_raw_u32(0).unwrap(),
         });
+        test_serialize_deserialize(BrpScheduleGraphParams {
+            schedule: Some("Update".to_owned()),
+            format: BrpScheduleGraphFormat::Dot,
+        });
+        test_serialize_deserialize(BrpScheduleGraphResponse::Json(vec![
+            ScheduleGraphExport::default(),
+        ]));
+    }
+
+    #[test]
+    fn query_watching() {
+        use bevy_ecs::{component::Component, reflect::ReflectComponent, system::RunSystemOnce};
+        use bevy_reflect::{Reflect, TypePath};
+
+        #[derive(Component, Reflect)]
+        #[reflect(Component)]
+        struct Position(u32);
+
+        #[derive(Component, Reflect)]
+        #[reflect(Component)]
+        struct Frozen;
+
+        #[derive(Component)]
+        struct Velocity;
+
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Position>();
+            registry.register::<Frozen>();
+        }
+        let a = world.spawn(Position(0)).id();
+        let b = world.spawn(Position(1)).id();
+        let d = world.spawn((Position(3), Velocity)).id();
+        world.register_component::<Frozen>();
+        world.clear_trackers();
+
+        let params = serde_json::json!({
+            "data": { "components": [Position::type_path()] },
+            "filter": { "without": [Frozen::type_path()] },
+        });
+        let watch = |world: &mut World| {
+            let response = world
+                .run_system_once_with(process_remote_query_watching_request, Some(params.clone()))
+                .unwrap()
+                .unwrap();
+            world.clear_trackers();
+            response.map(|value| serde_json::from_value::<BrpQueryWatchingResponse>(value).unwrap())
+        };
+
+        assert_eq!(watch(&mut world), None);
+
+        // Changes to components that are not part of the query are not reported.
+        world.entity_mut(d).insert(Velocity);
+        assert_eq!(watch(&mut world), None);
+
+        world.entity_mut(a).insert(Position(5));
+        world.despawn(b);
+        let c = world.spawn(Position(2)).id();
+        let response = watch(&mut world).unwrap();
+        assert_eq!(
+            response
+                .added
+                .iter()
+                .map(|row| row.entity)
+                .collect::<Vec<_>>(),
+            [c]
+        );
+        assert_eq!(response.changed.len(), 1);
+        assert_eq!(response.changed[0].entity, a);
+        assert_eq!(
+            response.changed[0].components[Position::type_path()],
+            serde_json::json!(5)
+        );
+        assert_eq!(response.removed, [b]);
+
+        world.entity_mut(a).insert(Frozen);
+        let response = watch(&mut world).unwrap();
+        assert_eq!(response.removed, [a]);
+        assert!(response.added.is_empty() && response.changed.is_empty());
+
+        world.entity_mut(a).remove::<Frozen>();
+        let response = watch(&mut world).unwrap();
+        assert_eq!(
+            response
+                .added
+                .iter()
+                .map(|row| row.entity)
+                .collect::<Vec<_>>(),
+            [a]
+        );
+        assert_eq!(watch(&mut world), None);
+    }
+
+    #[test]
+    fn schedule_graph() {
+        use bevy_ecs::{
+            schedule::{Schedule, ScheduleLabel},
+            system::RunSystemOnce,
+        };
+
+        #[derive(ScheduleLabel, Hash, PartialEq, Eq, Debug, Clone)]
+        struct TestSchedule;
+
+        let mut world = World::new();
+        let mut schedule = Schedule::new(TestSchedule);
+        schedule.add_systems(|| {});
+        schedule.initialize(&mut world).unwrap();
+        world.init_resource::<Schedules>();
+        world.resource_mut::<Schedules>().insert(schedule);
+
+        let response = world
+            .run_system_once_with(
+                process_remote_schedule_graph_request,
+                Some(serde_json::json!({ "schedule": "TestSchedule" })),
+            )
+            .unwrap()
+            .unwrap();
+        let exports: Vec<ScheduleGraphExport> = serde_json::from_value(response).unwrap();
+        assert_eq!(exports.len(), 1);
+        assert_eq!(exports[0].systems.len(), 1);
+
+        let response = world
+            .run_system_once_with(
+                process_remote_schedule_graph_request,
+                Some(serde_json::json!({ "format": "dot" })),
+            )
+            .unwrap()
+            .unwrap();
+        let dots: Vec<String> = serde_json::from_value(response).unwrap();
+        assert!(dots[0].starts_with("digraph \"TestSchedule\""));
+
+        let error = world
+            .run_system_once_with(
+                process_remote_schedule_graph_request,
+                Some(serde_json::json!({ "schedule": "Missing" })),
+            )
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(error.code, error_codes::SCHEDULE_NOT_PRESENT);
+    }
+
+    #[cfg(feature = "bevy_asset")]
+    #[test]
+    fn asset_graph_without_asset_server() {
+        use bevy_ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        let error = world
+            .run_system_once_with(process_remote_asset_graph_request, None)
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(error.code, error_codes::RESOURCE_NOT_PRESENT);
+    }
+
+    #[test]
+    fn run_system() {
+        use bevy_ecs::{
+            resource::Resource,
+            system::{ResMut, RunSystemOnce},
+        };
+
+        #[derive(Resource, Default)]
+        struct Counter(u32);
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        let system = world.register_system(|mut counter: ResMut<Counter>| counter.0 += 1);
+        world
+            .entity_mut(system.entity())
+            .insert(Name::new("increment"));
+
+        world
+            .run_system_once_with(
+                process_remote_run_system_request,
+                Some(serde_json::json!({ "name": "increment" })),
+            )
+            .unwrap()
+            .unwrap();
+        world
+            .run_system_once_with(
+                process_remote_run_system_request,
+                Some(serde_json::json!({ "entity": system.entity() })),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(world.resource::<Counter>().0, 2);
+
+        let error = world
+            .run_system_once_with(
+                process_remote_run_system_request,
+                Some(serde_json::json!({ "name": "missing" })),
+            )
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(error.code, error_codes::SYSTEM_NOT_PRESENT);
+    }
+
+    #[cfg(feature = "reflect_functions")]
+    #[test]
+    fn call_function() {
+        use bevy_ecs::system::RunSystemOnce;
+        use bevy_reflect::Reflect;
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Point {
+            x: f32,
+            y: f32,
+        }
+
+        fn scale(point: &Point, factor: f32) -> Point {
+            Point {
+                x: point.x * factor,
+                y: point.y * factor,
+            }
+        }
+
+        let mut world = World::new();
+        let type_registry = AppTypeRegistry::default();
+        type_registry.write().register::<Point>();
+        world.insert_resource(type_registry);
+        let function_registry = AppFunctionRegistry::default();
+        function_registry
+            .write()
+            .register_with_name("scale", scale)
+            .unwrap();
+        world.insert_resource(function_registry);
+
+        let response = world
+            .run_system_once_with(
+                process_remote_call_function_request,
+                Some(serde_json::json!({
+                    "function": "scale",
+                    "args": [{ "x": 1.0, "y": 2.0 }, 2.0],
+                })),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(response, serde_json::json!({ "x": 2.0, "y": 4.0 }));
+
+        let error = world
+            .run_system_once_with(
+                process_remote_call_function_request,
+                Some(serde_json::json!({ "function": "scale", "args": [1.0] })),
+            )
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(error.code, error_codes::FUNCTION_ERROR);
+
+        let error = world
+            .run_system_once_with(
+                process_remote_call_function_request,
+                Some(serde_json::json!({ "function": "missing" })),
+            )
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(error.code, error_codes::FUNCTION_NOT_PRESENT);
     }
 }
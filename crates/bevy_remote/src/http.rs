@@ -5,11 +5,16 @@
 //!
 //! Clients are expected to `POST` JSON requests to the root URL; see the `client`
 //! example for a trivial example of use.
+//!
+//! By default, any client that can reach the server may call any method. Use
+//! [`HttpAccessControl`] to require bearer tokens, to restrict the methods each token
+//! may call, and to only accept cross-origin requests from known origins.
 
 #![cfg(not(target_family = "wasm"))]
 
 use crate::{
-    error_codes, BrpBatch, BrpError, BrpMessage, BrpRequest, BrpResponse, BrpResult, BrpSender,
+    builtin_methods, error_codes, BrpBatch, BrpError, BrpMessage, BrpRequest, BrpResponse,
+    BrpResult, BrpSender,
 };
 use anyhow::Result as AnyhowResult;
 use async_channel::{Receiver, Sender};
@@ -27,14 +32,14 @@ use core::{
 use http_body_util::{BodyExt as _, Full};
 use hyper::{
     body::{Body, Bytes, Frame, Incoming},
-    header::{HeaderName, HeaderValue},
+    header::{self, HeaderMap, HeaderName, HeaderValue},
     server::conn::http1,
-    service, Request, Response,
+    service, Method, Request, Response, StatusCode,
 };
 use serde_json::Value;
 use smol_hyper::rt::{FuturesIo, SmolTimer};
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     net::{TcpListener, TcpStream},
 };
 
@@ -87,6 +92,152 @@ impl Default for Headers {
     }
 }
 
+/// The methods a client is allowed to call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MethodAccess {
+    /// Every method may be called.
+    All,
+    /// Only the methods in the set may be called.
+    Only(HashSet<String>),
+}
+
+impl MethodAccess {
+    /// Allow only the given methods.
+    pub fn only(methods: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self::Only(methods.into_iter().map(Into::into).collect())
+    }
+
+    /// Allow only the built-in methods that do not modify the world.
+    pub fn read_only() -> Self {
+        let methods = [
+            builtin_methods::BRP_GET_METHOD,
+            builtin_methods::BRP_QUERY_METHOD,
+            builtin_methods::BRP_LIST_METHOD,
+            builtin_methods::BRP_GET_AND_WATCH_METHOD,
+            builtin_methods::BRP_LIST_AND_WATCH_METHOD,
+            builtin_methods::BRP_QUERY_AND_WATCH_METHOD,
+            builtin_methods::BRP_GET_RESOURCE_METHOD,
+            builtin_methods::BRP_LIST_RESOURCES_METHOD,
+            builtin_methods::BRP_REGISTRY_SCHEMA_METHOD,
+            builtin_methods::BRP_SCHEDULE_GRAPH_METHOD,
+            builtin_methods::RPC_DISCOVER_METHOD,
+        ];
+        #[cfg(feature = "bevy_asset")]
+        let methods = methods
+            .into_iter()
+            .chain([builtin_methods::BRP_ASSET_GRAPH_METHOD]);
+        Self::only(methods)
+    }
+
+    /// Whether the method with the given name may be called.
+    pub fn allows(&self, method: &str) -> bool {
+        match self {
+            Self::All => true,
+            Self::Only(methods) => methods.contains(method),
+        }
+    }
+}
+
+/// Authentication and origin restrictions applied to every request received by the
+/// [`RemoteHttpPlugin`].
+///
+/// The `RemoteWebSocketPlugin` of the `websocket` module accepts the same restrictions, checking
+/// the token and origin once when a connection is opened.
+///
+/// With the default configuration, every request is accepted and may call any method.
+///
+/// # Example
+///
+/// ```ignore
+/// let access_control = HttpAccessControl::new()
+///     // Playtesters may only look at the world...
+///     .with_token("playtest-token", MethodAccess::read_only())
+///     // ...while developers may call any method.
+///     .with_token("dev-token", MethodAccess::All)
+///     .with_allowed_origin("http://192.168.1.20:8080");
+///
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugins(RemotePlugin::default())
+///     .add_plugins(RemoteHttpPlugin::default().with_access_control(access_control))
+///     .run();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HttpAccessControl {
+    /// The accepted bearer tokens and the methods each of them may call.
+    ///
+    /// If empty, requests are not authenticated.
+    tokens: Vec<(String, MethodAccess)>,
+    /// The origins that cross-origin requests are accepted from.
+    ///
+    /// If empty, requests from any origin are accepted.
+    allowed_origins: HashSet<String>,
+}
+
+impl HttpAccessControl {
+    /// Create a new instance of `HttpAccessControl` that accepts every request.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Accept requests carrying the given bearer token in their `Authorization` header,
+    /// allowing them to call the methods in `access`.
+    ///
+    /// Once a token has been added, requests without a known token are rejected with
+    /// [`error_codes::UNAUTHORIZED`].
+    #[must_use]
+    pub fn with_token(mut self, token: impl Into<String>, access: MethodAccess) -> Self {
+        let token = token.into();
+        self.tokens.retain(|(existing, _)| *existing != token);
+        self.tokens.push((token, access));
+        self
+    }
+
+    /// Accept cross-origin requests from the given origin, e.g. `http://localhost:8080`.
+    ///
+    /// Once an origin has been added, requests whose `Origin` header names any other origin
+    /// are rejected with [`error_codes::ORIGIN_NOT_ALLOWED`]. Requests without an `Origin`
+    /// header, such as those of non-browser clients, are not affected.
+    #[must_use]
+    pub fn with_allowed_origin(mut self, origin: impl Into<String>) -> Self {
+        self.allowed_origins.insert(origin.into());
+        self
+    }
+
+    /// Whether requests with the given `Origin` header are accepted.
+    pub fn allows_origin(&self, origin: &str) -> bool {
+        self.allowed_origins.is_empty() || self.allowed_origins.contains(origin)
+    }
+
+    /// Returns the methods that may be called by a request with the given `Authorization`
+    /// header, or [`None`] if the request must be rejected.
+    pub fn authorize(&self, authorization: Option<&str>) -> Option<&MethodAccess> {
+        static UNRESTRICTED: MethodAccess = MethodAccess::All;
+
+        if self.tokens.is_empty() {
+            return Some(&UNRESTRICTED);
+        }
+
+        let (scheme, token) = authorization?.trim().split_once(' ')?;
+        if !scheme.eq_ignore_ascii_case("bearer") {
+            return None;
+        }
+
+        // Compare against every token so that the response time does not reveal which
+        // token prefixes are valid.
+        let token = token.trim_start().as_bytes();
+        self.tokens.iter().fold(None, |found, (candidate, access)| {
+            let matches = constant_time_eq(candidate.as_bytes(), token);
+            found.or(matches.then_some(access))
+        })
+    }
+}
+
+/// Compares two byte slices in time that only depends on their lengths.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
 /// Add this plugin to your [`App`] to allow remote connections over HTTP to inspect and modify entities.
 /// It requires the [`RemotePlugin`](super::RemotePlugin).
 ///
@@ -103,6 +254,8 @@ pub struct RemoteHttpPlugin {
     port: u16,
     /// The headers that Bevy will include in its HTTP responses
     headers: Headers,
+    /// The authentication and origin restrictions applied to requests.
+    access_control: HttpAccessControl,
 }
 
 impl Default for RemoteHttpPlugin {
@@ -111,6 +264,7 @@ impl Default for RemoteHttpPlugin {
             address: DEFAULT_ADDR,
             port: DEFAULT_PORT,
             headers: Headers::new(),
+            access_control: HttpAccessControl::new(),
         }
     }
 }
@@ -120,6 +274,7 @@ impl Plugin for RemoteHttpPlugin {
         app.insert_resource(HostAddress(self.address))
             .insert_resource(HostPort(self.port))
             .insert_resource(HostHeaders(self.headers.clone()))
+            .insert_resource(HostAccessControl(self.access_control.clone()))
             .add_systems(Startup, start_http_server);
     }
 }
@@ -173,6 +328,31 @@ impl RemoteHttpPlugin {
         self.headers = self.headers.insert(name, value);
         self
     }
+    /// Set the authentication and origin restrictions applied to requests.
+    ///
+    /// See [`HttpAccessControl`] for an example.
+    #[must_use]
+    pub fn with_access_control(mut self, access_control: HttpAccessControl) -> Self {
+        self.access_control = access_control;
+        self
+    }
+    /// Require requests to carry a bearer token, and accept the given one with access to
+    /// the methods in `access`.
+    ///
+    /// See [`HttpAccessControl::with_token`].
+    #[must_use]
+    pub fn with_token(mut self, token: impl Into<String>, access: MethodAccess) -> Self {
+        self.access_control = self.access_control.with_token(token, access);
+        self
+    }
+    /// Only accept cross-origin requests from the given origin and any other allowed ones.
+    ///
+    /// See [`HttpAccessControl::with_allowed_origin`].
+    #[must_use]
+    pub fn with_allowed_origin(mut self, origin: impl Into<String>) -> Self {
+        self.access_control = self.access_control.with_allowed_origin(origin);
+        self
+    }
 }
 
 /// A resource containing the IP address that Bevy will host on.
@@ -194,12 +374,17 @@ pub struct HostPort(pub u16);
 #[derive(Debug, Resource)]
 struct HostHeaders(pub Headers);
 
+/// A resource containing the authentication and origin restrictions applied to requests.
+#[derive(Debug, Resource)]
+struct HostAccessControl(pub HttpAccessControl);
+
 /// A system that starts up the Bevy Remote Protocol HTTP server.
 fn start_http_server(
     request_sender: Res<BrpSender>,
     address: Res<HostAddress>,
     remote_port: Res<HostPort>,
     headers: Res<HostHeaders>,
+    access_control: Res<HostAccessControl>,
 ) {
     IoTaskPool::get()
         .spawn(server_main(
@@ -207,6 +392,7 @@ fn start_http_server(
             remote_port.0,
             request_sender.clone(),
             headers.0.clone(),
+            access_control.0.clone(),
         ))
         .detach();
 }
@@ -217,11 +403,13 @@ async fn server_main(
     port: u16,
     request_sender: Sender<BrpMessage>,
     headers: Headers,
+    access_control: HttpAccessControl,
 ) -> AnyhowResult<()> {
     listen(
         Async::<TcpListener>::bind((address, port))?,
         &request_sender,
         &headers,
+        &access_control,
     )
     .await
 }
@@ -230,15 +418,17 @@ async fn listen(
     listener: Async<TcpListener>,
     request_sender: &Sender<BrpMessage>,
     headers: &Headers,
+    access_control: &HttpAccessControl,
 ) -> AnyhowResult<()> {
     loop {
         let (client, _) = listener.accept().await?;
 
         let request_sender = request_sender.clone();
         let headers = headers.clone();
+        let access_control = access_control.clone();
         IoTaskPool::get()
             .spawn(async move {
-                let _ = handle_client(client, request_sender, headers).await;
+                let _ = handle_client(client, request_sender, headers, access_control).await;
             })
             .detach();
     }
@@ -248,13 +438,14 @@ async fn handle_client(
     client: Async<TcpStream>,
     request_sender: Sender<BrpMessage>,
     headers: Headers,
+    access_control: HttpAccessControl,
 ) -> AnyhowResult<()> {
     http1::Builder::new()
         .timer(SmolTimer::new())
         .serve_connection(
             FuturesIo::new(client),
             service::service_fn(|request| {
-                process_request_batch(request, &request_sender, &headers)
+                process_request_batch(request, &request_sender, &headers, &access_control)
             }),
         )
         .await?;
@@ -268,13 +459,74 @@ async fn process_request_batch(
     request: Request<Incoming>,
     request_sender: &Sender<BrpMessage>,
     headers: &Headers,
+    access_control: &HttpAccessControl,
 ) -> AnyhowResult<Response<BrpHttpBody>> {
+    let origin = request
+        .headers()
+        .get(header::ORIGIN)
+        .and_then(|origin| origin.to_str().ok())
+        .map(ToOwned::to_owned);
+    if let Some(origin) = &origin
+        && !access_control.allows_origin(origin)
+    {
+        let error = BrpError {
+            code: error_codes::ORIGIN_NOT_ALLOWED,
+            message: format!("Requests from origin `{origin}` are not allowed"),
+            data: None,
+        };
+        let mut response = error_response(StatusCode::FORBIDDEN, error)?;
+        insert_headers(response.headers_mut(), headers);
+        return Ok(response);
+    }
+
+    // Answer CORS preflight requests, which never carry credentials.
+    if request.method() == Method::OPTIONS {
+        let mut response = Response::new(BrpHttpBody::Complete(Full::default()));
+        *response.status_mut() = StatusCode::NO_CONTENT;
+        if let Some(origin) = &origin {
+            insert_cors_headers(response.headers_mut(), access_control, origin);
+            if !access_control.allowed_origins.is_empty() {
+                response.headers_mut().insert(
+                    header::ACCESS_CONTROL_ALLOW_METHODS,
+                    HeaderValue::from_static("POST, OPTIONS"),
+                );
+                response.headers_mut().insert(
+                    header::ACCESS_CONTROL_ALLOW_HEADERS,
+                    HeaderValue::from_static("Content-Type, Authorization"),
+                );
+            }
+        }
+        insert_headers(response.headers_mut(), headers);
+        return Ok(response);
+    }
+
+    let authorization = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|authorization| authorization.to_str().ok());
+    let Some(method_access) = access_control.authorize(authorization) else {
+        let error = BrpError {
+            code: error_codes::UNAUTHORIZED,
+            message: String::from("Missing or invalid bearer token"),
+            data: None,
+        };
+        let mut response = error_response(StatusCode::UNAUTHORIZED, error)?;
+        response
+            .headers_mut()
+            .insert(header::WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"));
+        if let Some(origin) = &origin {
+            insert_cors_headers(response.headers_mut(), access_control, origin);
+        }
+        insert_headers(response.headers_mut(), headers);
+        return Ok(response);
+    };
+
     let batch_bytes = request.into_body().collect().await?.to_bytes();
     let batch: Result<BrpBatch, _> = serde_json::from_slice(&batch_bytes);
 
     let result = match batch {
         Ok(BrpBatch::Single(request)) => {
-            let response = process_single_request(request, request_sender).await?;
+            let response = process_single_request(request, request_sender, method_access).await?;
             match response {
                 BrpHttpResponse::Complete(res) => {
                     BrpHttpResponse::Complete(serde_json::to_string(&res)?)
@@ -286,7 +538,8 @@ async fn process_request_batch(
             let mut responses = Vec::new();
 
             for request in requests {
-                let response = process_single_request(request, request_sender).await?;
+                let response =
+                    process_single_request(request, request_sender, method_access).await?;
                 match response {
                     BrpHttpResponse::Complete(res) => responses.push(res),
                     BrpHttpResponse::Stream(BrpStream { id, .. }) => {
@@ -324,7 +577,7 @@ async fn process_request_batch(
                 serialized.as_bytes().to_owned(),
             ))));
             response.headers_mut().insert(
-                hyper::header::CONTENT_TYPE,
+                header::CONTENT_TYPE,
                 HeaderValue::from_static("application/json"),
             );
             response
@@ -332,23 +585,61 @@ async fn process_request_batch(
         BrpHttpResponse::Stream(stream) => {
             let mut response = Response::new(BrpHttpBody::Stream(stream));
             response.headers_mut().insert(
-                hyper::header::CONTENT_TYPE,
+                header::CONTENT_TYPE,
                 HeaderValue::from_static("text/event-stream"),
             );
             response
         }
     };
-    for (key, value) in &headers.headers {
-        response.headers_mut().insert(key, value.clone());
+    if let Some(origin) = &origin {
+        insert_cors_headers(response.headers_mut(), access_control, origin);
     }
+    insert_headers(response.headers_mut(), headers);
+    Ok(response)
+}
+
+/// Builds a response with the given HTTP status containing a single JSON-RPC error,
+/// for requests that are rejected before any of their contents are processed.
+fn error_response(status: StatusCode, error: BrpError) -> AnyhowResult<Response<BrpHttpBody>> {
+    let serialized = serde_json::to_string(&BrpResponse::new(None, Err(error)))?;
+    let mut response = Response::new(BrpHttpBody::Complete(Full::new(Bytes::from(serialized))));
+    *response.status_mut() = status;
+    response.headers_mut().insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static("application/json"),
+    );
     Ok(response)
 }
 
+/// Inserts the extra headers configured by the user.
+fn insert_headers(response_headers: &mut HeaderMap, headers: &Headers) {
+    for (key, value) in &headers.headers {
+        response_headers.insert(key, value.clone());
+    }
+}
+
+/// Tells browsers that responses may be read by the (already validated) requesting origin,
+/// if the allowed origins are restricted.
+fn insert_cors_headers(
+    response_headers: &mut HeaderMap,
+    access_control: &HttpAccessControl,
+    origin: &str,
+) {
+    if access_control.allowed_origins.is_empty() {
+        return;
+    }
+    if let Ok(origin) = HeaderValue::from_str(origin) {
+        response_headers.insert(header::ACCESS_CONTROL_ALLOW_ORIGIN, origin);
+        response_headers.insert(header::VARY, HeaderValue::from_static("Origin"));
+    }
+}
+
 /// A helper function for the Bevy Remote Protocol server that processes a single
 /// request coming from a client.
 async fn process_single_request(
     request: Value,
     request_sender: &Sender<BrpMessage>,
+    method_access: &MethodAccess,
 ) -> AnyhowResult<BrpHttpResponse<BrpResponse, BrpStream>> {
     // Reach in and get the request ID early so that we can report it even when parsing fails.
     let id = request.as_object().and_then(|map| map.get("id")).cloned();
@@ -378,6 +669,17 @@ async fn process_single_request(
         )));
     }
 
+    if !method_access.allows(&request.method) {
+        return Ok(BrpHttpResponse::Complete(BrpResponse::new(
+            id,
+            Err(BrpError {
+                code: error_codes::METHOD_NOT_ALLOWED,
+                message: format!("Method `{}` is not allowed for this token", request.method),
+                data: None,
+            }),
+        )));
+    }
+
     let watch = request.method.contains("+watch");
     let size = if watch { 8 } else { 1 };
     let (result_sender, result_receiver) = async_channel::bounded(size);
@@ -461,3 +763,159 @@ impl Body for BrpHttpBody {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RemotePlugin;
+    use bevy_app::TaskPoolPlugin;
+    use core::{net::SocketAddr, time::Duration};
+    use serde_json::json;
+    use std::{
+        io::{Read, Write},
+        thread,
+    };
+
+    /// Sends a `POST` request with the given extra `headers` and JSON `body` to the server at
+    /// `address`, returning the status code, the lowercase headers and the JSON body of the response.
+    ///
+    /// HTTP/1.0 is used so that the response body is neither chunked nor kept alive.
+    fn post(address: SocketAddr, headers: &[(&str, &str)], body: Value) -> (u16, String, Value) {
+        let body = body.to_string();
+        let mut request = format!(
+            "POST / HTTP/1.0\r\nContent-Type: application/json\r\nContent-Length: {}\r\n",
+            body.len()
+        );
+        for (name, value) in headers {
+            request.push_str(&format!("{name}: {value}\r\n"));
+        }
+        request.push_str("\r\n");
+        request.push_str(&body);
+
+        let mut stream = TcpStream::connect(address).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let (status_line, headers) = head.split_once("\r\n").unwrap();
+        let status = status_line.split(' ').nth(1).unwrap().parse().unwrap();
+        (
+            status,
+            headers.to_ascii_lowercase(),
+            serde_json::from_str(body).unwrap(),
+        )
+    }
+
+    #[test]
+    fn access_control() {
+        let unrestricted = HttpAccessControl::new();
+        assert_eq!(unrestricted.authorize(None), Some(&MethodAccess::All));
+        assert!(unrestricted.allows_origin("http://example.com"));
+
+        let access_control = HttpAccessControl::new()
+            .with_token("reader", MethodAccess::read_only())
+            .with_token("writer", MethodAccess::All)
+            .with_allowed_origin("http://localhost:8080");
+
+        assert_eq!(access_control.authorize(None), None);
+        assert_eq!(access_control.authorize(Some("Bearer unknown")), None);
+        assert_eq!(access_control.authorize(Some("Basic writer")), None);
+        assert_eq!(
+            access_control.authorize(Some("Bearer writer")),
+            Some(&MethodAccess::All)
+        );
+
+        let reader = access_control.authorize(Some("bearer reader")).unwrap();
+        assert!(reader.allows(builtin_methods::BRP_QUERY_METHOD));
+        assert!(!reader.allows(builtin_methods::BRP_INSERT_METHOD));
+        #[cfg(feature = "bevy_asset")]
+        assert!(reader.allows(builtin_methods::BRP_ASSET_GRAPH_METHOD));
+
+        assert!(access_control.allows_origin("http://localhost:8080"));
+        assert!(!access_control.allows_origin("http://example.com"));
+    }
+
+    #[test]
+    fn access_control_requests() {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), RemotePlugin::default()));
+        app.update();
+
+        // Serve the plugin's connections on a free port, with requests handled by the app.
+        let listener = Async::<TcpListener>::bind((DEFAULT_ADDR, 0)).unwrap();
+        let address = listener.get_ref().local_addr().unwrap();
+        let request_sender = Sender::clone(app.world().resource::<BrpSender>());
+        let access_control = HttpAccessControl::new()
+            .with_token("reader", MethodAccess::read_only())
+            .with_allowed_origin("http://localhost:8080");
+        IoTaskPool::get()
+            .spawn(async move {
+                let _ = listen(listener, &request_sender, &Headers::new(), &access_control).await;
+            })
+            .detach();
+
+        let client = thread::spawn(move || {
+            let list =
+                json!({ "jsonrpc": "2.0", "id": 1, "method": builtin_methods::BRP_LIST_METHOD });
+
+            let (status, headers, body) = post(address, &[], list.clone());
+            assert_eq!(status, 401);
+            assert!(headers.contains("www-authenticate: bearer"));
+            assert_eq!(body["error"]["code"], json!(error_codes::UNAUTHORIZED));
+
+            let (status, headers, body) = post(
+                address,
+                &[("Authorization", "Bearer unknown")],
+                list.clone(),
+            );
+            assert_eq!(status, 401);
+            assert!(headers.contains("www-authenticate: bearer"));
+            assert_eq!(body["error"]["code"], json!(error_codes::UNAUTHORIZED));
+
+            let (status, headers, body) = post(
+                address,
+                &[
+                    ("Authorization", "Bearer reader"),
+                    ("Origin", "http://example.com"),
+                ],
+                list.clone(),
+            );
+            assert_eq!(status, 403);
+            assert!(!headers.contains("access-control-allow-origin"));
+            assert_eq!(
+                body["error"]["code"],
+                json!(error_codes::ORIGIN_NOT_ALLOWED)
+            );
+
+            let (status, _, body) = post(
+                address,
+                &[("Authorization", "Bearer reader")],
+                json!({ "jsonrpc": "2.0", "id": 2, "method": builtin_methods::BRP_INSERT_METHOD }),
+            );
+            assert_eq!(status, 200);
+            assert_eq!(body["id"], json!(2));
+            assert_eq!(
+                body["error"]["code"],
+                json!(error_codes::METHOD_NOT_ALLOWED)
+            );
+
+            let (status, headers, body) = post(
+                address,
+                &[
+                    ("Authorization", "Bearer reader"),
+                    ("Origin", "http://localhost:8080"),
+                ],
+                list,
+            );
+            assert_eq!(status, 200);
+            assert!(headers.contains("access-control-allow-origin: http://localhost:8080"));
+            assert!(body["result"].is_array());
+        });
+
+        while !client.is_finished() {
+            app.update();
+            thread::sleep(Duration::from_millis(1));
+        }
+        client.join().unwrap();
+    }
+}
@@ -3,8 +3,9 @@
 //! Adding the [`RemotePlugin`] to your [`App`] will setup everything needed without
 //! starting any transports. To start accepting remote connections you will need to
 //! add a second plugin like the [`RemoteHttpPlugin`](http::RemoteHttpPlugin) to enable communication
-//! over HTTP. These *remote clients* can inspect and alter the state of the
-//! entity-component system.
+//! over HTTP, or the `RemoteWebSocketPlugin` of the `websocket` module (behind the `websocket`
+//! feature, or `remote_websocket` from the `bevy` crate) to multiplex requests over a persistent
+//! WebSocket. These *remote clients* can inspect and alter the state of the entity-component system.
 //!
 //! The Bevy Remote Protocol is based on the JSON-RPC 2.0 protocol.
 //!
@@ -409,6 +410,27 @@
 //! - `removed`: An array of fully-qualified type names of components removed from the entity
 //!   in the last tick.
 //!
+//! ### `bevy/query+watch`
+//!
+//! Watch the entities matching a query, receiving only what changed since the last tick.
+//! Use `bevy/query` with the same parameters to get the initial state.
+//!
+//! `params`: The same as `bevy/query`.
+//!
+//! `result`:
+//! - `added` (optional): An array of entities that started matching the query in the last tick,
+//!   in the same format as the `bevy/query` result.
+//! - `changed` (optional): An array of entities still matching the query whose requested
+//!   components changed in the last tick. Each element contains:
+//!   - `entity`: The ID of the entity.
+//!   - `components` (optional): A map associating each type name to its value, for the requested
+//!     components that were added or changed.
+//!   - `removed` (optional): An array of fully-qualified type names of optional components removed
+//!     from the entity.
+//!   - `has` (optional): The same map as in `bevy/query`, present only if one of its values changed.
+//! - `removed` (optional): An array of IDs of the entities that stopped matching the query or were
+//!   despawned in the last tick.
+//!
 //! ### `bevy/get_resource`
 //!
 //! Extract the value of a given resource from the world.
@@ -456,6 +478,85 @@
 //!
 //! `result`: An array of [fully-qualified type names] of registered resource types.
 //!
+//! ### `bevy/schedule_graph`
+//!
+//! Export the systems, system sets, ordering edges, run conditions, ambiguities and
+//! automatically inserted sync points of schedules, for use by debugging tools.
+//!
+//! Schedules that are currently running (such as the one the remote methods are processed in)
+//! and schedules that have never run are not available.
+//!
+//! `params` (optional):
+//! - `schedule` (optional): The debug name of the schedule to export, e.g. `Update`.
+//!   If omitted, every available schedule is exported.
+//! - `format` (optional): Either `json` (the default) or `dot`.
+//!
+//! `result`: An array with one element per exported schedule: a JSON object describing the schedule graph
+//! (see [`ScheduleGraphExport`]) for the `json` format, or a string containing a [Graphviz] DOT
+//! document for the `dot` format.
+//!
+//! [`ScheduleGraphExport`]: bevy_ecs::schedule::ScheduleGraphExport
+//! [Graphviz]: https://graphviz.org
+//!
+//! ### `bevy/run_system`
+//!
+//! Run a one-shot system that was registered with [`World::register_system`] (or one of its
+//! variants). Only systems without input and output can be run.
+//!
+//! `params`:
+//! - `entity` (optional): The ID of the entity holding the system, see [`SystemId::entity`].
+//! - `name` (optional): The value of the [`Name`] component on the entity holding the system.
+//!
+//! Exactly one of `entity` and `name` must be given.
+//!
+//! `result`: null.
+//!
+//! [`World::register_system`]: bevy_ecs::world::World::register_system
+//! [`SystemId::entity`]: bevy_ecs::system::SystemId::entity
+//! [`Name`]: bevy_ecs::name::Name
+//!
+//! ### `bevy/asset_graph`
+//!
+//! Export the assets tracked by the [`AssetServer`] and the dependencies between them, for use by
+//! debugging tools. This method is only available with the `bevy_asset` feature.
+//!
+//! `params` (optional):
+//! - `path` (optional): The path of an asset to explain, e.g. `models/ship.gltf#Mesh0`.
+//!
+//! `result`:
+//! - `nodes`: An array with one object per asset, sorted by path (see [`AssetDependencyNode`]):
+//!   - `path`: The path of the asset, or null.
+//!   - `type_path`: The [fully-qualified type name] of the asset type.
+//!   - `load_state`: One of `NotLoaded`, `Loading`, `Loaded` or `Failed`.
+//!   - `strong_handles`: The number of strong handles to the asset.
+//!   - `loaded_bytes`: The number of bytes read to load the asset, or null.
+//!   - `dependencies`: The indices in `nodes` of the assets this asset depends on.
+//!   - `dependents`: The indices in `nodes` of the assets depending on this asset.
+//!   - `entities`: The IDs of the entities with components holding strong handles to the asset.
+//!     Only components and asset types registered for reflection are inspected.
+//! - `why_loaded`: If `path` was given, an array with one element per asset at `path`: the
+//!   indices in `nodes` of the shortest chain of dependents from that asset to an asset that no
+//!   other asset depends on (see [`AssetDependencyGraph::why_loaded`]).
+//!
+//! [`AssetServer`]: bevy_asset::AssetServer
+//! [`AssetDependencyNode`]: bevy_asset::AssetDependencyNode
+//! [`AssetDependencyGraph::why_loaded`]: bevy_asset::AssetDependencyGraph::why_loaded
+//!
+//! ### `bevy/call_function`
+//!
+//! Call a function registered in the [`AppFunctionRegistry`]. This method is only available
+//! with the `reflect_functions` feature.
+//!
+//! `params`:
+//! - `function`: The name the function was registered under.
+//! - `args` (optional): An array of arguments, each deserialized through the type registry
+//!   according to the type of the matching function parameter. Reference parameters are
+//!   passed a reference to the deserialized value. Defaults to no arguments.
+//!
+//! `result`: The value returned by the function, or null if it returns `()`.
+//!
+//! [`AppFunctionRegistry`]: bevy_ecs::reflect::AppFunctionRegistry
+//!
 //! ## Custom methods
 //!
 //! In addition to the provided methods, the Bevy Remote Protocol can be extended to include custom
@@ -521,6 +622,8 @@ pub mod builtin_methods;
 #[cfg(feature = "http")]
 pub mod http;
 pub mod schemas;
+#[cfg(feature = "websocket")]
+pub mod websocket;
 
 const CHANNEL_SIZE: usize = 16;
 
@@ -575,7 +678,7 @@ impl RemotePlugin {
 
 impl Default for RemotePlugin {
     fn default() -> Self {
-        Self::empty()
+        let plugin = Self::empty()
             .with_method(
                 builtin_methods::BRP_GET_METHOD,
                 builtin_methods::process_remote_get_request,
@@ -624,6 +727,10 @@ impl Default for RemotePlugin {
                 builtin_methods::BRP_LIST_AND_WATCH_METHOD,
                 builtin_methods::process_remote_list_watching_request,
             )
+            .with_watching_method(
+                builtin_methods::BRP_QUERY_AND_WATCH_METHOD,
+                builtin_methods::process_remote_query_watching_request,
+            )
             .with_method(
                 builtin_methods::BRP_GET_RESOURCE_METHOD,
                 builtin_methods::process_remote_get_resource_request,
@@ -648,6 +755,28 @@ impl Default for RemotePlugin {
                 builtin_methods::BRP_REGISTRY_SCHEMA_METHOD,
                 builtin_methods::export_registry_types,
             )
+            .with_method(
+                builtin_methods::BRP_SCHEDULE_GRAPH_METHOD,
+                builtin_methods::process_remote_schedule_graph_request,
+            )
+            .with_method(
+                builtin_methods::BRP_RUN_SYSTEM_METHOD,
+                builtin_methods::process_remote_run_system_request,
+            );
+
+        #[cfg(feature = "bevy_asset")]
+        let plugin = plugin.with_method(
+            builtin_methods::BRP_ASSET_GRAPH_METHOD,
+            builtin_methods::process_remote_asset_graph_request,
+        );
+
+        #[cfg(feature = "reflect_functions")]
+        let plugin = plugin.with_method(
+            builtin_methods::BRP_CALL_FUNCTION_METHOD,
+            builtin_methods::process_remote_call_function_request,
+        );
+
+        plugin
     }
 }
 
@@ -975,6 +1104,76 @@ impl BrpError {
             data: None,
         }
     }
+
+    /// Schedule was not present in the world.
+    #[must_use]
+    pub fn schedule_not_present(schedule: &str) -> Self {
+        Self {
+            code: error_codes::SCHEDULE_NOT_PRESENT,
+            message: format!("Schedule `{schedule}` not present in the world"),
+            data: None,
+        }
+    }
+
+    /// An arbitrary schedule error.
+    #[must_use]
+    pub fn schedule_error<E: ToString>(error: E) -> Self {
+        Self {
+            code: error_codes::SCHEDULE_ERROR,
+            message: error.to_string(),
+            data: None,
+        }
+    }
+
+    /// One-shot system was not registered in the world.
+    #[must_use]
+    pub fn system_not_present(system: &str) -> Self {
+        Self {
+            code: error_codes::SYSTEM_NOT_PRESENT,
+            message: format!("System `{system}` not registered in the world"),
+            data: None,
+        }
+    }
+
+    /// An arbitrary one-shot system error.
+    #[must_use]
+    pub fn system_error<E: ToString>(error: E) -> Self {
+        Self {
+            code: error_codes::SYSTEM_ERROR,
+            message: error.to_string(),
+            data: None,
+        }
+    }
+
+    /// Function was not present in the function registry.
+    #[must_use]
+    pub fn function_not_present(function: &str) -> Self {
+        Self {
+            code: error_codes::FUNCTION_NOT_PRESENT,
+            message: format!("Function `{function}` not present in the function registry"),
+            data: None,
+        }
+    }
+
+    /// An arbitrary function error.
+    #[must_use]
+    pub fn function_error<E: ToString>(error: E) -> Self {
+        Self {
+            code: error_codes::FUNCTION_ERROR,
+            message: error.to_string(),
+            data: None,
+        }
+    }
+
+    /// Asset was not tracked by the asset server.
+    #[must_use]
+    pub fn asset_not_present(asset: &str) -> Self {
+        Self {
+            code: error_codes::ASSET_NOT_PRESENT,
+            message: format!("Asset `{asset}` not present in the asset server"),
+            data: None,
+        }
+    }
 }
 
 /// Error codes used by BRP.
@@ -999,6 +1198,15 @@ pub mod error_codes {
 
     // Bevy errors (i.e. application errors)
 
+    /// The request did not carry a valid bearer token.
+    pub const UNAUTHORIZED: i16 = -23101;
+
+    /// The bearer token of the request does not allow calling the method.
+    pub const METHOD_NOT_ALLOWED: i16 = -23102;
+
+    /// The request was sent from an origin that is not allowed.
+    pub const ORIGIN_NOT_ALLOWED: i16 = -23103;
+
     /// Entity not found.
     pub const ENTITY_NOT_FOUND: i16 = -23401;
 
@@ -1016,6 +1224,27 @@ pub mod error_codes {
 
     /// Could not find resource in the world.
     pub const RESOURCE_NOT_PRESENT: i16 = -23502;
+
+    /// Could not find schedule in the world.
+    pub const SCHEDULE_NOT_PRESENT: i16 = -23601;
+
+    /// Could not inspect schedule.
+    pub const SCHEDULE_ERROR: i16 = -23602;
+
+    /// Could not find one-shot system in the world.
+    pub const SYSTEM_NOT_PRESENT: i16 = -23701;
+
+    /// Could not run one-shot system.
+    pub const SYSTEM_ERROR: i16 = -23702;
+
+    /// Could not find function in the function registry.
+    pub const FUNCTION_NOT_PRESENT: i16 = -23801;
+
+    /// Could not call function or serialize its result.
+    pub const FUNCTION_ERROR: i16 = -23802;
+
+    /// Could not find asset in the asset server.
+    pub const ASSET_NOT_PRESENT: i16 = -23901;
 }
 
 /// The result of a request.
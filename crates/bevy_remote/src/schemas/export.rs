@@ -0,0 +1,655 @@
+//! Module exporting the types of a [`TypeRegistry`] as JSON Schema and TypeScript definitions,
+//! for tools working with the data of an app without connecting to it.
+use alloc::collections::{BTreeMap, VecDeque};
+use bevy_ecs::reflect::{ReflectComponent, ReflectResource};
+use bevy_platform::collections::{HashMap, HashSet};
+use bevy_reflect::{
+    prelude::ReflectDefault,
+    serde::{ReflectVersion, SerializationData, TypedReflectSerializer},
+    EnumInfo, NamedField, Type, TypeData, TypeInfo, TypeRegistration, TypeRegistry, UnnamedField,
+    VariantInfo,
+};
+use core::{any::TypeId, fmt::Write};
+use serde_json::{json, Value};
+
+use crate::schemas::{
+    json_schema::{export_type, SchemaJsonType, SchemaType},
+    SchemaTypesMetadata,
+};
+
+/// A set of registered types to export as a JSON Schema document or as TypeScript declarations.
+///
+/// The export contains the given types and all the registered types they reference,
+/// such as the types of their fields.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::reflect::AppTypeRegistry;
+/// # use bevy_remote::schemas::{export::TypeExport, SchemaTypesMetadata};
+/// let registry = AppTypeRegistry::default();
+/// let registry = registry.read();
+/// let metadata = SchemaTypesMetadata::default();
+/// let export = TypeExport::components_and_resources(&registry, &metadata);
+///
+/// let schema = serde_json::to_string_pretty(&export.json_schema()).unwrap();
+/// let declarations = export.typescript();
+/// ```
+pub struct TypeExport<'a> {
+    registry: &'a TypeRegistry,
+    metadata: &'a SchemaTypesMetadata,
+    roots: Vec<&'a TypeRegistration>,
+    types: Vec<&'a TypeRegistration>,
+}
+
+impl<'a> TypeExport<'a> {
+    /// Creates an export of the given types and of the types they reference.
+    ///
+    /// Types that are not registered are skipped.
+    pub fn new(
+        registry: &'a TypeRegistry,
+        metadata: &'a SchemaTypesMetadata,
+        type_ids: impl IntoIterator<Item = TypeId>,
+    ) -> Self {
+        let mut roots = type_ids
+            .into_iter()
+            .filter_map(|type_id| registry.get(type_id))
+            .collect::<Vec<_>>();
+        roots.sort_by_key(|registration| registration.type_info().type_path());
+        roots.dedup_by_key(|registration| registration.type_id());
+
+        let mut visited = roots
+            .iter()
+            .map(|registration| registration.type_id())
+            .collect::<HashSet<_>>();
+        let mut queue = roots.iter().copied().collect::<VecDeque<_>>();
+        let mut types = Vec::new();
+        while let Some(registration) = queue.pop_front() {
+            types.push(registration);
+            for type_id in referenced_types(registration.type_info()) {
+                if visited.insert(type_id)
+                    && let Some(registration) = registry.get(type_id)
+                {
+                    queue.push_back(registration);
+                }
+            }
+        }
+        types.sort_by_key(|registration| registration.type_info().type_path());
+
+        Self {
+            registry,
+            metadata,
+            roots,
+            types,
+        }
+    }
+
+    /// Creates an export of the registered components and resources, and of the types they reference.
+    pub fn components_and_resources(
+        registry: &'a TypeRegistry,
+        metadata: &'a SchemaTypesMetadata,
+    ) -> Self {
+        let type_ids = registry
+            .iter()
+            .filter(|registration| {
+                registration.contains::<ReflectComponent>()
+                    || registration.contains::<ReflectResource>()
+            })
+            .map(TypeRegistration::type_id)
+            .collect::<Vec<_>>();
+        Self::new(registry, metadata, type_ids)
+    }
+
+    /// Returns an iterator over the exported types, ordered by type path.
+    pub fn types(&self) -> impl ExactSizeIterator<Item = &'a TypeRegistration> + '_ {
+        self.types.iter().copied()
+    }
+
+    /// Exports the types as a JSON Schema document, with the schema of each type in its `$defs`.
+    ///
+    /// The schemas are the same as the ones of the `bevy/registry/schema` method of the BRP,
+    /// with the `default` value of the types with [`ReflectDefault`].
+    pub fn json_schema(&self) -> Value {
+        let defs = self
+            .types
+            .iter()
+            .map(|registration| {
+                let (type_path, mut schema) = export_type(registration, self.metadata);
+                schema.default = self.default_value(registration);
+                (type_path, schema)
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "$defs": defs,
+        })
+    }
+
+    /// Exports the types as TypeScript declarations of the JSON data of their reflected serialization.
+    ///
+    /// Structs, tuple structs and enums are declared under their short type path,
+    /// or their full type path when the short path is ambiguous,
+    /// while the other types are written inline where they are used.
+    /// The declarations end with `Components` and `Resources` interfaces mapping the type paths of the exported
+    /// components and resources to their declarations.
+    pub fn typescript(&self) -> String {
+        TypeScriptWriter::new(self).write()
+    }
+
+    /// Serializes the default value of the type, if it has a [`ReflectDefault`].
+    fn default_value(&self, registration: &TypeRegistration) -> Option<Value> {
+        let default = registration.data::<ReflectDefault>()?.default();
+        let serializer = TypedReflectSerializer::new(default.as_partial_reflect(), self.registry);
+        serde_json::to_value(serializer).ok()
+    }
+}
+
+/// Returns the [`TypeId`]s of the types directly referenced by a type, such as the types of its fields.
+fn referenced_types(info: &TypeInfo) -> Vec<TypeId> {
+    match info {
+        TypeInfo::Struct(info) => info.iter().map(NamedField::type_id).collect(),
+        TypeInfo::TupleStruct(info) => info.iter().map(UnnamedField::type_id).collect(),
+        TypeInfo::Tuple(info) => info.iter().map(UnnamedField::type_id).collect(),
+        TypeInfo::List(info) => vec![info.item_ty().id()],
+        TypeInfo::Array(info) => vec![info.item_ty().id()],
+        TypeInfo::Map(info) => vec![info.key_ty().id(), info.value_ty().id()],
+        TypeInfo::Set(info) => vec![info.value_ty().id()],
+        TypeInfo::Enum(info) => info
+            .iter()
+            .flat_map(|variant| match variant {
+                VariantInfo::Struct(variant) => variant.iter().map(NamedField::type_id).collect(),
+                VariantInfo::Tuple(variant) => variant.iter().map(UnnamedField::type_id).collect(),
+                VariantInfo::Unit(_) => Vec::new(),
+            })
+            .collect(),
+        TypeInfo::Opaque(_) => Vec::new(),
+    }
+}
+
+/// Returns true if the enum is an [`Option`], which is serialized as its value or `null`.
+fn is_option(info: &EnumInfo) -> bool {
+    info.type_path_table().module_path() == Some("core::option")
+        && info.type_path_table().ident() == Some("Option")
+}
+
+/// Writes the TypeScript declarations of a [`TypeExport`].
+struct TypeScriptWriter<'a, 'b> {
+    export: &'b TypeExport<'a>,
+    names: HashMap<TypeId, String>,
+    output: String,
+}
+
+impl<'a, 'b> TypeScriptWriter<'a, 'b> {
+    fn new(export: &'b TypeExport<'a>) -> Self {
+        let declared = export
+            .types
+            .iter()
+            .filter(|registration| match registration.type_info() {
+                TypeInfo::Struct(_) | TypeInfo::TupleStruct(_) => true,
+                TypeInfo::Enum(info) => !is_option(info),
+                _ => false,
+            })
+            .collect::<Vec<_>>();
+
+        let mut short_names = HashMap::<String, usize>::default();
+        for registration in &declared {
+            *short_names
+                .entry(identifier(
+                    registration.type_info().type_path_table().short_path(),
+                ))
+                .or_default() += 1;
+        }
+        let names = declared
+            .iter()
+            .map(|registration| {
+                let table = registration.type_info().type_path_table();
+                let short_name = identifier(table.short_path());
+                let name = if short_names[&short_name] > 1 {
+                    identifier(table.path())
+                } else {
+                    short_name
+                };
+                (registration.type_id(), name)
+            })
+            .collect();
+
+        Self {
+            export,
+            names,
+            output: String::new(),
+        }
+    }
+
+    fn write(mut self) -> String {
+        self.output
+            .push_str("// Generated from the Bevy type registry. Do not edit.\n");
+
+        if self
+            .export
+            .types
+            .iter()
+            .any(|registration| registration.contains::<ReflectVersion>())
+        {
+            self.output.push_str(
+                "\n/** A value of a versioned type, serialized along with its version. */\n\
+                export type Versioned<T> = { \"@version\": number; \"@data\": T };\n",
+            );
+        }
+
+        for registration in &self.export.types {
+            if self.names.contains_key(&registration.type_id()) {
+                self.write_declaration(registration);
+            }
+        }
+
+        self.write_interface::<ReflectComponent>("Components");
+        self.write_interface::<ReflectResource>("Resources");
+
+        self.output
+    }
+
+    fn write_declaration(&mut self, registration: &TypeRegistration) {
+        let info = registration.type_info();
+        let name = &self.names[&registration.type_id()];
+        let serialization_data = registration.data::<SerializationData>();
+        let is_skipped =
+            |index| serialization_data.is_some_and(|data| data.is_field_skipped(index));
+
+        self.output.push('\n');
+        #[cfg(feature = "documentation")]
+        let docs = info.docs();
+        #[cfg(not(feature = "documentation"))]
+        let docs: Option<&str> = None;
+        let default = self.export.default_value(registration);
+        write_doc_comment(&mut self.output, "", docs, default.as_ref());
+
+        let declaration = match info {
+            TypeInfo::Struct(info) => {
+                let mut declaration = format!("export interface {name} {{\n");
+                for (index, field) in info.iter().enumerate() {
+                    if is_skipped(index) {
+                        continue;
+                    }
+                    #[cfg(feature = "documentation")]
+                    write_doc_comment(&mut declaration, "  ", field.docs(), None);
+                    let _ = writeln!(
+                        declaration,
+                        "  {}: {};",
+                        property_name(field.name()),
+                        self.reference(field.ty())
+                    );
+                }
+                declaration.push_str("}\n");
+                declaration
+            }
+            TypeInfo::TupleStruct(info) => {
+                let fields = info
+                    .iter()
+                    .filter(|field| !is_skipped(field.index()))
+                    .map(|field| self.reference(field.ty()))
+                    .collect::<Vec<_>>();
+                // Tuple structs with a single field are serialized as their field
+                if info.field_len() == 1 && serialization_data.is_none() {
+                    format!("export type {name} = {};\n", fields[0])
+                } else {
+                    format!("export type {name} = [{}];\n", fields.join(", "))
+                }
+            }
+            TypeInfo::Enum(info) => {
+                let mut declaration = format!("export type {name} =\n");
+                for variant in info.iter() {
+                    let value = match variant {
+                        VariantInfo::Unit(variant) => format!("{:?}", variant.name()),
+                        VariantInfo::Tuple(variant) => {
+                            let fields = variant
+                                .iter()
+                                .map(|field| self.reference(field.ty()))
+                                .collect::<Vec<_>>();
+                            let value = if fields.len() == 1 {
+                                fields[0].clone()
+                            } else {
+                                format!("[{}]", fields.join(", "))
+                            };
+                            format!("{{ {}: {value} }}", property_name(variant.name()))
+                        }
+                        VariantInfo::Struct(variant) => {
+                            let fields = variant
+                                .iter()
+                                .map(|field| {
+                                    format!(
+                                        "{}: {}",
+                                        property_name(field.name()),
+                                        self.reference(field.ty())
+                                    )
+                                })
+                                .collect::<Vec<_>>();
+                            format!(
+                                "{{ {}: {{ {} }} }}",
+                                property_name(variant.name()),
+                                fields.join("; ")
+                            )
+                        }
+                    };
+                    #[cfg(feature = "documentation")]
+                    write_doc_comment(&mut declaration, "  ", variant.docs(), None);
+                    let _ = writeln!(declaration, "  | {value}");
+                }
+                if info.variant_len() == 0 {
+                    declaration.push_str("  never");
+                } else {
+                    declaration.pop();
+                }
+                declaration.push_str(";\n");
+                declaration
+            }
+            _ => unreachable!("only structs, tuple structs and enums are declared"),
+        };
+        self.output.push_str(&declaration);
+    }
+
+    /// Writes an interface mapping the type paths of the exported types with the type data `D` to their types.
+    fn write_interface<D: TypeData>(&mut self, name: &str) {
+        let _ = write!(self.output, "\nexport interface {name} {{\n");
+        for registration in &self.export.roots {
+            if registration.contains::<D>() {
+                let info = registration.type_info();
+                let reference = self.reference(info.ty());
+                let _ = writeln!(self.output, "  {:?}: {reference};", info.type_path());
+            }
+        }
+        self.output.push_str("}\n");
+    }
+
+    /// Returns the TypeScript type of the JSON data of the given type where it is used.
+    fn reference(&self, ty: &Type) -> String {
+        let Some(registration) = self.export.registry.get(ty.id()) else {
+            return String::from("unknown");
+        };
+        let reference = match registration.type_info() {
+            TypeInfo::Opaque(info) => match info.map_json_type() {
+                SchemaType::Boolean => "boolean",
+                SchemaType::Float | SchemaType::Int | SchemaType::Uint => "number",
+                SchemaType::String => "string",
+                _ => "unknown",
+            }
+            .into(),
+            TypeInfo::List(info) => array(&self.reference(&info.item_ty())),
+            TypeInfo::Array(info) => array(&self.reference(&info.item_ty())),
+            TypeInfo::Set(info) => array(&self.reference(&info.value_ty())),
+            TypeInfo::Map(info) => format!("Record<string, {}>", self.reference(&info.value_ty())),
+            TypeInfo::Tuple(info) => format!(
+                "[{}]",
+                info.iter()
+                    .map(|field| self.reference(field.ty()))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            TypeInfo::Enum(info) if is_option(info) => match info.variant("Some") {
+                Some(VariantInfo::Tuple(variant)) if variant.field_len() == 1 => {
+                    format!(
+                        "{} | null",
+                        self.reference(variant.field_at(0).unwrap().ty())
+                    )
+                }
+                _ => "unknown".into(),
+            },
+            _ => self.names[&ty.id()].clone(),
+        };
+        if registration.contains::<ReflectVersion>() {
+            format!("Versioned<{reference}>")
+        } else {
+            reference
+        }
+    }
+}
+
+/// Writes a doc comment with the given docs and default value, if there is either.
+fn write_doc_comment(
+    output: &mut String,
+    indent: &str,
+    docs: Option<&str>,
+    default: Option<&Value>,
+) {
+    if docs.is_none() && default.is_none() {
+        return;
+    }
+    let _ = writeln!(output, "{indent}/**");
+    #[cfg(feature = "documentation")]
+    if let Some(docs) = docs {
+        for line in super::json_schema::description(docs).lines() {
+            if line.is_empty() {
+                let _ = writeln!(output, "{indent} *");
+            } else {
+                let _ = writeln!(output, "{indent} * {line}");
+            }
+        }
+        if default.is_some() {
+            let _ = writeln!(output, "{indent} *");
+        }
+    }
+    #[cfg(not(feature = "documentation"))]
+    let _ = docs;
+    if let Some(default) = default {
+        let _ = writeln!(output, "{indent} * @default {default}");
+    }
+    let _ = writeln!(output, "{indent} */");
+}
+
+/// Returns the TypeScript type of an array of the given item type.
+fn array(item: &str) -> String {
+    if item.contains(' ') {
+        format!("({item})[]")
+    } else {
+        format!("{item}[]")
+    }
+}
+
+/// Returns the name of a property, quoted if it is not a valid identifier.
+fn property_name(name: &str) -> String {
+    if is_identifier(name) {
+        name.into()
+    } else {
+        format!("{name:?}")
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    name.chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Converts a type path to a TypeScript identifier, replacing the characters that are not allowed by underscores.
+fn identifier(type_path: &str) -> String {
+    let mut identifier = String::with_capacity(type_path.len());
+    for c in type_path.chars() {
+        if c.is_ascii_alphanumeric() {
+            identifier.push(c);
+        } else if !identifier.ends_with('_') {
+            identifier.push('_');
+        }
+    }
+    let identifier = identifier.trim_matches('_');
+    if identifier.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{identifier}")
+    } else {
+        identifier.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{component::Component, reflect::AppTypeRegistry, resource::Resource};
+    use bevy_reflect::{Reflect, TypePath};
+
+    #[derive(Reflect, Component, Default)]
+    #[reflect(Component, Default)]
+    struct Health {
+        current: f32,
+        max: Option<u32>,
+    }
+
+    #[derive(Reflect, Component)]
+    #[reflect(Component)]
+    enum Shape {
+        Point,
+        Circle(f32),
+        Rect { width: f32, height: f32 },
+    }
+
+    #[derive(Reflect, Resource)]
+    #[reflect(Resource)]
+    struct Names(Vec<String>);
+
+    #[derive(Reflect)]
+    struct Unexported;
+
+    fn registry() -> AppTypeRegistry {
+        let registry = AppTypeRegistry::default();
+        {
+            let mut registry = registry.write();
+            registry.register::<Health>();
+            registry.register::<Shape>();
+            registry.register::<Names>();
+            registry.register::<Unexported>();
+        }
+        registry
+    }
+
+    #[test]
+    fn export_components_and_resources() {
+        let registry = registry();
+        let registry = registry.read();
+        let metadata = SchemaTypesMetadata::default();
+        let export = TypeExport::components_and_resources(&registry, &metadata);
+
+        let paths = export
+            .types()
+            .map(|registration| registration.type_info().type_path())
+            .collect::<Vec<_>>();
+        assert!(paths.contains(&Health::type_path()));
+        assert!(paths.contains(&Shape::type_path()));
+        assert!(paths.contains(&Names::type_path()));
+        // Referenced by the fields of the exported types
+        assert!(paths.contains(&Option::<u32>::type_path()));
+        assert!(paths.contains(&Vec::<String>::type_path()));
+        assert!(!paths.contains(&Unexported::type_path()));
+        assert!(paths.is_sorted());
+    }
+
+    #[test]
+    fn export_json_schema() {
+        let registry = registry();
+        let registry = registry.read();
+        let metadata = SchemaTypesMetadata::default();
+        let schema = TypeExport::components_and_resources(&registry, &metadata).json_schema();
+
+        let health = &schema["$defs"][Health::type_path()];
+        assert_eq!(health["shortPath"], "Health");
+        assert_eq!(health["default"], json!({ "current": 0.0, "max": null }));
+        assert!(schema["$defs"][Shape::type_path()].get("default").is_none());
+        assert_eq!(
+            schema["$defs"][Shape::type_path()]["oneOf"]
+                .as_array()
+                .map(Vec::len),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn export_typescript() {
+        let registry = registry();
+        let registry = registry.read();
+        let metadata = SchemaTypesMetadata::default();
+        let typescript = TypeExport::components_and_resources(&registry, &metadata).typescript();
+
+        assert!(typescript.contains(
+            "/**\n * @default {\"current\":0.0,\"max\":null}\n */\n\
+            export interface Health {\n  current: number;\n  max: number | null;\n}\n"
+        ));
+        assert!(typescript.contains(
+            "export type Shape =\n  | \"Point\"\n  | { Circle: number }\n  | { Rect: { width: number; height: number } };\n"
+        ));
+        assert!(typescript.contains("export type Names = string[];\n"));
+        assert!(typescript.contains(&format!(
+            "export interface Components {{\n  {:?}: Health;\n  {:?}: Shape;\n}}\n",
+            Health::type_path(),
+            Shape::type_path()
+        )));
+        assert!(typescript.contains(&format!(
+            "export interface Resources {{\n  {:?}: Names;\n}}\n",
+            Names::type_path()
+        )));
+        assert!(!typescript.contains("Unexported"));
+        assert!(!typescript.contains("Versioned"));
+    }
+
+    #[cfg(feature = "documentation")]
+    #[test]
+    fn export_documentation() {
+        /// The speed of an entity.
+        ///
+        /// In meters per second.
+        #[derive(Reflect, Component)]
+        #[reflect(Component)]
+        struct Speed {
+            /// The maximum speed.
+            max: f32,
+        }
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<Speed>();
+        let metadata = SchemaTypesMetadata::default();
+        let export = TypeExport::components_and_resources(&registry, &metadata);
+
+        assert_eq!(
+            export.json_schema()["$defs"][Speed::type_path()]["description"],
+            "The speed of an entity.\n\nIn meters per second."
+        );
+        assert!(export.typescript().contains(
+            "/**\n * The speed of an entity.\n *\n * In meters per second.\n */\n\
+            export interface Speed {\n  /**\n   * The maximum speed.\n   */\n  max: number;\n}\n"
+        ));
+    }
+
+    #[test]
+    fn ambiguous_short_paths() {
+        mod a {
+            use bevy_reflect::Reflect;
+
+            #[derive(Reflect)]
+            pub struct Position(pub f32);
+        }
+        mod b {
+            use bevy_reflect::Reflect;
+
+            #[derive(Reflect)]
+            pub struct Position(pub f32, pub f32);
+        }
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<a::Position>();
+        registry.register::<b::Position>();
+        let metadata = SchemaTypesMetadata::default();
+        let typescript = TypeExport::new(
+            &registry,
+            &metadata,
+            [TypeId::of::<a::Position>(), TypeId::of::<b::Position>()],
+        )
+        .typescript();
+
+        assert!(typescript.contains(&format!(
+            "export type {} = number;\n",
+            identifier(a::Position::type_path())
+        )));
+        assert!(typescript.contains(&format!(
+            "export type {} = [number, number];\n",
+            identifier(b::Position::type_path())
+        )));
+    }
+}
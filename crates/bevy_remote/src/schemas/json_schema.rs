@@ -3,8 +3,8 @@
 use alloc::borrow::Cow;
 use bevy_platform::collections::HashMap;
 use bevy_reflect::{
-    GetTypeRegistration, NamedField, OpaqueInfo, TypeInfo, TypeRegistration, TypeRegistry,
-    VariantInfo,
+    serde::ReflectVersion, GetTypeRegistration, NamedField, OpaqueInfo, TypeInfo, TypeRegistration,
+    TypeRegistry, VariantInfo,
 };
 use core::any::TypeId;
 use serde::{Deserialize, Serialize};
@@ -62,13 +62,19 @@ impl From<(&TypeRegistration, &SchemaTypesMetadata)> for JsonSchemaBevyType {
             type_path: type_path.to_owned(),
             crate_name: binding.crate_name().map(str::to_owned),
             module_path: binding.module_path().map(str::to_owned),
+            version: reg.data::<ReflectVersion>().map(ReflectVersion::version),
+            #[cfg(feature = "documentation")]
+            description: t.docs().map(description),
             ..Default::default()
         };
         match t {
             TypeInfo::Struct(info) => {
                 typed_schema.properties = info
                     .iter()
-                    .map(|field| (field.name().to_owned(), field.ty().ref_type()))
+                    .map(|field| {
+                        let schema = with_field_description(field.ty().ref_type(), field);
+                        (field.name().to_owned(), schema)
+                    })
                     .collect::<HashMap<_, _>>();
                 typed_schema.required = info
                     .iter()
@@ -98,7 +104,12 @@ impl From<(&TypeRegistration, &SchemaTypesMetadata)> for JsonSchemaBevyType {
                     typed_schema.schema_type = SchemaType::Object;
                     typed_schema.one_of = info
                 .iter()
-                .map(|variant| match variant {
+                .map(|variant| {
+                    #[cfg_attr(
+                        not(feature = "documentation"),
+                        expect(unused_mut, reason = "only documented variants are modified")
+                    )]
+                    let mut schema = match variant {
                     VariantInfo::Struct(v) => json!({
                         "type": "object",
                         "kind": "Struct",
@@ -106,7 +117,10 @@ impl From<(&TypeRegistration, &SchemaTypesMetadata)> for JsonSchemaBevyType {
                         "shortPath": v.name(),
                         "properties": v
                             .iter()
-                            .map(|field| (field.name().to_owned(), field.ref_type()))
+                            .map(|field| {
+                                let schema = with_field_description(field.ref_type(), field);
+                                (field.name().to_owned(), schema)
+                            })
                             .collect::<Map<_, _>>(),
                         "additionalProperties": false,
                         "required": v
@@ -130,6 +144,12 @@ impl From<(&TypeRegistration, &SchemaTypesMetadata)> for JsonSchemaBevyType {
                         "typePath": format!("{}::{}", type_path, v.name()),
                         "shortPath": v.name(),
                     }),
+                    };
+                    #[cfg(feature = "documentation")]
+                    if let Some(docs) = variant.docs() {
+                        schema["description"] = description(docs).into();
+                    }
+                    schema
                 })
                 .collect::<Vec<_>>();
                 }
@@ -202,6 +222,12 @@ pub struct JsonSchemaBevyType {
     /// Bevy specific field, names of the types that type reflects.
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub reflect_types: Vec<String>,
+    /// Bevy specific field, the version of the type, if it has a [`ReflectVersion`].
+    ///
+    /// Versioned types are serialized as an object with their version under `"@version"`
+    /// and their data under `"@data"`.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub version: Option<u32>,
     /// Bevy specific field, [`TypeInfo`] type mapping.
     pub kind: SchemaKind,
     /// Bevy specific field, provided when [`SchemaKind`] `kind` field is equal to [`SchemaKind::Map`].
@@ -217,6 +243,19 @@ pub struct JsonSchemaBevyType {
     /// The type keyword is fundamental to JSON Schema. It specifies the data type for a schema.
     #[serde(rename = "type")]
     pub schema_type: SchemaType,
+    /// A description of the purpose of the instance described by this schema.
+    ///
+    /// Read from the doc comments of the type when the `documentation` feature is enabled.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description: Option<String>,
+    /// A default value of the instance described by this schema.
+    ///
+    /// Not set by [`export_type`], which has no access to the [`TypeRegistry`] needed to serialize the value.
+    /// See [`TypeExport`](super::export::TypeExport) for an export of the defaults of the types with [`ReflectDefault`].
+    ///
+    /// [`ReflectDefault`]: bevy_reflect::std_traits::ReflectDefault
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub default: Option<Value>,
     /// The behavior of this keyword depends on the presence and annotation results of "properties"
     /// and "patternProperties" within the same schema object.
     /// Validation with "additionalProperties" applies only to the child
@@ -357,6 +396,34 @@ impl SchemaJsonReference for &bevy_reflect::UnnamedField {
     }
 }
 
+/// Adds the description of a named field to its schema.
+#[cfg_attr(
+    not(feature = "documentation"),
+    expect(
+        unused_mut,
+        unused_variables,
+        reason = "only documented fields are described"
+    )
+)]
+fn with_field_description(mut schema: Value, field: &NamedField) -> Value {
+    #[cfg(feature = "documentation")]
+    if let Some(docs) = field.docs() {
+        schema["description"] = description(docs).into();
+    }
+    schema
+}
+
+/// Converts doc comments to a description, removing the leading space of each line.
+#[cfg(feature = "documentation")]
+pub(crate) fn description(docs: &str) -> String {
+    docs.lines()
+        .map(|line| line.strip_prefix(' ').unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_owned()
+}
+
 impl SchemaJsonReference for &NamedField {
     fn ref_type(self) -> Value {
         let type_path = self.type_path();
@@ -0,0 +1,739 @@
+//! The BRP transport using JSON-RPC over a WebSocket.
+//!
+//! Adding the [`RemoteWebSocketPlugin`] to your [`App`] causes Bevy to accept
+//! WebSocket connections (by default, on port 15703) while your app is running.
+//!
+//! Unlike the HTTP transport, a single connection carries any number of requests: clients send
+//! requests (or batches of requests) as text messages, and the server answers each of them with a
+//! text message carrying the same `id`, in the order the responses become available. Watching
+//! requests (`+watch` methods) keep sending responses with their `id` until they are cancelled with
+//! a [`bevy/cancel`](BRP_CANCEL_METHOD) request or the connection is closed.
+//!
+//! The same [`HttpAccessControl`] as the HTTP transport restricts who may connect: the bearer token
+//! and `Origin` header are checked during the WebSocket handshake, and the methods allowed for the
+//! token are checked for every request sent over the connection.
+
+#![cfg(not(target_family = "wasm"))]
+
+use crate::{
+    error_codes,
+    http::{HttpAccessControl, MethodAccess},
+    BrpBatch, BrpError, BrpMessage, BrpRequest, BrpResponse, BrpSender,
+};
+use alloc::sync::Arc;
+use async_channel::{Receiver, Sender};
+use async_io::Async;
+use async_tungstenite::{
+    tungstenite::{
+        handshake::server::{ErrorResponse, Request, Response},
+        http::{header, StatusCode},
+        Message,
+    },
+    WebSocketStream,
+};
+use bevy_app::{App, Plugin, Startup};
+use bevy_ecs::{
+    resource::Resource,
+    system::{Res, ResMut},
+};
+use bevy_log::error;
+use bevy_tasks::{futures_lite::StreamExt, IoTaskPool};
+use core::net::{IpAddr, Ipv4Addr};
+use serde::Deserialize;
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    net::{TcpListener, TcpStream},
+    sync::Mutex,
+};
+
+/// The default port that Bevy will listen on for WebSocket connections.
+///
+/// This is the port after the default HTTP port, so both transports can be used at the same time.
+pub const DEFAULT_PORT: u16 = 15703;
+
+/// The default host address that Bevy will use for its WebSocket server.
+pub const DEFAULT_ADDR: IpAddr = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1));
+
+/// The method path of a `bevy/cancel` request.
+///
+/// This request is handled by the WebSocket transport itself: it stops the ongoing request of the
+/// same connection whose `id` is given in its `params`, usually a watching request.
+///
+/// `params`:
+/// - `id`: The `id` of the request to cancel.
+///
+/// `result`: `null`.
+pub const BRP_CANCEL_METHOD: &str = "bevy/cancel";
+
+/// Add this plugin to your [`App`] to allow remote connections over a WebSocket to inspect and modify entities.
+/// It requires the [`RemotePlugin`](super::RemotePlugin).
+///
+/// This BRP transport cannot be used when targeting WASM.
+///
+/// The defaults are:
+/// - [`DEFAULT_ADDR`] : 127.0.0.1.
+/// - [`DEFAULT_PORT`] : 15703.
+///
+/// Using port `0` lets the operating system pick a free port, which can then be read from the
+/// [`WebSocketHostPort`] resource once the app has started.
+pub struct RemoteWebSocketPlugin {
+    /// The address that Bevy will bind to.
+    address: IpAddr,
+    /// The port that Bevy will listen on.
+    port: u16,
+    /// The authentication and origin restrictions applied to connections.
+    access_control: HttpAccessControl,
+}
+
+impl Default for RemoteWebSocketPlugin {
+    fn default() -> Self {
+        Self {
+            address: DEFAULT_ADDR,
+            port: DEFAULT_PORT,
+            access_control: HttpAccessControl::new(),
+        }
+    }
+}
+
+impl Plugin for RemoteWebSocketPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(WebSocketHostAddress(self.address))
+            .insert_resource(WebSocketHostPort(self.port))
+            .insert_resource(WebSocketAccessControl(self.access_control.clone()))
+            .add_systems(Startup, start_websocket_server);
+    }
+}
+
+impl RemoteWebSocketPlugin {
+    /// Set the IP address that the server will use.
+    #[must_use]
+    pub fn with_address(mut self, address: impl Into<IpAddr>) -> Self {
+        self.address = address.into();
+        self
+    }
+    /// Set the remote port that the server will listen on.
+    #[must_use]
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+    /// Set the authentication and origin restrictions applied to connections.
+    ///
+    /// See [`HttpAccessControl`] for an example.
+    #[must_use]
+    pub fn with_access_control(mut self, access_control: HttpAccessControl) -> Self {
+        self.access_control = access_control;
+        self
+    }
+    /// Require connections to carry a bearer token, and accept the given one with access to
+    /// the methods in `access`.
+    ///
+    /// See [`HttpAccessControl::with_token`].
+    #[must_use]
+    pub fn with_token(mut self, token: impl Into<String>, access: MethodAccess) -> Self {
+        self.access_control = self.access_control.with_token(token, access);
+        self
+    }
+    /// Only accept connections from the given origin and any other allowed ones.
+    ///
+    /// See [`HttpAccessControl::with_allowed_origin`].
+    #[must_use]
+    pub fn with_allowed_origin(mut self, origin: impl Into<String>) -> Self {
+        self.access_control = self.access_control.with_allowed_origin(origin);
+        self
+    }
+}
+
+/// A resource containing the IP address that Bevy will host the WebSocket server on.
+///
+/// Currently, changing this while the application is running has no effect; this merely
+/// reflects the IP address that is set during the setup of the [`RemoteWebSocketPlugin`].
+#[derive(Debug, Resource)]
+pub struct WebSocketHostAddress(pub IpAddr);
+
+/// A resource containing the port number that Bevy will listen on for WebSocket connections.
+///
+/// Once the server has started, this is the port it is actually bound to.
+#[derive(Debug, Resource)]
+pub struct WebSocketHostPort(pub u16);
+
+/// A resource containing the authentication and origin restrictions applied to connections.
+#[derive(Debug, Resource)]
+struct WebSocketAccessControl(pub HttpAccessControl);
+
+/// A system that starts up the Bevy Remote Protocol WebSocket server.
+fn start_websocket_server(
+    request_sender: Res<BrpSender>,
+    address: Res<WebSocketHostAddress>,
+    mut port: ResMut<WebSocketHostPort>,
+    access_control: Res<WebSocketAccessControl>,
+) {
+    let listener = match Async::<TcpListener>::bind((address.0, port.0)) {
+        Ok(listener) => listener,
+        Err(err) => {
+            error!(
+                "Failed to start the BRP WebSocket server on {}:{}: {err}",
+                address.0, port.0
+            );
+            return;
+        }
+    };
+    if let Ok(local_address) = listener.get_ref().local_addr() {
+        port.0 = local_address.port();
+    }
+
+    IoTaskPool::get()
+        .spawn(listen(
+            listener,
+            request_sender.clone(),
+            access_control.0.clone(),
+        ))
+        .detach();
+}
+
+#[expect(
+    clippy::result_large_err,
+    reason = "the handshake callback signature is defined by `tungstenite`"
+)]
+async fn listen(
+    listener: Async<TcpListener>,
+    request_sender: Sender<BrpMessage>,
+    access_control: HttpAccessControl,
+) {
+    while let Ok((client, _)) = listener.accept().await {
+        let request_sender = request_sender.clone();
+        let access_control = access_control.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                let mut method_access = None;
+                let handshake = async_tungstenite::accept_hdr_async(
+                    client,
+                    |request: &Request, response: Response| {
+                        method_access = Some(authorize_handshake(request, &access_control)?);
+                        Ok(response)
+                    },
+                )
+                .await;
+                if let (Ok(websocket), Some(method_access)) = (handshake, method_access) {
+                    handle_client(websocket, request_sender, method_access).await;
+                }
+            })
+            .detach();
+    }
+}
+
+/// Checks the `Origin` and `Authorization` headers of a WebSocket handshake, returning the
+/// methods the connection may call, or the HTTP response rejecting the connection.
+#[expect(
+    clippy::result_large_err,
+    reason = "the error is returned as is from the handshake callback"
+)]
+fn authorize_handshake(
+    request: &Request,
+    access_control: &HttpAccessControl,
+) -> Result<MethodAccess, ErrorResponse> {
+    let headers = request.headers();
+    if let Some(origin) = headers
+        .get(header::ORIGIN)
+        .and_then(|origin| origin.to_str().ok())
+        && !access_control.allows_origin(origin)
+    {
+        return Err(error_response(
+            StatusCode::FORBIDDEN,
+            BrpError {
+                code: error_codes::ORIGIN_NOT_ALLOWED,
+                message: format!("Connections from origin `{origin}` are not allowed"),
+                data: None,
+            },
+        ));
+    }
+
+    let authorization = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|authorization| authorization.to_str().ok());
+    let Some(method_access) = access_control.authorize(authorization) else {
+        let mut response = error_response(
+            StatusCode::UNAUTHORIZED,
+            BrpError {
+                code: error_codes::UNAUTHORIZED,
+                message: String::from("Missing or invalid bearer token"),
+                data: None,
+            },
+        );
+        response.headers_mut().insert(
+            header::WWW_AUTHENTICATE,
+            header::HeaderValue::from_static("Bearer"),
+        );
+        return Err(response);
+    };
+    Ok(method_access.clone())
+}
+
+/// Builds a handshake response with the given HTTP status containing a single JSON-RPC error.
+fn error_response(status: StatusCode, error: BrpError) -> ErrorResponse {
+    let mut response =
+        ErrorResponse::new(serde_json::to_string(&BrpResponse::new(None, Err(error))).ok());
+    *response.status_mut() = status;
+    response.headers_mut().insert(
+        header::CONTENT_TYPE,
+        header::HeaderValue::from_static("application/json"),
+    );
+    response
+}
+
+/// The requests of a connection that are still waiting for responses, by their serialized `id`.
+///
+/// Closing the receiver of a request tells the [`RemotePlugin`](super::RemotePlugin) to stop processing it.
+type OngoingRequests = Arc<Mutex<HashMap<String, Receiver<crate::BrpResult>>>>;
+
+async fn handle_client(
+    websocket: WebSocketStream<Async<TcpStream>>,
+    request_sender: Sender<BrpMessage>,
+    method_access: MethodAccess,
+) {
+    let (mut websocket_sender, mut websocket_receiver) = websocket.split();
+
+    // Responses are produced concurrently by each request, and written by a single task.
+    let (response_sender, response_receiver) = async_channel::unbounded::<String>();
+    let writer = IoTaskPool::get().spawn(async move {
+        while let Ok(response) = response_receiver.recv().await {
+            if websocket_sender
+                .send(Message::text(response))
+                .await
+                .is_err()
+            {
+                break;
+            }
+        }
+        let _ = websocket_sender.close(None).await;
+    });
+
+    let ongoing = OngoingRequests::default();
+    while let Some(Ok(message)) = websocket_receiver.next().await {
+        let batch = match &message {
+            Message::Text(text) => serde_json::from_str::<BrpBatch>(text.as_str()),
+            Message::Binary(bytes) => serde_json::from_slice::<BrpBatch>(bytes),
+            Message::Close(_) => break,
+            _ => continue,
+        };
+        match batch {
+            Ok(BrpBatch::Single(request)) => {
+                process_request(
+                    request,
+                    &request_sender,
+                    &response_sender,
+                    &ongoing,
+                    &method_access,
+                )
+                .await;
+            }
+            Ok(BrpBatch::Batch(requests)) => {
+                for request in requests {
+                    process_request(
+                        request,
+                        &request_sender,
+                        &response_sender,
+                        &ongoing,
+                        &method_access,
+                    )
+                    .await;
+                }
+            }
+            Err(err) => {
+                send_response(
+                    &response_sender,
+                    BrpResponse::new(
+                        None,
+                        Err(BrpError {
+                            code: error_codes::PARSE_ERROR,
+                            message: err.to_string(),
+                            data: None,
+                        }),
+                    ),
+                );
+            }
+        }
+    }
+
+    // The client is gone: stop every ongoing request, including watching ones.
+    for (_, receiver) in ongoing.lock().unwrap().drain() {
+        receiver.close();
+    }
+    response_sender.close();
+    writer.await;
+}
+
+/// A helper function for the Bevy Remote Protocol WebSocket server that processes a single
+/// request coming from a client.
+///
+/// Responses are sent to `response_sender` as soon as they are available, without waiting for them here.
+async fn process_request(
+    request: Value,
+    request_sender: &Sender<BrpMessage>,
+    response_sender: &Sender<String>,
+    ongoing: &OngoingRequests,
+    method_access: &MethodAccess,
+) {
+    // Reach in and get the request ID early so that we can report it even when parsing fails.
+    let id = request.as_object().and_then(|map| map.get("id")).cloned();
+
+    let request: BrpRequest = match serde_json::from_value(request) {
+        Ok(v) => v,
+        Err(err) => {
+            send_response(
+                response_sender,
+                BrpResponse::new(
+                    id,
+                    Err(BrpError {
+                        code: error_codes::INVALID_REQUEST,
+                        message: err.to_string(),
+                        data: None,
+                    }),
+                ),
+            );
+            return;
+        }
+    };
+
+    if request.jsonrpc != "2.0" {
+        send_response(
+            response_sender,
+            BrpResponse::new(
+                id,
+                Err(BrpError {
+                    code: error_codes::INVALID_REQUEST,
+                    message: String::from("JSON-RPC request requires `\"jsonrpc\": \"2.0\"`"),
+                    data: None,
+                }),
+            ),
+        );
+        return;
+    }
+
+    if request.method == BRP_CANCEL_METHOD {
+        let result = cancel_request(request.params, ongoing);
+        send_response(response_sender, BrpResponse::new(request.id, result));
+        return;
+    }
+
+    if !method_access.allows(&request.method) {
+        send_response(
+            response_sender,
+            BrpResponse::new(
+                request.id,
+                Err(BrpError {
+                    code: error_codes::METHOD_NOT_ALLOWED,
+                    message: format!("Method `{}` is not allowed for this token", request.method),
+                    data: None,
+                }),
+            ),
+        );
+        return;
+    }
+
+    let key = request.id.as_ref().map(Value::to_string);
+    let watch = request.method.contains("+watch");
+    let size = if watch { 8 } else { 1 };
+    let (result_sender, result_receiver) = async_channel::bounded(size);
+
+    if let Some(key) = &key {
+        let mut ongoing = ongoing.lock().unwrap();
+        ongoing.retain(|_, receiver| !receiver.is_closed());
+        if ongoing.contains_key(key) {
+            drop(ongoing);
+            send_response(
+                response_sender,
+                BrpResponse::new(
+                    request.id,
+                    Err(BrpError {
+                        code: error_codes::INVALID_REQUEST,
+                        message: format!("A request with the id {key} is already ongoing"),
+                        data: None,
+                    }),
+                ),
+            );
+            return;
+        }
+        ongoing.insert(key.clone(), result_receiver.clone());
+    }
+
+    let _ = request_sender
+        .send(BrpMessage {
+            method: request.method,
+            params: request.params,
+            sender: result_sender,
+        })
+        .await;
+
+    let response_sender = response_sender.clone();
+    IoTaskPool::get()
+        .spawn(async move {
+            // Cancelling closes the channel, which ends this loop.
+            while let Ok(result) = result_receiver.recv().await {
+                send_response(
+                    &response_sender,
+                    BrpResponse::new(request.id.clone(), result),
+                );
+                if !watch {
+                    break;
+                }
+            }
+            result_receiver.close();
+        })
+        .detach();
+}
+
+/// The parameters of a [`bevy/cancel`](BRP_CANCEL_METHOD) request.
+#[derive(Deserialize)]
+struct BrpCancelParams {
+    id: Value,
+}
+
+/// Stops the ongoing request whose `id` is given in `params`.
+fn cancel_request(params: Option<Value>, ongoing: &OngoingRequests) -> crate::BrpResult {
+    let Some(BrpCancelParams { id }) =
+        params.and_then(|params| serde_json::from_value(params).ok())
+    else {
+        return Err(BrpError {
+            code: error_codes::INVALID_PARAMS,
+            message: String::from("`bevy/cancel` requires the `id` of the request to cancel"),
+            data: None,
+        });
+    };
+
+    let key = id.to_string();
+    match ongoing.lock().unwrap().remove(&key) {
+        Some(receiver) if !receiver.is_closed() => {
+            receiver.close();
+            Ok(Value::Null)
+        }
+        _ => Err(BrpError {
+            code: error_codes::INVALID_PARAMS,
+            message: format!("No ongoing request with the id {key}"),
+            data: None,
+        }),
+    }
+}
+
+fn send_response(response_sender: &Sender<String>, response: BrpResponse) {
+    if let Ok(serialized) = serde_json::to_string(&response) {
+        let _ = response_sender.try_send(serialized);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        builtin_methods::{BRP_LIST_METHOD, BRP_QUERY_METHOD},
+        BrpResult, RemotePlugin, RemoteWatchingRequests,
+    };
+    use async_tungstenite::tungstenite::{client::IntoClientRequest, http::HeaderValue, Error};
+    use bevy_app::TaskPoolPlugin;
+    use bevy_ecs::system::In;
+    use core::net::SocketAddr;
+    use core::time::Duration;
+    use serde_json::json;
+    use std::thread;
+
+    async fn send(websocket: &mut WebSocketStream<Async<TcpStream>>, request: Value) {
+        websocket
+            .send(Message::text(request.to_string()))
+            .await
+            .unwrap();
+    }
+
+    async fn receive(websocket: &mut WebSocketStream<Async<TcpStream>>) -> Value {
+        loop {
+            if let Message::Text(text) = websocket.next().await.unwrap().unwrap() {
+                return serde_json::from_str(text.as_str()).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn multiplexed_requests_and_cancellation() {
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            RemotePlugin::default().with_watching_method(
+                "test/frames+watch",
+                |In(_): In<Option<Value>>| -> BrpResult<Option<Value>> { Ok(Some(json!("frame"))) },
+            ),
+            RemoteWebSocketPlugin::default().with_port(0),
+        ));
+        app.update();
+        let address = SocketAddr::new(DEFAULT_ADDR, app.world().resource::<WebSocketHostPort>().0);
+
+        let client = thread::spawn(move || {
+            bevy_tasks::block_on(async {
+                let stream = Async::<TcpStream>::connect(address).await.unwrap();
+                let (mut websocket, _) =
+                    async_tungstenite::client_async(format!("ws://{address}"), stream)
+                        .await
+                        .unwrap();
+
+                // A watching request and an instant request on the same connection.
+                send(
+                    &mut websocket,
+                    json!([
+                        { "jsonrpc": "2.0", "id": "watch", "method": "test/frames+watch" },
+                        { "jsonrpc": "2.0", "id": 1, "method": "bevy/list" },
+                    ]),
+                )
+                .await;
+                let (mut watch_count, mut list_received) = (0, false);
+                while watch_count < 2 || !list_received {
+                    let response = receive(&mut websocket).await;
+                    if response["id"] == json!("watch") {
+                        assert_eq!(response["result"], json!("frame"));
+                        watch_count += 1;
+                    } else {
+                        assert_eq!(response["id"], json!(1));
+                        assert!(response["result"].is_array());
+                        list_received = true;
+                    }
+                }
+
+                send(
+                    &mut websocket,
+                    json!({
+                        "jsonrpc": "2.0",
+                        "id": 2,
+                        "method": BRP_CANCEL_METHOD,
+                        "params": { "id": "watch" },
+                    }),
+                )
+                .await;
+                loop {
+                    let response = receive(&mut websocket).await;
+                    if response["id"] == json!(2) {
+                        assert_eq!(response["result"], Value::Null);
+                        break;
+                    }
+                }
+
+                // The request is no longer ongoing.
+                send(
+                    &mut websocket,
+                    json!({
+                        "jsonrpc": "2.0",
+                        "id": 3,
+                        "method": BRP_CANCEL_METHOD,
+                        "params": { "id": "watch" },
+                    }),
+                )
+                .await;
+                loop {
+                    let response = receive(&mut websocket).await;
+                    if response["id"] == json!(3) {
+                        assert_eq!(
+                            response["error"]["code"],
+                            json!(error_codes::INVALID_PARAMS)
+                        );
+                        break;
+                    }
+                }
+            });
+        });
+
+        while !client.is_finished() {
+            app.update();
+            thread::sleep(Duration::from_millis(1));
+        }
+        client.join().unwrap();
+
+        // The cancelled watching request is cleaned up by the next update.
+        app.update();
+        assert!(app
+            .world()
+            .resource::<RemoteWatchingRequests>()
+            .0
+            .is_empty());
+    }
+
+    #[test]
+    fn access_control() {
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            RemotePlugin::default(),
+            RemoteWebSocketPlugin::default()
+                .with_port(0)
+                .with_token("reader", MethodAccess::only([BRP_LIST_METHOD]))
+                .with_allowed_origin("http://localhost:8080"),
+        ));
+        app.update();
+        let address = SocketAddr::new(DEFAULT_ADDR, app.world().resource::<WebSocketHostPort>().0);
+
+        let connect = move |authorization: Option<&'static str>, origin: &'static str| async move {
+            let stream = Async::<TcpStream>::connect(address).await.unwrap();
+            let mut request = format!("ws://{address}").into_client_request().unwrap();
+            request
+                .headers_mut()
+                .insert(header::ORIGIN, HeaderValue::from_static(origin));
+            if let Some(authorization) = authorization {
+                request.headers_mut().insert(
+                    header::AUTHORIZATION,
+                    HeaderValue::from_static(authorization),
+                );
+            }
+            async_tungstenite::client_async(request, stream).await
+        };
+        let rejection = move |authorization, origin| async move {
+            let Err(Error::Http(response)) = connect(authorization, origin).await else {
+                panic!("the handshake should be rejected");
+            };
+            let body: Value = serde_json::from_slice(response.body().as_ref().unwrap()).unwrap();
+            (response.status(), body["error"]["code"].clone())
+        };
+
+        let client = thread::spawn(move || {
+            bevy_tasks::block_on(async {
+                assert_eq!(
+                    (StatusCode::UNAUTHORIZED, json!(error_codes::UNAUTHORIZED)),
+                    rejection(None, "http://localhost:8080").await
+                );
+                assert_eq!(
+                    (StatusCode::UNAUTHORIZED, json!(error_codes::UNAUTHORIZED)),
+                    rejection(Some("Bearer unknown"), "http://localhost:8080").await
+                );
+                assert_eq!(
+                    (
+                        StatusCode::FORBIDDEN,
+                        json!(error_codes::ORIGIN_NOT_ALLOWED)
+                    ),
+                    rejection(Some("Bearer reader"), "http://example.com").await
+                );
+
+                let (mut websocket, _) = connect(Some("Bearer reader"), "http://localhost:8080")
+                    .await
+                    .unwrap();
+                send(
+                    &mut websocket,
+                    json!({ "jsonrpc": "2.0", "id": 1, "method": BRP_LIST_METHOD }),
+                )
+                .await;
+                assert!(receive(&mut websocket).await["result"].is_array());
+                send(
+                    &mut websocket,
+                    json!({ "jsonrpc": "2.0", "id": 2, "method": BRP_QUERY_METHOD }),
+                )
+                .await;
+                let response = receive(&mut websocket).await;
+                assert_eq!(response["id"], json!(2));
+                assert_eq!(
+                    response["error"]["code"],
+                    json!(error_codes::METHOD_NOT_ALLOWED)
+                );
+            });
+        });
+
+        while !client.is_finished() {
+            app.update();
+            thread::sleep(Duration::from_millis(1));
+        }
+        client.join().unwrap();
+    }
+}
@@ -0,0 +1,290 @@
+use crate::{
+    serde::{SceneDeserializer, SceneSerializer},
+    DynamicScene, DynamicSceneSaverSettings, HandlePathDeserializer, HandlePathSerializer,
+};
+use bevy_asset::{
+    io::{Reader, Writer},
+    saver::{AssetSaver, SavedAsset},
+    AssetLoader, AsyncWriteExt, LoadContext,
+};
+use bevy_ecs::{
+    reflect::AppTypeRegistry,
+    world::{FromWorld, World},
+};
+use bevy_reflect::{
+    serde::{ReflectDeserializerProcessor, ReflectSerializerProcessor},
+    TypeRegistry, TypeRegistryArc,
+};
+use serde::de::DeserializeSeed;
+use thiserror::Error;
+
+/// Unique identifier for the binary scene format, written at the start of every file.
+pub const BINARY_SCENE_MAGIC: [u8; 8] = *b"BEVYSCN\0";
+
+/// The version of the binary scene format written by [`DynamicScene::serialize_binary`].
+///
+/// Files written with a different version are rejected by [`BinarySceneLoader`].
+pub const BINARY_SCENE_VERSION: u32 = 1;
+
+impl DynamicScene {
+    /// Serialize this dynamic scene into the compact binary scene format (`.scn.bin`).
+    ///
+    /// The file starts with [`BINARY_SCENE_MAGIC`] and [`BINARY_SCENE_VERSION`], followed by the
+    /// scene encoded with [`SceneSerializer`] through [`postcard`]. It is much smaller and faster
+    /// to load than the RON format, but is not human-readable and, like the RON format, relies on
+    /// the types in the scene being registered with the same reflected layout when loading it.
+    /// To deserialize the scene, use [`DynamicScene::deserialize_binary`] or the
+    /// [`BinarySceneLoader`].
+    pub fn serialize_binary(&self, registry: &TypeRegistry) -> Result<Vec<u8>, postcard::Error> {
+        self.serialize_binary_with_processor(registry, &())
+    }
+
+    fn serialize_binary_with_processor<P: ReflectSerializerProcessor>(
+        &self,
+        registry: &TypeRegistry,
+        processor: &P,
+    ) -> Result<Vec<u8>, postcard::Error> {
+        let mut bytes = Vec::with_capacity(BINARY_SCENE_MAGIC.len() + size_of::<u32>());
+        bytes.extend_from_slice(&BINARY_SCENE_MAGIC);
+        bytes.extend_from_slice(&BINARY_SCENE_VERSION.to_le_bytes());
+        postcard::to_extend(
+            &SceneSerializer::with_processor(self, registry, processor),
+            bytes,
+        )
+    }
+
+    /// Deserialize a dynamic scene from the binary scene format written by
+    /// [`DynamicScene::serialize_binary`].
+    pub fn deserialize_binary(
+        bytes: &[u8],
+        registry: &TypeRegistry,
+    ) -> Result<Self, BinarySceneLoaderError> {
+        Self::deserialize_binary_with_processor(bytes, registry, None::<&mut ()>)
+    }
+
+    fn deserialize_binary_with_processor<P: ReflectDeserializerProcessor>(
+        bytes: &[u8],
+        registry: &TypeRegistry,
+        processor: Option<&mut P>,
+    ) -> Result<Self, BinarySceneLoaderError> {
+        let Some(bytes) = bytes.strip_prefix(&BINARY_SCENE_MAGIC) else {
+            return Err(BinarySceneLoaderError::WrongFileType);
+        };
+        let Some((version, bytes)) = bytes.split_first_chunk::<{ size_of::<u32>() }>() else {
+            return Err(BinarySceneLoaderError::WrongFileType);
+        };
+        let version = u32::from_le_bytes(*version);
+        if version != BINARY_SCENE_VERSION {
+            return Err(BinarySceneLoaderError::WrongVersion { found: version });
+        }
+
+        let scene_deserializer = SceneDeserializer {
+            type_registry: registry,
+            processor,
+        };
+        Ok(scene_deserializer.deserialize(&mut postcard::Deserializer::from_bytes(bytes))?)
+    }
+}
+
+/// Asset loader for a Bevy dynamic scene in the binary scene format (`.scn.bin`).
+///
+/// The loader handles assets serialized with [`DynamicScene::serialize_binary`] or the
+/// [`BinarySceneSaver`]. Asset handles written as asset paths by the saver are loaded as
+/// dependencies of the scene.
+#[derive(Debug)]
+pub struct BinarySceneLoader {
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for BinarySceneLoader {
+    fn from_world(world: &mut World) -> Self {
+        let type_registry = world.resource::<AppTypeRegistry>();
+        BinarySceneLoader {
+            type_registry: type_registry.0.clone(),
+        }
+    }
+}
+
+/// Possible errors that can be produced by [`BinarySceneLoader`]
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum BinarySceneLoaderError {
+    /// An [IO Error](std::io::Error)
+    #[error("Error while trying to read the scene file: {0}")]
+    Io(#[from] std::io::Error),
+    /// The file does not start with [`BINARY_SCENE_MAGIC`].
+    #[error("The file is not a binary scene")]
+    WrongFileType,
+    /// The file was written with a different version of the binary scene format.
+    #[error("Expected binary scene version {BINARY_SCENE_VERSION} but found version {found}")]
+    WrongVersion {
+        /// The version of the file.
+        found: u32,
+    },
+    /// A [Postcard Error](postcard::Error)
+    #[error("Could not decode binary scene: {0}")]
+    Postcard(#[from] postcard::Error),
+}
+
+impl AssetLoader for BinarySceneLoader {
+    type Asset = DynamicScene;
+    type Settings = ();
+    type Error = BinarySceneLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let mut processor = HandlePathDeserializer { load_context };
+        DynamicScene::deserialize_binary_with_processor(
+            &bytes,
+            &self.type_registry.read(),
+            Some(&mut processor),
+        )
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["scn.bin"]
+    }
+}
+
+/// Asset saver writing a [`DynamicScene`] in the binary scene format (`.scn.bin`), to be loaded
+/// with the [`BinarySceneLoader`].
+///
+/// This can be used with the asset processor to convert the RON scenes that are edited by hand
+/// into binary scenes that ship with the game. Like the [`DynamicSceneSaver`](crate::DynamicSceneSaver),
+/// asset handles in the scene are written as the path of their asset.
+#[derive(Debug)]
+pub struct BinarySceneSaver {
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for BinarySceneSaver {
+    fn from_world(world: &mut World) -> Self {
+        let type_registry = world.resource::<AppTypeRegistry>();
+        BinarySceneSaver {
+            type_registry: type_registry.0.clone(),
+        }
+    }
+}
+
+/// Possible errors that can be produced by [`BinarySceneSaver`]
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum BinarySceneSaverError {
+    /// An [IO Error](std::io::Error)
+    #[error("Error while trying to write the scene file: {0}")]
+    Io(#[from] std::io::Error),
+    /// A [Postcard Error](postcard::Error)
+    #[error("Could not encode binary scene: {0}")]
+    Postcard(#[from] postcard::Error),
+}
+
+impl AssetSaver for BinarySceneSaver {
+    type Asset = DynamicScene;
+    type Settings = DynamicSceneSaverSettings;
+    type OutputLoader = BinarySceneLoader;
+    type Error = BinarySceneSaverError;
+
+    async fn save(
+        &self,
+        writer: &mut Writer,
+        asset: SavedAsset<'_, Self::Asset>,
+        settings: &Self::Settings,
+    ) -> Result<(), Self::Error> {
+        let processor = HandlePathSerializer::new(&asset, settings);
+        let bytes =
+            asset.serialize_binary_with_processor(&self.type_registry.read(), &processor)?;
+        writer.write_all(&bytes).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{
+        component::Component,
+        entity::{Entity, EntityHashMap},
+        hierarchy::ChildOf,
+        reflect::{AppTypeRegistry, ReflectComponent},
+        world::World,
+    };
+    use bevy_reflect::Reflect;
+
+    use crate::{BinarySceneLoaderError, DynamicScene, DynamicSceneBuilder};
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Secret(String);
+
+    fn create_world() -> World {
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        {
+            let mut registry = registry.write();
+            registry.register::<Health>();
+            registry.register::<Secret>();
+            registry.register::<ChildOf>();
+        }
+        world.insert_resource(registry);
+        world
+    }
+
+    #[test]
+    fn roundtrip_preserves_hierarchy_and_filter() {
+        let mut world = create_world();
+        let parent = world.spawn((Health(10), Secret("hidden".into()))).id();
+        let child = world.spawn((Health(5), ChildOf(parent))).id();
+
+        let scene = DynamicSceneBuilder::from_world(&world)
+            .deny_component::<Secret>()
+            .extract_entities([parent, child].into_iter())
+            .build();
+
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let bytes = scene.serialize_binary(&registry.read()).unwrap();
+        let deserialized = DynamicScene::deserialize_binary(&bytes, &registry.read()).unwrap();
+        assert_eq!(2, deserialized.entities.len());
+
+        let mut destination = create_world();
+        let mut entity_map = EntityHashMap::default();
+        deserialized
+            .write_to_world(&mut destination, &mut entity_map)
+            .unwrap();
+
+        let parent = entity_map[&parent];
+        let child = entity_map[&child];
+        assert_eq!(Some(&Health(10)), destination.get::<Health>(parent));
+        assert_eq!(None, destination.get::<Secret>(parent));
+        assert_eq!(Some(&Health(5)), destination.get::<Health>(child));
+        assert_eq!(parent, destination.get::<ChildOf>(child).unwrap().parent());
+        assert_eq!(2, destination.query::<Entity>().iter(&destination).count());
+    }
+
+    #[test]
+    fn rejects_other_files() {
+        let world = create_world();
+        let registry = world.resource::<AppTypeRegistry>().read();
+
+        let bytes = DynamicScene::default().serialize_binary(&registry).unwrap();
+        let mut wrong_version = bytes.clone();
+        wrong_version[8] += 1;
+
+        assert!(matches!(
+            DynamicScene::deserialize_binary(b"(resources: {}, entities: {})", &registry),
+            Err(BinarySceneLoaderError::WrongFileType)
+        ));
+        assert!(matches!(
+            DynamicScene::deserialize_binary(&wrong_version, &registry),
+            Err(BinarySceneLoaderError::WrongVersion { found: 2 })
+        ));
+    }
+}
@@ -12,13 +12,18 @@
 
 extern crate alloc;
 
+#[cfg(feature = "serialize")]
+mod binary_scene;
 mod components;
 mod dynamic_scene;
 mod dynamic_scene_builder;
 mod reflect_utils;
 mod scene;
+mod scene_diff;
 mod scene_filter;
 mod scene_loader;
+#[cfg(feature = "serialize")]
+mod scene_saver;
 mod scene_spawner;
 
 #[cfg(feature = "serialize")]
@@ -27,12 +32,17 @@ pub mod serde;
 /// Rusty Object Notation, a crate used to serialize and deserialize bevy scenes.
 pub use bevy_asset::ron;
 
+#[cfg(feature = "serialize")]
+pub use binary_scene::*;
 pub use components::*;
 pub use dynamic_scene::*;
 pub use dynamic_scene_builder::*;
 pub use scene::*;
+pub use scene_diff::*;
 pub use scene_filter::*;
 pub use scene_loader::*;
+#[cfg(feature = "serialize")]
+pub use scene_saver::*;
 pub use scene_spawner::*;
 
 /// The scene prelude.
@@ -61,6 +71,7 @@ impl Plugin for ScenePlugin {
         app.init_asset::<DynamicScene>()
             .init_asset::<Scene>()
             .init_asset_loader::<SceneLoader>()
+            .init_asset_loader::<BinarySceneLoader>()
             .init_resource::<SceneSpawner>()
             .register_type::<SceneRoot>()
             .register_type::<DynamicSceneRoot>()
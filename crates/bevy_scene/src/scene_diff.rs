@@ -0,0 +1,830 @@
+use crate::{
+    reflect_utils::clone_reflect_value, DynamicEntity, DynamicScene, InstanceId, SceneSpawnError,
+};
+use bevy_ecs::{
+    entity::{Entity, EntityHashMap, EntityHashSet, SceneEntityMapper},
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+    relationship::RelationshipHookMode,
+    world::World,
+};
+use bevy_platform::collections::HashMap;
+use bevy_reflect::{
+    diff::{ApplyPatchError, Patch, PatchOp},
+    PartialReflect, ReflectMut, TypeRegistration, TypeRegistry,
+};
+use thiserror::Error;
+
+/// The difference between two [`DynamicScene`]s.
+///
+/// A diff is computed with [`SceneDiff::new`] and describes how to turn the first scene into the
+/// second one: which entities were added or removed, which of their components were added or
+/// removed, and how the remaining components changed. The same is tracked for resources. Changed
+/// values are recorded as a [`Patch`], see [`bevy_reflect::diff`].
+///
+/// A diff can be applied to a [`DynamicScene`] with [`SceneDiff::apply_to_scene`], to the entities
+/// of a scene instance with [`SceneDiff::write_to_world`] or
+/// [`SceneSpawner::apply_diff_sync`](crate::SceneSpawner::apply_diff_sync). With the `serialize`
+/// feature, it can be serialized with `serde::SceneDiffSerializer`, e.g. to store an edit history.
+///
+/// Entities are identified by their id within the scenes, just like in a [`DynamicScene`].
+#[derive(Default)]
+pub struct SceneDiff {
+    /// Entities that were added, or whose components changed.
+    pub entities: Vec<EntityDiff>,
+    /// Entities that were removed.
+    pub removed_entities: Vec<Entity>,
+    /// Resources that were added or changed.
+    pub resources: Vec<ValueDiff>,
+    /// The type paths of the resources that were removed.
+    pub removed_resources: Vec<String>,
+}
+
+/// The changes made to a single entity of a [`SceneDiff`].
+pub struct EntityDiff {
+    /// The identifier of the entity within the scenes.
+    pub entity: Entity,
+    /// Components that were added or changed.
+    ///
+    /// Entities that were added have every one of their components inserted.
+    pub components: Vec<ValueDiff>,
+    /// The type paths of the components that were removed.
+    pub removed_components: Vec<String>,
+}
+
+/// A component or resource that was added or changed in a [`SceneDiff`].
+///
+/// Entities referenced by the values of the diff use their ids within the scenes.
+pub enum ValueDiff {
+    /// The value was added, and replaces any existing value of the same type.
+    Insert(Box<dyn PartialReflect>),
+    /// The value changed, turning into the result of applying the patch to it.
+    Patch {
+        /// The type path of the patched component or resource.
+        type_path: String,
+        /// The changes made to the component or resource.
+        patch: Patch,
+    },
+}
+
+impl ValueDiff {
+    /// The type path of the added or changed component or resource.
+    pub fn type_path(&self) -> &str {
+        match self {
+            ValueDiff::Insert(value) => value.reflect_type_path(),
+            ValueDiff::Patch { type_path, .. } => type_path,
+        }
+    }
+}
+
+/// Errors that can occur when applying a [`SceneDiff`].
+#[derive(Error, Debug)]
+pub enum SceneDiffError {
+    /// A type of the diff could not be found in the type registry, or is not a component or resource.
+    #[error(transparent)]
+    Spawn(#[from] SceneSpawnError),
+    /// A component or resource was patched, but it is not present.
+    #[error("cannot patch `{type_path}` as it is not present")]
+    MissingValue {
+        /// Type of the missing component or resource.
+        type_path: String,
+    },
+    /// A component or resource could not be patched.
+    #[error("cannot patch `{type_path}`: {message}")]
+    InvalidPatch {
+        /// Type of the patched component or resource.
+        type_path: String,
+        /// Why the value could not be patched.
+        message: String,
+    },
+    /// The scene instance to apply the diff to does not exist.
+    #[error("scene instance does not exist")]
+    NonExistentInstance {
+        /// Id of the non-existent scene instance.
+        id: InstanceId,
+    },
+    /// An entity changed by the diff was spawned from the scene, but has since been despawned.
+    #[error("entity {entity} of the scene has been despawned")]
+    DespawnedEntity {
+        /// Id of the despawned entity within the scene.
+        entity: Entity,
+    },
+}
+
+impl SceneDiffError {
+    fn invalid_patch(type_path: &str, error: ApplyPatchError) -> Self {
+        SceneDiffError::InvalidPatch {
+            type_path: type_path.to_string(),
+            message: error.to_string(),
+        }
+    }
+}
+
+impl SceneDiff {
+    /// Compute the changes required to turn the scene `from` into the scene `to`.
+    pub fn new(from: &DynamicScene, to: &DynamicScene) -> Self {
+        let mut diff = SceneDiff::default();
+
+        let from_entities: HashMap<Entity, &DynamicEntity> = from
+            .entities
+            .iter()
+            .map(|entity| (entity.entity, entity))
+            .collect();
+        let to_entities: HashMap<Entity, &DynamicEntity> = to
+            .entities
+            .iter()
+            .map(|entity| (entity.entity, entity))
+            .collect();
+
+        for to_entity in &to.entities {
+            let from_components = from_entities
+                .get(&to_entity.entity)
+                .map(|entity| entity.components.as_slice())
+                .unwrap_or_default();
+            let (components, removed_components) =
+                diff_values(from_components, &to_entity.components);
+            if !from_entities.contains_key(&to_entity.entity)
+                || !components.is_empty()
+                || !removed_components.is_empty()
+            {
+                diff.entities.push(EntityDiff {
+                    entity: to_entity.entity,
+                    components,
+                    removed_components,
+                });
+            }
+        }
+        diff.removed_entities = from
+            .entities
+            .iter()
+            .map(|entity| entity.entity)
+            .filter(|entity| !to_entities.contains_key(entity))
+            .collect();
+
+        (diff.resources, diff.removed_resources) = diff_values(&from.resources, &to.resources);
+
+        diff
+    }
+
+    /// Whether the diff contains no changes.
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+            && self.removed_entities.is_empty()
+            && self.resources.is_empty()
+            && self.removed_resources.is_empty()
+    }
+
+    /// Apply the changes of this diff to the given scene.
+    ///
+    /// Applying a diff computed with [`SceneDiff::new`] to its `from` scene results in a scene
+    /// equal to its `to` scene.
+    pub fn apply_to_scene(
+        &self,
+        scene: &mut DynamicScene,
+        type_registry: &TypeRegistry,
+    ) -> Result<(), SceneDiffError> {
+        let removed_entities: EntityHashSet = self.removed_entities.iter().copied().collect();
+        scene
+            .entities
+            .retain(|entity| !removed_entities.contains(&entity.entity));
+
+        let mut indices: EntityHashMap<usize> = scene
+            .entities
+            .iter()
+            .enumerate()
+            .map(|(index, entity)| (entity.entity, index))
+            .collect();
+
+        for entity_diff in &self.entities {
+            let index = *indices.entry(entity_diff.entity).or_insert_with(|| {
+                scene.entities.push(DynamicEntity {
+                    entity: entity_diff.entity,
+                    components: Vec::new(),
+                });
+                scene.entities.len() - 1
+            });
+            let components = &mut scene.entities[index].components;
+            components.retain(|component| {
+                !entity_diff
+                    .removed_components
+                    .iter()
+                    .any(|type_path| type_path == component.reflect_type_path())
+            });
+            patch_values(components, &entity_diff.components, type_registry)?;
+        }
+
+        scene.resources.retain(|resource| {
+            !self
+                .removed_resources
+                .iter()
+                .any(|type_path| type_path == resource.reflect_type_path())
+        });
+        patch_values(&mut scene.resources, &self.resources, type_registry)
+    }
+
+    /// Apply the changes of this diff to entities that were spawned from a scene.
+    ///
+    /// The `entity_map` maps the entities of the scene to the entities in the world, as filled by
+    /// [`DynamicScene::write_to_world`]. Entities added by the diff are spawned and added to the map,
+    /// while entities removed by the diff are despawned and removed from it.
+    ///
+    /// Like [`DynamicScene::write_to_world`], relationship hooks are not run when inserting components,
+    /// as both sides of relationships are expected to be part of the diff.
+    ///
+    /// Entities referenced by inserted components are mapped using their
+    /// [`MapEntities`](bevy_ecs::entity::MapEntities) implementation. Entities referenced by the
+    /// values of a [`Patch`] are mapped by visiting them through reflection, which does not reach
+    /// entities stored in opaque types or used as keys of nested maps.
+    ///
+    /// Returns an error without changing the world if a type of the diff is not registered, or if an
+    /// entity changed by the diff has been despawned from the world.
+    pub fn write_to_world(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+    ) -> Result<(), SceneDiffError> {
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
+        let type_registry = type_registry.read();
+
+        for entity_diff in &self.entities {
+            let type_paths = entity_diff
+                .removed_components
+                .iter()
+                .map(String::as_str)
+                .chain(entity_diff.components.iter().map(ValueDiff::type_path));
+            for type_path in type_paths {
+                component_registration(&type_registry, type_path)?;
+            }
+            if let Some(&entity) = entity_map.get(&entity_diff.entity)
+                && world.get_entity(entity).is_err()
+            {
+                return Err(SceneDiffError::DespawnedEntity {
+                    entity: entity_diff.entity,
+                });
+            }
+        }
+        let type_paths = self
+            .removed_resources
+            .iter()
+            .map(String::as_str)
+            .chain(self.resources.iter().map(ValueDiff::type_path));
+        for type_path in type_paths {
+            resource_registration(&type_registry, type_path)?;
+        }
+
+        for entity in &self.removed_entities {
+            if let Some(entity) = entity_map.remove(entity)
+                && let Ok(entity_mut) = world.get_entity_mut(entity)
+            {
+                entity_mut.despawn();
+            }
+        }
+
+        // Spawn added entities first, so that any component can reference them.
+        for entity_diff in &self.entities {
+            entity_map
+                .entry(entity_diff.entity)
+                .or_insert_with(|| world.spawn_empty().id());
+        }
+
+        for entity_diff in &self.entities {
+            let entity = entity_map[&entity_diff.entity];
+            // Hooks and observers may still despawn entities while the diff is written.
+            let despawned = || SceneDiffError::DespawnedEntity {
+                entity: entity_diff.entity,
+            };
+
+            for type_path in &entity_diff.removed_components {
+                let (_, reflect_component) = component_registration(&type_registry, type_path)?;
+                reflect_component
+                    .remove(&mut world.get_entity_mut(entity).map_err(|_| despawned())?);
+            }
+
+            for value_diff in &entity_diff.components {
+                let (registration, reflect_component) =
+                    component_registration(&type_registry, value_diff.type_path())?;
+
+                match value_diff {
+                    // Inserted components are written just like `DynamicScene::write_to_world` does.
+                    ValueDiff::Insert(value) => {
+                        SceneEntityMapper::world_scope(entity_map, world, |world, mapper| {
+                            reflect_component.apply_or_insert_mapped(
+                                &mut world.get_entity_mut(entity).map_err(|_| despawned())?,
+                                value.as_partial_reflect(),
+                                &type_registry,
+                                mapper,
+                                RelationshipHookMode::Skip,
+                            );
+                            Ok::<_, SceneDiffError>(())
+                        })?;
+                    }
+                    ValueDiff::Patch { type_path, patch } => {
+                        let mut patched = {
+                            let entity_ref = world.get_entity(entity).map_err(|_| despawned())?;
+                            let component =
+                                reflect_component.reflect(entity_ref).ok_or_else(|| {
+                                    SceneDiffError::MissingValue {
+                                        type_path: type_path.clone(),
+                                    }
+                                })?;
+                            clone_reflect_value(component.as_partial_reflect(), registration)
+                        };
+                        map_patch(patch, &type_registry, entity_map)
+                            .apply(patched.as_mut())
+                            .map_err(|error| SceneDiffError::invalid_patch(type_path, error))?;
+                        // The component is already mapped, so it must not be mapped again.
+                        reflect_component.apply_or_insert_mapped(
+                            &mut world.get_entity_mut(entity).map_err(|_| despawned())?,
+                            patched.as_partial_reflect(),
+                            &type_registry,
+                            &mut (),
+                            RelationshipHookMode::Skip,
+                        );
+                    }
+                }
+            }
+        }
+
+        for type_path in &self.removed_resources {
+            let (_, reflect_resource) = resource_registration(&type_registry, type_path)?;
+            reflect_resource.remove(world);
+        }
+
+        for value_diff in &self.resources {
+            let (registration, reflect_resource) =
+                resource_registration(&type_registry, value_diff.type_path())?;
+
+            let patched = match value_diff {
+                ValueDiff::Insert(value) => {
+                    let mut value = clone_diff_value(value.as_partial_reflect(), &type_registry);
+                    map_reflected_entities(value.as_mut(), entity_map);
+                    value
+                }
+                ValueDiff::Patch { type_path, patch } => {
+                    let resource = reflect_resource.reflect(&*world).map_err(|_| {
+                        SceneDiffError::MissingValue {
+                            type_path: type_path.clone(),
+                        }
+                    })?;
+                    let mut patched =
+                        clone_reflect_value(resource.as_partial_reflect(), registration);
+                    map_patch(patch, &type_registry, entity_map)
+                        .apply(patched.as_mut())
+                        .map_err(|error| SceneDiffError::invalid_patch(type_path, error))?;
+                    patched
+                }
+            };
+            reflect_resource.insert(world, patched.as_partial_reflect(), &type_registry);
+        }
+
+        Ok(())
+    }
+}
+
+/// Computes the changes turning the values of `from` into the values of `to`, along with the
+/// type paths of the values only present in `from`.
+fn diff_values(
+    from: &[Box<dyn PartialReflect>],
+    to: &[Box<dyn PartialReflect>],
+) -> (Vec<ValueDiff>, Vec<String>) {
+    let mut changes = Vec::new();
+    for to_value in to {
+        let type_path = to_value.reflect_type_path();
+        let patch = from
+            .iter()
+            .find(|from_value| from_value.reflect_type_path() == type_path)
+            .map(|from_value| {
+                Patch::diff(
+                    from_value.as_partial_reflect(),
+                    to_value.as_partial_reflect(),
+                )
+            });
+        match patch {
+            Some(Ok(patch)) if patch.is_empty() => {}
+            Some(Ok(patch)) => changes.push(ValueDiff::Patch {
+                type_path: type_path.to_string(),
+                patch,
+            }),
+            // Values that cannot be diffed, e.g. because a changed field cannot be cloned, are
+            // replaced as a whole.
+            Some(Err(_)) | None => changes.push(ValueDiff::Insert(clone_value(
+                to_value.as_partial_reflect(),
+            ))),
+        }
+    }
+
+    let removed = from
+        .iter()
+        .map(|from_value| from_value.reflect_type_path())
+        .filter(|type_path| {
+            !to.iter()
+                .any(|to_value| to_value.reflect_type_path() == *type_path)
+        })
+        .map(ToString::to_string)
+        .collect();
+
+    (changes, removed)
+}
+
+/// Clones a value without a type registry, preferring a concrete clone over a dynamic one.
+fn clone_value(value: &dyn PartialReflect) -> Box<dyn PartialReflect> {
+    value
+        .reflect_clone()
+        .map(PartialReflect::into_partial_reflect)
+        .unwrap_or_else(|_| value.to_dynamic())
+}
+
+/// Clones a value of a diff, converting it to its concrete type if it is registered.
+fn clone_diff_value(
+    value: &dyn PartialReflect,
+    type_registry: &TypeRegistry,
+) -> Box<dyn PartialReflect> {
+    match value
+        .get_represented_type_info()
+        .and_then(|type_info| type_registry.get(type_info.type_id()))
+    {
+        Some(registration) => clone_reflect_value(value, registration),
+        None => value.to_dynamic(),
+    }
+}
+
+/// Applies the changes to the values with the same type path in `values`.
+fn patch_values(
+    values: &mut Vec<Box<dyn PartialReflect>>,
+    changes: &[ValueDiff],
+    type_registry: &TypeRegistry,
+) -> Result<(), SceneDiffError> {
+    for change in changes {
+        let existing = values
+            .iter_mut()
+            .find(|existing| existing.reflect_type_path() == change.type_path());
+        match (change, existing) {
+            (ValueDiff::Insert(value), Some(existing)) => {
+                *existing = clone_diff_value(value.as_partial_reflect(), type_registry);
+            }
+            (ValueDiff::Insert(value), None) => {
+                values.push(clone_diff_value(value.as_partial_reflect(), type_registry));
+            }
+            (ValueDiff::Patch { type_path, patch }, Some(existing)) => patch
+                .apply(existing.as_mut())
+                .map_err(|error| SceneDiffError::invalid_patch(type_path, error))?,
+            (ValueDiff::Patch { type_path, .. }, None) => {
+                return Err(SceneDiffError::MissingValue {
+                    type_path: type_path.clone(),
+                })
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Clones the operations of `patch`, mapping the entities referenced by their values with
+/// [`map_reflected_entities`].
+fn map_patch(
+    patch: &Patch,
+    type_registry: &TypeRegistry,
+    entity_map: &EntityHashMap<Entity>,
+) -> Patch {
+    let map = |value: &dyn PartialReflect| {
+        let mut value = clone_diff_value(value, type_registry);
+        map_reflected_entities(value.as_mut(), entity_map);
+        value
+    };
+
+    patch
+        .iter()
+        .map(|op| match op {
+            PatchOp::Set { path, value } => PatchOp::Set {
+                path: path.clone(),
+                value: map(value.as_ref()),
+            },
+            PatchOp::ChangeVariant { path, value } => PatchOp::ChangeVariant {
+                path: path.clone(),
+                value: map(value.as_ref()),
+            },
+            PatchOp::ListInsert { path, index, value } => PatchOp::ListInsert {
+                path: path.clone(),
+                index: *index,
+                value: map(value.as_ref()),
+            },
+            PatchOp::ListRemove { path, index } => PatchOp::ListRemove {
+                path: path.clone(),
+                index: *index,
+            },
+            PatchOp::MapInsert { path, key, value } => PatchOp::MapInsert {
+                path: path.clone(),
+                key: map(key.as_ref()),
+                value: map(value.as_ref()),
+            },
+            PatchOp::MapRemove { path, key } => PatchOp::MapRemove {
+                path: path.clone(),
+                key: map(key.as_ref()),
+            },
+            PatchOp::SetInsert { path, value } => PatchOp::SetInsert {
+                path: path.clone(),
+                value: map(value.as_ref()),
+            },
+            PatchOp::SetRemove { path, value } => PatchOp::SetRemove {
+                path: path.clone(),
+                value: map(value.as_ref()),
+            },
+        })
+        .collect::<Vec<_>>()
+        .into()
+}
+
+/// Maps every [`Entity`] reachable through reflection in `value`, leaving the entities
+/// missing from `entity_map` untouched.
+fn map_reflected_entities(value: &mut dyn PartialReflect, entity_map: &EntityHashMap<Entity>) {
+    if let Some(entity) = value.try_downcast_mut::<Entity>() {
+        if let Some(mapped) = entity_map.get(entity) {
+            *entity = *mapped;
+        }
+        return;
+    }
+
+    match value.reflect_mut() {
+        ReflectMut::Struct(value) => {
+            for index in 0..value.field_len() {
+                map_reflected_entities(value.field_at_mut(index).unwrap(), entity_map);
+            }
+        }
+        ReflectMut::TupleStruct(value) => {
+            for index in 0..value.field_len() {
+                map_reflected_entities(value.field_mut(index).unwrap(), entity_map);
+            }
+        }
+        ReflectMut::Tuple(value) => {
+            for index in 0..value.field_len() {
+                map_reflected_entities(value.field_mut(index).unwrap(), entity_map);
+            }
+        }
+        ReflectMut::List(value) => {
+            for index in 0..value.len() {
+                map_reflected_entities(value.get_mut(index).unwrap(), entity_map);
+            }
+        }
+        ReflectMut::Array(value) => {
+            for index in 0..value.len() {
+                map_reflected_entities(value.get_mut(index).unwrap(), entity_map);
+            }
+        }
+        ReflectMut::Enum(value) => {
+            for index in 0..value.field_len() {
+                map_reflected_entities(value.field_at_mut(index).unwrap(), entity_map);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn component_registration<'a>(
+    type_registry: &'a TypeRegistry,
+    type_path: &str,
+) -> Result<(&'a TypeRegistration, &'a ReflectComponent), SceneSpawnError> {
+    let registration = type_registry.get_with_type_path(type_path).ok_or_else(|| {
+        SceneSpawnError::UnregisteredButReflectedType {
+            type_path: type_path.to_string(),
+        }
+    })?;
+    let reflect_component = registration.data::<ReflectComponent>().ok_or_else(|| {
+        SceneSpawnError::UnregisteredComponent {
+            type_path: type_path.to_string(),
+        }
+    })?;
+    Ok((registration, reflect_component))
+}
+
+fn resource_registration<'a>(
+    type_registry: &'a TypeRegistry,
+    type_path: &str,
+) -> Result<(&'a TypeRegistration, &'a ReflectResource), SceneSpawnError> {
+    let registration = type_registry.get_with_type_path(type_path).ok_or_else(|| {
+        SceneSpawnError::UnregisteredButReflectedType {
+            type_path: type_path.to_string(),
+        }
+    })?;
+    let reflect_resource = registration.data::<ReflectResource>().ok_or_else(|| {
+        SceneSpawnError::UnregisteredResource {
+            type_path: type_path.to_string(),
+        }
+    })?;
+    Ok((registration, reflect_resource))
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_asset::Assets;
+    use bevy_ecs::query::With;
+    use bevy_ecs::{
+        component::Component,
+        entity::{Entity, EntityHashMap},
+        reflect::{AppTypeRegistry, ReflectComponent},
+        world::World,
+    };
+    use bevy_reflect::{Reflect, TypePath};
+
+    use crate::{
+        DynamicScene, SceneDiff, SceneDiffError, SceneSpawnError, SceneSpawner, ValueDiff,
+    };
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Link {
+        #[entities]
+        target: Entity,
+        strength: u32,
+    }
+
+    fn create_world() -> World {
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        {
+            let mut registry = registry.write();
+            registry.register::<Position>();
+            registry.register::<Health>();
+            registry.register::<Link>();
+        }
+        world.insert_resource(registry);
+        world
+    }
+
+    /// Creates two versions of a scene, with:
+    /// - a `kept` entity whose `Position` moved along `x`, which lost its `Health` and gained a `Link`,
+    /// - a `removed` entity,
+    /// - an `added` entity, targeted by the new `Link`.
+    fn create_scenes() -> (World, DynamicScene, DynamicScene, [Entity; 3]) {
+        let mut world = create_world();
+        let kept = world.spawn((Position { x: 1.0, y: 2.0 }, Health(3))).id();
+        let removed = world.spawn(Health(7)).id();
+        let from = DynamicScene::from_world(&world);
+
+        world.despawn(removed);
+        let added = world.spawn(Health(9)).id();
+        world
+            .entity_mut(kept)
+            .insert((
+                Position { x: 5.0, y: 2.0 },
+                Link {
+                    target: added,
+                    strength: 1,
+                },
+            ))
+            .remove::<Health>();
+        let to = DynamicScene::from_world(&world);
+
+        (world, from, to, [kept, removed, added])
+    }
+
+    #[test]
+    fn diff_and_apply_to_scene() {
+        let (world, mut from, to, [kept, removed, added]) = create_scenes();
+        let registry = world.resource::<AppTypeRegistry>().read();
+
+        let diff = SceneDiff::new(&from, &to);
+        assert_eq!(vec![removed], diff.removed_entities);
+        assert_eq!(2, diff.entities.len());
+
+        let kept_diff = diff.entities.iter().find(|e| e.entity == kept).unwrap();
+        assert_eq!(
+            vec![Health::type_path().to_string()],
+            kept_diff.removed_components
+        );
+        let mut changes = kept_diff
+            .components
+            .iter()
+            .map(|change| match change {
+                ValueDiff::Insert(value) => (value.reflect_type_path(), Vec::new()),
+                ValueDiff::Patch { type_path, patch } => (
+                    type_path.as_str(),
+                    patch.iter().map(|op| op.path().to_string()).collect(),
+                ),
+            })
+            .collect::<Vec<_>>();
+        changes.sort();
+        assert_eq!(
+            vec![
+                (Link::type_path(), Vec::new()),
+                (Position::type_path(), vec![".x".to_string()])
+            ],
+            changes
+        );
+        assert!(diff.entities.iter().any(|e| e.entity == added));
+
+        diff.apply_to_scene(&mut from, &registry).unwrap();
+        assert!(SceneDiff::new(&from, &to).is_empty());
+        assert!(SceneDiff::new(&to, &to).is_empty());
+    }
+
+    #[test]
+    fn apply_to_scene_instance() {
+        let (mut world, from, to, [kept, ..]) = create_scenes();
+
+        let mut destination = create_world();
+        destination.insert_resource(Assets::<DynamicScene>::default());
+        let handle = destination.resource_mut::<Assets<DynamicScene>>().add(from);
+        let mut spawner = SceneSpawner::default();
+        let instance = spawner
+            .spawn_dynamic_sync(&mut destination, &handle)
+            .unwrap();
+
+        let from = destination
+            .resource::<Assets<DynamicScene>>()
+            .get(&handle)
+            .unwrap();
+        let diff = SceneDiff::new(from, &to);
+        spawner
+            .apply_diff_sync(&mut destination, instance, &diff)
+            .unwrap();
+
+        let find = |destination: &mut World, health: u32| {
+            destination
+                .query::<(Entity, &Health)>()
+                .iter(destination)
+                .find(|(_, h)| h.0 == health)
+                .map(|(entity, _)| entity)
+        };
+        let instance_kept = destination
+            .query_filtered::<Entity, With<Position>>()
+            .single(&destination)
+            .unwrap();
+        let instance_added = find(&mut destination, 9).unwrap();
+        assert_eq!(None, find(&mut destination, 7));
+        assert_eq!(None, destination.get::<Health>(instance_kept));
+        assert_eq!(
+            Some(&Position { x: 5.0, y: 2.0 }),
+            destination.get::<Position>(instance_kept)
+        );
+        assert_eq!(
+            Some(&Link {
+                target: instance_added,
+                strength: 1
+            }),
+            destination.get::<Link>(instance_kept)
+        );
+        assert_eq!(2, spawner.iter_instance_entities(instance).count());
+
+        // Only patching the target of the link must still map it to the instance entity.
+        let other = world.spawn(Health(11)).id();
+        world.get_mut::<Link>(kept).unwrap().target = other;
+        let relinked = DynamicScene::from_world(&world);
+        let diff = SceneDiff::new(&to, &relinked);
+        spawner
+            .apply_diff_sync(&mut destination, instance, &diff)
+            .unwrap();
+
+        let instance_other = find(&mut destination, 11).unwrap();
+        assert_eq!(
+            instance_other,
+            destination.get::<Link>(instance_kept).unwrap().target
+        );
+        assert_eq!(3, spawner.iter_instance_entities(instance).count());
+    }
+
+    #[test]
+    fn write_to_world_checks_before_changing_the_world() {
+        #[derive(Component, Reflect)]
+        #[reflect(Component)]
+        struct Unregistered;
+
+        let (_, from, to, [kept, removed, _]) = create_scenes();
+        let mut destination = create_world();
+        let mut entity_map = EntityHashMap::default();
+        from.write_to_world(&mut destination, &mut entity_map)
+            .unwrap();
+        let instance_removed = entity_map[&removed];
+
+        let mut diff = SceneDiff::new(&from, &to);
+        diff.entities[0]
+            .components
+            .push(ValueDiff::Insert(Box::new(Unregistered)));
+        let result = diff.write_to_world(&mut destination, &mut entity_map);
+        assert!(matches!(
+            result,
+            Err(SceneDiffError::Spawn(
+                SceneSpawnError::UnregisteredButReflectedType { .. }
+            ))
+        ));
+        assert!(destination.get_entity(instance_removed).is_ok());
+
+        let diff = SceneDiff::new(&from, &to);
+        destination.despawn(entity_map[&kept]);
+        let result = diff.write_to_world(&mut destination, &mut entity_map);
+        assert!(matches!(
+            result,
+            Err(SceneDiffError::DespawnedEntity { entity }) if entity == kept
+        ));
+        assert!(destination.get_entity(instance_removed).is_ok());
+        assert_eq!(2, entity_map.len());
+    }
+}
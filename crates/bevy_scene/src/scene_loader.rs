@@ -9,13 +9,19 @@ use thiserror::Error;
 #[cfg(feature = "serialize")]
 use {
     crate::{serde::SceneDeserializer, DynamicScene},
-    bevy_asset::{io::Reader, AssetLoader, LoadContext},
-    serde::de::DeserializeSeed,
+    bevy_asset::{io::Reader, AssetLoader, AssetPath, LoadContext, ReflectHandle},
+    bevy_reflect::{serde::ReflectDeserializerProcessor, PartialReflect, TypeRegistration},
+    serde::{
+        de::{DeserializeSeed, Deserializer},
+        Deserialize,
+    },
 };
 
 /// Asset loader for a Bevy dynamic scene (`.scn` / `.scn.ron`).
 ///
-/// The loader handles assets serialized with [`DynamicScene::serialize`].
+/// The loader handles assets serialized with [`DynamicScene::serialize`] or the
+/// [`DynamicSceneSaver`](crate::DynamicSceneSaver). Asset handles written as asset paths by the
+/// saver are loaded as dependencies of the scene.
 #[derive(Debug)]
 pub struct SceneLoader {
     #[cfg_attr(
@@ -56,14 +62,14 @@ impl AssetLoader for SceneLoader {
         &self,
         reader: &mut dyn Reader,
         _settings: &(),
-        _load_context: &mut LoadContext<'_>,
+        load_context: &mut LoadContext<'_>,
     ) -> Result<Self::Asset, Self::Error> {
         let mut bytes = Vec::new();
         reader.read_to_end(&mut bytes).await?;
         let mut deserializer = ron::de::Deserializer::from_bytes(&bytes)?;
-        let scene_deserializer = SceneDeserializer {
-            type_registry: &self.type_registry.read(),
-        };
+        let type_registry = self.type_registry.read();
+        let mut processor = HandlePathDeserializer { load_context };
+        let scene_deserializer = SceneDeserializer::with_processor(&type_registry, &mut processor);
         Ok(scene_deserializer
             .deserialize(&mut deserializer)
             .map_err(|e| deserializer.span_error(e))?)
@@ -73,3 +79,33 @@ impl AssetLoader for SceneLoader {
         &["scn", "scn.ron"]
     }
 }
+
+/// A [`ReflectDeserializerProcessor`] loading asset handles written as the path of their asset.
+#[cfg(feature = "serialize")]
+pub(crate) struct HandlePathDeserializer<'a, 'ctx> {
+    pub(crate) load_context: &'a mut LoadContext<'ctx>,
+}
+
+#[cfg(feature = "serialize")]
+impl ReflectDeserializerProcessor for HandlePathDeserializer<'_, '_> {
+    fn try_deserialize<'de, D>(
+        &mut self,
+        registration: &TypeRegistration,
+        _registry: &bevy_reflect::TypeRegistry,
+        deserializer: D,
+    ) -> Result<Result<Box<dyn PartialReflect>, D>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let Some(reflect_handle) = registration.data::<ReflectHandle>() else {
+            return Ok(Err(deserializer));
+        };
+        let path = AssetPath::deserialize(deserializer)?;
+        let handle = self
+            .load_context
+            .loader()
+            .with_dynamic_type(reflect_handle.asset_type_id())
+            .load(path);
+        Ok(Ok(reflect_handle.typed(handle).into_partial_reflect()))
+    }
+}
@@ -0,0 +1,301 @@
+use crate::{serde::SceneSerializer, serialize_ron, DynamicScene, SceneLoader};
+use bevy_asset::{
+    io::Writer,
+    saver::{AssetSaver, SavedAsset},
+    AsyncWriteExt, HandleSerializerProcessor, UntypedAssetId,
+};
+use bevy_ecs::{
+    reflect::AppTypeRegistry,
+    world::{FromWorld, World},
+};
+use bevy_platform::collections::HashSet;
+use bevy_reflect::{serde::ReflectSerializerProcessor, PartialReflect, TypeRegistryArc};
+use serde::{Deserialize, Serialize, Serializer};
+use thiserror::Error;
+
+/// Settings of the [`DynamicSceneSaver`] and the [`BinarySceneSaver`](crate::BinarySceneSaver).
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DynamicSceneSaverSettings {
+    /// The asset source that references to the labeled assets of the saved asset are loaded from.
+    ///
+    /// Labeled assets are not saved with the scene. When a scene derived from another asset is saved by the asset
+    /// processor, such as a scene converted from a glTF file, the processed file is replaced by the scene and the
+    /// labeled assets of the original file (its meshes, materials, ...) can only be loaded from an unprocessed
+    /// [`AssetSource`](bevy_asset::io::AssetSource) reading the same files. If `None`, these references keep their path.
+    pub labeled_asset_source: Option<String>,
+}
+
+/// Asset saver writing a [`DynamicScene`] in the RON scene format (`.scn` / `.scn.ron`), to be loaded with the
+/// [`SceneLoader`].
+///
+/// Asset handles in the scene are written as the path of their asset, and loaded again by the [`SceneLoader`].
+/// Saving a scene that references an asset without a path fails.
+///
+/// Use the [`BinarySceneSaver`](crate::BinarySceneSaver) to write scenes in the binary scene format instead.
+#[derive(Debug)]
+pub struct DynamicSceneSaver {
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for DynamicSceneSaver {
+    fn from_world(world: &mut World) -> Self {
+        let type_registry = world.resource::<AppTypeRegistry>();
+        DynamicSceneSaver {
+            type_registry: type_registry.0.clone(),
+        }
+    }
+}
+
+/// Possible errors that can be produced by [`DynamicSceneSaver`]
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum DynamicSceneSaverError {
+    /// An [IO Error](std::io::Error)
+    #[error("Error while trying to write the scene file: {0}")]
+    Io(#[from] std::io::Error),
+    /// A [RON Error](ron::Error)
+    #[error("Could not serialize scene: {0}")]
+    Ron(#[from] crate::ron::Error),
+}
+
+impl AssetSaver for DynamicSceneSaver {
+    type Asset = DynamicScene;
+    type Settings = DynamicSceneSaverSettings;
+    type OutputLoader = SceneLoader;
+    type Error = DynamicSceneSaverError;
+
+    async fn save(
+        &self,
+        writer: &mut Writer,
+        asset: SavedAsset<'_, Self::Asset>,
+        settings: &Self::Settings,
+    ) -> Result<(), Self::Error> {
+        let processor = HandlePathSerializer::new(&asset, settings);
+        let ron = serialize_ron(SceneSerializer::with_processor(
+            &asset,
+            &self.type_registry.read(),
+            &processor,
+        ))?;
+        writer.write_all(ron.as_bytes()).await?;
+        Ok(())
+    }
+}
+
+/// A [`ReflectSerializerProcessor`] writing asset handles as the path of their asset, like the
+/// [`HandleSerializerProcessor`], but loading the labeled assets of the saved asset from another source.
+pub(crate) struct HandlePathSerializer {
+    labeled_assets: HashSet<UntypedAssetId>,
+    labeled_asset_source: Option<String>,
+}
+
+impl HandlePathSerializer {
+    pub(crate) fn new(
+        asset: &SavedAsset<'_, DynamicScene>,
+        settings: &DynamicSceneSaverSettings,
+    ) -> Self {
+        let labeled_assets = asset
+            .iter_labels()
+            .filter_map(|label| asset.get_untyped_handle(label))
+            .map(|handle| handle.id())
+            .collect();
+        Self {
+            labeled_assets,
+            labeled_asset_source: settings.labeled_asset_source.clone(),
+        }
+    }
+}
+
+impl ReflectSerializerProcessor for HandlePathSerializer {
+    fn try_serialize<S>(
+        &self,
+        value: &dyn PartialReflect,
+        registry: &bevy_reflect::TypeRegistry,
+        serializer: S,
+    ) -> Result<Result<S::Ok, S>, S::Error>
+    where
+        S: Serializer,
+    {
+        HandleSerializerProcessor::try_serialize_mapped(
+            value,
+            registry,
+            serializer,
+            |handle, path| match &self.labeled_asset_source {
+                Some(source) if self.labeled_assets.contains(&handle.id()) => {
+                    path.clone().with_source(source.as_str()).into_owned()
+                }
+                _ => path.clone(),
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use bevy_app::{App, TaskPoolPlugin};
+    use bevy_asset::{
+        io::{
+            memory::{Dir, MemoryAssetReader},
+            AssetSource, AssetSourceId,
+        },
+        saver::{AssetSaver, SavedAsset},
+        Asset, AssetApp, AssetPlugin, AssetServer, Assets, ErasedLoadedAsset, Handle, LoadState,
+        LoadedAsset,
+    };
+    use bevy_ecs::{
+        component::Component,
+        entity::Entity,
+        reflect::{AppTypeRegistry, ReflectComponent},
+        world::{FromWorld, World},
+    };
+    use bevy_platform::collections::HashSet;
+    use bevy_reflect::{FromReflect, Reflect};
+    use bevy_tasks::block_on;
+
+    use super::{DynamicSceneSaver, HandlePathSerializer};
+    use crate::{
+        serde::SceneSerializer, serialize_ron, DynamicScene, DynamicSceneBuilder, ScenePlugin,
+    };
+
+    #[derive(Asset, Reflect)]
+    struct Image;
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct Sprite(Handle<Image>);
+
+    fn save(
+        world: &mut World,
+        processor: &HandlePathSerializer,
+    ) -> Result<String, crate::ron::Error> {
+        let entities: Vec<Entity> = world.query::<Entity>().iter(world).collect();
+        let scene = DynamicSceneBuilder::from_world(world)
+            .extract_entities(entities.into_iter())
+            .build();
+        let registry = world.resource::<AppTypeRegistry>().read();
+        serialize_ron(SceneSerializer::with_processor(
+            &scene, &registry, processor,
+        ))
+    }
+
+    #[test]
+    fn handles_are_saved_as_paths() {
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin::default(),
+            ScenePlugin,
+        ))
+        .init_asset::<Image>()
+        .register_asset_reflect::<Image>()
+        .register_type::<Sprite>();
+
+        let handle: Handle<Image> = app
+            .world()
+            .resource::<AssetServer>()
+            .load("textures/player.png");
+        let id = handle.id().untyped();
+        app.world_mut().spawn(Sprite(handle));
+
+        let ron = save(
+            app.world_mut(),
+            &HandlePathSerializer {
+                labeled_assets: HashSet::default(),
+                labeled_asset_source: None,
+            },
+        )
+        .unwrap();
+        assert!(ron.contains("\"textures/player.png\""), "{ron}");
+
+        let ron = save(
+            app.world_mut(),
+            &HandlePathSerializer {
+                labeled_assets: [id].into_iter().collect(),
+                labeled_asset_source: Some("original".into()),
+            },
+        )
+        .unwrap();
+        assert!(ron.contains("\"original://textures/player.png\""), "{ron}");
+
+        let handle = app.world_mut().resource_mut::<Assets<Image>>().add(Image);
+        app.world_mut().spawn(Sprite(handle));
+        assert!(save(
+            app.world_mut(),
+            &HandlePathSerializer {
+                labeled_assets: HashSet::default(),
+                labeled_asset_source: None,
+            },
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn saved_handles_are_loaded_again() {
+        let dir = Dir::default();
+        let reader = MemoryAssetReader { root: dir.clone() };
+        let mut app = App::new();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build().with_reader(move || Box::new(reader.clone())),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin::default(),
+            ScenePlugin,
+        ))
+        .init_asset::<Image>()
+        .register_asset_reflect::<Image>()
+        .register_type::<Sprite>();
+
+        let handle: Handle<Image> = app
+            .world()
+            .resource::<AssetServer>()
+            .load("textures/player.png");
+        app.world_mut().spawn(Sprite(handle.clone()));
+        let scene = DynamicScene::from_world(app.world());
+
+        let saver = DynamicSceneSaver::from_world(app.world_mut());
+        let loaded = ErasedLoadedAsset::from(LoadedAsset::from(scene));
+        let mut bytes = Vec::new();
+        block_on(saver.save(
+            &mut bytes,
+            SavedAsset::from_loaded(&loaded).unwrap(),
+            &Default::default(),
+        ))
+        .unwrap();
+        dir.insert_asset(Path::new("scene.scn.ron"), bytes);
+
+        let scene_handle: Handle<DynamicScene> =
+            app.world().resource::<AssetServer>().load("scene.scn.ron");
+        let mut loaded = None;
+        for _ in 0..1000 {
+            app.update();
+            let asset_server = app.world().resource::<AssetServer>();
+            if let Some(LoadState::Failed(err)) = asset_server.get_load_state(&scene_handle) {
+                panic!("{err}");
+            }
+            if let Some(scene) = app
+                .world()
+                .resource::<Assets<DynamicScene>>()
+                .get(&scene_handle)
+            {
+                loaded = Some(
+                    scene
+                        .entities
+                        .iter()
+                        .flat_map(|entity| &entity.components)
+                        .find_map(|component| Sprite::from_reflect(component.as_ref()))
+                        .unwrap(),
+                );
+                break;
+            }
+        }
+        let Sprite(loaded) = loaded.expect("the scene should be loaded");
+        assert_eq!(
+            loaded.path().map(ToString::to_string).as_deref(),
+            Some("textures/player.png")
+        );
+        assert_eq!(loaded.id(), handle.id());
+    }
+}
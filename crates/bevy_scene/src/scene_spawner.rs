@@ -1,4 +1,4 @@
-use crate::{DynamicScene, Scene};
+use crate::{DynamicScene, Scene, SceneDiff, SceneDiffError};
 use bevy_asset::{AssetEvent, AssetId, Assets, Handle};
 use bevy_ecs::{
     entity::{Entity, EntityHashMap},
@@ -240,6 +240,30 @@ impl SceneSpawner {
         }
     }
 
+    /// Immediately applies a [`SceneDiff`] to the entities of a scene instance.
+    ///
+    /// The diff uses the entities of the scene the instance was spawned from. Entities added by the
+    /// diff become part of the instance, and are attached to the instance parent like the other
+    /// root entities of the instance.
+    ///
+    /// Note that the scene asset itself is left untouched: if it is modified, the instance is
+    /// respawned from the asset and the changes of the diff are lost. Use
+    /// [`SceneDiff::apply_to_scene`] to update the asset as well.
+    pub fn apply_diff_sync(
+        &mut self,
+        world: &mut World,
+        instance_id: InstanceId,
+        diff: &SceneDiff,
+    ) -> Result<(), SceneDiffError> {
+        let instance = self
+            .spawned_instances
+            .get_mut(&instance_id)
+            .ok_or(SceneDiffError::NonExistentInstance { id: instance_id })?;
+        diff.write_to_world(world, &mut instance.entity_map)?;
+        Self::set_scene_instance_parent_sync(world, instance);
+        Ok(())
+    }
+
     fn despawn_instance_internal(world: &mut World, instance: &mut InstanceInfo) {
         for &entity in instance.entity_map.values() {
             if let Ok(entity_mut) = world.get_entity_mut(entity) {
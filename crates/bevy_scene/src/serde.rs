@@ -1,19 +1,21 @@
 //! `serde` serialization and deserialization implementation for Bevy scenes.
 
-use crate::{DynamicEntity, DynamicScene};
+use crate::{DynamicEntity, DynamicScene, EntityDiff, SceneDiff, ValueDiff};
 use bevy_ecs::entity::Entity;
 use bevy_platform::collections::HashSet;
 use bevy_reflect::{
+    diff::{PatchDeserializer, PatchSerializer},
     serde::{
-        ReflectDeserializer, TypeRegistrationDeserializer, TypedReflectDeserializer,
+        ReflectDeserializer, ReflectDeserializerProcessor, ReflectSerializer,
+        ReflectSerializerProcessor, TypeRegistrationDeserializer, TypedReflectDeserializer,
         TypedReflectSerializer,
     },
     PartialReflect, ReflectFromReflect, TypeRegistry,
 };
 use core::fmt::Formatter;
 use serde::{
-    de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor},
-    ser::{SerializeMap, SerializeStruct},
+    de::{DeserializeSeed, EnumAccess, Error, MapAccess, SeqAccess, VariantAccess, Visitor},
+    ser::{SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant},
     Deserialize, Deserializer, Serialize, Serializer,
 };
 
@@ -29,6 +31,37 @@ pub const ENTITY_STRUCT: &str = "Entity";
 /// Name of the serialized component field in an entity struct.
 pub const ENTITY_FIELD_COMPONENTS: &str = "components";
 
+/// Name of the serialized scene diff struct type.
+pub const SCENE_DIFF_STRUCT: &str = "SceneDiff";
+/// Name of the serialized changed entities field in a scene diff struct.
+pub const SCENE_DIFF_ENTITIES: &str = "entities";
+/// Name of the serialized removed entities field in a scene diff struct.
+pub const SCENE_DIFF_REMOVED_ENTITIES: &str = "removed_entities";
+/// Name of the serialized changed resources field in a scene diff struct.
+pub const SCENE_DIFF_RESOURCES: &str = "resources";
+/// Name of the serialized removed resources field in a scene diff struct.
+pub const SCENE_DIFF_REMOVED_RESOURCES: &str = "removed_resources";
+
+/// Name of the serialized entity diff struct type.
+pub const ENTITY_DIFF_STRUCT: &str = "EntityDiff";
+/// Name of the serialized entity field in an entity diff struct.
+pub const ENTITY_DIFF_ENTITY: &str = "entity";
+/// Name of the serialized changed components field in an entity diff struct.
+pub const ENTITY_DIFF_COMPONENTS: &str = "components";
+/// Name of the serialized removed components field in an entity diff struct.
+pub const ENTITY_DIFF_REMOVED_COMPONENTS: &str = "removed_components";
+
+/// Name of the serialized value diff enum type.
+pub const VALUE_DIFF_ENUM: &str = "ValueDiff";
+/// Name of the serialized variant of a value diff inserting a whole value.
+pub const VALUE_DIFF_INSERT: &str = "Insert";
+/// Name of the serialized variant of a value diff patching a value.
+pub const VALUE_DIFF_PATCH: &str = "Patch";
+/// Name of the serialized type path field in the patch variant of a value diff.
+pub const VALUE_DIFF_FIELD_TYPE_PATH: &str = "type_path";
+/// Name of the serialized patch field in the patch variant of a value diff.
+pub const VALUE_DIFF_FIELD_PATCH: &str = "patch";
+
 /// Serializer for a [`DynamicScene`].
 ///
 /// Helper object defining Bevy's serialize format for a [`DynamicScene`] and implementing
@@ -54,11 +87,16 @@ pub const ENTITY_FIELD_COMPONENTS: &str = "components";
 /// // Serialize through any serde-compatible Serializer
 /// let ron_string = bevy_scene::ron::ser::to_string(&scene_serializer);
 /// ```
-pub struct SceneSerializer<'a> {
+///
+/// The serialization of the components and resources can be customized with a
+/// [`ReflectSerializerProcessor`], see [`SceneSerializer::with_processor`].
+pub struct SceneSerializer<'a, P = ()> {
     /// The scene to serialize.
     pub scene: &'a DynamicScene,
     /// The type registry containing the types present in the scene.
     pub registry: &'a TypeRegistry,
+    /// The processor used to serialize the components and resources, if any.
+    pub processor: Option<&'a P>,
 }
 
 impl<'a> SceneSerializer<'a> {
@@ -69,11 +107,31 @@ impl<'a> SceneSerializer<'a> {
     ///
     /// [`World`]: bevy_ecs::world::World
     pub fn new(scene: &'a DynamicScene, registry: &'a TypeRegistry) -> Self {
-        SceneSerializer { scene, registry }
+        SceneSerializer {
+            scene,
+            registry,
+            processor: None,
+        }
     }
 }
 
-impl<'a> Serialize for SceneSerializer<'a> {
+impl<'a, P> SceneSerializer<'a, P> {
+    /// Create a new serializer from a [`DynamicScene`] and an associated [`TypeRegistry`], serializing the
+    /// components and resources of the scene with the given `processor`.
+    pub fn with_processor(
+        scene: &'a DynamicScene,
+        registry: &'a TypeRegistry,
+        processor: &'a P,
+    ) -> Self {
+        SceneSerializer {
+            scene,
+            registry,
+            processor: Some(processor),
+        }
+    }
+}
+
+impl<'a, P: ReflectSerializerProcessor> Serialize for SceneSerializer<'a, P> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -84,6 +142,7 @@ impl<'a> Serialize for SceneSerializer<'a> {
             &SceneMapSerializer {
                 entries: &self.scene.resources,
                 registry: self.registry,
+                processor: self.processor,
             },
         )?;
         state.serialize_field(
@@ -91,6 +150,7 @@ impl<'a> Serialize for SceneSerializer<'a> {
             &EntitiesSerializer {
                 entities: &self.scene.entities,
                 registry: self.registry,
+                processor: self.processor,
             },
         )?;
         state.end()
@@ -98,14 +158,16 @@ impl<'a> Serialize for SceneSerializer<'a> {
 }
 
 /// Handles serialization of multiple entities as a map of entity id to serialized entity.
-pub struct EntitiesSerializer<'a> {
+pub struct EntitiesSerializer<'a, P = ()> {
     /// The entities to serialize.
     pub entities: &'a [DynamicEntity],
     /// Type registry in which the component types used by the entities are registered.
     pub registry: &'a TypeRegistry,
+    /// The processor used to serialize the components, if any.
+    pub processor: Option<&'a P>,
 }
 
-impl<'a> Serialize for EntitiesSerializer<'a> {
+impl<'a, P: ReflectSerializerProcessor> Serialize for EntitiesSerializer<'a, P> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -117,6 +179,7 @@ impl<'a> Serialize for EntitiesSerializer<'a> {
                 &EntitySerializer {
                     entity,
                     registry: self.registry,
+                    processor: self.processor,
                 },
             )?;
         }
@@ -125,14 +188,16 @@ impl<'a> Serialize for EntitiesSerializer<'a> {
 }
 
 /// Handles entity serialization as a map of component type to component value.
-pub struct EntitySerializer<'a> {
+pub struct EntitySerializer<'a, P = ()> {
     /// The entity to serialize.
     pub entity: &'a DynamicEntity,
     /// Type registry in which the component types used by the entity are registered.
     pub registry: &'a TypeRegistry,
+    /// The processor used to serialize the components, if any.
+    pub processor: Option<&'a P>,
 }
 
-impl<'a> Serialize for EntitySerializer<'a> {
+impl<'a, P: ReflectSerializerProcessor> Serialize for EntitySerializer<'a, P> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -143,6 +208,7 @@ impl<'a> Serialize for EntitySerializer<'a> {
             &SceneMapSerializer {
                 entries: &self.entity.components,
                 registry: self.registry,
+                processor: self.processor,
             },
         )?;
         state.end()
@@ -156,14 +222,16 @@ impl<'a> Serialize for EntitySerializer<'a> {
 /// deserializing through [`SceneMapDeserializer`].
 ///
 /// Note: The entries are sorted by type path before they're serialized.
-pub struct SceneMapSerializer<'a> {
+pub struct SceneMapSerializer<'a, P = ()> {
     /// List of boxed values of unique type to serialize.
     pub entries: &'a [Box<dyn PartialReflect>],
     /// Type registry in which the types used in `entries` are registered.
     pub registry: &'a TypeRegistry,
+    /// The processor used to serialize the values, if any.
+    pub processor: Option<&'a P>,
 }
 
-impl<'a> Serialize for SceneMapSerializer<'a> {
+impl<'a, P: ReflectSerializerProcessor> Serialize for SceneMapSerializer<'a, P> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -185,10 +253,152 @@ impl<'a> Serialize for SceneMapSerializer<'a> {
         };
 
         for (type_path, partial_reflect) in sorted_entries {
-            state.serialize_entry(
-                type_path,
-                &TypedReflectSerializer::new(partial_reflect, self.registry),
-            )?;
+            match self.processor {
+                Some(processor) => state.serialize_entry(
+                    type_path,
+                    &TypedReflectSerializer::with_processor(
+                        partial_reflect,
+                        self.registry,
+                        processor,
+                    ),
+                )?,
+                None => state.serialize_entry(
+                    type_path,
+                    &TypedReflectSerializer::new(partial_reflect, self.registry),
+                )?,
+            }
+        }
+        state.end()
+    }
+}
+
+/// Serializer for a [`SceneDiff`].
+///
+/// Helper object defining Bevy's serialize format for a [`SceneDiff`] and implementing
+/// the [`Serialize`] trait for use with Serde. The diff is deserialized with [`SceneDiffDeserializer`].
+pub struct SceneDiffSerializer<'a> {
+    /// The diff to serialize.
+    pub diff: &'a SceneDiff,
+    /// The type registry containing the types present in the diff.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> SceneDiffSerializer<'a> {
+    /// Create a new serializer from a [`SceneDiff`] and an associated [`TypeRegistry`].
+    pub fn new(diff: &'a SceneDiff, registry: &'a TypeRegistry) -> Self {
+        SceneDiffSerializer { diff, registry }
+    }
+}
+
+impl<'a> Serialize for SceneDiffSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct(SCENE_DIFF_STRUCT, 4)?;
+        state.serialize_field(
+            SCENE_DIFF_ENTITIES,
+            &SeqSerializer(&self.diff.entities, |entity_diff| EntityDiffSerializer {
+                entity_diff,
+                registry: self.registry,
+            }),
+        )?;
+        state.serialize_field(SCENE_DIFF_REMOVED_ENTITIES, &self.diff.removed_entities)?;
+        state.serialize_field(
+            SCENE_DIFF_RESOURCES,
+            &SeqSerializer(&self.diff.resources, |value_diff| ValueDiffSerializer {
+                value_diff,
+                registry: self.registry,
+            }),
+        )?;
+        state.serialize_field(SCENE_DIFF_REMOVED_RESOURCES, &self.diff.removed_resources)?;
+        state.end()
+    }
+}
+
+/// Handles serialization of the changes made to an entity in a [`SceneDiff`].
+pub struct EntityDiffSerializer<'a> {
+    /// The entity diff to serialize.
+    pub entity_diff: &'a EntityDiff,
+    /// Type registry in which the types used by the patches are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for EntityDiffSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct(ENTITY_DIFF_STRUCT, 3)?;
+        state.serialize_field(ENTITY_DIFF_ENTITY, &self.entity_diff.entity)?;
+        state.serialize_field(
+            ENTITY_DIFF_COMPONENTS,
+            &SeqSerializer(&self.entity_diff.components, |value_diff| {
+                ValueDiffSerializer {
+                    value_diff,
+                    registry: self.registry,
+                }
+            }),
+        )?;
+        state.serialize_field(
+            ENTITY_DIFF_REMOVED_COMPONENTS,
+            &self.entity_diff.removed_components,
+        )?;
+        state.end()
+    }
+}
+
+/// Handles serialization of a [`ValueDiff`].
+///
+/// Inserted values are serialized through a [`ReflectSerializer`], and patches through a [`PatchSerializer`].
+pub struct ValueDiffSerializer<'a> {
+    /// The value diff to serialize.
+    pub value_diff: &'a ValueDiff,
+    /// Type registry in which the types of the values of the diff are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for ValueDiffSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.value_diff {
+            ValueDiff::Insert(value) => serializer.serialize_newtype_variant(
+                VALUE_DIFF_ENUM,
+                0,
+                VALUE_DIFF_INSERT,
+                &ReflectSerializer::new(value.as_partial_reflect(), self.registry),
+            ),
+            ValueDiff::Patch { type_path, patch } => {
+                let mut state =
+                    serializer.serialize_struct_variant(VALUE_DIFF_ENUM, 1, VALUE_DIFF_PATCH, 2)?;
+                state.serialize_field(VALUE_DIFF_FIELD_TYPE_PATH, type_path)?;
+                state.serialize_field(
+                    VALUE_DIFF_FIELD_PATCH,
+                    &PatchSerializer::new(patch, self.registry),
+                )?;
+                state.end()
+            }
+        }
+    }
+}
+
+/// Serializes a slice as a sequence, after mapping each element to a serializable value.
+struct SeqSerializer<'a, T, F>(&'a [T], F);
+
+impl<'a, T, F, U> Serialize for SeqSerializer<'a, T, F>
+where
+    F: Fn(&'a T) -> U,
+    U: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.0.len()))?;
+        for element in self.0 {
+            state.serialize_element(&(self.1)(element))?;
         }
         state.end()
     }
@@ -208,12 +418,38 @@ enum EntityField {
 }
 
 /// Handles scene deserialization.
-pub struct SceneDeserializer<'a> {
+///
+/// The deserialization of the components and resources can be customized with a
+/// [`ReflectDeserializerProcessor`].
+pub struct SceneDeserializer<'a, P: ReflectDeserializerProcessor = ()> {
     /// Type registry in which the components and resources types used in the scene to deserialize are registered.
     pub type_registry: &'a TypeRegistry,
+    /// The processor used to deserialize the components and resources, if any.
+    pub processor: Option<&'a mut P>,
+}
+
+impl<'a> SceneDeserializer<'a> {
+    /// Create a new deserializer for scenes whose types are registered in the given [`TypeRegistry`].
+    pub fn new(type_registry: &'a TypeRegistry) -> Self {
+        SceneDeserializer {
+            type_registry,
+            processor: None,
+        }
+    }
+}
+
+impl<'a, P: ReflectDeserializerProcessor> SceneDeserializer<'a, P> {
+    /// Create a new deserializer for scenes whose types are registered in the given [`TypeRegistry`], deserializing
+    /// the components and resources of the scene with the given `processor`.
+    pub fn with_processor(type_registry: &'a TypeRegistry, processor: &'a mut P) -> Self {
+        SceneDeserializer {
+            type_registry,
+            processor: Some(processor),
+        }
+    }
 }
 
-impl<'a, 'de> DeserializeSeed<'de> for SceneDeserializer<'a> {
+impl<'a, 'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de> for SceneDeserializer<'a, P> {
     type Value = DynamicScene;
 
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
@@ -225,35 +461,39 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneDeserializer<'a> {
             &[SCENE_RESOURCES, SCENE_ENTITIES],
             SceneVisitor {
                 type_registry: self.type_registry,
+                processor: self.processor,
             },
         )
     }
 }
 
-struct SceneVisitor<'a> {
+struct SceneVisitor<'a, P> {
     pub type_registry: &'a TypeRegistry,
+    pub processor: Option<&'a mut P>,
 }
 
-impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
+impl<'a, 'de, P: ReflectDeserializerProcessor> Visitor<'de> for SceneVisitor<'a, P> {
     type Value = DynamicScene;
 
     fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
         formatter.write_str("scene struct")
     }
 
-    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error>
     where
         A: SeqAccess<'de>,
     {
         let resources = seq
             .next_element_seed(SceneMapDeserializer {
                 registry: self.type_registry,
+                processor: self.processor.as_deref_mut(),
             })?
             .ok_or_else(|| Error::missing_field(SCENE_RESOURCES))?;
 
         let entities = seq
             .next_element_seed(SceneEntitiesDeserializer {
                 type_registry: self.type_registry,
+                processor: self.processor,
             })?
             .ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
 
@@ -263,7 +503,7 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
         })
     }
 
-    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    fn visit_map<A>(mut self, mut map: A) -> Result<Self::Value, A::Error>
     where
         A: MapAccess<'de>,
     {
@@ -277,6 +517,7 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
                     }
                     resources = Some(map.next_value_seed(SceneMapDeserializer {
                         registry: self.type_registry,
+                        processor: self.processor.as_deref_mut(),
                     })?);
                 }
                 SceneField::Entities => {
@@ -285,6 +526,7 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
                     }
                     entities = Some(map.next_value_seed(SceneEntitiesDeserializer {
                         type_registry: self.type_registry,
+                        processor: self.processor.as_deref_mut(),
                     })?);
                 }
             }
@@ -301,12 +543,16 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
 }
 
 /// Handles deserialization for a collection of entities.
-pub struct SceneEntitiesDeserializer<'a> {
+pub struct SceneEntitiesDeserializer<'a, P: ReflectDeserializerProcessor = ()> {
     /// Type registry in which the component types used by the entities to deserialize are registered.
     pub type_registry: &'a TypeRegistry,
+    /// The processor used to deserialize the components, if any.
+    pub processor: Option<&'a mut P>,
 }
 
-impl<'a, 'de> DeserializeSeed<'de> for SceneEntitiesDeserializer<'a> {
+impl<'a, 'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
+    for SceneEntitiesDeserializer<'a, P>
+{
     type Value = Vec<DynamicEntity>;
 
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
@@ -315,22 +561,24 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneEntitiesDeserializer<'a> {
     {
         deserializer.deserialize_map(SceneEntitiesVisitor {
             type_registry: self.type_registry,
+            processor: self.processor,
         })
     }
 }
 
-struct SceneEntitiesVisitor<'a> {
+struct SceneEntitiesVisitor<'a, P> {
     pub type_registry: &'a TypeRegistry,
+    pub processor: Option<&'a mut P>,
 }
 
-impl<'a, 'de> Visitor<'de> for SceneEntitiesVisitor<'a> {
+impl<'a, 'de, P: ReflectDeserializerProcessor> Visitor<'de> for SceneEntitiesVisitor<'a, P> {
     type Value = Vec<DynamicEntity>;
 
     fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
         formatter.write_str("map of entities")
     }
 
-    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    fn visit_map<A>(mut self, mut map: A) -> Result<Self::Value, A::Error>
     where
         A: MapAccess<'de>,
     {
@@ -339,6 +587,7 @@ impl<'a, 'de> Visitor<'de> for SceneEntitiesVisitor<'a> {
             let entity = map.next_value_seed(SceneEntityDeserializer {
                 entity,
                 type_registry: self.type_registry,
+                processor: self.processor.as_deref_mut(),
             })?;
             entities.push(entity);
         }
@@ -348,14 +597,18 @@ impl<'a, 'de> Visitor<'de> for SceneEntitiesVisitor<'a> {
 }
 
 /// Handle deserialization of an entity and its components.
-pub struct SceneEntityDeserializer<'a> {
+pub struct SceneEntityDeserializer<'a, P: ReflectDeserializerProcessor = ()> {
     /// Id of the deserialized entity.
     pub entity: Entity,
     /// Type registry in which the component types used by the entity to deserialize are registered.
     pub type_registry: &'a TypeRegistry,
+    /// The processor used to deserialize the components, if any.
+    pub processor: Option<&'a mut P>,
 }
 
-impl<'a, 'de> DeserializeSeed<'de> for SceneEntityDeserializer<'a> {
+impl<'a, 'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
+    for SceneEntityDeserializer<'a, P>
+{
     type Value = DynamicEntity;
 
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
@@ -368,17 +621,19 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneEntityDeserializer<'a> {
             SceneEntityVisitor {
                 entity: self.entity,
                 registry: self.type_registry,
+                processor: self.processor,
             },
         )
     }
 }
 
-struct SceneEntityVisitor<'a> {
+struct SceneEntityVisitor<'a, P> {
     pub entity: Entity,
     pub registry: &'a TypeRegistry,
+    pub processor: Option<&'a mut P>,
 }
 
-impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
+impl<'a, 'de, P: ReflectDeserializerProcessor> Visitor<'de> for SceneEntityVisitor<'a, P> {
     type Value = DynamicEntity;
 
     fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
@@ -392,6 +647,7 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
         let components = seq
             .next_element_seed(SceneMapDeserializer {
                 registry: self.registry,
+                processor: self.processor,
             })?
             .ok_or_else(|| Error::missing_field(ENTITY_FIELD_COMPONENTS))?;
 
@@ -401,7 +657,7 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
         })
     }
 
-    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    fn visit_map<A>(mut self, mut map: A) -> Result<Self::Value, A::Error>
     where
         A: MapAccess<'de>,
     {
@@ -415,6 +671,7 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
 
                     components = Some(map.next_value_seed(SceneMapDeserializer {
                         registry: self.registry,
+                        processor: self.processor.as_deref_mut(),
                     })?);
                 }
             }
@@ -431,12 +688,16 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
 }
 
 /// Handles deserialization of a sequence of values with unique types.
-pub struct SceneMapDeserializer<'a> {
+pub struct SceneMapDeserializer<'a, P: ReflectDeserializerProcessor = ()> {
     /// Type registry in which the types of the values to deserialize are registered.
     pub registry: &'a TypeRegistry,
+    /// The processor used to deserialize the values, if any.
+    pub processor: Option<&'a mut P>,
 }
 
-impl<'a, 'de> DeserializeSeed<'de> for SceneMapDeserializer<'a> {
+impl<'a, 'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
+    for SceneMapDeserializer<'a, P>
+{
     type Value = Vec<Box<dyn PartialReflect>>;
 
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
@@ -445,34 +706,46 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneMapDeserializer<'a> {
     {
         deserializer.deserialize_map(SceneMapVisitor {
             registry: self.registry,
+            processor: self.processor,
         })
     }
 }
 
-struct SceneMapVisitor<'a> {
+struct SceneMapVisitor<'a, P> {
     pub registry: &'a TypeRegistry,
+    pub processor: Option<&'a mut P>,
 }
 
-impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
+impl<'a, 'de, P: ReflectDeserializerProcessor> Visitor<'de> for SceneMapVisitor<'a, P> {
     type Value = Vec<Box<dyn PartialReflect>>;
 
     fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
         formatter.write_str("map of reflect types")
     }
 
-    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error>
     where
         A: SeqAccess<'de>,
     {
         let mut dynamic_properties = Vec::new();
-        while let Some(entity) = seq.next_element_seed(ReflectDeserializer::new(self.registry))? {
+        loop {
+            let entity = match self.processor.as_deref_mut() {
+                Some(processor) => seq.next_element_seed(ReflectDeserializer::with_processor(
+                    self.registry,
+                    processor,
+                ))?,
+                None => seq.next_element_seed(ReflectDeserializer::new(self.registry))?,
+            };
+            let Some(entity) = entity else {
+                break;
+            };
             dynamic_properties.push(entity);
         }
 
         Ok(dynamic_properties)
     }
 
-    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    fn visit_map<A>(mut self, mut map: A) -> Result<Self::Value, A::Error>
     where
         A: MapAccess<'de>,
     {
@@ -488,8 +761,18 @@ impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
                 )));
             }
 
-            let value =
-                map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?;
+            let value = match self.processor.as_deref_mut() {
+                Some(processor) => {
+                    map.next_value_seed(TypedReflectDeserializer::with_processor(
+                        registration,
+                        self.registry,
+                        processor,
+                    ))?
+                }
+                None => {
+                    map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?
+                }
+            };
 
             // Attempt to convert using FromReflect.
             let value = self
@@ -507,12 +790,417 @@ impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
     }
 }
 
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum SceneDiffField {
+    Entities,
+    RemovedEntities,
+    Resources,
+    RemovedResources,
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum EntityDiffField {
+    Entity,
+    Components,
+    RemovedComponents,
+}
+
+#[derive(Deserialize)]
+#[serde(variant_identifier)]
+enum ValueDiffVariant {
+    Insert,
+    Patch,
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum ValueDiffField {
+    TypePath,
+    Patch,
+}
+
+/// Handles [`SceneDiff`] deserialization, in the format written by [`SceneDiffSerializer`].
+#[derive(Clone, Copy)]
+pub struct SceneDiffDeserializer<'a> {
+    /// Type registry in which the types of the values used in the diff to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneDiffDeserializer<'a> {
+    type Value = SceneDiff;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            SCENE_DIFF_STRUCT,
+            &[
+                SCENE_DIFF_ENTITIES,
+                SCENE_DIFF_REMOVED_ENTITIES,
+                SCENE_DIFF_RESOURCES,
+                SCENE_DIFF_REMOVED_RESOURCES,
+            ],
+            SceneDiffVisitor {
+                type_registry: self.type_registry,
+            },
+        )
+    }
+}
+
+struct SceneDiffVisitor<'a> {
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for SceneDiffVisitor<'a> {
+    type Value = SceneDiff;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("scene diff struct")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let entities = seq
+            .next_element_seed(SeqDeserializer(EntityDiffDeserializer {
+                type_registry: self.type_registry,
+            }))?
+            .ok_or_else(|| Error::missing_field(SCENE_DIFF_ENTITIES))?;
+        let removed_entities = seq
+            .next_element()?
+            .ok_or_else(|| Error::missing_field(SCENE_DIFF_REMOVED_ENTITIES))?;
+        let resources = seq
+            .next_element_seed(SeqDeserializer(ValueDiffDeserializer {
+                type_registry: self.type_registry,
+            }))?
+            .ok_or_else(|| Error::missing_field(SCENE_DIFF_RESOURCES))?;
+        let removed_resources = seq
+            .next_element()?
+            .ok_or_else(|| Error::missing_field(SCENE_DIFF_REMOVED_RESOURCES))?;
+
+        Ok(SceneDiff {
+            entities,
+            removed_entities,
+            resources,
+            removed_resources,
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entities = None;
+        let mut removed_entities = None;
+        let mut resources = None;
+        let mut removed_resources = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                SceneDiffField::Entities => {
+                    if entities.is_some() {
+                        return Err(Error::duplicate_field(SCENE_DIFF_ENTITIES));
+                    }
+                    entities =
+                        Some(map.next_value_seed(SeqDeserializer(EntityDiffDeserializer {
+                            type_registry: self.type_registry,
+                        }))?);
+                }
+                SceneDiffField::RemovedEntities => {
+                    if removed_entities.is_some() {
+                        return Err(Error::duplicate_field(SCENE_DIFF_REMOVED_ENTITIES));
+                    }
+                    removed_entities = Some(map.next_value()?);
+                }
+                SceneDiffField::Resources => {
+                    if resources.is_some() {
+                        return Err(Error::duplicate_field(SCENE_DIFF_RESOURCES));
+                    }
+                    resources =
+                        Some(map.next_value_seed(SeqDeserializer(ValueDiffDeserializer {
+                            type_registry: self.type_registry,
+                        }))?);
+                }
+                SceneDiffField::RemovedResources => {
+                    if removed_resources.is_some() {
+                        return Err(Error::duplicate_field(SCENE_DIFF_REMOVED_RESOURCES));
+                    }
+                    removed_resources = Some(map.next_value()?);
+                }
+            }
+        }
+
+        Ok(SceneDiff {
+            entities: entities.ok_or_else(|| Error::missing_field(SCENE_DIFF_ENTITIES))?,
+            removed_entities: removed_entities
+                .ok_or_else(|| Error::missing_field(SCENE_DIFF_REMOVED_ENTITIES))?,
+            resources: resources.ok_or_else(|| Error::missing_field(SCENE_DIFF_RESOURCES))?,
+            removed_resources: removed_resources
+                .ok_or_else(|| Error::missing_field(SCENE_DIFF_REMOVED_RESOURCES))?,
+        })
+    }
+}
+
+/// Handles deserialization of the changes made to an entity in a [`SceneDiff`].
+#[derive(Clone, Copy)]
+pub struct EntityDiffDeserializer<'a> {
+    /// Type registry in which the types of the patch values to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for EntityDiffDeserializer<'a> {
+    type Value = EntityDiff;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            ENTITY_DIFF_STRUCT,
+            &[
+                ENTITY_DIFF_ENTITY,
+                ENTITY_DIFF_COMPONENTS,
+                ENTITY_DIFF_REMOVED_COMPONENTS,
+            ],
+            EntityDiffVisitor {
+                type_registry: self.type_registry,
+            },
+        )
+    }
+}
+
+struct EntityDiffVisitor<'a> {
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for EntityDiffVisitor<'a> {
+    type Value = EntityDiff;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("entity diff struct")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let entity = seq
+            .next_element()?
+            .ok_or_else(|| Error::missing_field(ENTITY_DIFF_ENTITY))?;
+        let components = seq
+            .next_element_seed(SeqDeserializer(ValueDiffDeserializer {
+                type_registry: self.type_registry,
+            }))?
+            .ok_or_else(|| Error::missing_field(ENTITY_DIFF_COMPONENTS))?;
+        let removed_components = seq
+            .next_element()?
+            .ok_or_else(|| Error::missing_field(ENTITY_DIFF_REMOVED_COMPONENTS))?;
+
+        Ok(EntityDiff {
+            entity,
+            components,
+            removed_components,
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entity = None;
+        let mut components = None;
+        let mut removed_components = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                EntityDiffField::Entity => {
+                    if entity.is_some() {
+                        return Err(Error::duplicate_field(ENTITY_DIFF_ENTITY));
+                    }
+                    entity = Some(map.next_value()?);
+                }
+                EntityDiffField::Components => {
+                    if components.is_some() {
+                        return Err(Error::duplicate_field(ENTITY_DIFF_COMPONENTS));
+                    }
+                    components =
+                        Some(map.next_value_seed(SeqDeserializer(ValueDiffDeserializer {
+                            type_registry: self.type_registry,
+                        }))?);
+                }
+                EntityDiffField::RemovedComponents => {
+                    if removed_components.is_some() {
+                        return Err(Error::duplicate_field(ENTITY_DIFF_REMOVED_COMPONENTS));
+                    }
+                    removed_components = Some(map.next_value()?);
+                }
+            }
+        }
+
+        Ok(EntityDiff {
+            entity: entity.ok_or_else(|| Error::missing_field(ENTITY_DIFF_ENTITY))?,
+            components: components.ok_or_else(|| Error::missing_field(ENTITY_DIFF_COMPONENTS))?,
+            removed_components: removed_components
+                .ok_or_else(|| Error::missing_field(ENTITY_DIFF_REMOVED_COMPONENTS))?,
+        })
+    }
+}
+
+/// Handles deserialization of a [`ValueDiff`], in the format written by [`ValueDiffSerializer`].
+#[derive(Clone, Copy)]
+pub struct ValueDiffDeserializer<'a> {
+    /// Type registry in which the types of the values of the diff to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ValueDiffDeserializer<'a> {
+    type Value = ValueDiff;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_enum(
+            VALUE_DIFF_ENUM,
+            &[VALUE_DIFF_INSERT, VALUE_DIFF_PATCH],
+            ValueDiffVisitor {
+                type_registry: self.type_registry,
+            },
+        )
+    }
+}
+
+struct ValueDiffVisitor<'a> {
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for ValueDiffVisitor<'a> {
+    type Value = ValueDiff;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("value diff enum")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        match data.variant()? {
+            (ValueDiffVariant::Insert, variant) => variant
+                .newtype_variant_seed(ReflectDeserializer::new(self.type_registry))
+                .map(ValueDiff::Insert),
+            (ValueDiffVariant::Patch, variant) => variant.struct_variant(
+                &[VALUE_DIFF_FIELD_TYPE_PATH, VALUE_DIFF_FIELD_PATCH],
+                ValuePatchVisitor {
+                    type_registry: self.type_registry,
+                },
+            ),
+        }
+    }
+}
+
+struct ValuePatchVisitor<'a> {
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for ValuePatchVisitor<'a> {
+    type Value = ValueDiff;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("value patch struct variant")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let type_path = seq
+            .next_element()?
+            .ok_or_else(|| Error::missing_field(VALUE_DIFF_FIELD_TYPE_PATH))?;
+        let patch = seq
+            .next_element_seed(PatchDeserializer::new(self.type_registry))?
+            .ok_or_else(|| Error::missing_field(VALUE_DIFF_FIELD_PATCH))?;
+
+        Ok(ValueDiff::Patch { type_path, patch })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut type_path = None;
+        let mut patch = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                ValueDiffField::TypePath => {
+                    if type_path.is_some() {
+                        return Err(Error::duplicate_field(VALUE_DIFF_FIELD_TYPE_PATH));
+                    }
+                    type_path = Some(map.next_value()?);
+                }
+                ValueDiffField::Patch => {
+                    if patch.is_some() {
+                        return Err(Error::duplicate_field(VALUE_DIFF_FIELD_PATCH));
+                    }
+                    patch = Some(map.next_value_seed(PatchDeserializer::new(self.type_registry))?);
+                }
+            }
+        }
+
+        Ok(ValueDiff::Patch {
+            type_path: type_path.ok_or_else(|| Error::missing_field(VALUE_DIFF_FIELD_TYPE_PATH))?,
+            patch: patch.ok_or_else(|| Error::missing_field(VALUE_DIFF_FIELD_PATCH))?,
+        })
+    }
+}
+
+/// Deserializes a sequence, using the same seed for every element.
+struct SeqDeserializer<S>(S);
+
+impl<'de, S> DeserializeSeed<'de> for SeqDeserializer<S>
+where
+    S: DeserializeSeed<'de> + Copy,
+{
+    type Value = Vec<S::Value>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, S> Visitor<'de> for SeqDeserializer<S>
+where
+    S: DeserializeSeed<'de> + Copy,
+{
+    type Value = Vec<S::Value>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("sequence")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = Vec::new();
+        while let Some(value) = seq.next_element_seed(self.0)? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
         ron,
-        serde::{SceneDeserializer, SceneSerializer},
-        DynamicScene, DynamicSceneBuilder,
+        serde::{SceneDeserializer, SceneDiffDeserializer, SceneDiffSerializer, SceneSerializer},
+        DynamicScene, DynamicSceneBuilder, SceneDiff,
     };
     use bevy_ecs::{
         entity::{Entity, EntityHashMap},
@@ -521,7 +1209,7 @@ mod tests {
         reflect::AppTypeRegistry,
         world::FromWorld,
     };
-    use bevy_reflect::{Reflect, ReflectDeserialize, ReflectSerialize};
+    use bevy_reflect::{reflect_trait, Reflect, ReflectBox, ReflectDeserialize, ReflectSerialize};
     use serde::{de::DeserializeSeed, Deserialize, Serialize};
     use std::io::BufReader;
 
@@ -593,6 +1281,30 @@ mod tests {
         }
     }
 
+    #[reflect_trait(boxed)]
+    trait Behaviour: Reflect {
+        fn speed(&self) -> f32;
+    }
+
+    #[derive(Reflect)]
+    #[reflect(Behaviour)]
+    struct Wander {
+        speed: f32,
+    }
+
+    impl Behaviour for Wander {
+        fn speed(&self) -> f32 {
+            self.speed
+        }
+    }
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct Brain {
+        #[reflect(remote = ReflectBox<dyn Behaviour>)]
+        behaviour: Box<dyn Behaviour>,
+    }
+
     fn create_world() -> World {
         let mut world = World::new();
         let registry = AppTypeRegistry::default();
@@ -611,6 +1323,8 @@ mod tests {
             registry.register::<MyEntityRef>();
             registry.register::<Entity>();
             registry.register::<MyResource>();
+            registry.register::<Brain>();
+            registry.register::<Wander>();
         }
         world.insert_resource(registry);
         world
@@ -696,9 +1410,8 @@ mod tests {
   },
 )"#;
         let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
-        let scene_deserializer = SceneDeserializer {
-            type_registry: &world.resource::<AppTypeRegistry>().read(),
-        };
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        let scene_deserializer = SceneDeserializer::new(&type_registry);
         let scene = scene_deserializer.deserialize(&mut deserializer).unwrap();
 
         assert_eq!(
@@ -731,9 +1444,7 @@ mod tests {
         let registry = world.resource::<AppTypeRegistry>().read();
         let serialized = scene.serialize(&registry).unwrap();
         let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
-        let scene_deserializer = SceneDeserializer {
-            type_registry: &registry,
-        };
+        let scene_deserializer = SceneDeserializer::new(&registry);
         let deserialized_scene = scene_deserializer.deserialize(&mut deserializer).unwrap();
         (scene, deserialized_scene)
     }
@@ -795,6 +1506,27 @@ mod tests {
         assert_eq!(&qux, world.query::<&Qux>().single(&world).unwrap());
     }
 
+    #[test]
+    fn should_roundtrip_boxed_trait_objects() {
+        let mut world = create_world();
+        world.spawn(Brain {
+            behaviour: Box::new(Wander { speed: 2.5 }),
+        });
+
+        let (scene, deserialized_scene) = roundtrip_ron(&world);
+
+        assert_eq!(1, deserialized_scene.entities.len());
+        assert_scene_eq(&scene, &deserialized_scene);
+
+        let mut world = create_world();
+        deserialized_scene
+            .write_to_world(&mut world, &mut EntityHashMap::default())
+            .unwrap();
+        let brain = world.query::<&Brain>().single(&world).unwrap();
+        assert!(brain.behaviour.as_reflect().is::<Wander>());
+        assert_eq!(2.5, brain.behaviour.speed());
+    }
+
     #[test]
     fn should_roundtrip_postcard() {
         let mut world = create_world();
@@ -823,9 +1555,7 @@ mod tests {
             serialized_scene
         );
 
-        let scene_deserializer = SceneDeserializer {
-            type_registry: registry,
-        };
+        let scene_deserializer = SceneDeserializer::new(registry);
         let deserialized_scene = scene_deserializer
             .deserialize(&mut postcard::Deserializer::from_bytes(&serialized_scene))
             .unwrap();
@@ -865,9 +1595,7 @@ mod tests {
             buf
         );
 
-        let scene_deserializer = SceneDeserializer {
-            type_registry: registry,
-        };
+        let scene_deserializer = SceneDeserializer::new(registry);
         let mut reader = BufReader::new(buf.as_slice());
 
         let deserialized_scene = scene_deserializer
@@ -910,9 +1638,7 @@ mod tests {
             serialized_scene
         );
 
-        let scene_deserializer = SceneDeserializer {
-            type_registry: registry,
-        };
+        let scene_deserializer = SceneDeserializer::new(registry);
 
         let (deserialized_scene, _read_bytes) =
             bincode::serde::seed_decode_from_slice(scene_deserializer, &serialized_scene, config)
@@ -923,6 +1649,51 @@ mod tests {
     }
 
     /// A crude equality checker for [`DynamicScene`], used solely for testing purposes.
+    #[test]
+    fn should_roundtrip_scene_diff() {
+        let mut world = create_world();
+
+        let a = world
+            .spawn(MyComponent {
+                foo: [1, 2, 3],
+                bar: (1.3, 3.7),
+                baz: MyEnum::Unit,
+            })
+            .id();
+        let b = world.spawn(Foo(123)).id();
+        let mut from = DynamicScene::from_world(&world);
+
+        world.get_mut::<MyComponent>(a).unwrap().bar.1 = 4.2;
+        world.entity_mut(b).remove::<Foo>().insert(Bar(345));
+        world.insert_resource(MyResource::default());
+        let to = DynamicScene::from_world(&world);
+
+        let registry = world.resource::<AppTypeRegistry>();
+        let registry = &registry.read();
+
+        let diff = SceneDiff::new(&from, &to);
+        let serialized_diff =
+            ron::ser::to_string(&SceneDiffSerializer::new(&diff, registry)).unwrap();
+        let diff_deserializer = SceneDiffDeserializer {
+            type_registry: registry,
+        };
+        let deserialized_diff = diff_deserializer
+            .deserialize(&mut ron::de::Deserializer::from_str(&serialized_diff).unwrap())
+            .unwrap();
+        assert!(serialized_diff.contains(r#"path:".bar.1""#));
+
+        let serialized_diff =
+            postcard::to_allocvec(&SceneDiffSerializer::new(&deserialized_diff, registry)).unwrap();
+        let deserialized_diff = diff_deserializer
+            .deserialize(&mut postcard::Deserializer::from_bytes(&serialized_diff))
+            .unwrap();
+
+        deserialized_diff
+            .apply_to_scene(&mut from, registry)
+            .unwrap();
+        assert!(SceneDiff::new(&from, &to).is_empty());
+    }
+
     fn assert_scene_eq(expected: &DynamicScene, received: &DynamicScene) {
         assert_eq!(
             expected.entities.len(),
@@ -12,13 +12,24 @@ impl Prepare for TestCommand {
         let no_fail_fast = args.keep_going();
         let jobs = args.build_jobs();
         let test_threads = args.test_threads();
+        let jobs_ref = jobs.as_ref();
+        let test_threads_ref = test_threads.as_ref();
 
         vec![PreparedCommand::new::<Self>(
             cmd!(
                 sh,
                 // `--benches` runs each benchmark once in order to verify that they behave
                 // correctly and do not panic.
-                "cargo test --workspace --lib --bins --tests --benches {no_fail_fast...} {jobs...} -- {test_threads...}"
+                "cargo test --workspace --lib --bins --tests --benches {no_fail_fast...} {jobs_ref...} -- {test_threads_ref...}"
+            ),
+            "Please fix failing tests in output above.",
+        ),
+        PreparedCommand::new::<Self>(
+            cmd!(
+                sh,
+                // Stepping changes how schedules run, so it needs its own pass.
+                // `bevy_utils/debug` is required by the stepping tests.
+                "cargo test -p bevy_ecs --lib --features bevy_debug_stepping,bevy_utils/debug {no_fail_fast...} {jobs_ref...} -- {test_threads_ref...}"
             ),
             "Please fix failing tests in output above.",
         )]
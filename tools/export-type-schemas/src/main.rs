@@ -0,0 +1,61 @@
+//! Tool that exports the components and resources registered by Bevy's default plugins
+//! as JSON Schema and TypeScript definitions, for tools working with the data of Bevy apps.
+
+#![expect(clippy::print_stdout, reason = "Allowed in tools.")]
+
+use std::{fs, path::PathBuf};
+
+use bevy::{
+    prelude::*,
+    remote::schemas::{export::TypeExport, SchemaTypesMetadata},
+    render::{settings::WgpuSettings, RenderPlugin},
+};
+use clap::Parser;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(short, long, default_value = ".")]
+    /// Directory to write `types.schema.json` and `types.d.ts` into
+    out_dir: PathBuf,
+}
+
+fn main() {
+    let args = Args::parse();
+
+    // The plugins are built without running the app and without a renderer,
+    // as only the types they register are needed.
+    let mut app = App::new();
+    app.add_plugins(
+        DefaultPlugins.set(RenderPlugin {
+            render_creation: WgpuSettings {
+                backends: None,
+                ..default()
+            }
+            .into(),
+            ..default()
+        }),
+    );
+    app.finish();
+    app.cleanup();
+
+    let registry = app.world().resource::<AppTypeRegistry>().read();
+    let metadata = SchemaTypesMetadata::default();
+    let export = TypeExport::components_and_resources(&registry, &metadata);
+
+    fs::create_dir_all(&args.out_dir).expect("failed to create the output directory");
+
+    let schema_path = args.out_dir.join("types.schema.json");
+    let schema = serde_json::to_string_pretty(&export.json_schema()).unwrap();
+    fs::write(&schema_path, schema).expect("failed to write the JSON Schema");
+
+    let typescript_path = args.out_dir.join("types.d.ts");
+    fs::write(&typescript_path, export.typescript())
+        .expect("failed to write the TypeScript definitions");
+
+    println!(
+        "Exported {} types to {} and {}",
+        export.types().len(),
+        schema_path.display(),
+        typescript_path.display()
+    );
+}